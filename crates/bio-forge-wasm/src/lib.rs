@@ -4,15 +4,17 @@
 //! enabling molecular structure manipulation directly in the browser or other WASM-capable environments.
 
 use bio_forge::io::{
-    IoContext, read_mmcif_structure, read_mol2_template, read_pdb_structure, write_mmcif_structure,
-    write_mmcif_topology, write_pdb_structure, write_pdb_topology,
+    IoContext, PdbWriteConfig, WriteOptions, read_mmcif_structure, read_mol2_template,
+    read_pdb_structure, read_structure_from_bytes, write_mmcif_structure, write_mmcif_topology,
+    write_pdb_structure, write_pdb_topology,
 };
 use bio_forge::ops::{
     Anion as CoreAnion, Cation as CoreCation, CleanConfig as CoreCleanConfig,
     HisStrategy as CoreHisStrategy, HydroConfig as CoreHydroConfig,
     SolvateConfig as CoreSolvateConfig, TopologyBuilder, Transform as CoreTransform,
-    add_hydrogens as core_add_hydrogens, clean_structure as core_clean_structure,
-    repair_structure as core_repair_structure, solvate_structure as core_solvate_structure,
+    WaterOrientation as CoreWaterOrientation, add_hydrogens as core_add_hydrogens,
+    clean_structure as core_clean_structure, repair_structure as core_repair_structure,
+    solvate_structure as core_solvate_structure,
 };
 use bio_forge::{
     Chain as CoreChain, ResidueCategory, StandardResidue, Structure as CoreStructure,
@@ -58,6 +60,10 @@ pub struct CleanConfig {
     /// Remove water molecules (HOH). Default: `false`
     #[serde(default)]
     pub remove_water: bool,
+    /// Keep waters within this distance (Å) of non-water heavy atoms when removing water.
+    /// Default: `undefined` (remove all)
+    #[serde(default)]
+    pub keep_water_within: Option<f64>,
     /// Remove ion residues. Default: `false`
     #[serde(default)]
     pub remove_ions: bool,
@@ -67,6 +73,9 @@ pub struct CleanConfig {
     /// Remove hetero residues (ligands). Default: `false`
     #[serde(default)]
     pub remove_hetero: bool,
+    /// Remove empty residues and residues missing backbone atoms. Default: `false`
+    #[serde(default)]
+    pub remove_incomplete_residues: bool,
     /// Specific residue names to remove. Default: `[]`
     #[serde(default)]
     pub remove_residue_names: Vec<String>,
@@ -79,9 +88,11 @@ impl From<CleanConfig> for CoreCleanConfig {
     fn from(cfg: CleanConfig) -> Self {
         CoreCleanConfig {
             remove_water: cfg.remove_water,
+            keep_water_within: cfg.keep_water_within,
             remove_ions: cfg.remove_ions,
             remove_hydrogens: cfg.remove_hydrogens,
             remove_hetero: cfg.remove_hetero,
+            remove_incomplete_residues: cfg.remove_incomplete_residues,
             remove_residue_names: cfg.remove_residue_names.into_iter().collect(),
             keep_residue_names: cfg.keep_residue_names.into_iter().collect(),
         }
@@ -141,9 +152,10 @@ impl From<HydroConfig> for CoreHydroConfig {
         };
         CoreHydroConfig {
             target_ph: cfg.target_ph,
-            remove_existing_h: cfg.remove_existing_h,
+            replace_existing: cfg.remove_existing_h,
             his_strategy,
             his_salt_bridge_protonation: cfg.his_salt_bridge_protonation,
+            check_clashes: false,
         }
     }
 }
@@ -185,6 +197,10 @@ pub struct SolvateConfig {
     /// Random seed for reproducible placement.
     #[serde(default)]
     pub rng_seed: Option<u64>,
+    /// Water hydrogen orientation: `"fixed"`, `"random"`, `"hbond"`. Default: `"random"`
+    #[tsify(type = "\"fixed\" | \"random\" | \"hbond\"")]
+    #[serde(default = "default_orient_waters")]
+    pub orient_waters: String,
 }
 
 fn default_margin() -> f64 {
@@ -202,6 +218,9 @@ fn default_cations() -> Vec<String> {
 fn default_anions() -> Vec<String> {
     vec!["Cl".to_string()]
 }
+fn default_orient_waters() -> String {
+    "random".to_string()
+}
 
 impl Default for SolvateConfig {
     fn default() -> Self {
@@ -215,6 +234,7 @@ impl Default for SolvateConfig {
             target_charge: 0,
             salt_concentration: None,
             rng_seed: None,
+            orient_waters: default_orient_waters(),
         }
     }
 }
@@ -245,6 +265,11 @@ impl From<SolvateConfig> for CoreSolvateConfig {
                 _ => None,
             })
             .collect();
+        let orient_waters = match cfg.orient_waters.to_lowercase().as_str() {
+            "fixed" => CoreWaterOrientation::Fixed,
+            "hbond" => CoreWaterOrientation::HBondAware,
+            _ => CoreWaterOrientation::Random,
+        };
         CoreSolvateConfig {
             margin: cfg.margin,
             water_spacing: cfg.water_spacing,
@@ -255,6 +280,9 @@ impl From<SolvateConfig> for CoreSolvateConfig {
             target_charge: cfg.target_charge,
             salt_concentration: cfg.salt_concentration,
             rng_seed: cfg.rng_seed,
+            orient_waters,
+            solvent: Default::default(),
+            solute_selection: None,
         }
     }
 }
@@ -433,7 +461,12 @@ impl Topology {
     #[wasm_bindgen(js_name = toPdb)]
     pub fn to_pdb(&self) -> Result<String, JsError> {
         let mut buf = Vec::new();
-        write_pdb_topology(BufWriter::new(&mut buf), &self.inner).map_err(to_js_error)?;
+        write_pdb_topology(
+            BufWriter::new(&mut buf),
+            &self.inner,
+            &PdbWriteConfig::default(),
+        )
+        .map_err(to_js_error)?;
         String::from_utf8(buf).map_err(to_js_error)
     }
 
@@ -441,7 +474,12 @@ impl Topology {
     #[wasm_bindgen(js_name = toPdbBytes)]
     pub fn to_pdb_bytes(&self) -> Result<Vec<u8>, JsError> {
         let mut buf = Vec::new();
-        write_pdb_topology(BufWriter::new(&mut buf), &self.inner).map_err(to_js_error)?;
+        write_pdb_topology(
+            BufWriter::new(&mut buf),
+            &self.inner,
+            &PdbWriteConfig::default(),
+        )
+        .map_err(to_js_error)?;
         Ok(buf)
     }
 
@@ -449,7 +487,12 @@ impl Topology {
     #[wasm_bindgen(js_name = toMmcif)]
     pub fn to_mmcif(&self) -> Result<String, JsError> {
         let mut buf = Vec::new();
-        write_mmcif_topology(BufWriter::new(&mut buf), &self.inner).map_err(to_js_error)?;
+        write_mmcif_topology(
+            BufWriter::new(&mut buf),
+            &self.inner,
+            &WriteOptions::default(),
+        )
+        .map_err(to_js_error)?;
         String::from_utf8(buf).map_err(to_js_error)
     }
 
@@ -457,7 +500,12 @@ impl Topology {
     #[wasm_bindgen(js_name = toMmcifBytes)]
     pub fn to_mmcif_bytes(&self) -> Result<Vec<u8>, JsError> {
         let mut buf = Vec::new();
-        write_mmcif_topology(BufWriter::new(&mut buf), &self.inner).map_err(to_js_error)?;
+        write_mmcif_topology(
+            BufWriter::new(&mut buf),
+            &self.inner,
+            &WriteOptions::default(),
+        )
+        .map_err(to_js_error)?;
         Ok(buf)
     }
 }
@@ -514,6 +562,14 @@ impl Structure {
         Ok(Structure { inner })
     }
 
+    /// Parses PDB or mmCIF bytes, detecting gzip compression and the format from the content.
+    #[wasm_bindgen(js_name = fromBytes)]
+    pub fn from_bytes(content: &[u8]) -> Result<Structure, JsError> {
+        let ctx = IoContext::new_default();
+        let inner = read_structure_from_bytes(content, &ctx).map_err(to_js_error)?;
+        Ok(Structure { inner })
+    }
+
     // -------------------------------------------------------------------------
     // Instance Methods
     // -------------------------------------------------------------------------
@@ -534,7 +590,12 @@ impl Structure {
     #[wasm_bindgen(js_name = toPdb)]
     pub fn to_pdb(&self) -> Result<String, JsError> {
         let mut buf = Vec::new();
-        write_pdb_structure(BufWriter::new(&mut buf), &self.inner).map_err(to_js_error)?;
+        write_pdb_structure(
+            BufWriter::new(&mut buf),
+            &self.inner,
+            &PdbWriteConfig::default(),
+        )
+        .map_err(to_js_error)?;
         String::from_utf8(buf).map_err(to_js_error)
     }
 
@@ -542,7 +603,12 @@ impl Structure {
     #[wasm_bindgen(js_name = toPdbBytes)]
     pub fn to_pdb_bytes(&self) -> Result<Vec<u8>, JsError> {
         let mut buf = Vec::new();
-        write_pdb_structure(BufWriter::new(&mut buf), &self.inner).map_err(to_js_error)?;
+        write_pdb_structure(
+            BufWriter::new(&mut buf),
+            &self.inner,
+            &PdbWriteConfig::default(),
+        )
+        .map_err(to_js_error)?;
         Ok(buf)
     }
 
@@ -550,7 +616,12 @@ impl Structure {
     #[wasm_bindgen(js_name = toMmcif)]
     pub fn to_mmcif(&self) -> Result<String, JsError> {
         let mut buf = Vec::new();
-        write_mmcif_structure(BufWriter::new(&mut buf), &self.inner).map_err(to_js_error)?;
+        write_mmcif_structure(
+            BufWriter::new(&mut buf),
+            &self.inner,
+            &WriteOptions::default(),
+        )
+        .map_err(to_js_error)?;
         String::from_utf8(buf).map_err(to_js_error)
     }
 
@@ -558,7 +629,12 @@ impl Structure {
     #[wasm_bindgen(js_name = toMmcifBytes)]
     pub fn to_mmcif_bytes(&self) -> Result<Vec<u8>, JsError> {
         let mut buf = Vec::new();
-        write_mmcif_structure(BufWriter::new(&mut buf), &self.inner).map_err(to_js_error)?;
+        write_mmcif_structure(
+            BufWriter::new(&mut buf),
+            &self.inner,
+            &WriteOptions::default(),
+        )
+        .map_err(to_js_error)?;
         Ok(buf)
     }
 
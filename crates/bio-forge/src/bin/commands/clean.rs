@@ -12,6 +12,9 @@ pub struct CleanArgs {
     /// Remove crystallographic water residues (HOH).
     #[arg(long)]
     pub water: bool,
+    /// Keep waters within this distance (Å) of non-water heavy atoms when removing water.
+    #[arg(long = "keep-water-within", value_name = "DIST", requires = "water")]
+    pub keep_water_within: Option<f64>,
     /// Remove ions from the structure.
     #[arg(long)]
     pub ions: bool,
@@ -21,6 +24,9 @@ pub struct CleanArgs {
     /// Remove hetero residues.
     #[arg(long)]
     pub hetero: bool,
+    /// Remove empty residues and residues missing backbone atoms.
+    #[arg(long)]
+    pub incomplete: bool,
     /// Residue names to keep regardless of other filters.
     #[arg(long = "keep", value_name = "RES_NAME")]
     pub keep: Vec<String>,
@@ -34,9 +40,11 @@ pub fn run(structure: &mut Structure, args: &CleanArgs) -> Result<()> {
     run_with_spinner("Cleaning structure", || {
         let config = CleanConfig {
             remove_water: args.water,
+            keep_water_within: args.keep_water_within,
             remove_ions: args.ions,
             remove_hydrogens: args.hydrogens,
             remove_hetero: args.hetero,
+            remove_incomplete_residues: args.incomplete,
             keep_residue_names: build_name_set(&args.keep),
             remove_residue_names: build_name_set(&args.remove),
         };
@@ -53,9 +53,10 @@ pub fn run(structure: &mut Structure, args: &HydroArgs) -> Result<()> {
     run_with_spinner("Adding hydrogens", || {
         let config = HydroConfig {
             target_ph: args.ph,
-            remove_existing_h: !args.no_strip,
+            replace_existing: !args.no_strip,
             his_strategy: args.his.into(),
             his_salt_bridge_protonation: !args.no_his_salt_bridge,
+            check_clashes: false,
         };
 
         add_hydrogens(structure, &config).context("Failed to add hydrogens")
@@ -1,7 +1,7 @@
 use std::collections::HashSet;
 use std::fmt;
 use std::fs::File;
-use std::io::{self as stdio, BufRead, BufReader, BufWriter, Write};
+use std::io::{self as stdio, BufWriter, Read, Write};
 use std::path::{Path, PathBuf};
 use std::time::Duration;
 
@@ -11,7 +11,7 @@ use indicatif::{ProgressBar, ProgressStyle};
 use std::io::IsTerminal;
 
 use bio_forge::io::{
-    IoContext, read_mmcif_structure, read_pdb_structure, write_mmcif_structure,
+    self as bf_io, IoContext, PdbWriteConfig, WriteOptions, write_mmcif_structure,
     write_mmcif_topology, write_pdb_structure, write_pdb_topology,
 };
 use bio_forge::templates;
@@ -37,18 +37,26 @@ pub enum StructureFormat {
 }
 
 impl StructureFormat {
-    /// Attempts to infer a format from a file path extension.
+    /// Attempts to infer a format from a file path extension, skipping a trailing `.gz`.
     pub fn from_path(path: &Path) -> Option<Self> {
-        path.extension()
-            .and_then(|ext| ext.to_str())
-            .and_then(Self::from_extension)
+        bf_io::StructureFormat::from_path(path).map(Self::from)
     }
+}
+
+impl From<bf_io::StructureFormat> for StructureFormat {
+    fn from(format: bf_io::StructureFormat) -> Self {
+        match format {
+            bf_io::StructureFormat::Pdb => Self::Pdb,
+            bf_io::StructureFormat::Mmcif => Self::Mmcif,
+        }
+    }
+}
 
-    fn from_extension(ext: &str) -> Option<Self> {
-        match ext.to_ascii_lowercase().as_str() {
-            "pdb" | "ent" => Some(Self::Pdb),
-            "cif" | "mmcif" => Some(Self::Mmcif),
-            _ => None,
+impl From<StructureFormat> for bf_io::StructureFormat {
+    fn from(format: StructureFormat) -> Self {
+        match format {
+            StructureFormat::Pdb => Self::Pdb,
+            StructureFormat::Mmcif => Self::Mmcif,
         }
     }
 }
@@ -79,8 +87,7 @@ pub fn load_input(params: &IoParameters) -> Result<(Structure, StructureFormat)>
     let structure = if let Some(path) = &params.input {
         let file = File::open(path)
             .with_context(|| format!("Failed to open input file {}", path.display()))?;
-        let reader = BufReader::new(file);
-        read_structure(reader, format, &io_context)
+        read_structure(file, format, &io_context)
             .with_context(|| format!("Failed to parse {} input from {}", format, path.display()))?
     } else {
         let stdin = stdio::stdin();
@@ -89,8 +96,7 @@ pub fn load_input(params: &IoParameters) -> Result<(Structure, StructureFormat)>
                 "No --input provided and stdin is a TTY. Provide -i/--input or pipe a structure into bioforge."
             );
         }
-        let reader = BufReader::new(stdin.lock());
-        read_structure(reader, format, &io_context)
+        read_structure(stdin.lock(), format, &io_context)
             .with_context(|| format!("Failed to parse {} input from stdin", format))?
     };
 
@@ -141,15 +147,12 @@ fn resolve_output_format(params: &IoParameters) -> Result<StructureFormat> {
     }
 }
 
-fn read_structure<R: BufRead>(
+fn read_structure<R: Read>(
     reader: R,
     format: StructureFormat,
     ctx: &IoContext,
 ) -> Result<Structure> {
-    match format {
-        StructureFormat::Pdb => read_pdb_structure(reader, ctx).map_err(anyhow::Error::new),
-        StructureFormat::Mmcif => read_mmcif_structure(reader, ctx).map_err(anyhow::Error::new),
-    }
+    bf_io::read_structure(reader, format.into(), ctx).map_err(anyhow::Error::new)
 }
 
 fn write_structure(
@@ -212,11 +215,11 @@ fn write_structure_with_format<W: Write>(
     format: StructureFormat,
 ) -> Result<()> {
     match format {
-        StructureFormat::Pdb => {
-            write_pdb_structure(writer, structure).map_err(anyhow::Error::new)?
-        }
+        StructureFormat::Pdb => write_pdb_structure(writer, structure, &PdbWriteConfig::default())
+            .map_err(anyhow::Error::new)?,
         StructureFormat::Mmcif => {
-            write_mmcif_structure(writer, structure).map_err(anyhow::Error::new)?
+            write_mmcif_structure(writer, structure, &WriteOptions::default())
+                .map_err(anyhow::Error::new)?
         }
     }
     Ok(())
@@ -228,10 +231,10 @@ fn write_topology_with_format<W: Write>(
     format: StructureFormat,
 ) -> Result<()> {
     match format {
-        StructureFormat::Pdb => write_pdb_topology(writer, topology).map_err(anyhow::Error::new)?,
-        StructureFormat::Mmcif => {
-            write_mmcif_topology(writer, topology).map_err(anyhow::Error::new)?
-        }
+        StructureFormat::Pdb => write_pdb_topology(writer, topology, &PdbWriteConfig::default())
+            .map_err(anyhow::Error::new)?,
+        StructureFormat::Mmcif => write_mmcif_topology(writer, topology, &WriteOptions::default())
+            .map_err(anyhow::Error::new)?,
     }
     Ok(())
 }
@@ -2,7 +2,7 @@ use anyhow::{Context, Result, bail};
 use clap::Args;
 
 use bio_forge::Structure;
-use bio_forge::ops::{Anion, Cation, SolvateConfig, solvate_structure};
+use bio_forge::ops::{Anion, Cation, SolvateConfig, WaterOrientation, solvate_structure};
 
 use crate::commands::{estimate_structure_charge, run_with_spinner};
 
@@ -34,9 +34,12 @@ pub struct SolvateArgs {
     /// Desired salt concentration (M)
     #[arg(long = "salt-concentration", value_name = "CONCENTRATION")]
     pub salt_concentration: Option<f64>,
-    /// Random seed used for deterministic ion placement.
+    /// Random seed used for reproducible water orientation and ion placement.
     #[arg(long, value_name = "INT")]
     pub seed: Option<u64>,
+    /// Orientation of added water hydrogens: fixed, random, or hbond.
+    #[arg(long = "orient-waters", value_name = "MODE", default_value = "random")]
+    pub orient_waters: String,
 }
 
 /// Applies the solvation routine with configured padding, ions, and RNG seed.
@@ -49,6 +52,7 @@ pub fn run(structure: &mut Structure, args: &SolvateArgs) -> Result<()> {
             anions: vec![parse_anion(&args.anion)?],
             salt_concentration: args.salt_concentration,
             rng_seed: args.seed,
+            orient_waters: parse_water_orientation(&args.orient_waters)?,
             ..SolvateConfig::default()
         };
 
@@ -89,3 +93,15 @@ fn parse_anion(value: &str) -> Result<Anion> {
         other => bail!("Unsupported anion '{}'. Choose from Cl, Br, I, F.", other),
     }
 }
+
+fn parse_water_orientation(value: &str) -> Result<WaterOrientation> {
+    match value.trim().to_ascii_lowercase().as_str() {
+        "fixed" => Ok(WaterOrientation::Fixed),
+        "random" => Ok(WaterOrientation::Random),
+        "hbond" => Ok(WaterOrientation::HBondAware),
+        other => bail!(
+            "Unsupported water orientation '{}'. Choose from fixed, random, hbond.",
+            other
+        ),
+    }
+}
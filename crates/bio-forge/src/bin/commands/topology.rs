@@ -7,7 +7,7 @@ use anyhow::{Context, Result, bail};
 use clap::Args;
 
 use bio_forge::io::read_mol2_template;
-use bio_forge::ops::TopologyBuilder;
+use bio_forge::ops::{TopologyBuilder, UnknownPolicy};
 use bio_forge::{Structure, Template, Topology};
 
 use crate::commands::run_with_spinner;
@@ -21,16 +21,22 @@ pub struct TopologyArgs {
     /// Additional hetero-residue templates (Tripos MOL2) to satisfy ligands (repeatable).
     #[arg(long = "hetero-template", value_name = "FILE")]
     pub hetero_templates: Vec<PathBuf>,
+    /// Handling of residues without a template: error, skip, or guess.
+    #[arg(long = "on-unknown", value_name = "POLICY", default_value = "error")]
+    pub on_unknown: String,
 }
 
 /// Generates a topology that can be written with CONECT/_struct_conn records.
 pub fn run(structure: Structure, args: &TopologyArgs) -> Result<Topology> {
     let hetero_templates = load_mol2_templates(&args.hetero_templates)?;
     let ss_cutoff = args.ss_cutoff;
+    let unknown_policy = parse_unknown_policy(&args.on_unknown)?;
 
     run_with_spinner("Building topology", move || {
         let builder = hetero_templates.into_iter().fold(
-            TopologyBuilder::new().disulfide_cutoff(ss_cutoff),
+            TopologyBuilder::new()
+                .disulfide_cutoff(ss_cutoff)
+                .on_unknown(unknown_policy),
             |builder, template| builder.add_hetero_template(template),
         );
 
@@ -38,6 +44,18 @@ pub fn run(structure: Structure, args: &TopologyArgs) -> Result<Topology> {
     })
 }
 
+fn parse_unknown_policy(value: &str) -> Result<UnknownPolicy> {
+    match value.trim().to_ascii_lowercase().as_str() {
+        "error" => Ok(UnknownPolicy::Error),
+        "skip" => Ok(UnknownPolicy::Skip),
+        "guess" => Ok(UnknownPolicy::GuessBonds),
+        other => bail!(
+            "Unsupported unknown-residue policy '{}'. Choose from error, skip, guess.",
+            other
+        ),
+    }
+}
+
 fn load_mol2_templates(paths: &[PathBuf]) -> Result<Vec<Template>> {
     let mut templates = Vec::new();
     let mut seen_names = HashSet::new();
@@ -99,6 +99,31 @@ impl<'a> TemplateView<'a> {
         })
     }
 
+    /// Iterates every template atom, heavy atoms first and hydrogens after.
+    ///
+    /// # Returns
+    ///
+    /// An iterator yielding `(name, element, Point)` tuples in declaration order, with
+    /// hydrogens reported as [`Element::H`].
+    pub fn all_atoms(&self) -> impl Iterator<Item = (&'a str, Element, Point)> {
+        let hydrogens = self
+            .inner
+            .schema
+            .hydrogens
+            .iter()
+            .map(|h| (h.name.as_str(), Element::H, Point::from(h.pos)));
+        self.heavy_atoms().chain(hydrogens)
+    }
+
+    /// Counts every template atom, including hydrogens.
+    ///
+    /// # Returns
+    ///
+    /// The number of heavy atoms plus the number of hydrogens.
+    pub fn atom_count(&self) -> usize {
+        self.inner.schema.atoms.len() + self.inner.schema.hydrogens.len()
+    }
+
     /// Iterates bonds as name pairs plus their bond order.
     ///
     /// # Returns
@@ -111,6 +136,51 @@ impl<'a> TemplateView<'a> {
             .iter()
             .map(|b| (b.a1.as_str(), b.a2.as_str(), b.order))
     }
+
+    /// Looks up the partial charge recorded for a heavy atom or hydrogen.
+    ///
+    /// # Arguments
+    ///
+    /// * `atom_name` - Atom name as declared in the template.
+    ///
+    /// # Returns
+    ///
+    /// `Some(charge)` in electrons when the template defines one, otherwise `None`.
+    pub fn partial_charge(&self, atom_name: &str) -> Option<f64> {
+        let schema = &self.inner.schema;
+        schema
+            .atoms
+            .iter()
+            .find(|a| a.name == atom_name)
+            .map(|a| a.partial_charge)
+            .or_else(|| {
+                schema
+                    .hydrogens
+                    .iter()
+                    .find(|h| h.name == atom_name)
+                    .map(|h| h.partial_charge)
+            })
+            .flatten()
+    }
+
+    /// Iterates every atom that carries a partial charge.
+    ///
+    /// # Returns
+    ///
+    /// An iterator over `(name, charge)` pairs, heavy atoms first, then hydrogens.
+    pub fn partial_charges(&self) -> impl Iterator<Item = (&'a str, f64)> {
+        let schema = &self.inner.schema;
+        schema
+            .atoms
+            .iter()
+            .filter_map(|a| a.partial_charge.map(|q| (a.name.as_str(), q)))
+            .chain(
+                schema
+                    .hydrogens
+                    .iter()
+                    .filter_map(|h| h.partial_charge.map(|q| (h.name.as_str(), q))),
+            )
+    }
 }
 
 #[cfg(test)]
@@ -145,11 +215,13 @@ mod tests {
                 name: "CA".to_string(),
                 element: Element::C,
                 pos: [0.0, 0.0, 0.0],
+                partial_charge: None,
             },
             schema::TemplateHeavyAtom {
                 name: "CB".to_string(),
                 element: Element::C,
                 pos: [1.0, 0.0, 0.0],
+                partial_charge: None,
             },
         ];
 
@@ -157,6 +229,7 @@ mod tests {
             name: "HA".to_string(),
             pos: [0.5, 1.0, 0.0],
             anchors: vec!["CA".to_string()],
+            partial_charge: None,
         }];
 
         let bonds = vec![schema::TemplateBond {
@@ -210,6 +283,7 @@ mod tests {
             name: "N".to_string(),
             element: Element::N,
             pos: [0.0, 0.0, 0.0],
+            partial_charge: None,
         }];
         let mock_template =
             create_mock_template("LYS", StandardResidue::LYS, 1, atoms, vec![], vec![]);
@@ -261,12 +335,27 @@ mod tests {
         assert_eq!(*pos, Point::new(0.5, 1.0, 0.0));
     }
 
+    #[test]
+    fn template_view_all_atoms_yields_heavy_atoms_then_hydrogens() {
+        let mock_template = create_simple_mock_template();
+        let view = TemplateView::new(&mock_template);
+
+        let atoms: Vec<_> = view.all_atoms().collect();
+
+        assert_eq!(view.atom_count(), 3);
+        assert_eq!(atoms.len(), view.atom_count());
+        assert_eq!(atoms[0], ("CA", Element::C, Point::new(0.0, 0.0, 0.0)));
+        assert_eq!(atoms[1], ("CB", Element::C, Point::new(1.0, 0.0, 0.0)));
+        assert_eq!(atoms[2], ("HA", Element::H, Point::new(0.5, 1.0, 0.0)));
+    }
+
     #[test]
     fn template_view_hydrogens_returns_empty_iterator_for_template_without_hydrogens() {
         let atoms = vec![schema::TemplateHeavyAtom {
             name: "CA".to_string(),
             element: Element::C,
             pos: [0.0, 0.0, 0.0],
+            partial_charge: None,
         }];
         let mock_template =
             create_mock_template("NO_H", StandardResidue::GLY, 0, atoms, vec![], vec![]);
@@ -284,11 +373,13 @@ mod tests {
                 name: "CA".to_string(),
                 element: Element::C,
                 pos: [0.0, 0.0, 0.0],
+                partial_charge: None,
             },
             schema::TemplateHeavyAtom {
                 name: "CB".to_string(),
                 element: Element::C,
                 pos: [1.0, 0.0, 0.0],
+                partial_charge: None,
             },
         ];
 
@@ -296,6 +387,7 @@ mod tests {
             name: "HA".to_string(),
             pos: [0.5, 1.0, 0.0],
             anchors: vec!["CA".to_string(), "CB".to_string()],
+            partial_charge: None,
         }];
 
         let mock_template = create_mock_template(
@@ -337,6 +429,7 @@ mod tests {
             name: "CA".to_string(),
             element: Element::C,
             pos: [0.0, 0.0, 0.0],
+            partial_charge: None,
         }];
         let mock_template =
             create_mock_template("NO_BONDS", StandardResidue::GLY, 0, atoms, vec![], vec![]);
@@ -354,16 +447,19 @@ mod tests {
                 name: "C1".to_string(),
                 element: Element::C,
                 pos: [0.0, 0.0, 0.0],
+                partial_charge: None,
             },
             schema::TemplateHeavyAtom {
                 name: "C2".to_string(),
                 element: Element::C,
                 pos: [1.0, 0.0, 0.0],
+                partial_charge: None,
             },
             schema::TemplateHeavyAtom {
                 name: "N1".to_string(),
                 element: Element::N,
                 pos: [2.0, 0.0, 0.0],
+                partial_charge: None,
             },
         ];
 
@@ -404,11 +500,13 @@ mod tests {
                 name: "C1".to_string(),
                 element: Element::C,
                 pos: [0.0, 0.0, 0.0],
+                partial_charge: None,
             },
             schema::TemplateHeavyAtom {
                 name: "C2".to_string(),
                 element: Element::C,
                 pos: [1.0, 0.0, 0.0],
+                partial_charge: None,
             },
         ];
 
@@ -506,4 +604,40 @@ mod tests {
             assert!(!hydrogens.is_empty());
         }
     }
+
+    #[test]
+    fn template_view_partial_charge_resolves_heavy_atoms_and_hydrogens() {
+        let atoms = vec![
+            schema::TemplateHeavyAtom {
+                name: "O".to_string(),
+                element: Element::O,
+                pos: [0.0, 0.0, 0.0],
+                partial_charge: Some(-0.8),
+            },
+            schema::TemplateHeavyAtom {
+                name: "C".to_string(),
+                element: Element::C,
+                pos: [1.0, 0.0, 0.0],
+                partial_charge: None,
+            },
+        ];
+        let hydrogens = vec![schema::TemplateHydrogen {
+            name: "H".to_string(),
+            pos: [0.0, 1.0, 0.0],
+            anchors: vec!["O".to_string()],
+            partial_charge: Some(0.4),
+        }];
+        let mock_template =
+            create_mock_template("TEST", StandardResidue::ALA, 0, atoms, hydrogens, vec![]);
+        let view = TemplateView::new(&mock_template);
+
+        assert_eq!(view.partial_charge("O"), Some(-0.8));
+        assert_eq!(view.partial_charge("H"), Some(0.4));
+        assert_eq!(view.partial_charge("C"), None);
+        assert_eq!(view.partial_charge("X"), None);
+        assert_eq!(
+            view.partial_charges().collect::<Vec<_>>(),
+            vec![("O", -0.8), ("H", 0.4)]
+        );
+    }
 }
@@ -46,6 +46,9 @@ pub struct TemplateHeavyAtom {
     pub element: Element,
     /// Reference coordinates (Å) used when seeding structures.
     pub pos: [f64; 3],
+    /// Optional force-field partial charge (e).
+    #[serde(default)]
+    pub partial_charge: Option<f64>,
 }
 
 /// Hydrogen definition with anchor metadata for idealized placement.
@@ -58,6 +61,9 @@ pub struct TemplateHydrogen {
     pub pos: [f64; 3],
     /// List of heavy-atom anchors that determine bonding context.
     pub anchors: Vec<String>,
+    /// Optional force-field partial charge (e).
+    #[serde(default)]
+    pub partial_charge: Option<f64>,
 }
 
 /// Bond between two named atoms and its order.
@@ -0,0 +1,74 @@
+//! Crate-wide error type unifying the IO, operations, and builder layers.
+//!
+//! Each layer keeps its own focused error enum. [`Error`] wraps them behind `From`
+//! conversions so applications can chain reading, processing, and writing with `?` while
+//! still matching on the originating layer or walking the `source()` chain.
+
+use crate::io;
+use crate::model::builder::BuildError;
+use crate::ops;
+use thiserror::Error;
+
+/// Any error produced by `bio-forge`.
+///
+/// The wrapped error keeps its own `Display` text and source chain, so the I/O cause of an
+/// [`io::Error::Io`] failure stays reachable through [`std::error::Error::source`].
+#[derive(Debug, Error)]
+#[non_exhaustive]
+pub enum Error {
+    /// Failure while reading or writing a file format.
+    #[error(transparent)]
+    Io(#[from] io::Error),
+
+    /// Failure while cleaning, repairing, or otherwise processing a structure.
+    #[error(transparent)]
+    Ops(#[from] ops::Error),
+
+    /// Failure while assembling a structure with [`crate::StructureBuilder`].
+    #[error(transparent)]
+    Build(#[from] BuildError),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::error::Error as _;
+
+    fn pipeline(fail_in_ops: bool) -> Result<(), Error> {
+        if fail_in_ops {
+            Err(ops::Error::BoxTooSmall)?;
+        }
+        Err(io::Error::from_io(
+            std::io::Error::new(std::io::ErrorKind::NotFound, "missing"),
+            None,
+        ))?;
+        Ok(())
+    }
+
+    #[test]
+    fn errors_from_each_layer_convert_with_question_mark() {
+        assert!(matches!(
+            pipeline(true),
+            Err(Error::Ops(ops::Error::BoxTooSmall))
+        ));
+        assert!(matches!(
+            pipeline(false),
+            Err(Error::Io(io::Error::Io { .. }))
+        ));
+
+        let build: Error = BuildError::NoActiveChain.into();
+        assert_eq!(build.to_string(), BuildError::NoActiveChain.to_string());
+    }
+
+    #[test]
+    fn io_source_is_reachable_through_the_chain() {
+        let err = pipeline(false).unwrap_err();
+
+        let io_err = err
+            .source()
+            .and_then(|s| s.downcast_ref::<std::io::Error>())
+            .expect("underlying io::Error");
+        assert_eq!(io_err.kind(), std::io::ErrorKind::NotFound);
+        assert!(err.to_string().contains("missing"));
+    }
+}
@@ -3,7 +3,8 @@
 //! This module exposes [`IoContext`], a registry-backed helper that maps thousands of PDB
 //! aliases to canonical residue codes and links those codes to [`StandardResidue`] values.
 //! File parsers call it to ensure consistent downstream handling of polymer types while
-//! exporters reuse it to apply user-provided aliases.
+//! exporters reuse it to apply user-provided aliases. [`ReadOptions`] carries the
+//! format-independent filters the readers apply while parsing.
 
 use crate::model::types::StandardResidue;
 use std::collections::HashMap;
@@ -368,6 +369,36 @@ impl Default for IoContext {
     }
 }
 
+/// Parse-time filters shared by the PDB and mmCIF readers.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ReadOptions {
+    /// Whether hydrogen atoms present in the file are kept; defaults to `true`.
+    ///
+    /// Disabling this drops them while parsing, which is cheaper than loading them and
+    /// stripping them afterwards via
+    /// [`CleanConfig::remove_hydrogens`](crate::ops::CleanConfig::remove_hydrogens).
+    /// Residue classification still sees the full atom list.
+    pub keep_hydrogens: bool,
+    /// Whether every alternate conformer is kept; defaults to `false`.
+    ///
+    /// By default only the highest-occupancy record of each atom name survives and its
+    /// [`Atom::alt_loc`](crate::Atom::alt_loc) is left unset. Enabling this keeps all
+    /// conformers, each labeled with its alternate-location identifier, so that
+    /// [`TopologyBuilder::altloc`](crate::ops::TopologyBuilder::altloc) can choose between
+    /// them.
+    pub keep_alt_locs: bool,
+}
+
+impl Default for ReadOptions {
+    /// Keeps every authored atom, collapsing alternate locations to the best-occupied one.
+    fn default() -> Self {
+        Self {
+            keep_hydrogens: true,
+            keep_alt_locs: false,
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -13,6 +13,7 @@ use thiserror::Error;
 /// The enum captures I/O failures, structured parser issues, unknown residues, and
 /// integrity mismatches so callers can inspect the variant and react accordingly.
 #[derive(Debug, Error)]
+#[non_exhaustive]
 pub enum Error {
     /// Wrapper around operating-system level I/O failures.
     ///
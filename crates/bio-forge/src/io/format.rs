@@ -0,0 +1,433 @@
+//! Format-agnostic structure reading with transparent gzip decompression.
+//!
+//! [`read_structure`] dispatches to the PDB or mmCIF reader for any [`Read`] source, and
+//! [`read_structure_from_path`] additionally infers the format from the file extension.
+//! [`read_structure_with_options`] adds parse-time filters such as dropping authored
+//! hydrogens, and [`read_structure_from_bytes`] detects the format from the content itself
+//! for callers without file names, such as browser hosts. All of them sniff the gzip magic
+//! bytes at the start of the stream and decompress on the fly when the `gzip` feature is
+//! enabled, regardless of the file name.
+
+use super::context::{IoContext, ReadOptions};
+use super::error::Error;
+use super::{mmcif, pdb};
+use crate::model::structure::Structure;
+use std::fs::File;
+use std::io::{BufRead, BufReader, Read};
+use std::path::Path;
+
+/// Leading bytes identifying a gzip stream.
+const GZIP_MAGIC: [u8; 2] = [0x1f, 0x8b];
+
+/// Text formats understood by [`read_structure`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StructureFormat {
+    /// Fixed-column PDB format.
+    Pdb,
+    /// Macromolecular CIF format.
+    Mmcif,
+}
+
+impl StructureFormat {
+    /// Infers the format from a file path extension.
+    ///
+    /// A trailing `.gz` is skipped, so `1abc.cif.gz` resolves to mmCIF.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - File path whose extension is inspected.
+    ///
+    /// # Returns
+    ///
+    /// `Some(format)` for `pdb`/`ent` and `cif`/`mmcif` extensions, otherwise `None`.
+    pub fn from_path(path: &Path) -> Option<Self> {
+        let ext = path.extension()?.to_str()?;
+        if ext.eq_ignore_ascii_case("gz") {
+            return Self::from_path(Path::new(path.file_stem()?));
+        }
+        match ext.to_ascii_lowercase().as_str() {
+            "pdb" | "ent" => Some(Self::Pdb),
+            "cif" | "mmcif" => Some(Self::Mmcif),
+            _ => None,
+        }
+    }
+
+    /// Detects the format from the leading records of (decompressed) structure text.
+    ///
+    /// Lines are scanned in order and the first decisive one wins: `data_`, `loop_`, or an
+    /// `_atom_site` item indicates mmCIF, while `HEADER`, `CRYST1`, `ATOM`, or `HETATM`
+    /// records indicate PDB. Because mmCIF files open with a `data_` block, their `ATOM`
+    /// rows are never reached.
+    ///
+    /// # Arguments
+    ///
+    /// * `content` - Structure text as raw bytes.
+    ///
+    /// # Returns
+    ///
+    /// `Some(format)` when a decisive line is found, otherwise `None`.
+    pub fn detect(content: &[u8]) -> Option<Self> {
+        const MMCIF_MARKERS: [&[u8]; 3] = [b"data_", b"loop_", b"_atom_site"];
+        const PDB_MARKERS: [&[u8]; 4] = [b"HEADER", b"CRYST1", b"ATOM", b"HETATM"];
+
+        content.split(|&b| b == b'\n').find_map(|line| {
+            let line = line.trim_ascii_start();
+            if MMCIF_MARKERS.iter().any(|m| line.starts_with(m)) {
+                Some(Self::Mmcif)
+            } else if PDB_MARKERS.iter().any(|m| line.starts_with(m)) {
+                Some(Self::Pdb)
+            } else {
+                None
+            }
+        })
+    }
+
+    /// Returns the human-readable format name.
+    ///
+    /// # Returns
+    ///
+    /// `"PDB"` or `"mmCIF"`.
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::Pdb => "PDB",
+            Self::Mmcif => "mmCIF",
+        }
+    }
+}
+
+/// Reads a structure from any byte source, decompressing gzip input transparently.
+///
+/// # Arguments
+///
+/// * `reader` - Source of PDB or mmCIF text, optionally gzip-compressed.
+/// * `format` - Format of the (decompressed) text.
+/// * `context` - Residue alias registry used during parsing.
+///
+/// # Returns
+///
+/// The parsed [`Structure`].
+///
+/// # Errors
+///
+/// Returns [`Error::Io`] when the stream cannot be read or decompressed,
+/// [`Error::InconsistentData`] when gzip input is seen without the `gzip` feature, and any
+/// error raised by the format-specific reader.
+pub fn read_structure<R: Read>(
+    reader: R,
+    format: StructureFormat,
+    context: &IoContext,
+) -> Result<Structure, Error> {
+    read_structure_with_options(reader, format, context, &ReadOptions::default())
+}
+
+/// Reads a structure from any byte source, applying parse-time [`ReadOptions`].
+///
+/// Behaves like [`read_structure`], including transparent gzip decompression.
+///
+/// # Arguments
+///
+/// * `reader` - Source of PDB or mmCIF text, optionally gzip-compressed.
+/// * `format` - Format of the (decompressed) text.
+/// * `context` - Residue alias registry used during parsing.
+/// * `options` - Filters such as whether authored hydrogens are kept.
+///
+/// # Returns
+///
+/// The parsed [`Structure`].
+///
+/// # Errors
+///
+/// Returns [`Error`] under the same conditions as [`read_structure`].
+pub fn read_structure_with_options<R: Read>(
+    reader: R,
+    format: StructureFormat,
+    context: &IoContext,
+    options: &ReadOptions,
+) -> Result<Structure, Error> {
+    let mut reader = BufReader::new(reader);
+    let is_gzip = reader
+        .fill_buf()
+        .map_err(|e| Error::from_io(e, None))?
+        .starts_with(&GZIP_MAGIC);
+
+    if !is_gzip {
+        return parse(reader, format, context, options);
+    }
+
+    #[cfg(feature = "gzip")]
+    {
+        let decoder = flate2::bufread::MultiGzDecoder::new(reader);
+        parse(BufReader::new(decoder), format, context, options)
+    }
+    #[cfg(not(feature = "gzip"))]
+    {
+        Err(Error::inconsistent_data(
+            format.name(),
+            None,
+            "input is gzip-compressed but the `gzip` feature is disabled",
+        ))
+    }
+}
+
+/// Reads a structure file, inferring its format from the extension.
+///
+/// Gzip-compressed files are detected by content, so both `model.pdb.gz` and a compressed
+/// file named `model.pdb` are accepted.
+///
+/// # Arguments
+///
+/// * `path` - File to read.
+/// * `context` - Residue alias registry used during parsing.
+///
+/// # Returns
+///
+/// The parsed [`Structure`].
+///
+/// # Errors
+///
+/// Returns [`Error::InconsistentData`] when the extension names no known format,
+/// [`Error::Io`] when the file cannot be opened or read, and any error raised by
+/// [`read_structure`].
+pub fn read_structure_from_path(
+    path: impl AsRef<Path>,
+    context: &IoContext,
+) -> Result<Structure, Error> {
+    let path = path.as_ref();
+    let format = StructureFormat::from_path(path).ok_or_else(|| {
+        Error::inconsistent_data(
+            "structure",
+            Some(path.to_path_buf()),
+            "unable to infer the format from the file extension",
+        )
+    })?;
+    let file = File::open(path).map_err(|e| Error::from_io(e, Some(path.to_path_buf())))?;
+
+    read_structure(file, format, context)
+}
+
+/// Reads a structure from an in-memory buffer, detecting compression and format.
+///
+/// Gzip input is decompressed first; the format is then chosen by
+/// [`StructureFormat::detect`], so neither a file name nor an extension is needed.
+///
+/// # Arguments
+///
+/// * `bytes` - PDB or mmCIF content, optionally gzip-compressed.
+/// * `context` - Residue alias registry used during parsing.
+///
+/// # Returns
+///
+/// The parsed [`Structure`].
+///
+/// # Errors
+///
+/// Returns [`Error::InconsistentData`] when the format cannot be detected or gzip input is
+/// seen without the `gzip` feature, [`Error::Io`] when decompression fails, and any error
+/// raised by the format-specific reader.
+pub fn read_structure_from_bytes(bytes: &[u8], context: &IoContext) -> Result<Structure, Error> {
+    if bytes.starts_with(&GZIP_MAGIC) {
+        #[cfg(feature = "gzip")]
+        {
+            let mut decompressed = Vec::new();
+            flate2::bufread::MultiGzDecoder::new(bytes)
+                .read_to_end(&mut decompressed)
+                .map_err(|e| Error::from_io(e, None))?;
+            return read_detected(&decompressed, context);
+        }
+        #[cfg(not(feature = "gzip"))]
+        {
+            return Err(Error::inconsistent_data(
+                "structure",
+                None,
+                "input is gzip-compressed but the `gzip` feature is disabled",
+            ));
+        }
+    }
+
+    read_detected(bytes, context)
+}
+
+/// Parses uncompressed text with the reader matching its detected format.
+fn read_detected(text: &[u8], context: &IoContext) -> Result<Structure, Error> {
+    let format = StructureFormat::detect(text).ok_or_else(|| {
+        Error::inconsistent_data("structure", None, "unable to detect PDB or mmCIF content")
+    })?;
+
+    parse(text, format, context, &ReadOptions::default())
+}
+
+/// Dispatches buffered text to the reader for `format`.
+fn parse<R: BufRead>(
+    reader: R,
+    format: StructureFormat,
+    context: &IoContext,
+    options: &ReadOptions,
+) -> Result<Structure, Error> {
+    match format {
+        StructureFormat::Pdb => pdb::reader::read_with_options(reader, context, options),
+        StructureFormat::Mmcif => mmcif::reader::read_with_options(reader, context, options),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Cursor;
+
+    const PDB: &str = "\
+ATOM      1  N   GLY A   1       0.000   0.000   0.000  1.00 20.00           N
+ATOM      2  CA  GLY A   1       1.458   0.000   0.000  1.00 20.00           C
+END
+";
+
+    #[test]
+    fn structure_format_from_path_skips_gzip_suffix() {
+        let cases = [
+            ("model.pdb", Some(StructureFormat::Pdb)),
+            ("pdb1abc.ent.gz", Some(StructureFormat::Pdb)),
+            ("1ABC.CIF.GZ", Some(StructureFormat::Mmcif)),
+            ("model.mmcif", Some(StructureFormat::Mmcif)),
+            ("model.gz", None),
+            ("model.xyz", None),
+            ("model", None),
+        ];
+        for (path, expected) in cases {
+            assert_eq!(
+                StructureFormat::from_path(Path::new(path)),
+                expected,
+                "{path}"
+            );
+        }
+    }
+
+    #[test]
+    fn read_structure_parses_plain_text() {
+        let context = IoContext::new_default();
+
+        let structure =
+            read_structure(Cursor::new(PDB), StructureFormat::Pdb, &context).expect("read");
+
+        assert_eq!(structure.atom_count(), 2);
+    }
+
+    #[cfg(feature = "gzip")]
+    fn gzip(text: &str) -> Vec<u8> {
+        use flate2::{Compression, write::GzEncoder};
+        use std::io::Write;
+
+        let mut encoder = GzEncoder::new(Vec::new(), Compression::default());
+        encoder.write_all(text.as_bytes()).unwrap();
+        encoder.finish().unwrap()
+    }
+
+    #[cfg(feature = "gzip")]
+    #[test]
+    fn read_structure_decompresses_gzip_input() {
+        let context = IoContext::new_default();
+
+        let structure = read_structure(Cursor::new(gzip(PDB)), StructureFormat::Pdb, &context)
+            .expect("read gzip");
+
+        assert_eq!(structure.atom_count(), 2);
+    }
+
+    #[cfg(feature = "gzip")]
+    #[test]
+    fn read_structure_from_bytes_decompresses_gzip_input() {
+        let context = IoContext::new_default();
+
+        let structure = read_structure_from_bytes(&gzip(PDB), &context).expect("read gzip");
+
+        assert_eq!(structure.atom_count(), 2);
+    }
+
+    #[cfg(feature = "gzip")]
+    #[test]
+    fn read_structure_from_path_detects_gzip_regardless_of_extension() {
+        let context = IoContext::new_default();
+        let path = std::env::temp_dir().join(format!("bio-forge-{}-gz.pdb", std::process::id()));
+        std::fs::write(&path, gzip(PDB)).unwrap();
+
+        let result = read_structure_from_path(&path, &context);
+        std::fs::remove_file(&path).ok();
+
+        assert_eq!(result.expect("read").atom_count(), 2);
+    }
+
+    #[cfg(not(feature = "gzip"))]
+    #[test]
+    fn read_structure_rejects_gzip_without_feature() {
+        let context = IoContext::new_default();
+        let bytes = [0x1f, 0x8b, 0x08, 0x00];
+
+        let err = read_structure(Cursor::new(bytes), StructureFormat::Pdb, &context)
+            .expect_err("gzip without feature");
+
+        assert!(matches!(err, Error::InconsistentData { .. }));
+    }
+
+    #[test]
+    fn structure_format_detect_inspects_leading_records() {
+        let cases: [(&str, Option<StructureFormat>); 5] = [
+            (PDB, Some(StructureFormat::Pdb)),
+            ("REMARK   1\nCRYST1   10.000\n", Some(StructureFormat::Pdb)),
+            (
+                "data_1ABC\nloop_\n_atom_site.id\nATOM 1 N\n",
+                Some(StructureFormat::Mmcif),
+            ),
+            (
+                "\n  _atom_site.group_PDB ATOM\n",
+                Some(StructureFormat::Mmcif),
+            ),
+            ("REMARK only\nEND\n", None),
+        ];
+        for (text, expected) in cases {
+            assert_eq!(StructureFormat::detect(text.as_bytes()), expected, "{text}");
+        }
+    }
+
+    #[test]
+    fn read_structure_from_bytes_detects_pdb_and_mmcif() {
+        let context = IoContext::new_default();
+        let mmcif = "\
+data_test
+loop_
+_atom_site.group_PDB
+_atom_site.id
+_atom_site.type_symbol
+_atom_site.label_atom_id
+_atom_site.label_comp_id
+_atom_site.label_asym_id
+_atom_site.label_seq_id
+_atom_site.Cartn_x
+_atom_site.Cartn_y
+_atom_site.Cartn_z
+ATOM 1 N N GLY A 1 0.000 0.000 0.000
+#
+";
+
+        let pdb = read_structure_from_bytes(PDB.as_bytes(), &context).expect("pdb");
+        let cif = read_structure_from_bytes(mmcif.as_bytes(), &context).expect("mmcif");
+
+        assert_eq!(pdb.atom_count(), 2);
+        assert_eq!(cif.atom_count(), 1);
+    }
+
+    #[test]
+    fn read_structure_from_bytes_rejects_undetectable_content() {
+        let context = IoContext::new_default();
+
+        let err = read_structure_from_bytes(b"hello\n", &context).expect_err("unknown");
+
+        assert!(matches!(err, Error::InconsistentData { .. }));
+    }
+
+    #[test]
+    fn read_structure_from_path_rejects_unknown_extension() {
+        let context = IoContext::new_default();
+
+        let err = read_structure_from_path("structure.xyz", &context).expect_err("unknown");
+
+        assert!(matches!(err, Error::InconsistentData { .. }));
+    }
+}
@@ -3,18 +3,19 @@
 //! The parser tokenizes loop-based `_atom_site` tables, applies context-specific residue
 //! aliasing, honors alternate locations using occupancy weights, and emits [`Structure`]
 //! instances with categorized residues, terminal annotations, and optional unit-cell
-//! vectors derived from `_cell.*` entries.
+//! vectors derived from `_cell.*` entries. Rows of an `_atom_site_anisotrop` loop are
+//! attached to the atom whose `_atom_site.id` they reference.
 
-use crate::io::context::IoContext;
+use crate::io::context::{IoContext, ReadOptions};
 use crate::io::error::Error;
 use crate::model::{
     atom::Atom,
     chain::Chain,
     residue::Residue,
     structure::Structure,
-    types::{Element, Point, ResidueCategory, ResiduePosition, StandardResidue},
+    types::{Element, Point, ResidueCategory, StandardResidue},
 };
-use std::collections::{BTreeMap, HashMap};
+use std::collections::{BTreeMap, HashMap, HashSet};
 use std::io::BufRead;
 use std::str::FromStr;
 
@@ -30,14 +31,19 @@ struct ResKey {
     i_code: Option<char>,
 }
 
+/// Atom name paired with the alternate-location label the atom is buffered under.
+type AtomKey = (String, Option<char>);
+
 /// Temporary aggregation of atoms prior to residue canonicalization.
 ///
 /// Each entry tracks the raw residue name, whether it originated from `HETATM`, and the
-/// best-occupancy atom positions keyed by atom name.
+/// best-occupancy atom positions keyed by atom name and kept alternate-location label. The
+/// label is `None` unless [`ReadOptions::keep_alt_locs`] is set. Each atom remembers the
+/// `_atom_site.id` of the row it came from so anisotropic parameters can find it.
 struct TempResidue {
     raw_name: String,
     is_hetatm: bool,
-    atoms: HashMap<String, (f64, Atom)>,
+    atoms: HashMap<AtomKey, (f64, Option<String>, Atom)>,
 }
 
 /// Column index bookkeeping for `_atom_site` loop headers.
@@ -47,8 +53,10 @@ struct TempResidue {
 #[derive(Default)]
 struct AtomSiteIndices {
     group_pdb: Option<usize>,
+    id: Option<usize>,
     auth_atom_id: Option<usize>,
     label_atom_id: Option<usize>,
+    label_alt_id: Option<usize>,
     auth_comp_id: Option<usize>,
     label_comp_id: Option<usize>,
     auth_asym_id: Option<usize>,
@@ -60,7 +68,19 @@ struct AtomSiteIndices {
     cartn_y: Option<usize>,
     cartn_z: Option<usize>,
     occupancy: Option<usize>,
+    b_iso: Option<usize>,
     type_symbol: Option<usize>,
+    pdbx_formal_charge: Option<usize>,
+}
+
+/// Column index bookkeeping for `_atom_site_anisotrop` loop headers.
+///
+/// `u` holds the columns of `U[1][1]`, `U[2][2]`, `U[3][3]`, `U[1][2]`, `U[1][3]` and
+/// `U[2][3]`, in the order [`Atom::anisou`] stores them.
+#[derive(Default)]
+struct AnisotropIndices {
+    id: Option<usize>,
+    u: [Option<usize>; 6],
 }
 
 /// DFA states for the mmCIF tokenizer.
@@ -71,6 +91,7 @@ enum ParserState {
     Base,
     InLoopHeader,
     InAtomSiteLoop,
+    InAnisotropLoop,
     InOtherLoop,
 }
 
@@ -95,6 +116,33 @@ enum ParserState {
 /// Returns [`Error`] when encountering malformed loop headers, truncated `_atom_site`
 /// records, unknown standard residues, or IO failures reported by `reader`.
 pub fn read<R: BufRead>(reader: R, context: &IoContext) -> Result<Structure, Error> {
+    read_with_options(reader, context, &ReadOptions::default())
+}
+
+/// Parses mmCIF text into a [`Structure`], applying parse-time [`ReadOptions`].
+///
+/// Behaves like [`read`], except that atoms rejected by `options` are dropped before the
+/// residues are assembled, and that [`ReadOptions::keep_alt_locs`] keeps every alternate
+/// conformer instead of the highest-occupancy one.
+///
+/// # Arguments
+///
+/// * `reader` - Any buffered reader that yields mmCIF text.
+/// * `context` - Alias and template tables that normalize residue names and metadata.
+/// * `options` - Filters such as whether authored hydrogens are kept.
+///
+/// # Returns
+///
+/// A populated [`Structure`] containing the retained atoms.
+///
+/// # Errors
+///
+/// Returns [`Error`] under the same conditions as [`read`].
+pub fn read_with_options<R: BufRead>(
+    reader: R,
+    context: &IoContext,
+    options: &ReadOptions,
+) -> Result<Structure, Error> {
     let mut structure = Structure::new();
 
     let mut chain_order: Vec<String> = Vec::new();
@@ -102,6 +150,8 @@ pub fn read<R: BufRead>(reader: R, context: &IoContext) -> Result<Structure, Err
 
     let mut state = ParserState::Base;
     let mut atom_indices = AtomSiteIndices::default();
+    let mut anisotrop_indices = AnisotropIndices::default();
+    let mut anisotrop = HashMap::new();
     let mut current_loop_headers = Vec::new();
     let mut line_num = 0;
 
@@ -149,9 +199,17 @@ pub fn read<R: BufRead>(reader: R, context: &IoContext) -> Result<Structure, Err
                         &tokens,
                         &atom_indices,
                         line_num,
+                        options.keep_alt_locs,
                         &mut chain_order,
                         &mut chain_map,
                     )?;
+                } else if current_loop_headers
+                    .iter()
+                    .any(|h| h.starts_with("_atom_site_anisotrop."))
+                {
+                    state = ParserState::InAnisotropLoop;
+                    anisotrop_indices = map_anisotrop_indices(&current_loop_headers);
+                    process_anisotrop_line(&tokens, &anisotrop_indices, line_num, &mut anisotrop)?;
                 } else {
                     state = ParserState::InOtherLoop;
                 }
@@ -168,11 +226,23 @@ pub fn read<R: BufRead>(reader: R, context: &IoContext) -> Result<Structure, Err
                         &tokens,
                         &atom_indices,
                         line_num,
+                        options.keep_alt_locs,
                         &mut chain_order,
                         &mut chain_map,
                     )?;
                 }
             }
+            ParserState::InAnisotropLoop => {
+                if tokens[0].starts_with('_') || tokens[0] == "loop_" {
+                    state = if tokens[0] == "loop_" {
+                        ParserState::InLoopHeader
+                    } else {
+                        ParserState::Base
+                    };
+                } else {
+                    process_anisotrop_line(&tokens, &anisotrop_indices, line_num, &mut anisotrop)?;
+                }
+            }
             ParserState::InOtherLoop => {
                 if tokens[0].starts_with('_') || tokens[0] == "loop_" {
                     state = if tokens[0] == "loop_" {
@@ -189,7 +259,14 @@ pub fn read<R: BufRead>(reader: R, context: &IoContext) -> Result<Structure, Err
         structure.box_vectors = Some(box_vectors);
     }
 
-    build_structure(structure, chain_order, chain_map, context)
+    build_structure(
+        structure,
+        chain_order,
+        chain_map,
+        &anisotrop,
+        context,
+        options,
+    )
 }
 
 /// Splits an mmCIF line into tokens while respecting quoted/semicolon blocks.
@@ -259,8 +336,10 @@ fn map_atom_site_indices(headers: &[String]) -> AtomSiteIndices {
     for (i, header) in headers.iter().enumerate() {
         match header.as_str() {
             "_atom_site.group_PDB" => indices.group_pdb = Some(i),
+            "_atom_site.id" => indices.id = Some(i),
             "_atom_site.auth_atom_id" => indices.auth_atom_id = Some(i),
             "_atom_site.label_atom_id" => indices.label_atom_id = Some(i),
+            "_atom_site.label_alt_id" => indices.label_alt_id = Some(i),
             "_atom_site.auth_comp_id" => indices.auth_comp_id = Some(i),
             "_atom_site.label_comp_id" => indices.label_comp_id = Some(i),
             "_atom_site.auth_asym_id" => indices.auth_asym_id = Some(i),
@@ -272,13 +351,93 @@ fn map_atom_site_indices(headers: &[String]) -> AtomSiteIndices {
             "_atom_site.Cartn_y" => indices.cartn_y = Some(i),
             "_atom_site.Cartn_z" => indices.cartn_z = Some(i),
             "_atom_site.occupancy" => indices.occupancy = Some(i),
+            "_atom_site.B_iso_or_equiv" => indices.b_iso = Some(i),
             "_atom_site.type_symbol" => indices.type_symbol = Some(i),
+            "_atom_site.pdbx_formal_charge" => indices.pdbx_formal_charge = Some(i),
             _ => {}
         }
     }
     indices
 }
 
+/// Maps `_atom_site_anisotrop` loop headers to column indices.
+///
+/// # Arguments
+///
+/// * `headers` - Ordered header strings collected immediately after `loop_`.
+///
+/// # Returns
+///
+/// A populated [`AnisotropIndices`] structure with optional indices for each field.
+fn map_anisotrop_indices(headers: &[String]) -> AnisotropIndices {
+    let mut indices = AnisotropIndices::default();
+    for (i, header) in headers.iter().enumerate() {
+        let slot = match header.as_str() {
+            "_atom_site_anisotrop.id" => {
+                indices.id = Some(i);
+                continue;
+            }
+            "_atom_site_anisotrop.U[1][1]" => 0,
+            "_atom_site_anisotrop.U[2][2]" => 1,
+            "_atom_site_anisotrop.U[3][3]" => 2,
+            "_atom_site_anisotrop.U[1][2]" => 3,
+            "_atom_site_anisotrop.U[1][3]" => 4,
+            "_atom_site_anisotrop.U[2][3]" => 5,
+            _ => continue,
+        };
+        indices.u[slot] = Some(i);
+    }
+    indices
+}
+
+/// Parses one `_atom_site_anisotrop` row into the table keyed by `_atom_site.id`.
+///
+/// Rows lacking the `id` column or any of the six `U` columns, or holding `.`/`?` in them,
+/// are skipped.
+///
+/// # Arguments
+///
+/// * `tokens` - Tokenized row from the `_atom_site_anisotrop` loop.
+/// * `indices` - Column indices resolved from the header.
+/// * `line_num` - Source line for contextual errors.
+/// * `anisotrop` - Collected displacement parameters keyed by atom site identifier.
+///
+/// # Returns
+///
+/// [`Ok`] if the row was accepted or skipped, or [`Error`] if it is shorter than its header
+/// or holds a non-numeric `U` value.
+fn process_anisotrop_line(
+    tokens: &[String],
+    indices: &AnisotropIndices,
+    line_num: usize,
+    anisotrop: &mut HashMap<String, [f64; 6]>,
+) -> Result<(), Error> {
+    let Some(id) = optional_token(tokens, indices.id, line_num)? else {
+        return Ok(());
+    };
+
+    let mut u = [0.0; 6];
+    for (value, idx) in u.iter_mut().zip(indices.u) {
+        let Some(text) = optional_token(tokens, idx, line_num)? else {
+            return Ok(());
+        };
+        if matches!(text, "." | "?") {
+            return Ok(());
+        }
+        *value = f64::from_str(text).map_err(|_| {
+            Error::parse(
+                "mmCIF",
+                None,
+                line_num,
+                "Invalid _atom_site_anisotrop component",
+            )
+        })?;
+    }
+
+    anisotrop.insert(id.to_string(), u);
+    Ok(())
+}
+
 /// Retrieves a mandatory token value by index with bounds checking.
 ///
 /// # Arguments
@@ -361,6 +520,8 @@ fn parse_coordinate(value: &str, axis: &str, line_num: usize) -> Result<f64, Err
 /// * `tokens` - Tokenized row from the `_atom_site` loop.
 /// * `indices` - Column indices resolved from the header.
 /// * `line_num` - Source line for contextual errors.
+/// * `keep_alt_locs` - Whether alternate conformers are stored separately instead of
+///   competing on occupancy.
 /// * `chain_order` - Mutable list capturing encounter order of chains.
 /// * `chain_map` - Aggregation of temporary residues keyed by [`ResKey`].
 ///
@@ -371,6 +532,7 @@ fn process_atom_line(
     tokens: &[String],
     indices: &AtomSiteIndices,
     line_num: usize,
+    keep_alt_locs: bool,
     chain_order: &mut Vec<String>,
     chain_map: &mut HashMap<String, BTreeMap<ResKey, TempResidue>>,
 ) -> Result<(), Error> {
@@ -468,6 +630,7 @@ fn process_atom_line(
     }
 
     let group_pdb = optional_token(tokens, indices.group_pdb, line_num)?;
+    let site_id = optional_token(tokens, indices.id, line_num)?;
     let is_hetatm = matches!(group_pdb, Some(val) if val.eq_ignore_ascii_case("HETATM"));
 
     let atom_name = token(tokens, atom_name_idx, line_num)?;
@@ -475,13 +638,16 @@ fn process_atom_line(
     let chain_id_raw = token(tokens, chain_id_idx, line_num)?;
     let seq_id_str = token(tokens, seq_id_idx, line_num)?;
     let ins_code_str = optional_token(tokens, indices.pdbx_pdb_ins_code, line_num)?;
+    let alt_id_str = optional_token(tokens, indices.label_alt_id, line_num)?;
 
     let x_str = token(tokens, x_idx, line_num)?;
     let y_str = token(tokens, y_idx, line_num)?;
     let z_str = token(tokens, z_idx, line_num)?;
 
     let occ_str = optional_token(tokens, indices.occupancy, line_num)?;
+    let b_iso_str = optional_token(tokens, indices.b_iso, line_num)?;
     let elem_str = optional_token(tokens, indices.type_symbol, line_num)?;
+    let charge_str = optional_token(tokens, indices.pdbx_formal_charge, line_num)?;
 
     if matches!(x_str, "." | "?") || matches!(y_str, "." | "?") || matches!(z_str, "." | "?") {
         return Ok(());
@@ -506,16 +672,29 @@ fn process_atom_line(
         }
     });
 
+    let alt_loc = alt_id_str
+        .filter(|alt| keep_alt_locs && !matches!(*alt, "." | "?"))
+        .and_then(|alt| alt.chars().next());
+
     let occupancy = occ_str
         .filter(|occ| !matches!(*occ, "." | "?"))
         .and_then(|occ| f64::from_str(occ).ok())
         .unwrap_or(1.0);
 
+    let b_factor = b_iso_str
+        .filter(|b| !matches!(*b, "." | "?"))
+        .and_then(|b| f64::from_str(b).ok())
+        .unwrap_or(0.0);
+
     let element = elem_str
         .filter(|elem| !matches!(*elem, "." | "?"))
         .and_then(|elem| Element::from_str(elem).ok())
         .unwrap_or(Element::Unknown);
 
+    let formal_charge = charge_str
+        .filter(|c| !matches!(*c, "." | "?"))
+        .and_then(|c| c.trim_start_matches('+').parse::<i8>().ok());
+
     let chain_key = match chain_id_raw {
         "." | "?" => "?".to_string(),
         other => other.to_string(),
@@ -538,13 +717,20 @@ fn process_atom_line(
         atoms: HashMap::new(),
     });
 
-    let atom_key = atom_name.to_string();
-    let candidate = Atom::new(atom_name, element, pos);
+    let atom_key = (atom_name.to_string(), alt_loc);
+    let mut candidate = Atom::new(atom_name, element, pos);
+    candidate.occupancy = occupancy;
+    candidate.b_factor = b_factor;
+    candidate.formal_charge = formal_charge;
+    candidate.alt_loc = alt_loc;
 
     match temp_res.atoms.get(&atom_key) {
-        Some((old_occ, _)) if occupancy <= *old_occ => {}
+        Some((old_occ, _, _)) if occupancy <= *old_occ => {}
         _ => {
-            temp_res.atoms.insert(atom_key, (occupancy, candidate));
+            let site_id = site_id.map(str::to_string);
+            temp_res
+                .atoms
+                .insert(atom_key, (occupancy, site_id, candidate));
         }
     }
 
@@ -561,7 +747,9 @@ fn process_atom_line(
 /// * `structure` - Empty [`Structure`] to populate.
 /// * `chain_order` - Encounter order recorded during parsing.
 /// * `chain_map` - Buffered residues grouped by chain/key.
+/// * `anisotrop` - Anisotropic displacement parameters keyed by `_atom_site.id`.
 /// * `context` - IO context used for residue aliasing and templates.
+/// * `options` - Parse-time atom filters.
 ///
 /// # Returns
 ///
@@ -574,7 +762,9 @@ fn build_structure(
     mut structure: Structure,
     chain_order: Vec<String>,
     mut chain_map: HashMap<String, BTreeMap<ResKey, TempResidue>>,
+    anisotrop: &HashMap<String, [f64; 6]>,
     context: &IoContext,
+    options: &ReadOptions,
 ) -> Result<Structure, Error> {
     for chain_id in chain_order {
         if let Some(residues) = chain_map.remove(&chain_id) {
@@ -592,10 +782,16 @@ fn build_structure(
                     std_enum,
                     category,
                 );
-
-                let mut sorted_atoms: Vec<Atom> =
-                    temp_res.atoms.into_values().map(|v| v.1).collect();
-                sorted_atoms.sort_by(|a, b| a.name.cmp(&b.name));
+                let mut sorted_atoms: Vec<Atom> = temp_res
+                    .atoms
+                    .into_values()
+                    .map(|(_, site_id, mut atom)| {
+                        atom.anisou = site_id.and_then(|id| anisotrop.get(&id).copied());
+                        atom
+                    })
+                    .filter(|a| options.keep_hydrogens || a.element != Element::H)
+                    .collect();
+                sorted_atoms.sort_by(|a, b| (&a.name, a.alt_loc).cmp(&(&b.name, b.alt_loc)));
 
                 for atom in sorted_atoms {
                     residue.add_atom(atom);
@@ -607,7 +803,7 @@ fn build_structure(
         }
     }
 
-    calculate_residue_positions(&mut structure);
+    structure.assign_positions();
 
     Ok(structure)
 }
@@ -635,76 +831,20 @@ fn determine_category(
         Ok(ResidueCategory::Standard)
     } else if !temp_res.is_hetatm {
         Err(Error::unknown_standard_residue(&temp_res.raw_name, None))
-    } else if temp_res.atoms.len() == 1 {
+    } else if temp_res
+        .atoms
+        .keys()
+        .map(|(name, _)| name.as_str())
+        .collect::<HashSet<_>>()
+        .len()
+        == 1
+    {
         Ok(ResidueCategory::Ion)
     } else {
         Ok(ResidueCategory::Hetero)
     }
 }
 
-/// Labels residues within each chain as terminal, internal, or unspecified.
-///
-/// Polymers are identified by standard templates (excluding water), with the first/last
-/// residues marked as N/C or 5'/3' termini depending on polymer type.
-///
-/// # Arguments
-///
-/// * `structure` - Structure whose residues require positional annotations.
-fn calculate_residue_positions(structure: &mut Structure) {
-    for chain in structure.iter_chains_mut() {
-        let polymer_indices: Vec<usize> = chain
-            .iter_residues()
-            .enumerate()
-            .filter(|(_, res)| {
-                res.category == ResidueCategory::Standard
-                    && res.standard_name != Some(StandardResidue::HOH)
-            })
-            .map(|(i, _)| i)
-            .collect();
-
-        if polymer_indices.is_empty() {
-            continue;
-        }
-
-        let first_idx = *polymer_indices.first().unwrap();
-        let last_idx = *polymer_indices.last().unwrap();
-
-        for (i, residue) in chain.iter_residues_mut().enumerate() {
-            if residue.category != ResidueCategory::Standard
-                || residue.standard_name == Some(StandardResidue::HOH)
-            {
-                residue.position = ResiduePosition::None;
-                continue;
-            }
-
-            let is_protein = matches!(residue.standard_name, Some(std) if std.is_protein());
-            let is_nucleic = matches!(residue.standard_name, Some(std) if std.is_nucleic());
-
-            if i == first_idx {
-                residue.position = if is_protein {
-                    ResiduePosition::NTerminal
-                } else if is_nucleic {
-                    ResiduePosition::FivePrime
-                } else {
-                    ResiduePosition::Internal
-                };
-            } else if i == last_idx {
-                residue.position = if is_protein {
-                    ResiduePosition::CTerminal
-                } else if is_nucleic {
-                    ResiduePosition::ThreePrime
-                } else {
-                    ResiduePosition::Internal
-                };
-            } else if polymer_indices.contains(&i) {
-                residue.position = ResiduePosition::Internal;
-            } else {
-                residue.position = ResiduePosition::None;
-            }
-        }
-    }
-}
-
 /// Converts `_cell.length_*` and `_cell.angle_*` parameters into box vectors.
 ///
 /// # Arguments
@@ -754,7 +894,7 @@ fn process_cell_parameters(params: &HashMap<String, String>) -> Option<[[f64; 3]
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::io::context::IoContext;
+    use crate::io::context::{IoContext, ReadOptions};
     use crate::model::types::{ResidueCategory, ResiduePosition, StandardResidue};
     use std::io::Cursor;
 
@@ -823,6 +963,26 @@ mod tests {
         assert_eq!(residues[1].position, ResiduePosition::CTerminal);
     }
 
+    #[test]
+    fn read_with_options_drops_hydrogens_when_requested() {
+        let rows = "\
+            ATOM N GLY A 1 ? 0.000 0.000 0.000 1.00 N\n\
+            ATOM H GLY A 1 ? -0.500 0.800 0.000 1.00 H\n\
+            ATOM CA GLY A 1 ? 1.458 0.000 0.000 1.00 C\n";
+        let cif = format!("data_h\n{ATOM_SITE_HEADER}{rows}");
+        let context = IoContext::new_default();
+        let options = ReadOptions {
+            keep_hydrogens: false,
+            ..ReadOptions::default()
+        };
+
+        let stripped = read_with_options(Cursor::new(cif.as_bytes()), &context, &options).unwrap();
+
+        let names: Vec<_> = stripped.iter_atoms().map(|a| a.name.to_string()).collect();
+        assert_eq!(names, vec!["CA", "N"]);
+        assert_eq!(parse_structure(&cif).atom_count(), 3);
+    }
+
     #[test]
     fn read_aliases_water_and_applies_occupancy_filter() {
         let rows = "\
@@ -949,6 +1109,122 @@ mod tests {
         assert_eq!(ion.atom_count(), 1);
     }
 
+    #[test]
+    fn read_with_options_keeps_labeled_alternate_conformers() {
+        let cif = "data_altloc\n\
+            loop_\n\
+            _atom_site.group_PDB\n\
+            _atom_site.label_atom_id\n\
+            _atom_site.label_alt_id\n\
+            _atom_site.label_comp_id\n\
+            _atom_site.auth_asym_id\n\
+            _atom_site.auth_seq_id\n\
+            _atom_site.Cartn_x\n\
+            _atom_site.Cartn_y\n\
+            _atom_site.Cartn_z\n\
+            _atom_site.occupancy\n\
+            _atom_site.type_symbol\n\
+            ATOM N . GLY A 1 0.000 0.000 0.000 1.00 N\n\
+            ATOM CA A GLY A 1 1.000 0.000 0.000 0.40 C\n\
+            ATOM CA B GLY A 1 2.000 0.000 0.000 0.60 C\n";
+        let options = ReadOptions {
+            keep_alt_locs: true,
+            ..ReadOptions::default()
+        };
+
+        let structure = read_with_options(
+            Cursor::new(cif.as_bytes()),
+            &IoContext::new_default(),
+            &options,
+        )
+        .unwrap();
+
+        let labels: Vec<_> = structure
+            .iter_atoms()
+            .map(|a| (a.name.to_string(), a.alt_loc))
+            .collect();
+        assert_eq!(
+            labels,
+            vec![
+                ("CA".to_string(), Some('A')),
+                ("CA".to_string(), Some('B')),
+                ("N".to_string(), None),
+            ]
+        );
+
+        let collapsed = parse_structure(cif);
+        let ca = collapsed.iter_atoms().find(|a| a.name == "CA").unwrap();
+        assert_eq!(collapsed.atom_count(), 2);
+        assert_eq!(ca.alt_loc, None);
+        assert!((ca.pos.x - 2.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn read_attaches_anisotrop_rows_to_retained_atoms_by_site_id() {
+        let cif = "data_anisou\n\
+            loop_\n\
+            _atom_site.group_PDB\n\
+            _atom_site.id\n\
+            _atom_site.label_atom_id\n\
+            _atom_site.label_comp_id\n\
+            _atom_site.auth_asym_id\n\
+            _atom_site.auth_seq_id\n\
+            _atom_site.Cartn_x\n\
+            _atom_site.Cartn_y\n\
+            _atom_site.Cartn_z\n\
+            _atom_site.occupancy\n\
+            _atom_site.type_symbol\n\
+            ATOM 1 N GLY A 1 0.000 0.000 0.000 1.00 N\n\
+            ATOM 2 CA GLY A 1 1.000 0.000 0.000 0.40 C\n\
+            ATOM 3 CA GLY A 1 2.000 0.000 0.000 0.60 C\n\
+            ATOM 4 C GLY A 1 3.000 0.000 0.000 1.00 C\n\
+            #\n\
+            loop_\n\
+            _atom_site_anisotrop.id\n\
+            _atom_site_anisotrop.type_symbol\n\
+            _atom_site_anisotrop.U[1][1]\n\
+            _atom_site_anisotrop.U[2][2]\n\
+            _atom_site_anisotrop.U[3][3]\n\
+            _atom_site_anisotrop.U[1][2]\n\
+            _atom_site_anisotrop.U[1][3]\n\
+            _atom_site_anisotrop.U[2][3]\n\
+            1 N 0.2406 0.1892 -0.1262 0.0044 -0.0121 -0.0181\n\
+            2 C 0.1 0.1 0.1 0 0 0\n\
+            3 C 0.5 0.5 0.5 0 0 0\n\
+            #\n";
+
+        let structure = parse_structure(cif);
+        let residue = structure.chain("A").unwrap().residue(1, None).unwrap();
+
+        assert_eq!(
+            residue.atom("N").unwrap().anisou,
+            Some([0.2406, 0.1892, -0.1262, 0.0044, -0.0121, -0.0181])
+        );
+        assert_eq!(
+            residue.atom("CA").unwrap().anisou,
+            Some([0.5, 0.5, 0.5, 0.0, 0.0, 0.0])
+        );
+        assert_eq!(residue.atom("C").unwrap().anisou, None);
+    }
+
+    #[test]
+    fn read_rejects_malformed_anisotrop_component() {
+        let cif = format!(
+            "data_bad\n{ATOM_SITE_HEADER}ATOM N GLY A 1 ? 0.0 0.0 0.0 1.00 N\n\
+             loop_\n\
+             _atom_site_anisotrop.id\n\
+             _atom_site_anisotrop.U[1][1]\n\
+             _atom_site_anisotrop.U[2][2]\n\
+             _atom_site_anisotrop.U[3][3]\n\
+             _atom_site_anisotrop.U[1][2]\n\
+             _atom_site_anisotrop.U[1][3]\n\
+             _atom_site_anisotrop.U[2][3]\n\
+             1 0.1 abc 0.1 0 0 0\n"
+        );
+
+        assert!(matches!(parse_result(&cif), Err(Error::Parse { .. })));
+    }
+
     #[test]
     fn read_prefers_atoms_with_highest_occupancy_and_retains_hydrogens() {
         let rows = "\
@@ -972,6 +1248,31 @@ mod tests {
         assert!(residue.atom("H1").is_some());
     }
 
+    #[test]
+    fn read_parses_isotropic_b_factors() {
+        let cif = "\
+            data_bfactor\n\
+            loop_\n\
+            _atom_site.group_PDB\n\
+            _atom_site.auth_atom_id\n\
+            _atom_site.auth_comp_id\n\
+            _atom_site.auth_asym_id\n\
+            _atom_site.auth_seq_id\n\
+            _atom_site.Cartn_x\n\
+            _atom_site.Cartn_y\n\
+            _atom_site.Cartn_z\n\
+            _atom_site.B_iso_or_equiv\n\
+            _atom_site.type_symbol\n\
+            ATOM N GLY A 1 0.000 0.000 0.000 87.25 N\n\
+            ATOM CA GLY A 1 1.000 0.000 0.000 ? C\n";
+
+        let structure = parse_structure(cif);
+        let residue = structure.chain("A").unwrap().residue(1, None).unwrap();
+
+        assert_eq!(residue.atom("N").unwrap().b_factor, 87.25);
+        assert_eq!(residue.atom("CA").unwrap().b_factor, 0.0);
+    }
+
     #[test]
     fn read_supports_residues_with_insertion_codes() {
         let rows = "\
@@ -1022,4 +1323,41 @@ mod tests {
             other => panic!("unexpected error: {other:?}"),
         }
     }
+
+    #[test]
+    fn read_numbers_residues_by_author_sequence_without_an_override() {
+        let cif = "\
+            data_auth\n\
+            loop_\n\
+            _atom_site.group_PDB\n\
+            _atom_site.label_atom_id\n\
+            _atom_site.label_comp_id\n\
+            _atom_site.label_asym_id\n\
+            _atom_site.label_seq_id\n\
+            _atom_site.Cartn_x\n\
+            _atom_site.Cartn_y\n\
+            _atom_site.Cartn_z\n\
+            _atom_site.auth_seq_id\n\
+            ATOM CA ALA A 1 0.0 0.0 0.0 101\n";
+        let structure = parse_structure(cif);
+        let residue = structure
+            .find_residue("A", 101, None)
+            .expect("author-numbered residue");
+        assert_eq!(residue.auth_id, None, "id already holds the author number");
+
+        let label_only = "\
+            data_label\n\
+            loop_\n\
+            _atom_site.group_PDB\n\
+            _atom_site.label_atom_id\n\
+            _atom_site.label_comp_id\n\
+            _atom_site.label_asym_id\n\
+            _atom_site.label_seq_id\n\
+            _atom_site.Cartn_x\n\
+            _atom_site.Cartn_y\n\
+            _atom_site.Cartn_z\n\
+            ATOM CA ALA A 1 0.0 0.0 0.0\n";
+        let structure = parse_structure(label_only);
+        assert_eq!(structure.find_residue("A", 1, None).unwrap().auth_id, None);
+    }
 }
@@ -1,36 +1,70 @@
 //! mmCIF writer utilities that encode structures and topologies into loop-based records.
 //!
-//! The serializer emits `data_` headers, optional `_cell.*` metadata, `_atom_site` rows with
-//! consistent numbering, and `_struct_conn` loops reconstructed from [`Topology`] bonds so
-//! downstream crystallography pipelines can round-trip `bio-forge` structures.
+//! The serializer emits `data_` headers, optional `_cell.*` metadata, `_entity_poly` and
+//! `_entity_poly_seq` polymer descriptions, `_atom_site` rows with consistent numbering
+//! (optionally tagged with model numbers), `_atom_site_anisotrop` displacement parameters,
+//! and `_struct_conn` loops
+//! reconstructed from [`Topology`] bonds and optional non-covalent interactions so downstream
+//! crystallography pipelines can round-trip `bio-forge` structures.
 
 use crate::io::error::Error;
+use crate::io::selection::{select_structure, select_topology};
 use crate::model::{
-    atom::Atom, residue::Residue, structure::Structure, topology::Topology, types::BondOrder,
+    atom::Atom,
+    chain::Chain,
+    residue::Residue,
+    structure::Structure,
+    topology::{Interaction, InteractionKind, Topology},
+    types::BondOrder,
 };
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::io::Write;
 
+/// Formatting options applied by the mmCIF writers.
+///
+/// Unlike PDB, mmCIF fields are whitespace-delimited, so precision is free to vary without
+/// breaking column alignment.
+#[derive(Debug, Clone, PartialEq)]
+pub struct WriteOptions {
+    /// Number of decimal places emitted for Cartesian coordinates.
+    pub coord_decimals: usize,
+}
+
+impl Default for WriteOptions {
+    /// Emits coordinates with three decimals, matching conventional mmCIF output.
+    fn default() -> Self {
+        Self { coord_decimals: 3 }
+    }
+}
+
 /// Serializes a [`Structure`] into mmCIF format with optional cell metadata.
 ///
-/// The writer emits a `data_` header, `_cell.*` entries when box vectors exist, and a
-/// complete `_atom_site` loop that captures atom identities, entity IDs, and coordinates.
+/// The writer emits a `data_` header, `_cell.*` entries when box vectors exist,
+/// `_entity_poly` and `_entity_poly_seq` loops for polymer entities, and a complete
+/// `_atom_site` loop that captures atom identities, entity IDs, and coordinates.
 ///
 /// # Arguments
 ///
 /// * `writer` - Destination that implements [`Write`].
 /// * `structure` - Source structure providing atoms and unit-cell information.
+/// * `options` - Formatting options such as coordinate precision.
 ///
 /// # Returns
 ///
 /// [`Ok`] on success or [`Error`] if any IO operation fails.
-pub fn write_structure<W: Write>(writer: W, structure: &Structure) -> Result<(), Error> {
-    let mut ctx = WriterContext::new(writer);
+pub fn write_structure<W: Write>(
+    writer: W,
+    structure: &Structure,
+    options: &WriteOptions,
+) -> Result<(), Error> {
+    let mut ctx = WriterContext::new(writer, options);
 
     ctx.write_header()?;
 
     ctx.write_cell(structure.box_vectors)?;
 
+    ctx.write_entity_poly(structure)?;
+
     ctx.write_entity_poly_seq(structure)?;
 
     ctx.write_atoms(structure)?;
@@ -41,29 +75,175 @@ pub fn write_structure<W: Write>(writer: W, structure: &Structure) -> Result<(),
 /// Serializes a [`Topology`] into mmCIF, including `_struct_conn` bond loops.
 ///
 /// Coordinates are emitted via [`write_structure`] semantics; additionally, bonds are
-/// converted into `_struct_conn` records with distance and order annotations.
+/// converted into `_struct_conn` records with distance and order annotations. Coordination
+/// bonds are written as `metalc` rows with an unknown value order.
 ///
 /// # Arguments
 ///
 /// * `writer` - Output sink implementing [`Write`].
 /// * `topology` - Topology containing a structure and bond list to serialize.
+/// * `options` - Formatting options such as coordinate precision.
 ///
 /// # Returns
 ///
 /// [`Ok`] when writing succeeds or [`Error`] if IO fails or bonds reference missing atoms.
-pub fn write_topology<W: Write>(writer: W, topology: &Topology) -> Result<(), Error> {
-    let mut ctx = WriterContext::new(writer);
+pub fn write_topology<W: Write>(
+    writer: W,
+    topology: &Topology,
+    options: &WriteOptions,
+) -> Result<(), Error> {
+    let mut ctx = WriterContext::new(writer, options);
     let structure = topology.structure();
 
     ctx.write_header()?;
 
     ctx.write_cell(structure.box_vectors)?;
 
+    ctx.write_entity_poly(structure)?;
+
     ctx.write_entity_poly_seq(structure)?;
 
     ctx.write_atoms(structure)?;
 
-    ctx.write_connections(topology)?;
+    ctx.write_connections(topology, &[])?;
+
+    Ok(())
+}
+
+/// Serializes a subset of a [`Structure`]'s atoms into mmCIF format.
+///
+/// Only the listed global atom indices (in [`Structure::iter_atoms`] order) are emitted;
+/// chain and residue grouping is kept and `_atom_site.id` values are assigned afresh.
+/// Indices beyond the atom count are ignored. The structure itself is left untouched.
+///
+/// # Arguments
+///
+/// * `writer` - Destination that implements [`Write`].
+/// * `structure` - Source structure.
+/// * `indices` - Global atom indices to write.
+/// * `options` - Formatting options such as coordinate precision.
+///
+/// # Returns
+///
+/// [`Ok`] on success or [`Error`] if any IO operation fails.
+pub fn write_selection<W: Write>(
+    writer: W,
+    structure: &Structure,
+    indices: &[usize],
+    options: &WriteOptions,
+) -> Result<(), Error> {
+    write_structure(writer, &select_structure(structure, indices), options)
+}
+
+/// Serializes a subset of a [`Topology`] into mmCIF, including `_struct_conn` bond loops.
+///
+/// Atoms are selected as in [`write_selection`]; a bond is written only when both of its
+/// endpoints are selected.
+///
+/// # Arguments
+///
+/// * `writer` - Output sink implementing [`Write`].
+/// * `topology` - Source topology.
+/// * `indices` - Global atom indices to write.
+/// * `options` - Formatting options such as coordinate precision.
+///
+/// # Returns
+///
+/// [`Ok`] when writing succeeds or [`Error`] if IO fails.
+pub fn write_topology_selection<W: Write>(
+    writer: W,
+    topology: &Topology,
+    indices: &[usize],
+    options: &WriteOptions,
+) -> Result<(), Error> {
+    write_topology(writer, &select_topology(topology, indices), options)
+}
+
+/// Serializes a [`Topology`] into mmCIF together with non-covalent interactions.
+///
+/// Covalent bonds are written exactly as in [`write_topology`]; each interaction then adds a
+/// `_struct_conn` row with `conn_type_id` `hydrog` or `metalc`, its distance, and an unknown
+/// (`?`) value order. Connection IDs continue across both groups.
+///
+/// # Arguments
+///
+/// * `writer` - Output sink implementing [`Write`].
+/// * `topology` - Topology containing a structure and bond list to serialize.
+/// * `interactions` - Hydrogen bonds and metal coordination contacts to annotate.
+/// * `options` - Formatting options such as coordinate precision.
+///
+/// # Returns
+///
+/// [`Ok`] when writing succeeds or [`Error`] if IO fails or a bond or interaction references
+/// a missing atom.
+pub fn write_topology_with_interactions<W: Write>(
+    writer: W,
+    topology: &Topology,
+    interactions: &[Interaction],
+    options: &WriteOptions,
+) -> Result<(), Error> {
+    let mut ctx = WriterContext::new(writer, options);
+    let structure = topology.structure();
+
+    ctx.write_header()?;
+
+    ctx.write_cell(structure.box_vectors)?;
+
+    ctx.write_entity_poly(structure)?;
+
+    ctx.write_entity_poly_seq(structure)?;
+
+    ctx.write_atoms(structure)?;
+
+    ctx.write_connections(topology, interactions)?;
+
+    Ok(())
+}
+
+/// Serializes several models of the same system into a single mmCIF data block.
+///
+/// All models share one `_atom_site` loop with an extra `pdbx_PDB_model_num` column numbered
+/// from 1, as used for NMR ensembles and trajectories. Atom serials continue across models
+/// so every `_atom_site.id` stays unique within the block. Cell and sequence metadata are
+/// taken from the first model.
+///
+/// # Arguments
+///
+/// * `writer` - Destination that implements [`Write`].
+/// * `models` - Models to serialize, in model-number order.
+/// * `options` - Formatting options such as coordinate precision.
+///
+/// # Returns
+///
+/// [`Ok`] on success or [`Error`] if any IO operation fails.
+///
+/// # Errors
+///
+/// Returns [`Error::InconsistentData`] when `models` is empty.
+pub fn write_models<W: Write>(
+    writer: W,
+    models: &[Structure],
+    options: &WriteOptions,
+) -> Result<(), Error> {
+    let first = models.first().ok_or_else(|| {
+        Error::inconsistent_data("mmCIF", None, "no models supplied for multi-model output")
+    })?;
+    let mut ctx = WriterContext::new(writer, options);
+
+    ctx.write_header()?;
+
+    ctx.write_cell(first.box_vectors)?;
+
+    ctx.write_entity_poly(first)?;
+
+    ctx.write_entity_poly_seq(first)?;
+
+    ctx.write_atom_site_header(true)?;
+    for (model_num, model) in (1..).zip(models) {
+        ctx.write_atom_rows(model, Some(model_num))?;
+    }
+    writeln!(ctx.writer, "#").map_err(|e| Error::from_io(e, None))?;
+    ctx.write_anisotrop()?;
 
     Ok(())
 }
@@ -71,9 +251,11 @@ pub fn write_topology<W: Write>(writer: W, topology: &Topology) -> Result<(), Er
 /// Stateful helper that tracks atom numbering and writes mmCIF sections.
 struct WriterContext<W> {
     writer: W,
+    coord_decimals: usize,
     current_atom_id: usize,
     atom_index_to_id: HashMap<usize, usize>,
     residue_label_map: HashMap<(String, i32, Option<char>), String>,
+    anisotrop_rows: Vec<String>,
 }
 
 impl<W: Write> WriterContext<W> {
@@ -82,12 +264,15 @@ impl<W: Write> WriterContext<W> {
     /// # Arguments
     ///
     /// * `writer` - Sink receiving the emitted mmCIF text.
-    fn new(writer: W) -> Self {
+    /// * `options` - Formatting options controlling numeric precision.
+    fn new(writer: W, options: &WriteOptions) -> Self {
         Self {
             writer,
+            coord_decimals: options.coord_decimals,
             current_atom_id: 1,
             atom_index_to_id: HashMap::new(),
             residue_label_map: HashMap::new(),
+            anisotrop_rows: Vec::new(),
         }
     }
 
@@ -142,6 +327,57 @@ impl<W: Write> WriterContext<W> {
         Ok(())
     }
 
+    /// Writes the `_entity_poly` loop describing each polymer entity.
+    ///
+    /// One row is emitted per entity that has polymer residues, giving its polymer type,
+    /// one-letter sequence, and the comma-separated chains that share it.
+    ///
+    /// # Arguments
+    ///
+    /// * `structure` - Structure whose polymer entities will be described.
+    fn write_entity_poly(&mut self, structure: &Structure) -> Result<(), Error> {
+        let entity_ids = structure.entity_ids();
+        let mut entities: Vec<(usize, &Chain, Vec<&str>)> = Vec::new();
+
+        for (chain, &entity_id) in structure.iter_chains().zip(&entity_ids) {
+            if chain.sequence_one_letter().is_empty() {
+                continue;
+            }
+            match entities.iter_mut().find(|(id, _, _)| *id == entity_id) {
+                Some((_, _, strands)) => strands.push(&chain.id),
+                None => entities.push((entity_id, chain, vec![&chain.id])),
+            }
+        }
+
+        if entities.is_empty() {
+            return Ok(());
+        }
+
+        writeln!(self.writer, "loop_").map_err(|e| Error::from_io(e, None))?;
+        for field in [
+            "entity_id",
+            "type",
+            "nstd_linkage",
+            "nstd_monomer",
+            "pdbx_seq_one_letter_code",
+            "pdbx_strand_id",
+        ] {
+            writeln!(self.writer, "_entity_poly.{field}").map_err(|e| Error::from_io(e, None))?;
+        }
+        for (entity_id, chain, strands) in entities {
+            writeln!(
+                self.writer,
+                "{} {} no no {} {}",
+                entity_id,
+                quote_string(polymer_type(chain)),
+                chain.sequence_one_letter(),
+                quote_string(&strands.join(","))
+            )
+            .map_err(|e| Error::from_io(e, None))?;
+        }
+        writeln!(self.writer, "#").map_err(|e| Error::from_io(e, None))
+    }
+
     /// Writes the `_entity_poly_seq` loop for polymer chains.
     ///
     /// # Arguments
@@ -149,16 +385,13 @@ impl<W: Write> WriterContext<W> {
     /// * `structure` - Structure whose polymer residues will be serialized.
     fn write_entity_poly_seq(&mut self, structure: &Structure) -> Result<(), Error> {
         let mut buffer = Vec::new();
-        let mut next_entity_id = 1usize;
-        let mut entity_ids: HashMap<smol_str::SmolStr, usize> = HashMap::new();
+        let entity_ids = structure.entity_ids();
+        let mut emitted = HashSet::new();
 
-        for chain in structure.iter_chains() {
-            let chain_id = chain.id.clone();
-            let entity_id = *entity_ids.entry(chain_id).or_insert_with(|| {
-                let val = next_entity_id;
-                next_entity_id += 1;
-                val
-            });
+        for (chain, &entity_id) in structure.iter_chains().zip(&entity_ids) {
+            if !emitted.insert(entity_id) {
+                continue;
+            }
 
             let polymer_residues: Vec<_> = chain
                 .iter_residues()
@@ -200,6 +433,47 @@ impl<W: Write> WriterContext<W> {
     ///
     /// * `structure` - Structure whose atoms will be serialized.
     fn write_atoms(&mut self, structure: &Structure) -> Result<(), Error> {
+        self.write_atom_site_header(false)?;
+        self.write_atom_rows(structure, None)?;
+        writeln!(self.writer, "#").map_err(|e| Error::from_io(e, None))?;
+        self.write_anisotrop()
+    }
+
+    /// Writes the `_atom_site_anisotrop` loop for atoms emitted since the last call.
+    ///
+    /// Rows are collected by [`WriterContext::write_atom_record`] for atoms that carry
+    /// anisotropic displacement parameters; nothing is written when there are none.
+    fn write_anisotrop(&mut self) -> Result<(), Error> {
+        if self.anisotrop_rows.is_empty() {
+            return Ok(());
+        }
+
+        writeln!(self.writer, "loop_").map_err(|e| Error::from_io(e, None))?;
+        for field in [
+            "id",
+            "type_symbol",
+            "U[1][1]",
+            "U[2][2]",
+            "U[3][3]",
+            "U[1][2]",
+            "U[1][3]",
+            "U[2][3]",
+        ] {
+            writeln!(self.writer, "_atom_site_anisotrop.{field}")
+                .map_err(|e| Error::from_io(e, None))?;
+        }
+        for row in self.anisotrop_rows.drain(..) {
+            writeln!(self.writer, "{row}").map_err(|e| Error::from_io(e, None))?;
+        }
+        writeln!(self.writer, "#").map_err(|e| Error::from_io(e, None))
+    }
+
+    /// Writes the `_atom_site` loop header.
+    ///
+    /// # Arguments
+    ///
+    /// * `with_model_num` - Whether to declare the trailing `pdbx_PDB_model_num` column.
+    fn write_atom_site_header(&mut self, with_model_num: bool) -> Result<(), Error> {
         writeln!(self.writer, "loop_").map_err(|e| Error::from_io(e, None))?;
         writeln!(self.writer, "_atom_site.group_PDB").map_err(|e| Error::from_io(e, None))?;
         writeln!(self.writer, "_atom_site.id").map_err(|e| Error::from_io(e, None))?;
@@ -221,20 +495,35 @@ impl<W: Write> WriterContext<W> {
         writeln!(self.writer, "_atom_site.auth_comp_id").map_err(|e| Error::from_io(e, None))?;
         writeln!(self.writer, "_atom_site.auth_asym_id").map_err(|e| Error::from_io(e, None))?;
         writeln!(self.writer, "_atom_site.auth_atom_id").map_err(|e| Error::from_io(e, None))?;
+        writeln!(self.writer, "_atom_site.pdbx_formal_charge")
+            .map_err(|e| Error::from_io(e, None))?;
+        if with_model_num {
+            writeln!(self.writer, "_atom_site.pdbx_PDB_model_num")
+                .map_err(|e| Error::from_io(e, None))?;
+        }
+        Ok(())
+    }
 
+    /// Writes `_atom_site` rows for one structure and assigns mmCIF atom IDs.
+    ///
+    /// # Arguments
+    ///
+    /// * `structure` - Structure whose atoms will be serialized.
+    /// * `model_num` - Model number appended to each row, or `None` for single-model output.
+    fn write_atom_rows(
+        &mut self,
+        structure: &Structure,
+        model_num: Option<usize>,
+    ) -> Result<(), Error> {
         self.atom_index_to_id.clear();
         self.residue_label_map.clear();
-        let mut entity_ids: HashMap<smol_str::SmolStr, usize> = HashMap::new();
-        let mut next_entity_id = 1usize;
+        self.atom_index_to_id.reserve(structure.atom_count());
+        self.residue_label_map.reserve(structure.residue_count());
+        let entity_ids = structure.entity_ids();
         let mut global_atom_index = 0usize;
 
-        for chain in structure.iter_chains() {
+        for (chain, &entity_id) in structure.iter_chains().zip(&entity_ids) {
             let chain_id = chain.id.clone();
-            let entity_id = *entity_ids.entry(chain_id.clone()).or_insert_with(|| {
-                let val = next_entity_id;
-                next_entity_id += 1;
-                val
-            });
 
             let mut polymer_seq_id = 0;
 
@@ -266,6 +555,7 @@ impl<W: Write> WriterContext<W> {
                         &chain_id,
                         entity_id,
                         &label_seq_id,
+                        model_num,
                     )?;
 
                     self.atom_index_to_id.insert(global_atom_index, atom_id);
@@ -274,7 +564,6 @@ impl<W: Write> WriterContext<W> {
                 }
             }
         }
-        writeln!(self.writer, "#").map_err(|e| Error::from_io(e, None))?;
         Ok(())
     }
 
@@ -288,6 +577,8 @@ impl<W: Write> WriterContext<W> {
     /// * `chain_id` - Parent chain identifier string.
     /// * `entity_id` - Numeric entity identifier assigned to the chain.
     /// * `label_seq_id` - Sequential residue index for polymers, or `.` for others.
+    /// * `model_num` - Optional model number written as the final column.
+    #[allow(clippy::too_many_arguments)]
     fn write_atom_record(
         &mut self,
         group_pdb: &str,
@@ -296,10 +587,15 @@ impl<W: Write> WriterContext<W> {
         chain_id: &str,
         entity_id: usize,
         label_seq_id: &str,
+        model_num: Option<usize>,
     ) -> Result<(), Error> {
         let atom_id = self.current_atom_id;
         let type_symbol = atom.element.symbol();
         let label_atom_id = quote_string(&atom.name);
+        let label_alt_id = atom
+            .alt_loc
+            .map(String::from)
+            .unwrap_or_else(|| ".".to_string());
         let label_comp_id = quote_string(&residue.name);
         let label_asym_id = quote_string(chain_id);
         let ins_code = residue
@@ -307,18 +603,31 @@ impl<W: Write> WriterContext<W> {
             .map(|c| c.to_string())
             .unwrap_or_else(|| "?".to_string());
 
-        let auth_seq_id = residue.id.to_string();
+        let auth_seq_id = residue.auth_id.unwrap_or(residue.id).to_string();
         let auth_comp_id = label_comp_id.clone();
         let auth_asym_id = label_asym_id.clone();
         let auth_atom_id = label_atom_id.clone();
+        let formal_charge = atom
+            .formal_charge
+            .map(|c| c.to_string())
+            .unwrap_or_else(|| "?".to_string());
+        let model_col = model_num.map(|n| format!(" {n}")).unwrap_or_default();
+
+        if let Some(u) = atom.anisou {
+            self.anisotrop_rows.push(format!(
+                "{atom_id} {type_symbol} {:.4} {:.4} {:.4} {:.4} {:.4} {:.4}",
+                u[0], u[1], u[2], u[3], u[4], u[5]
+            ));
+        }
 
         writeln!(
             self.writer,
-            "{group_pdb} {atom_id} {type_symbol} {label_atom_id} . {label_comp_id} {label_asym_id} {entity_id} {label_seq_id} {ins_code} {x:.3} {y:.3} {z:.3} 1.00 0.00 {auth_seq_id} {auth_comp_id} {auth_asym_id} {auth_atom_id}",
+            "{group_pdb} {atom_id} {type_symbol} {label_atom_id} {label_alt_id} {label_comp_id} {label_asym_id} {entity_id} {label_seq_id} {ins_code} {x:.prec$} {y:.prec$} {z:.prec$} {occupancy:.2} {b_factor:.2} {auth_seq_id} {auth_comp_id} {auth_asym_id} {auth_atom_id} {formal_charge}{model_col}",
             group_pdb = group_pdb,
             atom_id = atom_id,
             type_symbol = type_symbol,
             label_atom_id = label_atom_id,
+            label_alt_id = label_alt_id,
             label_comp_id = label_comp_id,
             label_asym_id = label_asym_id,
             entity_id = entity_id,
@@ -327,29 +636,56 @@ impl<W: Write> WriterContext<W> {
             x = atom.pos.x,
             y = atom.pos.y,
             z = atom.pos.z,
+            prec = self.coord_decimals,
+            occupancy = atom.occupancy,
+            b_factor = atom.b_factor,
             auth_seq_id = auth_seq_id,
             auth_comp_id = auth_comp_id,
             auth_asym_id = auth_asym_id,
-            auth_atom_id = auth_atom_id
+            auth_atom_id = auth_atom_id,
+            formal_charge = formal_charge,
+            model_col = model_col
         )
         .map_err(|e| Error::from_io(e, None))
     }
 
-    /// Serializes topology bonds into `_struct_conn` records with distances.
+    /// Serializes topology bonds and interactions into `_struct_conn` records with distances.
     ///
     /// # Arguments
     ///
     /// * `topology` - Topology whose bonds will be emitted.
+    /// * `interactions` - Non-covalent interactions appended after the covalent bonds.
     ///
     /// # Returns
     ///
-    /// [`Ok`] if all bonds were written or [`Error::InconsistentData`] when atom indices are
-    /// missing because coordinates were not emitted beforehand.
-    fn write_connections(&mut self, topology: &Topology) -> Result<(), Error> {
-        if topology.bond_count() == 0 {
+    /// [`Ok`] if all connections were written or [`Error::InconsistentData`] when atom
+    /// indices are missing because coordinates were not emitted beforehand.
+    fn write_connections(
+        &mut self,
+        topology: &Topology,
+        interactions: &[Interaction],
+    ) -> Result<(), Error> {
+        if topology.bond_count() == 0 && interactions.is_empty() {
             return Ok(());
         }
 
+        let bond_atoms = topology
+            .bonds()
+            .iter()
+            .flat_map(|b| [("bond", b.a1_idx), ("bond", b.a2_idx)]);
+        let interaction_atoms = interactions
+            .iter()
+            .flat_map(|i| [("interaction", i.a1_idx), ("interaction", i.a2_idx)]);
+        for (source, idx) in bond_atoms.chain(interaction_atoms) {
+            if !self.atom_index_to_id.contains_key(&idx) {
+                return Err(Error::inconsistent_data(
+                    "mmCIF",
+                    None,
+                    format!("{source} references atom index {idx} that was not written"),
+                ));
+            }
+        }
+
         writeln!(self.writer, "loop_").map_err(|e| Error::from_io(e, None))?;
         writeln!(self.writer, "_struct_conn.id").map_err(|e| Error::from_io(e, None))?;
         writeln!(self.writer, "_struct_conn.conn_type_id").map_err(|e| Error::from_io(e, None))?;
@@ -401,96 +737,147 @@ impl<W: Write> WriterContext<W> {
         writeln!(self.writer, "_struct_conn.pdbx_value_order")
             .map_err(|e| Error::from_io(e, None))?;
 
-        let atom_lookup: Vec<(&crate::model::chain::Chain, &Residue, &Atom)> =
+        let atom_lookup: Vec<(&Chain, &Residue, &Atom)> =
             topology.structure().iter_atoms_with_context().collect();
 
-        for (conn_idx, bond) in topology.bonds().iter().enumerate() {
-            let _ = self.atom_index_to_id.get(&bond.a1_idx).ok_or_else(|| {
-                Error::inconsistent_data(
-                    "mmCIF",
-                    None,
-                    format!(
-                        "bond references atom index {} that was not written",
-                        bond.a1_idx
-                    ),
-                )
-            })?;
-            let _ = self.atom_index_to_id.get(&bond.a2_idx).ok_or_else(|| {
-                Error::inconsistent_data(
-                    "mmCIF",
-                    None,
-                    format!(
-                        "bond references atom index {} that was not written",
-                        bond.a2_idx
-                    ),
-                )
-            })?;
-
-            let (chain1, res1, atom1) = atom_lookup[bond.a1_idx];
-            let (chain2, res2, atom2) = atom_lookup[bond.a2_idx];
-
-            let label_seq_1 = self
-                .residue_label_map
-                .get(&(chain1.id.to_string(), res1.id, res1.insertion_code))
-                .map(|s| s.as_str())
-                .unwrap_or("?");
-            let label_seq_2 = self
-                .residue_label_map
-                .get(&(chain2.id.to_string(), res2.id, res2.insertion_code))
-                .map(|s| s.as_str())
-                .unwrap_or("?");
-
-            let conn_id = format!("conn_{:04}", conn_idx + 1);
-            let conn_type_id = "covale";
-            let symmetry = "1_555";
-            let order_str = match bond.order {
-                BondOrder::Single => "SING",
-                BondOrder::Double => "DOUB",
-                BondOrder::Triple => "TRIP",
-                BondOrder::Aromatic => "AROM",
+        let bond_rows = topology.bonds().iter().map(|bond| {
+            let (conn_type_id, order_str) = match bond.order {
+                BondOrder::Single => ("covale", "SING"),
+                BondOrder::Double => ("covale", "DOUB"),
+                BondOrder::Triple => ("covale", "TRIP"),
+                BondOrder::Aromatic => ("covale", "AROM"),
+                BondOrder::Coordination => ("metalc", "?"),
             };
-            let dist = atom1.distance(atom2);
-
-            let ins1 = res1
-                .insertion_code
-                .map(|c| c.to_string())
-                .unwrap_or_else(|| "?".to_string());
-            let ins2 = res2
-                .insertion_code
-                .map(|c| c.to_string())
-                .unwrap_or_else(|| "?".to_string());
-
-            writeln!(
-                self.writer,
-                "{conn_id} {conn_type_id} {pt1_atom} . {pt1_res} {pt1_asym} {pt1_seq} {pt1_ins} {symmetry} {pt1_auth_asym} {pt1_auth_res} {pt1_auth_seq} {pt2_atom} . {pt2_res} {pt2_asym} {pt2_seq} {pt2_ins} {symmetry} {pt2_auth_asym} {pt2_auth_res} {pt2_auth_seq} {dist:.3} {order_str}",
-                conn_id = conn_id,
-                conn_type_id = conn_type_id,
-                pt1_atom = quote_string(&atom1.name),
-                pt1_res = quote_string(&res1.name),
-                pt1_asym = quote_string(&chain1.id),
-                pt1_seq = label_seq_1,
-                pt1_ins = ins1,
-                symmetry = symmetry,
-                pt1_auth_asym = quote_string(&chain1.id),
-                pt1_auth_res = quote_string(&res1.name),
-                pt1_auth_seq = res1.id,
-                pt2_atom = quote_string(&atom2.name),
-                pt2_res = quote_string(&res2.name),
-                pt2_asym = quote_string(&chain2.id),
-                pt2_seq = label_seq_2,
-                pt2_ins = ins2,
-                pt2_auth_asym = quote_string(&chain2.id),
-                pt2_auth_res = quote_string(&res2.name),
-                pt2_auth_seq = res2.id,
-                dist = dist,
-                order_str = order_str
-            )
-            .map_err(|e| Error::from_io(e, None))?;
+            (bond.a1_idx, bond.a2_idx, conn_type_id, order_str)
+        });
+        let interaction_rows = interactions.iter().map(|interaction| {
+            let conn_type_id = match interaction.kind {
+                InteractionKind::HydrogenBond => "hydrog",
+                InteractionKind::MetalCoordination => "metalc",
+            };
+            (interaction.a1_idx, interaction.a2_idx, conn_type_id, "?")
+        });
+
+        for (conn_idx, (a1_idx, a2_idx, conn_type_id, order_str)) in
+            bond_rows.chain(interaction_rows).enumerate()
+        {
+            self.write_connection_record(
+                conn_idx + 1,
+                conn_type_id,
+                atom_lookup[a1_idx],
+                atom_lookup[a2_idx],
+                order_str,
+            )?;
         }
         writeln!(self.writer, "#").map_err(|e| Error::from_io(e, None))?;
 
         Ok(())
     }
+
+    /// Formats a single `_struct_conn` row between two atoms.
+    ///
+    /// # Arguments
+    ///
+    /// * `conn_num` - One-based connection number used to build the row ID.
+    /// * `conn_type_id` - mmCIF connection type (`covale`, `hydrog`, or `metalc`).
+    /// * `partner1` - Chain, residue, and atom of the first partner.
+    /// * `partner2` - Chain, residue, and atom of the second partner.
+    /// * `order_str` - Value order code, or `?` when not applicable.
+    fn write_connection_record(
+        &mut self,
+        conn_num: usize,
+        conn_type_id: &str,
+        partner1: (&Chain, &Residue, &Atom),
+        partner2: (&Chain, &Residue, &Atom),
+        order_str: &str,
+    ) -> Result<(), Error> {
+        let (chain1, res1, atom1) = partner1;
+        let (chain2, res2, atom2) = partner2;
+
+        let label_seq_1 = self
+            .residue_label_map
+            .get(&(chain1.id.to_string(), res1.id, res1.insertion_code))
+            .map(|s| s.as_str())
+            .unwrap_or("?");
+        let label_seq_2 = self
+            .residue_label_map
+            .get(&(chain2.id.to_string(), res2.id, res2.insertion_code))
+            .map(|s| s.as_str())
+            .unwrap_or("?");
+
+        let conn_id = format!("conn_{conn_num:04}");
+        let symmetry = "1_555";
+        let dist = atom1.distance(atom2);
+
+        let ins1 = res1
+            .insertion_code
+            .map(|c| c.to_string())
+            .unwrap_or_else(|| "?".to_string());
+        let ins2 = res2
+            .insertion_code
+            .map(|c| c.to_string())
+            .unwrap_or_else(|| "?".to_string());
+
+        writeln!(
+            self.writer,
+            "{conn_id} {conn_type_id} {pt1_atom} . {pt1_res} {pt1_asym} {pt1_seq} {pt1_ins} {symmetry} {pt1_auth_asym} {pt1_auth_res} {pt1_auth_seq} {pt2_atom} . {pt2_res} {pt2_asym} {pt2_seq} {pt2_ins} {symmetry} {pt2_auth_asym} {pt2_auth_res} {pt2_auth_seq} {dist:.3} {order_str}",
+            conn_id = conn_id,
+            conn_type_id = conn_type_id,
+            pt1_atom = quote_string(&atom1.name),
+            pt1_res = quote_string(&res1.name),
+            pt1_asym = quote_string(&chain1.id),
+            pt1_seq = label_seq_1,
+            pt1_ins = ins1,
+            symmetry = symmetry,
+            pt1_auth_asym = quote_string(&chain1.id),
+            pt1_auth_res = quote_string(&res1.name),
+            pt1_auth_seq = res1.auth_id.unwrap_or(res1.id),
+            pt2_atom = quote_string(&atom2.name),
+            pt2_res = quote_string(&res2.name),
+            pt2_asym = quote_string(&chain2.id),
+            pt2_seq = label_seq_2,
+            pt2_ins = ins2,
+            pt2_auth_asym = quote_string(&chain2.id),
+            pt2_auth_res = quote_string(&res2.name),
+            pt2_auth_seq = res2.auth_id.unwrap_or(res2.id),
+            dist = dist,
+            order_str = order_str
+        )
+        .map_err(|e| Error::from_io(e, None))
+    }
+}
+
+/// Classifies a chain's polymer residues into an mmCIF `_entity_poly.type` value.
+///
+/// # Arguments
+///
+/// * `chain` - Chain whose protein and nucleic residues are inspected.
+///
+/// # Returns
+///
+/// `polypeptide(L)`, `polydeoxyribonucleotide`, `polyribonucleotide`, the DNA/RNA hybrid
+/// type, or `other` when amino acids and nucleotides are mixed.
+fn polymer_type(chain: &Chain) -> &'static str {
+    let (mut protein, mut dna, mut rna) = (false, false, false);
+    for standard in chain.iter_residues().filter_map(|r| r.standard_name) {
+        if standard.is_protein() {
+            protein = true;
+        } else if standard.is_nucleic() {
+            if standard.canonical_name().starts_with('D') {
+                dna = true;
+            } else {
+                rna = true;
+            }
+        }
+    }
+
+    match (protein, dna, rna) {
+        (true, false, false) => "polypeptide(L)",
+        (false, true, false) => "polydeoxyribonucleotide",
+        (false, false, true) => "polyribonucleotide",
+        (false, true, true) => "polydeoxyribonucleotide/polyribonucleotide hybrid",
+        _ => "other",
+    }
 }
 
 /// Wraps strings containing whitespace or quotes with CIF-safe quoting.
@@ -524,7 +911,7 @@ mod tests {
     use crate::model::atom::Atom;
     use crate::model::chain::Chain;
     use crate::model::residue::Residue;
-    use crate::model::topology::{Bond, Topology};
+    use crate::model::topology::{Bond, Interaction, InteractionKind, Topology};
     use crate::model::types::{BondOrder, Element, Point, ResidueCategory, StandardResidue};
 
     fn create_atom(name: &str, element: Element) -> Atom {
@@ -570,7 +957,8 @@ mod tests {
         let structure = build_test_structure();
         let mut buffer = Vec::new();
 
-        write_structure(&mut buffer, &structure).expect("structure write failed");
+        write_structure(&mut buffer, &structure, &WriteOptions::default())
+            .expect("structure write failed");
         let output = String::from_utf8(buffer).expect("invalid UTF-8");
 
         assert!(output.contains("data_bio_forge_export"));
@@ -610,7 +998,8 @@ mod tests {
         structure.add_chain(chain);
 
         let mut buffer = Vec::new();
-        write_structure(&mut buffer, &structure).expect("structure write failed");
+        write_structure(&mut buffer, &structure, &WriteOptions::default())
+            .expect("structure write failed");
         let output = String::from_utf8(buffer).expect("invalid UTF-8");
 
         assert!(output.contains("_entity_poly_seq.entity_id"));
@@ -621,6 +1010,124 @@ mod tests {
         assert!(!output.contains("1 3 HOH"));
     }
 
+    fn polymer_chain(id: &str, residues: &[(&str, StandardResidue)]) -> Chain {
+        let mut chain = Chain::new(id);
+        for (i, (name, std)) in (1..).zip(residues) {
+            let mut residue = create_residue(i, name, Some(*std), ResidueCategory::Standard);
+            residue.add_atom(create_atom("CA", Element::C));
+            chain.add_residue(residue);
+        }
+        chain
+    }
+
+    fn entity_poly_rows(output: &str) -> Vec<&str> {
+        output
+            .lines()
+            .skip_while(|l| *l != "_entity_poly.pdbx_strand_id")
+            .skip(1)
+            .take_while(|l| *l != "#")
+            .collect()
+    }
+
+    #[test]
+    fn write_entity_poly_lists_sequence_and_strands_per_entity() {
+        let mut structure = Structure::new();
+        let dimer = [("MET", StandardResidue::MET), ("TRP", StandardResidue::TRP)];
+        structure.add_chain(polymer_chain("A", &dimer));
+        structure.add_chain(polymer_chain("B", &[("GLY", StandardResidue::GLY)]));
+        structure.add_chain(polymer_chain("C", &dimer));
+        let mut water_chain = Chain::new("W");
+        let mut water = create_residue(
+            1,
+            "HOH",
+            Some(StandardResidue::HOH),
+            ResidueCategory::Hetero,
+        );
+        water.add_atom(create_atom("O", Element::O));
+        water_chain.add_residue(water);
+        structure.add_chain(water_chain);
+
+        let mut buffer = Vec::new();
+        write_structure(&mut buffer, &structure, &WriteOptions::default())
+            .expect("structure write failed");
+        let output = String::from_utf8(buffer).expect("invalid UTF-8");
+
+        assert_eq!(
+            entity_poly_rows(&output),
+            vec![
+                "1 polypeptide(L) no no MW A,C",
+                "2 polypeptide(L) no no G B",
+            ]
+        );
+        assert!(
+            output.find("_entity_poly.entity_id").unwrap()
+                < output.find("_entity_poly_seq.entity_id").unwrap()
+        );
+    }
+
+    #[test]
+    fn write_entity_poly_classifies_nucleic_polymer_types() {
+        let mut structure = Structure::new();
+        structure.add_chain(polymer_chain(
+            "D",
+            &[("DA", StandardResidue::DA), ("DT", StandardResidue::DT)],
+        ));
+        structure.add_chain(polymer_chain("R", &[("U", StandardResidue::U)]));
+        structure.add_chain(polymer_chain(
+            "H",
+            &[("DG", StandardResidue::DG), ("C", StandardResidue::C)],
+        ));
+
+        let mut buffer = Vec::new();
+        write_structure(&mut buffer, &structure, &WriteOptions::default())
+            .expect("structure write failed");
+        let output = String::from_utf8(buffer).expect("invalid UTF-8");
+
+        assert_eq!(
+            entity_poly_rows(&output),
+            vec![
+                "1 polydeoxyribonucleotide no no AT D",
+                "2 polyribonucleotide no no U R",
+                "3 'polydeoxyribonucleotide/polyribonucleotide hybrid' no no GC H",
+            ]
+        );
+    }
+
+    #[test]
+    fn write_entity_poly_is_omitted_without_polymers() {
+        let mut structure = Structure::new();
+        let mut chain = Chain::new("L");
+        let mut lig = create_residue(1, "LIG", None, ResidueCategory::Hetero);
+        lig.add_atom(create_atom("C1", Element::C));
+        chain.add_residue(lig);
+        structure.add_chain(chain);
+
+        let mut buffer = Vec::new();
+        write_structure(&mut buffer, &structure, &WriteOptions::default())
+            .expect("structure write failed");
+        let output = String::from_utf8(buffer).expect("invalid UTF-8");
+
+        assert!(!output.contains("_entity_poly"));
+    }
+
+    #[test]
+    fn structure_with_entity_poly_reads_back() {
+        let mut structure = Structure::new();
+        structure.add_chain(polymer_chain(
+            "A",
+            &[("ALA", StandardResidue::ALA), ("GLY", StandardResidue::GLY)],
+        ));
+
+        let mut buffer = Vec::new();
+        write_structure(&mut buffer, &structure, &WriteOptions::default())
+            .expect("structure write failed");
+        let context = crate::io::IoContext::new_default();
+        let parsed = crate::io::mmcif::reader::read(buffer.as_slice(), &context).expect("read");
+
+        assert_eq!(parsed.atom_count(), 2);
+        assert_eq!(parsed.chain("A").unwrap().sequence_one_letter(), "AG");
+    }
+
     #[test]
     fn label_seq_id_is_sequential_and_skips_non_polymers() {
         let mut structure = Structure::new();
@@ -651,7 +1158,8 @@ mod tests {
         structure.add_chain(chain);
 
         let mut buffer = Vec::new();
-        write_structure(&mut buffer, &structure).expect("structure write failed");
+        write_structure(&mut buffer, &structure, &WriteOptions::default())
+            .expect("structure write failed");
         let output = String::from_utf8(buffer).expect("invalid UTF-8");
 
         let lines: Vec<&str> = output.lines().collect();
@@ -697,8 +1205,8 @@ mod tests {
         let mut chain_b = Chain::new("B");
         let mut res_b = create_residue(
             1,
-            "ALA",
-            Some(StandardResidue::ALA),
+            "GLY",
+            Some(StandardResidue::GLY),
             ResidueCategory::Standard,
         );
         res_b.add_atom(create_atom("CA", Element::C));
@@ -706,7 +1214,8 @@ mod tests {
         structure.add_chain(chain_b);
 
         let mut buffer = Vec::new();
-        write_structure(&mut buffer, &structure).expect("structure write failed");
+        write_structure(&mut buffer, &structure, &WriteOptions::default())
+            .expect("structure write failed");
         let output = String::from_utf8(buffer).expect("invalid UTF-8");
 
         let atom_lines: Vec<&str> = output.lines().filter(|l| l.starts_with("ATOM")).collect();
@@ -721,13 +1230,168 @@ mod tests {
         assert_eq!(parts_b[7], "2");
     }
 
+    #[test]
+    fn chains_with_identical_sequences_share_entity_ids() {
+        let mut structure = Structure::new();
+        for (chain_id, res_name, std) in [
+            ("A", "ALA", StandardResidue::ALA),
+            ("B", "GLY", StandardResidue::GLY),
+            ("C", "ALA", StandardResidue::ALA),
+        ] {
+            let mut chain = Chain::new(chain_id);
+            let mut residue = create_residue(1, res_name, Some(std), ResidueCategory::Standard);
+            residue.add_atom(create_atom("CA", Element::C));
+            chain.add_residue(residue);
+            structure.add_chain(chain);
+        }
+        let mut water_chain = Chain::new("W");
+        let mut water = create_residue(
+            1,
+            "HOH",
+            Some(StandardResidue::HOH),
+            ResidueCategory::Standard,
+        );
+        water.add_atom(create_atom("O", Element::O));
+        water_chain.add_residue(water);
+        structure.add_chain(water_chain);
+
+        let mut buffer = Vec::new();
+        write_structure(&mut buffer, &structure, &WriteOptions::default())
+            .expect("structure write failed");
+        let output = String::from_utf8(buffer).expect("invalid UTF-8");
+
+        let entities: Vec<(String, String)> = output
+            .lines()
+            .filter(|l| l.starts_with("ATOM") || l.starts_with("HETATM"))
+            .map(|l| {
+                let parts: Vec<&str> = l.split_whitespace().collect();
+                (parts[6].to_string(), parts[7].to_string())
+            })
+            .collect();
+        let expected: Vec<(String, String)> = [("A", "1"), ("B", "2"), ("C", "1"), ("W", "3")]
+            .iter()
+            .map(|(c, e)| (c.to_string(), e.to_string()))
+            .collect();
+        assert_eq!(entities, expected);
+
+        assert_eq!(output.matches("1 1 ALA n").count(), 1);
+        assert!(output.contains("2 1 GLY n"));
+    }
+
+    #[test]
+    fn write_structure_emits_atom_site_anisotrop_for_refined_atoms() {
+        let mut structure = Structure::new();
+        let mut chain = Chain::new("A");
+        let mut residue = create_residue(1, "LIG", None, ResidueCategory::Hetero);
+        residue.add_atom(Atom::new("C1", Element::C, Point::origin()));
+        let mut refined = Atom::new("O2", Element::O, Point::new(1.2, 0.0, 0.0));
+        refined.anisou = Some([0.2406, 0.1892, -0.1262, 0.0044, -0.0121, -0.0181]);
+        residue.add_atom(refined);
+        chain.add_residue(residue);
+        structure.add_chain(chain);
+
+        let mut buffer = Vec::new();
+        write_structure(&mut buffer, &structure, &WriteOptions::default())
+            .expect("structure write failed");
+        let output = String::from_utf8(buffer).expect("invalid UTF-8");
+
+        let rows: Vec<&str> = output
+            .lines()
+            .skip_while(|l| *l != "_atom_site_anisotrop.U[2][3]")
+            .skip(1)
+            .take_while(|l| *l != "#")
+            .collect();
+        assert_eq!(
+            rows,
+            vec!["2 O 0.2406 0.1892 -0.1262 0.0044 -0.0121 -0.0181"]
+        );
+        assert!(
+            output.find("_atom_site.id").unwrap() < output.find("_atom_site_anisotrop.id").unwrap()
+        );
+    }
+
+    #[test]
+    fn anisotropic_parameters_survive_a_write_read_round_trip() {
+        let mut structure = Structure::new();
+        let mut chain = Chain::new("A");
+        let mut residue = create_residue(1, "LIG", None, ResidueCategory::Hetero);
+        residue.add_atom(Atom::new("C1", Element::C, Point::origin()));
+        let mut refined = Atom::new("O2", Element::O, Point::new(1.2, 0.0, 0.0));
+        refined.anisou = Some([0.2406, 0.1892, -0.1262, 0.0044, -0.0121, -0.0181]);
+        residue.add_atom(refined);
+        chain.add_residue(residue);
+        structure.add_chain(chain);
+
+        let mut buffer = Vec::new();
+        write_structure(&mut buffer, &structure, &WriteOptions::default())
+            .expect("structure write failed");
+        let reread =
+            crate::io::mmcif::reader::read(buffer.as_slice(), &crate::io::IoContext::new_default())
+                .expect("round trip read failed");
+
+        let residue = reread.chain("A").unwrap().residue(1, None).unwrap();
+        assert_eq!(residue.atom("C1").unwrap().anisou, None);
+        assert_eq!(
+            residue.atom("O2").unwrap().anisou,
+            Some([0.2406, 0.1892, -0.1262, 0.0044, -0.0121, -0.0181])
+        );
+    }
+
+    #[test]
+    fn write_structure_omits_anisotrop_without_parameters() {
+        let mut buffer = Vec::new();
+        write_structure(
+            &mut buffer,
+            &build_test_structure(),
+            &WriteOptions::default(),
+        )
+        .expect("structure write failed");
+        let output = String::from_utf8(buffer).expect("invalid UTF-8");
+
+        assert!(!output.contains("_atom_site_anisotrop"));
+    }
+
+    #[test]
+    fn write_structure_honors_coordinate_precision() {
+        let mut structure = Structure::new();
+        let mut chain = Chain::new("A");
+        let mut residue = create_residue(1, "LIG", None, ResidueCategory::Hetero);
+        residue.add_atom(Atom::new("C1", Element::C, Point::new(1.234567, -2.5, 0.0)));
+        chain.add_residue(residue);
+        structure.add_chain(chain);
+
+        let mut default_buf = Vec::new();
+        write_structure(&mut default_buf, &structure, &WriteOptions::default())
+            .expect("structure write failed");
+        let mut precise_buf = Vec::new();
+        let options = WriteOptions { coord_decimals: 5 };
+        write_structure(&mut precise_buf, &structure, &options).expect("structure write failed");
+
+        let coords = |buf: Vec<u8>| -> Vec<String> {
+            let output = String::from_utf8(buf).expect("invalid UTF-8");
+            let line = output
+                .lines()
+                .find(|l| l.starts_with("HETATM"))
+                .expect("atom line")
+                .to_string();
+            line.split_whitespace()
+                .skip(10)
+                .take(3)
+                .map(String::from)
+                .collect()
+        };
+        assert_eq!(coords(default_buf), vec!["1.235", "-2.500", "0.000"]);
+        assert_eq!(coords(precise_buf), vec!["1.23457", "-2.50000", "0.00000"]);
+    }
+
     #[test]
     fn write_topology_emits_struct_conn_records() {
         let structure = build_test_structure();
         let topology = Topology::new(structure.clone(), vec![Bond::new(0, 1, BondOrder::Single)]);
 
         let mut buffer = Vec::new();
-        write_topology(&mut buffer, &topology).expect("topology write failed");
+        write_topology(&mut buffer, &topology, &WriteOptions::default())
+            .expect("topology write failed");
         let output = String::from_utf8(buffer).expect("invalid UTF-8");
 
         assert!(output.contains("_struct_conn.id"));
@@ -748,14 +1412,132 @@ mod tests {
         assert_eq!(tokens[23], "SING");
     }
 
+    #[test]
+    fn write_topology_selection_renumbers_atoms_and_filters_bonds() {
+        let structure = build_test_structure();
+        let topology = Topology::new(
+            structure,
+            vec![
+                Bond::new(0, 1, BondOrder::Single),
+                Bond::new(1, 2, BondOrder::Single),
+            ],
+        );
+
+        let mut buffer = Vec::new();
+        write_topology_selection(&mut buffer, &topology, &[1, 2], &WriteOptions::default())
+            .expect("selection write failed");
+        let output = String::from_utf8(buffer).expect("invalid UTF-8");
+
+        let atom_ids: Vec<(&str, &str)> = output
+            .lines()
+            .filter(|l| l.starts_with("ATOM") || l.starts_with("HETATM"))
+            .map(|l| {
+                let tokens: Vec<&str> = l.split_whitespace().collect();
+                (tokens[1], tokens[3])
+            })
+            .collect();
+        assert_eq!(atom_ids, vec![("1", "CA"), ("2", "C1")]);
+
+        let conn_lines: Vec<&str> = output.lines().filter(|l| l.starts_with("conn_")).collect();
+        assert_eq!(conn_lines.len(), 1);
+        let tokens: Vec<&str> = conn_lines[0].split_whitespace().collect();
+        assert_eq!(tokens[2], "CA");
+        assert_eq!(tokens[12], "C1");
+    }
+
+    #[test]
+    fn write_topology_with_interactions_appends_hydrog_and_metalc_rows() {
+        let structure = build_test_structure();
+        let topology = Topology::new(structure, vec![Bond::new(0, 1, BondOrder::Single)]);
+        let interactions = [
+            Interaction::new(0, 2, InteractionKind::HydrogenBond),
+            Interaction::new(2, 1, InteractionKind::MetalCoordination),
+        ];
+
+        let mut buffer = Vec::new();
+        write_topology_with_interactions(
+            &mut buffer,
+            &topology,
+            &interactions,
+            &WriteOptions::default(),
+        )
+        .expect("topology write failed");
+        let output = String::from_utf8(buffer).expect("invalid UTF-8");
+
+        let rows: Vec<Vec<&str>> = output
+            .lines()
+            .filter(|l| l.starts_with("conn_"))
+            .map(|l| l.split_whitespace().collect())
+            .collect();
+        assert_eq!(rows.len(), 3);
+        assert_eq!(rows[0][1], "covale");
+        assert_eq!(rows[0][23], "SING");
+
+        assert_eq!(rows[1][0], "conn_0002");
+        assert_eq!(rows[1][1], "hydrog");
+        assert_eq!(rows[1][2], "N");
+        assert_eq!(rows[1][12], "C1");
+        assert_eq!(rows[1][22], format!("{:.3}", 77.0f64.sqrt()));
+        assert_eq!(rows[1][23], "?");
+
+        assert_eq!(rows[2][1], "metalc");
+        assert_eq!(rows[2][2], "C1");
+        assert_eq!(rows[2][12], "CA");
+    }
+
+    #[test]
+    fn write_topology_writes_coordination_bonds_as_metalc() {
+        let topology = Topology::new(
+            build_test_structure(),
+            vec![
+                Bond::new(0, 1, BondOrder::Single),
+                Bond::new(0, 2, BondOrder::Coordination),
+            ],
+        );
+
+        let mut buffer = Vec::new();
+        write_topology(&mut buffer, &topology, &WriteOptions::default())
+            .expect("topology write failed");
+        let output = String::from_utf8(buffer).expect("invalid UTF-8");
+
+        let rows: Vec<Vec<&str>> = output
+            .lines()
+            .filter(|l| l.starts_with("conn_"))
+            .map(|l| l.split_whitespace().collect())
+            .collect();
+        assert_eq!(rows.len(), 2);
+        assert_eq!((rows[0][1], rows[0][23]), ("covale", "SING"));
+        assert_eq!((rows[1][1], rows[1][23]), ("metalc", "?"));
+    }
+
+    #[test]
+    fn write_topology_with_interactions_rejects_unknown_atoms() {
+        let topology = Topology::new(build_test_structure(), Vec::new());
+        let interactions = [Interaction::new(0, 9, InteractionKind::HydrogenBond)];
+
+        let err = write_topology_with_interactions(
+            Vec::new(),
+            &topology,
+            &interactions,
+            &WriteOptions::default(),
+        )
+        .expect_err("should fail");
+
+        assert!(
+            matches!(err, Error::InconsistentData { details, .. } if details.contains("interaction references atom index 9"))
+        );
+    }
+
     #[test]
     fn write_connections_returns_error_when_atom_missing() {
         let structure = build_test_structure();
         let topology = Topology::new(structure.clone(), vec![Bond::new(0, 1, BondOrder::Single)]);
 
-        let mut ctx = WriterContext::new(Vec::new());
+        let mut ctx = WriterContext::new(Vec::new(), &WriteOptions::default());
 
-        let err = ctx.write_connections(&topology).expect_err("should fail");
+        let err = ctx
+            .write_connections(&topology, &[])
+            .expect_err("should fail");
         match err {
             Error::InconsistentData { details, .. } => {
                 assert!(details.contains("bond references atom index"));
@@ -763,4 +1545,142 @@ mod tests {
             _ => panic!("wrong error type"),
         }
     }
+
+    #[test]
+    fn write_models_emits_single_atom_site_loop_with_model_numbers() {
+        let first = build_test_structure();
+        let mut second = first.clone();
+        for atom in second.iter_atoms_mut() {
+            atom.pos.x += 1.0;
+        }
+
+        let mut buffer = Vec::new();
+        write_models(&mut buffer, &[first, second], &WriteOptions::default())
+            .expect("models write failed");
+        let output = String::from_utf8(buffer).expect("invalid UTF-8");
+
+        assert_eq!(output.matches("_atom_site.group_PDB").count(), 1);
+        assert_eq!(output.matches("data_").count(), 1);
+        let header_pos = output.find("_atom_site.pdbx_PDB_model_num").unwrap();
+        assert!(header_pos > output.find("_atom_site.auth_atom_id").unwrap());
+
+        let rows: Vec<Vec<&str>> = output
+            .lines()
+            .filter(|l| l.starts_with("ATOM") || l.starts_with("HETATM"))
+            .map(|l| l.split_whitespace().collect())
+            .collect();
+        assert_eq!(rows.len(), 6);
+        let ids: Vec<&str> = rows.iter().map(|r| r[1]).collect();
+        assert_eq!(ids, vec!["1", "2", "3", "4", "5", "6"]);
+        let models: Vec<&str> = rows.iter().map(|r| *r.last().unwrap()).collect();
+        assert_eq!(models, vec!["1", "1", "1", "2", "2", "2"]);
+        assert_eq!(rows[0][10], "0.000");
+        assert_eq!(rows[3][10], "1.000");
+    }
+
+    #[test]
+    fn formal_charges_round_trip_through_mmcif() {
+        let mut structure = build_test_structure();
+        structure.iter_atoms_mut().next().unwrap().formal_charge = Some(1);
+
+        let mut buffer = Vec::new();
+        write_structure(&mut buffer, &structure, &WriteOptions::default())
+            .expect("structure write failed");
+        let output = String::from_utf8(buffer).expect("invalid UTF-8");
+        assert!(output.contains("_atom_site.pdbx_formal_charge"));
+
+        let reread = crate::io::read_mmcif_structure(
+            std::io::Cursor::new(output),
+            &crate::io::IoContext::new_default(),
+        )
+        .expect("output should parse");
+        let charges: Vec<Option<i8>> = reread.iter_atoms().map(|a| a.formal_charge).collect();
+        assert_eq!(charges.iter().filter(|c| c.is_some()).count(), 1);
+        assert_eq!(
+            reread
+                .chain("A")
+                .unwrap()
+                .residue(1, None)
+                .unwrap()
+                .atom("N")
+                .unwrap()
+                .formal_charge,
+            Some(1)
+        );
+    }
+
+    #[test]
+    fn write_models_rejects_empty_model_list() {
+        let err = write_models(Vec::new(), &[], &WriteOptions::default()).unwrap_err();
+        assert!(matches!(err, Error::InconsistentData { .. }));
+    }
+
+    #[test]
+    fn write_structure_omits_model_number_column() {
+        let mut buffer = Vec::new();
+        write_structure(
+            &mut buffer,
+            &build_test_structure(),
+            &WriteOptions::default(),
+        )
+        .expect("structure write failed");
+        let output = String::from_utf8(buffer).expect("invalid UTF-8");
+
+        assert!(!output.contains("pdbx_PDB_model_num"));
+    }
+
+    #[test]
+    fn write_structure_emits_author_numbering_independently() {
+        let mut structure = build_test_structure();
+        let gly = structure.find_residue_mut("A", 1, None).unwrap();
+        gly.auth_id = Some(42);
+        let topology = Topology::new(structure, vec![Bond::new(0, 1, BondOrder::Single)]);
+
+        let mut buffer = Vec::new();
+        write_topology(&mut buffer, &topology, &WriteOptions::default())
+            .expect("topology write failed");
+        let output = String::from_utf8(buffer).expect("invalid UTF-8");
+
+        let rows: Vec<Vec<&str>> = output
+            .lines()
+            .filter(|l| l.starts_with("ATOM") || l.starts_with("HETATM"))
+            .map(|l| l.split_whitespace().collect())
+            .collect();
+        assert_eq!((rows[0][8], rows[0][15]), ("1", "42"));
+        assert_eq!((rows[2][8], rows[2][15]), (".", "2"));
+
+        let conn: Vec<&str> = output
+            .lines()
+            .find(|l| l.starts_with("conn_0001"))
+            .unwrap()
+            .split_whitespace()
+            .collect();
+        assert_eq!((conn[6], conn[11]), ("1", "42"));
+    }
+
+    #[test]
+    fn renumbered_residues_are_written_with_their_new_author_numbers() {
+        let cif = "data_x\nloop_\n_atom_site.group_PDB\n_atom_site.label_atom_id\n\
+            _atom_site.label_comp_id\n_atom_site.label_asym_id\n_atom_site.Cartn_x\n\
+            _atom_site.Cartn_y\n_atom_site.Cartn_z\n_atom_site.auth_seq_id\n\
+            ATOM CA ALA A 0.0 0.0 0.0 250\n";
+        let context = crate::io::IoContext::new_default();
+        let mut structure = crate::io::read_mmcif_structure(cif.as_bytes(), &context).unwrap();
+        structure.find_residue_mut("A", 250, None).unwrap().id = 1;
+
+        let mut buffer = Vec::new();
+        write_structure(&mut buffer, &structure, &WriteOptions::default()).unwrap();
+        let output = String::from_utf8(buffer.clone()).expect("invalid UTF-8");
+        let reread = crate::io::read_mmcif_structure(buffer.as_slice(), &context).unwrap();
+
+        let row: Vec<&str> = output
+            .lines()
+            .find(|l| l.starts_with("ATOM"))
+            .unwrap()
+            .split_whitespace()
+            .collect();
+        assert_eq!(row[15], "1");
+        assert!(reread.find_residue("A", 1, None).is_some());
+        assert!(reread.find_residue("A", 250, None).is_none());
+    }
 }
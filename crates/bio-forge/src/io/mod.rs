@@ -1,27 +1,58 @@
 //! IO front-end exposing structure parsing and export utilities for common biomolecular formats.
 //!
 //! The module re-exports format-specific readers and writers so applications can import PDB,
-//! mmCIF, or MOL2 data into `bio-forge` structures, enrich them via the operations pipeline,
-//! and export updated coordinates or topologies without touching lower-level submodules.
+//! mmCIF (optionally gzip-compressed), or MOL2 data into `bio-forge` structures, enrich them
+//! via the operations pipeline, and export updated coordinates or topologies (including SDF
+//! for ligands, CSV/TSV atom tables, or any atom selection) without touching lower-level
+//! submodules.
 
 mod context;
+mod csv;
 mod error;
+mod format;
 mod mmcif;
 mod mol2;
 mod pdb;
+mod sdf;
+mod selection;
 
-pub use pdb::reader::read as read_pdb_structure;
+pub use pdb::reader::{
+    read as read_pdb_structure, read_topology as read_pdb_topology,
+    read_topology_with_options as read_pdb_topology_with_options,
+    read_with_options as read_pdb_structure_with_options,
+};
+pub use pdb::symmetry::{
+    BiomtOperator, read_biomt as read_pdb_biomt_operators, read_mtrix as read_pdb_mtrix_operators,
+};
 pub use pdb::writer::{
+    LineEnding, PdbWriteConfig, write_selection as write_pdb_selection,
     write_structure as write_pdb_structure, write_topology as write_pdb_topology,
+    write_topology_selection as write_pdb_topology_selection,
 };
 
-pub use mmcif::reader::read as read_mmcif_structure;
+pub use mmcif::reader::{
+    read as read_mmcif_structure, read_with_options as read_mmcif_structure_with_options,
+};
 pub use mmcif::writer::{
+    WriteOptions, write_models as write_mmcif_models, write_selection as write_mmcif_selection,
     write_structure as write_mmcif_structure, write_topology as write_mmcif_topology,
+    write_topology_selection as write_mmcif_topology_selection,
+    write_topology_with_interactions as write_mmcif_topology_with_interactions,
 };
 
 pub use mol2::reader::read as read_mol2_template;
 
-pub use context::IoContext;
+pub use sdf::writer::write_topology as write_sdf_topology;
+
+pub use csv::writer::{
+    write_atoms as write_csv_atoms, write_atoms_delimited as write_delimited_atoms,
+};
+
+pub use format::{
+    StructureFormat, read_structure, read_structure_from_bytes, read_structure_from_path,
+    read_structure_with_options,
+};
+
+pub use context::{IoContext, ReadOptions};
 
 pub use error::Error;
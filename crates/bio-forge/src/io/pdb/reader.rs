@@ -2,18 +2,21 @@
 //!
 //! The parser ingests legacy PDB records, applies `IoContext` aliasing to residues, filters
 //! alternate locations by occupancy, and emits a fully linked [`Structure`] with terminal
-//! classifications and optional unit-cell vectors.
+//! classifications and optional unit-cell vectors. `ANISOU` records are attached to the atom
+//! whose `ATOM`/`HETATM` record precedes them. [`read_topology`] additionally honors
+//! `CONECT` records so bonds authored in the file survive without template-based rebuilding.
 
-use crate::io::context::IoContext;
+use crate::io::context::{IoContext, ReadOptions};
 use crate::io::error::Error;
 use crate::model::{
     atom::Atom,
     chain::Chain,
     residue::Residue,
     structure::Structure,
-    types::{Element, Point, ResidueCategory, ResiduePosition, StandardResidue},
+    topology::{Bond, Topology},
+    types::{BondOrder, Element, Point, ResidueCategory, StandardResidue},
 };
-use std::collections::{BTreeMap, HashMap};
+use std::collections::{BTreeMap, HashMap, HashSet};
 use std::io::BufRead;
 use std::path::Path;
 use std::str::FromStr;
@@ -32,6 +35,12 @@ struct ResKey {
     i_code: Option<char>,
 }
 
+/// Atom name paired with the alternate-location label the atom is buffered under.
+///
+/// The label is `None` unless [`ReadOptions::keep_alt_locs`] is set, so by default every
+/// conformer of an atom name competes for the same entry.
+type AtomKey = (String, Option<char>);
+
 /// Temporary residue buffer that holds atoms prior to canonicalization.
 ///
 /// The parser collects all atoms for a residue, tracks whether it originated from an
@@ -42,8 +51,20 @@ struct TempResidue {
     raw_name: String,
     /// Indicates if the residue was described via an `HETATM` entry.
     is_hetatm: bool,
-    /// Atom table keyed by atom name with occupancy values for altloc filtering.
-    atoms: HashMap<String, (f64, Atom)>,
+    /// First non-blank segment identifier (columns 73-76) seen for the residue.
+    segid: Option<String>,
+    /// Atom table keyed by atom name and kept label, with occupancy values for altloc
+    /// filtering.
+    atoms: HashMap<AtomKey, (f64, Atom)>,
+}
+
+/// Atom serials and `CONECT` pairs gathered while reading a topology.
+#[derive(Default)]
+struct Connectivity {
+    /// Maps atom serial numbers to the residue key and atom key they were parsed into.
+    serials: HashMap<usize, (ResKey, AtomKey)>,
+    /// Bonded serial pairs together with the line number of their `CONECT` record.
+    pairs: Vec<(usize, usize, usize)>,
 }
 
 /// Parses a legacy PDB stream into a [`Structure`] using the supplied IO context.
@@ -80,12 +101,168 @@ struct TempResidue {
 /// assert_eq!(structure.residue_count(), 1);
 /// ```
 pub fn read<R: BufRead>(reader: R, context: &IoContext) -> Result<Structure, Error> {
+    read_with_options(reader, context, &ReadOptions::default())
+}
+
+/// Parses a legacy PDB stream into a [`Structure`], applying parse-time [`ReadOptions`].
+///
+/// Behaves like [`read`], except that atoms rejected by `options` are dropped before the
+/// residues are assembled, and that [`ReadOptions::keep_alt_locs`] keeps every alternate
+/// conformer instead of the highest-occupancy one.
+///
+/// # Arguments
+///
+/// * `reader` - Any buffered reader that yields PDB lines.
+/// * `context` - Lookup tables and alias mappings that normalize residue names.
+/// * `options` - Filters such as whether authored hydrogens are kept.
+///
+/// # Returns
+///
+/// A populated [`Structure`] containing the retained atoms.
+///
+/// # Errors
+///
+/// Returns [`Error`] under the same conditions as [`read`].
+pub fn read_with_options<R: BufRead>(
+    reader: R,
+    context: &IoContext,
+    options: &ReadOptions,
+) -> Result<Structure, Error> {
+    read_records(reader, context, options, None)
+}
+
+/// Parses a legacy PDB stream into a [`Topology`] whose bonds come from `CONECT` records.
+///
+/// Atoms are read exactly as in [`read`]. Every serial listed on a `CONECT` line is resolved
+/// to its atom's global index and paired with the anchor serial as a single bond; duplicate
+/// bonds (including the mirrored records most files contain) are collapsed. Serials of
+/// alternate locations that were discarded resolve to the retained atom.
+///
+/// # Arguments
+///
+/// * `reader` - Any buffered reader that yields PDB lines.
+/// * `context` - Lookup tables and alias mappings that normalize residue names.
+///
+/// # Returns
+///
+/// A [`Topology`] pairing the parsed structure with the file-authored bonds.
+///
+/// # Errors
+///
+/// Returns [`Error`] for the same conditions as [`read`], and when a `CONECT` record holds a
+/// malformed serial or references a serial that no atom record declared.
+pub fn read_topology<R: BufRead>(reader: R, context: &IoContext) -> Result<Topology, Error> {
+    read_topology_with_options(reader, context, &ReadOptions::default())
+}
+
+/// Parses a legacy PDB stream into a [`Topology`], applying parse-time [`ReadOptions`].
+///
+/// Behaves like [`read_topology`], except that atoms rejected by `options` are dropped as in
+/// [`read_with_options`]. `CONECT` bonds touching a dropped atom are dropped with it.
+///
+/// # Arguments
+///
+/// * `reader` - Any buffered reader that yields PDB lines.
+/// * `context` - Lookup tables and alias mappings that normalize residue names.
+/// * `options` - Filters such as whether authored hydrogens are kept.
+///
+/// # Returns
+///
+/// A [`Topology`] pairing the retained atoms with the file-authored bonds between them.
+///
+/// # Errors
+///
+/// Returns [`Error`] under the same conditions as [`read_topology`].
+pub fn read_topology_with_options<R: BufRead>(
+    reader: R,
+    context: &IoContext,
+    options: &ReadOptions,
+) -> Result<Topology, Error> {
+    let mut connectivity = Connectivity::default();
+    let structure = read_records(reader, context, options, Some(&mut connectivity))?;
+
+    // Keyed by chain, residue number, insertion code, atom name and alternate location.
+    type SiteKey<'a> = (&'a str, i32, Option<char>, &'a str, Option<char>);
+    let global_indices: HashMap<SiteKey, usize> = structure
+        .iter_atoms_with_context()
+        .enumerate()
+        .map(|(idx, (chain, residue, atom))| {
+            (
+                (
+                    chain.id.as_str(),
+                    residue.id,
+                    residue.insertion_code,
+                    atom.name.as_str(),
+                    atom.alt_loc,
+                ),
+                idx,
+            )
+        })
+        .collect();
+
+    // Declared serials whose atom was filtered out resolve to `None` rather than an error.
+    let resolve = |serial: usize, line_num: usize| {
+        let (key, (atom_name, alt_loc)) = connectivity.serials.get(&serial).ok_or_else(|| {
+            Error::parse(
+                "PDB",
+                None,
+                line_num,
+                format!("CONECT references unknown atom serial {serial}"),
+            )
+        })?;
+        Ok(global_indices
+            .get(&(
+                key.chain_id.as_str(),
+                key.res_seq,
+                key.i_code,
+                atom_name.as_str(),
+                *alt_loc,
+            ))
+            .copied())
+    };
+
+    let mut bonds = Vec::with_capacity(connectivity.pairs.len());
+    for &(line_num, serial1, serial2) in &connectivity.pairs {
+        let idx1 = resolve(serial1, line_num)?;
+        let idx2 = resolve(serial2, line_num)?;
+        if let (Some(idx1), Some(idx2)) = (idx1, idx2)
+            && idx1 != idx2
+        {
+            bonds.push(Bond::new(idx1, idx2, BondOrder::Single));
+        }
+    }
+
+    bonds.sort_unstable();
+    bonds.dedup();
+
+    Ok(Topology::new(structure, bonds))
+}
+
+/// Shared parsing pass behind [`read_with_options`] and [`read_topology_with_options`].
+///
+/// # Arguments
+///
+/// * `reader` - Any buffered reader that yields PDB lines.
+/// * `context` - Lookup tables and alias mappings that normalize residue names.
+/// * `options` - Parse-time atom filters.
+/// * `connectivity` - When provided, collects atom serials and `CONECT` pairs.
+///
+/// # Returns
+///
+/// The populated [`Structure`].
+fn read_records<R: BufRead>(
+    reader: R,
+    context: &IoContext,
+    options: &ReadOptions,
+    mut connectivity: Option<&mut Connectivity>,
+) -> Result<Structure, Error> {
     let mut structure = Structure::new();
 
     let mut chain_order: Vec<String> = Vec::new();
     let mut chain_map: HashMap<String, BTreeMap<ResKey, TempResidue>> = HashMap::new();
 
     let mut line_num = 0;
+    let mut last_atom: Option<(Option<usize>, (ResKey, AtomKey))> = None;
 
     for line in reader.lines() {
         line_num += 1;
@@ -96,11 +273,42 @@ pub fn read<R: BufRead>(reader: R, context: &IoContext) -> Result<Structure, Err
             continue;
         }
 
+        if let Some(connectivity) = connectivity.as_deref_mut()
+            && line.starts_with("CONECT")
+        {
+            parse_conect(&line, line_num, &mut connectivity.pairs)?;
+            continue;
+        }
+
         let is_atom = line.starts_with("ATOM  ");
         let is_hetatm = line.starts_with("HETATM");
 
         if is_atom || is_hetatm {
-            parse_atom_record(&line, line_num, is_hetatm, &mut chain_order, &mut chain_map)?;
+            let stored = parse_atom_record(
+                &line,
+                line_num,
+                is_hetatm,
+                options.keep_alt_locs,
+                &mut chain_order,
+                &mut chain_map,
+                connectivity.as_deref_mut().map(|c| &mut c.serials),
+            )?;
+            last_atom = stored.map(|key| (parse_record_serial(&line), key));
+        } else if line.starts_with("ANISOU") {
+            let Some((serial, (res_key, atom_key))) = &last_atom else {
+                continue;
+            };
+            if parse_record_serial(&line) != *serial {
+                continue;
+            }
+            let anisou = parse_anisou(&line, line_num)?;
+            if let Some((_, atom)) = chain_map
+                .get_mut(&res_key.chain_id)
+                .and_then(|residues| residues.get_mut(res_key))
+                .and_then(|residue| residue.atoms.get_mut(atom_key))
+            {
+                atom.anisou = Some(anisou);
+            }
         }
     }
 
@@ -111,10 +319,15 @@ pub fn read<R: BufRead>(reader: R, context: &IoContext) -> Result<Structure, Err
             for (res_key, temp_res) in residues {
                 let (canonical_name, std_enum) = context.classify_residue(&temp_res.raw_name);
 
+                let distinct_names: HashSet<&str> = temp_res
+                    .atoms
+                    .keys()
+                    .map(|(name, _)| name.as_str())
+                    .collect();
                 let category = determine_category(
                     temp_res.is_hetatm,
                     std_enum,
-                    temp_res.atoms.len(),
+                    distinct_names.len(),
                     &temp_res.raw_name,
                     None,
                 )?;
@@ -126,10 +339,15 @@ pub fn read<R: BufRead>(reader: R, context: &IoContext) -> Result<Structure, Err
                     std_enum,
                     category,
                 );
+                residue.segid = temp_res.segid;
 
-                let mut sorted_atoms: Vec<Atom> =
-                    temp_res.atoms.into_values().map(|v| v.1).collect();
-                sorted_atoms.sort_by(|a, b| a.name.cmp(&b.name));
+                let mut sorted_atoms: Vec<Atom> = temp_res
+                    .atoms
+                    .into_values()
+                    .map(|v| v.1)
+                    .filter(|a| options.keep_hydrogens || a.element != Element::H)
+                    .collect();
+                sorted_atoms.sort_by(|a, b| (&a.name, a.alt_loc).cmp(&(&b.name, b.alt_loc)));
 
                 for atom in sorted_atoms {
                     residue.add_atom(atom);
@@ -141,7 +359,7 @@ pub fn read<R: BufRead>(reader: R, context: &IoContext) -> Result<Structure, Err
         }
     }
 
-    calculate_residue_positions(&mut structure);
+    structure.assign_positions();
 
     Ok(structure)
 }
@@ -153,28 +371,34 @@ pub fn read<R: BufRead>(reader: R, context: &IoContext) -> Result<Structure, Err
 /// * `line` - Raw PDB record line.
 /// * `line_num` - Current line number for diagnostics.
 /// * `is_hetatm` - Indicates whether the record originated from `HETATM`.
+/// * `keep_alt_locs` - Whether alternate conformers are buffered separately instead of
+///   competing on occupancy.
 /// * `chain_order` - Preserves the encounter order of chains.
 /// * `chain_map` - Aggregates temporary residues keyed by [`ResKey`].
+/// * `serials` - When provided, maps the record's atom serial to its residue and atom key.
 ///
 /// # Returns
 ///
-/// [`Ok`] on successful parsing; [`Error`] if numeric fields are malformed or lines are too short.
+/// The residue key and atom key when the record was kept, `None` when it lost the alternate
+/// location comparison to an earlier record; [`Error`] if numeric fields are malformed or
+/// lines are too short.
 fn parse_atom_record(
     line: &str,
     line_num: usize,
     is_hetatm: bool,
+    keep_alt_locs: bool,
     chain_order: &mut Vec<String>,
     chain_map: &mut HashMap<String, BTreeMap<ResKey, TempResidue>>,
-) -> Result<(), Error> {
+    serials: Option<&mut HashMap<usize, (ResKey, AtomKey)>>,
+) -> Result<Option<(ResKey, AtomKey)>, Error> {
     if line.len() < 54 {
         return Err(Error::parse("PDB", None, line_num, "Atom record too short"));
     }
 
     let atom_field = &line[12..16];
     let atom_name = atom_field.trim().to_string();
-    let _alt_loc = line.chars().nth(16).unwrap_or(' ');
-    let res_name = line[17..20].trim().to_string();
-    let chain_id = line.chars().nth(21).unwrap_or(' ').to_string();
+    let alt_loc = line.chars().nth(16).filter(|c| *c != ' ' && keep_alt_locs);
+    let (res_name, chain_id) = split_residue_and_chain(line);
     let res_seq_str = &line[22..26];
     let i_code_char = line.chars().nth(26).unwrap_or(' ');
 
@@ -187,6 +411,13 @@ fn parse_atom_record(
     } else {
         "1.00"
     };
+    let b_factor_str = if line.len() >= 66 {
+        &line[60..66]
+    } else {
+        "0.00"
+    };
+    let segid = line.get(72..76.min(line.len())).unwrap_or("").trim();
+    let formal_charge = line.get(78..80).and_then(parse_formal_charge);
     let element_str = if line.len() >= 78 {
         &line[76..78]
     } else {
@@ -219,9 +450,10 @@ fn parse_atom_record(
     let pos = Point::new(x, y, z);
 
     let occupancy = occ_str.trim().parse::<f64>().unwrap_or(1.0);
+    let b_factor = b_factor_str.trim().parse::<f64>().unwrap_or(0.0);
 
     let element = if element_str.trim().is_empty() {
-        parse_element_from_name(atom_field)
+        Element::from_atom_name(atom_field, is_hetatm)
     } else {
         Element::from_str(element_str.trim()).unwrap_or(Element::Unknown)
     };
@@ -238,32 +470,158 @@ fn parse_atom_record(
         i_code,
     };
 
-    let temp_res = residues.entry(res_key).or_insert_with(|| TempResidue {
-        raw_name: res_name,
-        is_hetatm,
-        atoms: HashMap::new(),
-    });
+    if let Some(serials) = serials
+        && let Ok(serial) = line[6..11].trim().parse::<usize>()
+    {
+        serials.insert(serial, (res_key.clone(), (atom_name.clone(), alt_loc)));
+    }
 
-    match temp_res.atoms.get(&atom_name) {
-        Some((old_occ, _)) => {
-            if occupancy > *old_occ {
-                temp_res.atoms.insert(
-                    atom_name.clone(),
-                    (occupancy, Atom::new(&atom_name, element, pos)),
-                );
-            }
+    let temp_res = residues
+        .entry(res_key.clone())
+        .or_insert_with(|| TempResidue {
+            raw_name: res_name,
+            is_hetatm,
+            segid: None,
+            atoms: HashMap::new(),
+        });
+    if temp_res.segid.is_none() && !segid.is_empty() {
+        temp_res.segid = Some(segid.to_string());
+    }
+
+    let mut atom = Atom::new(&atom_name, element, pos);
+    atom.occupancy = occupancy;
+    atom.b_factor = b_factor;
+    atom.formal_charge = formal_charge;
+    atom.alt_loc = alt_loc;
+
+    let atom_key = (atom_name, alt_loc);
+    let keep = match temp_res.atoms.get(&atom_key) {
+        Some((old_occ, _)) => occupancy > *old_occ,
+        None => true,
+    };
+    if !keep {
+        return Ok(None);
+    }
+    temp_res.atoms.insert(atom_key.clone(), (occupancy, atom));
+
+    Ok(Some((res_key, atom_key)))
+}
+
+/// Reads the atom serial from columns 7-11 of an atom-level record.
+fn parse_record_serial(line: &str) -> Option<usize> {
+    line.get(6..11)?.trim().parse().ok()
+}
+
+/// Parses the six anisotropic displacement components of an `ANISOU` record.
+///
+/// The file stores each `Uij` as an integer in units of 10⁻⁴ Å².
+///
+/// # Arguments
+///
+/// * `line` - Raw `ANISOU` record line.
+/// * `line_num` - Current line number for diagnostics.
+///
+/// # Returns
+///
+/// `[U11, U22, U33, U12, U13, U23]` in Å²; [`Error`] if a field is missing or malformed.
+fn parse_anisou(line: &str, line_num: usize) -> Result<[f64; 6], Error> {
+    let mut values = [0.0; 6];
+    for (i, value) in values.iter_mut().enumerate() {
+        let start = 28 + 7 * i;
+        *value = line
+            .get(start..start + 7)
+            .and_then(|field| field.trim().parse::<i64>().ok())
+            .ok_or_else(|| Error::parse("PDB", None, line_num, "Invalid ANISOU component"))?
+            as f64
+            / 1.0e4;
+    }
+    Ok(values)
+}
+
+/// Parses a `CONECT` record into bonded serial pairs.
+///
+/// # Arguments
+///
+/// * `line` - Raw `CONECT` record line.
+/// * `line_num` - Current line number for diagnostics.
+/// * `pairs` - Receives `(line_num, anchor_serial, bonded_serial)` entries.
+///
+/// # Returns
+///
+/// [`Ok`] on success; [`Error`] if the anchor is missing or any serial field is malformed.
+fn parse_conect(
+    line: &str,
+    line_num: usize,
+    pairs: &mut Vec<(usize, usize, usize)>,
+) -> Result<(), Error> {
+    let parse_serial = |start: usize| -> Result<Option<usize>, Error> {
+        let field = line.get(start..(start + 5).min(line.len())).unwrap_or("");
+        if field.trim().is_empty() {
+            return Ok(None);
         }
-        None => {
-            temp_res.atoms.insert(
-                atom_name.clone(),
-                (occupancy, Atom::new(&atom_name, element, pos)),
-            );
+        field
+            .trim()
+            .parse::<usize>()
+            .map(Some)
+            .map_err(|_| Error::parse("PDB", None, line_num, "Invalid CONECT serial number"))
+    };
+
+    let anchor = parse_serial(6)?
+        .ok_or_else(|| Error::parse("PDB", None, line_num, "CONECT record without atom serial"))?;
+
+    for start in [11, 16, 21, 26] {
+        if let Some(partner) = parse_serial(start)? {
+            pairs.push((line_num, anchor, partner));
         }
     }
 
     Ok(())
 }
 
+/// Splits columns 18-22 into the residue name and chain identifier.
+///
+/// Column 21 is ambiguous: it either extends a four-character residue name (`TIP3`, `POPC`)
+/// or holds the first character of a two-character chain ID. It is read as part of the
+/// residue name when column 22 is blank or the four characters form a known residue name,
+/// in which case the chain ID is column 22 alone; otherwise the residue name ends at column
+/// 20 and columns 21-22 form the chain ID.
+fn split_residue_and_chain(line: &str) -> (String, String) {
+    let column = |idx: usize| line.chars().nth(idx).unwrap_or(' ');
+    let (wide, narrow) = (column(20), column(21));
+    let four_char_name = line.get(17..21).filter(|name| {
+        !wide.is_whitespace()
+            && !name.contains(char::is_whitespace)
+            && (narrow.is_whitespace() || StandardResidue::from_name(name).is_some())
+    });
+
+    match four_char_name {
+        Some(name) => (name.to_string(), narrow.to_string()),
+        None if !wide.is_whitespace() => {
+            (line[17..20].trim().to_string(), format!("{wide}{narrow}"))
+        }
+        None => (line[17..20].trim().to_string(), narrow.to_string()),
+    }
+}
+
+/// Parses the two-character charge field from PDB columns 79-80 (e.g. `2+`, `1-`).
+///
+/// # Arguments
+///
+/// * `field` - Raw charge columns.
+///
+/// # Returns
+///
+/// The signed charge, or `None` when the field is blank or malformed.
+fn parse_formal_charge(field: &str) -> Option<i8> {
+    let mut chars = field.trim().chars();
+    let magnitude = chars.next()?.to_digit(10)? as i8;
+    match (chars.next(), chars.next()) {
+        (Some('+'), None) => Some(magnitude),
+        (Some('-'), None) => Some(-magnitude),
+        _ => None,
+    }
+}
+
 /// Converts a `CRYST1` record into orthogonal box vectors.
 ///
 /// # Arguments
@@ -336,82 +694,6 @@ fn parse_cryst1(line: &str, line_num: usize) -> Result<[[f64; 3]; 3], Error> {
     Ok([[v1_x, v1_y, v1_z], [v2_x, v2_y, v2_z], [v3_x, v3_y, v3_z]])
 }
 
-/// Infers an element symbol from an atom name when columns 77‑78 are blank.
-///
-/// Strips non-alphabetic characters, tries two-letter, then single-letter lookups, and
-/// ultimately falls back to [`Element::Unknown`].
-fn parse_element_from_name(field: &str) -> Element {
-    fn parse_single(ch: char) -> Option<Element> {
-        if !ch.is_ascii_alphabetic() {
-            return None;
-        }
-
-        let symbol = ch.to_ascii_uppercase().to_string();
-        Element::from_str(&symbol)
-            .ok()
-            .filter(|el| *el != Element::Unknown)
-    }
-
-    fn parse_pair(first: char, second: char) -> Option<Element> {
-        if !(first.is_ascii_alphabetic() && second.is_ascii_alphabetic()) {
-            return None;
-        }
-
-        let symbol = format!(
-            "{}{}",
-            first.to_ascii_uppercase(),
-            second.to_ascii_lowercase()
-        );
-        Element::from_str(&symbol)
-            .ok()
-            .filter(|el| *el != Element::Unknown)
-    }
-
-    let letters: Vec<(usize, char)> = field
-        .char_indices()
-        .filter(|(_, ch)| ch.is_ascii_alphabetic())
-        .collect();
-
-    if letters.is_empty() {
-        return Element::Unknown;
-    }
-
-    if letters.len() >= 2 {
-        let (first_idx, first_char) = letters[0];
-        let (second_idx, second_char) = letters[1];
-        let contiguous = second_idx == first_idx + first_char.len_utf8();
-        if let Some(el) = (first_idx == 0 && contiguous)
-            .then(|| parse_pair(first_char, second_char))
-            .flatten()
-        {
-            return el;
-        }
-    }
-
-    if let Some(el) = parse_single(letters[0].1) {
-        return el;
-    }
-
-    if letters.len() >= 2 {
-        for window in letters.windows(2) {
-            let (first_idx, first_char) = window[0];
-            let (second_idx, second_char) = window[1];
-            let contiguous = second_idx == first_idx + first_char.len_utf8();
-            if let Some(el) = contiguous
-                .then(|| parse_pair(first_char, second_char))
-                .flatten()
-            {
-                return el;
-            }
-            if let Some(el) = parse_single(second_char) {
-                return el;
-            }
-        }
-    }
-
-    Element::Unknown
-}
-
 /// Assigns a residue category using template matches or heuristics.
 ///
 /// Standard templates override everything else. Pure `ATOM` residues without templates
@@ -447,75 +729,10 @@ fn determine_category(
     }
 }
 
-/// Updates residue positional annotations (N/C terminus, 5'/3', etc.).
-///
-/// The algorithm locates the first/last polymer residues within each chain (excluding water
-/// and heterogens) and marks termini based on whether the polymer is protein or nucleic.
-fn calculate_residue_positions(structure: &mut Structure) {
-    for chain in structure.iter_chains_mut() {
-        let polymer_indices: Vec<usize> = chain
-            .iter_residues()
-            .enumerate()
-            .filter(|(_, res)| {
-                res.category == ResidueCategory::Standard
-                    && res.standard_name != Some(StandardResidue::HOH)
-            })
-            .map(|(i, _)| i)
-            .collect();
-
-        if polymer_indices.is_empty() {
-            continue;
-        }
-
-        let first_idx = *polymer_indices.first().unwrap();
-        let last_idx = *polymer_indices.last().unwrap();
-
-        for (i, residue) in chain.iter_residues_mut().enumerate() {
-            if residue.category != ResidueCategory::Standard
-                || residue.standard_name == Some(StandardResidue::HOH)
-            {
-                residue.position = ResiduePosition::None;
-                continue;
-            }
-
-            let is_protein = matches!(
-                residue.standard_name,
-                Some(std) if std.is_protein()
-            );
-            let is_nucleic = matches!(
-                residue.standard_name,
-                Some(std) if std.is_nucleic()
-            );
-
-            if i == first_idx {
-                if is_protein {
-                    residue.position = ResiduePosition::NTerminal;
-                } else if is_nucleic {
-                    residue.position = ResiduePosition::FivePrime;
-                } else {
-                    residue.position = ResiduePosition::Internal;
-                }
-            } else if i == last_idx {
-                if is_protein {
-                    residue.position = ResiduePosition::CTerminal;
-                } else if is_nucleic {
-                    residue.position = ResiduePosition::ThreePrime;
-                } else {
-                    residue.position = ResiduePosition::Internal;
-                }
-            } else if polymer_indices.contains(&i) {
-                residue.position = ResiduePosition::Internal;
-            } else {
-                residue.position = ResiduePosition::None;
-            }
-        }
-    }
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::io::context::IoContext;
+    use crate::io::context::{IoContext, ReadOptions};
     use crate::model::types::{Element, ResidueCategory, ResiduePosition, StandardResidue};
     use std::io::Cursor;
 
@@ -575,6 +792,73 @@ mod tests {
         assert_eq!(gly.position, ResiduePosition::CTerminal);
     }
 
+    #[test]
+    fn read_with_options_drops_hydrogens_when_requested() {
+        const PDB_DATA: &str = "\
+            ATOM      1  N   GLY A   1       0.000   0.000   0.000  1.00 20.00           N\n\
+            ATOM      2  CA  GLY A   1       1.458   0.000   0.000  1.00 20.00           C\n\
+            ATOM      3  H   GLY A   1      -0.500   0.800   0.000  1.00 20.00           H\n\
+            HETATM    4  O   HOH B   1       5.000   5.000   5.000  1.00 20.00           O\n\
+            HETATM    5  H1  HOH B   1       5.900   5.000   5.000  1.00 20.00           H\n\
+            END\n";
+        let context = IoContext::new_default();
+        let options = ReadOptions {
+            keep_hydrogens: false,
+            ..ReadOptions::default()
+        };
+
+        let stripped =
+            read_with_options(Cursor::new(PDB_DATA.as_bytes()), &context, &options).unwrap();
+        let full = parse_structure(PDB_DATA);
+
+        assert_eq!(stripped.atom_count(), 3);
+        assert!(stripped.iter_atoms().all(|a| a.element != Element::H));
+        assert!(stripped.iter_atoms().all(|a| !a.generated));
+        assert_eq!(full.atom_count(), 5);
+        let water = stripped.chain("B").unwrap().residue(1, None).unwrap();
+        assert_eq!(
+            water.category,
+            full.chain("B").unwrap().residue(1, None).unwrap().category
+        );
+    }
+
+    #[test]
+    fn read_attaches_anisou_to_preceding_retained_atom() {
+        const PDB_DATA: &str = "\
+            ATOM      1  N   GLY A   1       0.000   0.000   0.000  1.00 20.00           N\n\
+            ANISOU    1  N   GLY A   1     2406   1892  -1262     44   -121   -181       N\n\
+            ATOM      2  CA AGLY A   1       1.458   0.000   0.000  0.60 20.00           C\n\
+            ANISOU    2  CA AGLY A   1     1000   1000   1000      0      0      0       C\n\
+            ATOM      3  CA BGLY A   1       1.500   0.000   0.000  0.40 20.00           C\n\
+            ANISOU    3  CA BGLY A   1     9999   9999   9999      0      0      0       C\n\
+            ATOM      4  C   GLY A   1       2.000   1.000   0.000  1.00 20.00           C\n\
+            ANISOU    9  C   GLY A   1     5000   5000   5000      0      0      0       C\n\
+            END\n";
+
+        let structure = parse_structure(PDB_DATA);
+
+        let gly = structure.chain("A").unwrap().residue(1, None).unwrap();
+        let n = gly.atom("N").unwrap().anisou.expect("N anisou");
+        let expected = [0.2406, 0.1892, -0.1262, 0.0044, -0.0121, -0.0181];
+        for (actual, expected) in n.iter().zip(expected) {
+            assert!((actual - expected).abs() < 1e-9);
+        }
+        assert_eq!(
+            gly.atom("CA").unwrap().anisou,
+            Some([0.1, 0.1, 0.1, 0.0, 0.0, 0.0])
+        );
+        assert_eq!(gly.atom("C").unwrap().anisou, None);
+    }
+
+    #[test]
+    fn read_rejects_malformed_anisou_record() {
+        const PDB_DATA: &str = "\
+            ATOM      1  N   GLY A   1       0.000   0.000   0.000  1.00 20.00           N\n\
+            ANISOU    1  N   GLY A   1     2406   abcd  -1262     44   -121   -181       N\n";
+
+        assert!(parse_result(PDB_DATA).is_err());
+    }
+
     #[test]
     fn read_aliases_water_and_applies_occupancy_filter() {
         const PDB_DATA: &str = "\
@@ -602,6 +886,19 @@ mod tests {
         assert_eq!(ion.atom("NA").unwrap().name, "NA");
     }
 
+    #[test]
+    fn read_parses_b_factor_column() {
+        const PDB_DATA: &str = "\
+            ATOM      1  N   GLY A   1       0.000   0.000   0.000  1.00 91.50           N\n\
+            ATOM      2  CA  GLY A   1       1.000   0.000   0.000  1.00\n";
+
+        let structure = parse_structure(PDB_DATA);
+        let residue = structure.find_residue("A", 1, None).unwrap();
+
+        assert_eq!(residue.atom("N").unwrap().b_factor, 91.5);
+        assert_eq!(residue.atom("CA").unwrap().b_factor, 0.0);
+    }
+
     #[test]
     fn read_handles_scrambled_chain_and_residue_records() {
         const PDB_DATA: &str = "\
@@ -725,6 +1022,108 @@ mod tests {
         );
     }
 
+    #[test]
+    fn read_with_options_keeps_labeled_alternate_conformers() {
+        const PDB_DATA: &str = "\
+            ATOM      1  N   GLY D   1       0.000   0.000   0.000  1.00 12.00           N\n\
+            ATOM      2  CA AGLY D   1       1.000   0.000   0.000  0.40 12.00           C\n\
+            ANISOU    2  CA AGLY D   1     1000   1000   1000      0      0      0       C\n\
+            ATOM      3  CA BGLY D   1       2.000   0.000   0.000  0.60 12.00           C\n\
+            CONECT    1    3\n\
+            END\n";
+        let options = ReadOptions {
+            keep_alt_locs: true,
+            ..ReadOptions::default()
+        };
+
+        let mut cursor = Cursor::new(PDB_DATA.as_bytes());
+        let topology =
+            read_topology_with_options(&mut cursor, &IoContext::new_default(), &options).unwrap();
+        let structure = topology.structure();
+        let residue = structure.chain("D").unwrap().residue(1, None).unwrap();
+
+        let labels: Vec<_> = residue
+            .iter_atoms()
+            .map(|a| (a.name.to_string(), a.alt_loc))
+            .collect();
+        assert_eq!(
+            labels,
+            vec![
+                ("CA".to_string(), Some('A')),
+                ("CA".to_string(), Some('B')),
+                ("N".to_string(), None),
+            ]
+        );
+        assert!(residue.atoms()[0].anisou.is_some());
+        assert!(residue.atoms()[1].anisou.is_none());
+        assert_eq!(topology.bonds(), &[Bond::new(1, 2, BondOrder::Single)]);
+        assert!(structure.validate().is_empty());
+
+        let collapsed = parse_structure(PDB_DATA);
+        assert!(collapsed.iter_atoms().all(|a| a.alt_loc.is_none()));
+        assert_eq!(collapsed.atom_count(), 2);
+    }
+
+    #[test]
+    fn read_parses_two_character_chain_ids() {
+        let pdb = "\
+ATOM      1  CA  GLY A   1       0.000   0.000   0.000  1.00 20.00           C
+ATOM      2  CA  GLYAB   1       3.800   0.000   0.000  1.00 20.00           C
+";
+        let structure = parse_structure(pdb);
+
+        let ids: Vec<&str> = structure.iter_chains().map(|c| c.id.as_str()).collect();
+        assert_eq!(ids, vec!["A", "AB"]);
+    }
+
+    #[test]
+    fn read_keeps_four_character_residue_names_out_of_chain_id() {
+        let pdb = "\
+HETATM    1  OH2 TIP3W   1       0.000   0.000   0.000  1.00 20.00           O
+HETATM    2  P   POPC    2       5.000   0.000   0.000  1.00 20.00           P
+ATOM      3  CA  GLYAB   1       3.800   0.000   0.000  1.00 20.00           C
+";
+        let structure = parse_structure(pdb);
+
+        let ids: Vec<&str> = structure.iter_chains().map(|c| c.id.as_str()).collect();
+        assert_eq!(ids, vec!["W", " ", "AB"]);
+        let water = structure.find_residue("W", 1, None).unwrap();
+        assert_eq!(water.standard_name, Some(StandardResidue::HOH));
+        assert_eq!(structure.find_residue(" ", 2, None).unwrap().name, "POPC");
+        assert_eq!(structure.find_residue("AB", 1, None).unwrap().name, "GLY");
+    }
+
+    #[test]
+    fn read_parses_formal_charge_columns() {
+        let pdb = "\
+HETATM    1 MG    MG A   1       0.000   0.000   0.000  1.00 20.00          MG2+
+HETATM    2 CL    CL A   2       3.000   0.000   0.000  1.00 20.00          CL1-
+HETATM    3 NA    NA A   3       6.000   0.000   0.000  1.00 20.00          NA
+";
+        let structure = parse_structure(pdb);
+
+        let charges: Vec<Option<i8>> = structure.iter_atoms().map(|a| a.formal_charge).collect();
+        assert_eq!(charges, vec![Some(2), Some(-1), None]);
+        assert_eq!(parse_formal_charge("??"), None);
+    }
+
+    #[test]
+    fn read_parses_segment_identifiers() {
+        let pdb = "\
+ATOM      1  CA  GLY A   1       0.000   0.000   0.000  1.00 20.00      PROA C
+ATOM      2  CA  GLY B   1       3.800   0.000   0.000  1.00 20.00           C
+ATOM      3  CA  GLY C   1       7.600   0.000   0.000  1.00 20.00      WAT
+";
+        let structure = parse_structure(pdb);
+
+        let segids: Vec<Option<&str>> = structure
+            .iter_chains()
+            .flat_map(|c| c.iter_residues())
+            .map(|r| r.segid.as_deref())
+            .collect();
+        assert_eq!(segids, vec![Some("PROA"), None, Some("WAT")]);
+    }
+
     #[test]
     fn read_supports_residues_with_insertion_codes() {
         const PDB_DATA: &str = "\
@@ -755,6 +1154,96 @@ mod tests {
         );
     }
 
+    #[test]
+    fn read_topology_builds_deduplicated_bonds_from_conect_records() {
+        const PDB_DATA: &str = "\
+            HETATM    1  O1  LIG A   1       0.000   0.000   0.000  1.00  0.00           O\n\
+            HETATM    2  C2  LIG A   1       1.400   0.000   0.000  1.00  0.00           C\n\
+            HETATM    3  C1  LIG A   1       2.800   0.000   0.000  1.00  0.00           C\n\
+            CONECT    1    2\n\
+            CONECT    2    1    3\n\
+            CONECT    3    2\n\
+            END\n";
+
+        let mut cursor = Cursor::new(PDB_DATA.as_bytes());
+        let topology = read_topology(&mut cursor, &IoContext::new_default()).unwrap();
+
+        let names: Vec<&str> = topology
+            .structure()
+            .iter_atoms()
+            .map(|a| a.name.as_str())
+            .collect();
+        assert_eq!(names, vec!["C1", "C2", "O1"]);
+        assert_eq!(
+            topology.bonds(),
+            &[
+                Bond::new(0, 1, BondOrder::Single),
+                Bond::new(1, 2, BondOrder::Single),
+            ]
+        );
+    }
+
+    #[test]
+    fn read_topology_with_options_drops_bonds_to_filtered_hydrogens() {
+        const PDB_DATA: &str = "\
+            HETATM    1  O1  LIG A   1       0.000   0.000   0.000  1.00  0.00           O\n\
+            HETATM    2  C1  LIG A   1       1.400   0.000   0.000  1.00  0.00           C\n\
+            HETATM    3  H1  LIG A   1      -0.900   0.000   0.000  1.00  0.00           H\n\
+            CONECT    1    2    3\n\
+            CONECT    3    1\n\
+            END\n";
+        let options = ReadOptions {
+            keep_hydrogens: false,
+            ..ReadOptions::default()
+        };
+
+        let mut cursor = Cursor::new(PDB_DATA.as_bytes());
+        let topology =
+            read_topology_with_options(&mut cursor, &IoContext::new_default(), &options).unwrap();
+
+        assert_eq!(topology.atom_count(), 2);
+        assert!(
+            topology
+                .structure()
+                .iter_atoms()
+                .all(|a| a.element != Element::H)
+        );
+        assert_eq!(topology.bonds(), &[Bond::new(0, 1, BondOrder::Single)]);
+    }
+
+    #[test]
+    fn read_topology_errors_on_unknown_conect_serial() {
+        const PDB_DATA: &str = "\
+            HETATM    1  C1  LIG A   1       0.000   0.000   0.000  1.00  0.00           C\n\
+            CONECT    1   42\n";
+
+        let mut cursor = Cursor::new(PDB_DATA.as_bytes());
+        let err = read_topology(&mut cursor, &IoContext::new_default()).unwrap_err();
+
+        match err {
+            Error::Parse {
+                line_number,
+                details,
+                ..
+            } => {
+                assert_eq!(line_number, 2);
+                assert!(details.contains("42"));
+            }
+            other => panic!("unexpected error: {other:?}"),
+        }
+    }
+
+    #[test]
+    fn read_ignores_conect_records() {
+        const PDB_DATA: &str = "\
+            HETATM    1  C1  LIG A   1       0.000   0.000   0.000  1.00  0.00           C\n\
+            CONECT    1   42\n";
+
+        let structure = parse_structure(PDB_DATA);
+
+        assert_eq!(structure.atom_count(), 1);
+    }
+
     #[test]
     fn read_errors_on_unknown_standard_atom_record() {
         const PDB_DATA: &str = "\
@@ -773,17 +1262,17 @@ mod tests {
 
     #[test]
     fn parse_element_handles_common_atom_name_patterns() {
-        assert_eq!(parse_element_from_name(" CA "), Element::C);
-        assert_eq!(parse_element_from_name(" N  "), Element::N);
-        assert_eq!(parse_element_from_name(" C1 "), Element::C);
-        assert_eq!(parse_element_from_name("1HG1"), Element::H);
-        assert_eq!(parse_element_from_name(" HG "), Element::H);
-        assert_eq!(parse_element_from_name("FE  "), Element::Fe);
-        assert_eq!(parse_element_from_name("ZN  "), Element::Zn);
-        assert_eq!(parse_element_from_name("BR  "), Element::Br);
-        assert_eq!(parse_element_from_name("CL  "), Element::Cl);
-        assert_eq!(parse_element_from_name("HG  "), Element::Hg);
-        assert_eq!(parse_element_from_name("Se  "), Element::Se);
-        assert_eq!(parse_element_from_name("XE  "), Element::Xe);
+        assert_eq!(Element::from_atom_name(" CA ", true), Element::C);
+        assert_eq!(Element::from_atom_name(" N  ", true), Element::N);
+        assert_eq!(Element::from_atom_name(" C1 ", true), Element::C);
+        assert_eq!(Element::from_atom_name("1HG1", true), Element::H);
+        assert_eq!(Element::from_atom_name(" HG ", true), Element::H);
+        assert_eq!(Element::from_atom_name("FE  ", true), Element::Fe);
+        assert_eq!(Element::from_atom_name("ZN  ", true), Element::Zn);
+        assert_eq!(Element::from_atom_name("BR  ", true), Element::Br);
+        assert_eq!(Element::from_atom_name("CL  ", true), Element::Cl);
+        assert_eq!(Element::from_atom_name("HG  ", true), Element::Hg);
+        assert_eq!(Element::from_atom_name("Se  ", true), Element::Se);
+        assert_eq!(Element::from_atom_name("XE  ", true), Element::Xe);
     }
 }
@@ -0,0 +1,356 @@
+//! Parsers for crystallographic symmetry operators stored in PDB headers.
+//!
+//! `REMARK 350 BIOMT` records describe how to build the biological assembly from the
+//! asymmetric unit, while `MTRIX` records list non-crystallographic symmetry operators. Both
+//! are converted into rigid [`Isometry3`] transforms; BIOMT operators additionally carry the
+//! chains named by their `APPLY THE FOLLOWING TO CHAINS:` record, ready for
+//! `ops::build_assembly`, while MTRIX operators feed `ops::apply_symmetry`.
+
+use crate::io::error::Error;
+use nalgebra::{Isometry3, Matrix3, Rotation3, Translation3, UnitQuaternion};
+use std::io::BufRead;
+
+/// Biological-assembly operator read from `REMARK 350 BIOMT` records.
+#[derive(Debug, Clone, PartialEq)]
+pub struct BiomtOperator {
+    /// Chain identifiers the operator applies to; empty when the file names no chains, in
+    /// which case the operator applies to every chain.
+    pub chains: Vec<String>,
+    /// Rigid transform mapping asymmetric-unit coordinates onto the assembly copy.
+    pub transform: Isometry3<f64>,
+}
+
+/// Operator rows collected while scanning the file, keyed by operator serial.
+struct PendingOperator {
+    /// Serial number declared in the record.
+    serial: String,
+    /// Rotation matrix rows followed by the translation component.
+    rows: [[f64; 4]; 3],
+    /// Bit mask of the rows that have been populated.
+    seen: u8,
+}
+
+/// Reads the `REMARK 350 BIOMT` operators of the first biomolecule in a PDB file.
+///
+/// Only the operators listed under the first `BIOMOLECULE:` entry are returned, in the order
+/// of their serial numbers as they appear in the file. Each operator carries the chain list
+/// of the `APPLY THE FOLLOWING TO CHAINS:` record (including `AND CHAINS:` continuation
+/// lines) that precedes it.
+///
+/// # Arguments
+///
+/// * `reader` - Buffered source containing PDB text.
+///
+/// # Returns
+///
+/// The list of assembly operators with their target chains; empty when the file has no
+/// BIOMT records.
+///
+/// # Errors
+///
+/// Returns [`Error::Parse`] for malformed numeric fields or [`Error::InconsistentData`] when
+/// an operator lacks one of its three matrix rows.
+///
+/// # Examples
+///
+/// ```
+/// use bio_forge::io::read_pdb_biomt_operators;
+/// use std::io::Cursor;
+///
+/// let pdb = "\
+/// REMARK 350 BIOMOLECULE: 1\n\
+/// REMARK 350   BIOMT1   1  1.000000  0.000000  0.000000        0.00000\n\
+/// REMARK 350   BIOMT2   1  0.000000  1.000000  0.000000        0.00000\n\
+/// REMARK 350   BIOMT3   1  0.000000  0.000000  1.000000        0.00000\n";
+/// let operators = read_pdb_biomt_operators(Cursor::new(pdb)).unwrap();
+/// assert_eq!(operators.len(), 1);
+/// assert!(operators[0].chains.is_empty());
+/// ```
+pub fn read_biomt<R: BufRead>(reader: R) -> Result<Vec<BiomtOperator>, Error> {
+    let mut operators = Vec::new();
+    let mut pending = Vec::new();
+    let mut chains: Vec<String> = Vec::new();
+    let mut biomolecules_seen = 0;
+
+    for (idx, line) in reader.lines().enumerate() {
+        let line_num = idx + 1;
+        let line = line.map_err(|e| Error::from_io(e, None))?;
+
+        if !line.starts_with("REMARK 350") {
+            continue;
+        }
+        if line.contains("BIOMOLECULE:") {
+            biomolecules_seen += 1;
+            continue;
+        }
+        if biomolecules_seen > 1 {
+            break;
+        }
+        if let Some((prefix, list)) = line.split_once("CHAINS:") {
+            if prefix.contains("APPLY THE FOLLOWING") {
+                collect_biomt(&mut operators, std::mem::take(&mut pending), &chains)?;
+                chains.clear();
+            }
+            chains.extend(
+                list.split(',')
+                    .map(str::trim)
+                    .filter(|id| !id.is_empty())
+                    .map(String::from),
+            );
+            continue;
+        }
+
+        let tokens: Vec<&str> = line.split_whitespace().collect();
+        let Some(record) = tokens.get(2).filter(|t| t.starts_with("BIOMT")) else {
+            continue;
+        };
+        if tokens.len() < 8 {
+            return Err(Error::parse(
+                "PDB",
+                None,
+                line_num,
+                "BIOMT record too short",
+            ));
+        }
+
+        add_row(&mut pending, record, tokens[3], &tokens[4..8], line_num)?;
+    }
+
+    collect_biomt(&mut operators, pending, &chains)?;
+    Ok(operators)
+}
+
+/// Finalizes the pending operators of one chain group and appends them with their chains.
+fn collect_biomt(
+    operators: &mut Vec<BiomtOperator>,
+    pending: Vec<PendingOperator>,
+    chains: &[String],
+) -> Result<(), Error> {
+    operators.extend(
+        finalize(pending)?
+            .into_iter()
+            .map(|transform| BiomtOperator {
+                chains: chains.to_vec(),
+                transform,
+            }),
+    );
+    Ok(())
+}
+
+/// Reads the `MTRIX` non-crystallographic symmetry operators from a PDB file.
+///
+/// Operators flagged as already applied (`iGiven` = 1 in column 60) are skipped because
+/// their copies are present in the coordinate section.
+///
+/// # Arguments
+///
+/// * `reader` - Buffered source containing PDB text.
+///
+/// # Returns
+///
+/// The list of operators that still need to be applied.
+///
+/// # Errors
+///
+/// Returns [`Error::Parse`] for malformed numeric fields or [`Error::InconsistentData`] when
+/// an operator lacks one of its three matrix rows.
+pub fn read_mtrix<R: BufRead>(reader: R) -> Result<Vec<Isometry3<f64>>, Error> {
+    let mut pending = Vec::new();
+    let mut given = Vec::new();
+
+    for (idx, line) in reader.lines().enumerate() {
+        let line_num = idx + 1;
+        let line = line.map_err(|e| Error::from_io(e, None))?;
+
+        if !line.starts_with("MTRIX") {
+            continue;
+        }
+
+        let tokens: Vec<&str> = line.split_whitespace().collect();
+        if tokens.len() < 6 {
+            return Err(Error::parse(
+                "PDB",
+                None,
+                line_num,
+                "MTRIX record too short",
+            ));
+        }
+
+        if tokens.get(6) == Some(&"1") {
+            given.push(tokens[1].to_string());
+        }
+        add_row(&mut pending, tokens[0], tokens[1], &tokens[2..6], line_num)?;
+    }
+
+    pending.retain(|op: &PendingOperator| !given.contains(&op.serial));
+    finalize(pending)
+}
+
+/// Stores one matrix row (`BIOMTn`/`MTRIXn`) into the pending operator list.
+fn add_row(
+    pending: &mut Vec<PendingOperator>,
+    record: &str,
+    serial: &str,
+    values: &[&str],
+    line_num: usize,
+) -> Result<(), Error> {
+    let row = match record.chars().last() {
+        Some('1') => 0,
+        Some('2') => 1,
+        Some('3') => 2,
+        _ => {
+            return Err(Error::parse(
+                "PDB",
+                None,
+                line_num,
+                format!("invalid symmetry record '{record}'"),
+            ));
+        }
+    };
+
+    let mut parsed = [0.0; 4];
+    for (slot, value) in parsed.iter_mut().zip(values) {
+        *slot = value
+            .parse::<f64>()
+            .map_err(|_| Error::parse("PDB", None, line_num, "Invalid symmetry operator value"))?;
+    }
+
+    let op = match pending.iter_mut().position(|op| op.serial == serial) {
+        Some(i) => &mut pending[i],
+        None => {
+            pending.push(PendingOperator {
+                serial: serial.to_string(),
+                rows: [[0.0; 4]; 3],
+                seen: 0,
+            });
+            pending.last_mut().unwrap()
+        }
+    };
+    op.rows[row] = parsed;
+    op.seen |= 1 << row;
+
+    Ok(())
+}
+
+/// Converts completed operators into isometries, rejecting partial definitions.
+fn finalize(pending: Vec<PendingOperator>) -> Result<Vec<Isometry3<f64>>, Error> {
+    pending
+        .into_iter()
+        .map(|op| {
+            if op.seen != 0b111 {
+                return Err(Error::inconsistent_data(
+                    "PDB",
+                    None,
+                    format!("symmetry operator {} is missing matrix rows", op.serial),
+                ));
+            }
+            let r = op.rows;
+            let matrix = Matrix3::new(
+                r[0][0], r[0][1], r[0][2], r[1][0], r[1][1], r[1][2], r[2][0], r[2][1], r[2][2],
+            );
+            let rotation = Rotation3::from_matrix_unchecked(matrix);
+            Ok(Isometry3::from_parts(
+                Translation3::new(r[0][3], r[1][3], r[2][3]),
+                UnitQuaternion::from_rotation_matrix(&rotation),
+            ))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::types::Point;
+    use std::io::Cursor;
+
+    const ASSEMBLY: &str = "\
+REMARK 350 BIOMOLECULE: 1
+REMARK 350 APPLY THE FOLLOWING TO CHAINS: A
+REMARK 350   BIOMT1   1  1.000000  0.000000  0.000000        0.00000
+REMARK 350   BIOMT2   1  0.000000  1.000000  0.000000        0.00000
+REMARK 350   BIOMT3   1  0.000000  0.000000  1.000000        0.00000
+REMARK 350   BIOMT1   2 -1.000000  0.000000  0.000000       10.00000
+REMARK 350   BIOMT2   2  0.000000 -1.000000  0.000000        0.00000
+REMARK 350   BIOMT3   2  0.000000  0.000000  1.000000        5.00000
+REMARK 350 BIOMOLECULE: 2
+REMARK 350   BIOMT1   1  1.000000  0.000000  0.000000        0.00000
+REMARK 350   BIOMT2   1  0.000000  1.000000  0.000000        0.00000
+REMARK 350   BIOMT3   1  0.000000  0.000000  1.000000        0.00000
+ATOM      1  N   GLY A   1       0.000   0.000   0.000  1.00 20.00           N
+";
+
+    #[test]
+    fn read_biomt_parses_operators_of_first_biomolecule() {
+        let ops = read_biomt(Cursor::new(ASSEMBLY)).unwrap();
+
+        assert_eq!(ops.len(), 2);
+        let p = Point::new(1.0, 2.0, 3.0);
+        assert!((ops[0].transform * p - p).norm() < 1e-9);
+        assert!((ops[1].transform * p - Point::new(9.0, -2.0, 8.0)).norm() < 1e-9);
+        assert!(ops.iter().all(|op| op.chains == vec!["A".to_string()]));
+    }
+
+    #[test]
+    fn read_biomt_assigns_chain_groups_to_their_operators() {
+        let pdb = "\
+REMARK 350 BIOMOLECULE: 1
+REMARK 350 APPLY THE FOLLOWING TO CHAINS: A, B,
+REMARK 350                    AND CHAINS: C
+REMARK 350   BIOMT1   1  1.000000  0.000000  0.000000        0.00000
+REMARK 350   BIOMT2   1  0.000000  1.000000  0.000000        0.00000
+REMARK 350   BIOMT3   1  0.000000  0.000000  1.000000        0.00000
+REMARK 350 APPLY THE FOLLOWING TO CHAINS: D
+REMARK 350   BIOMT1   1  1.000000  0.000000  0.000000        4.00000
+REMARK 350   BIOMT2   1  0.000000  1.000000  0.000000        0.00000
+REMARK 350   BIOMT3   1  0.000000  0.000000  1.000000        0.00000
+";
+        let ops = read_biomt(Cursor::new(pdb)).unwrap();
+
+        assert_eq!(ops.len(), 2);
+        assert_eq!(ops[0].chains, vec!["A", "B", "C"]);
+        assert_eq!(ops[1].chains, vec!["D"]);
+        let moved = ops[1].transform * Point::origin();
+        assert!((moved - Point::new(4.0, 0.0, 0.0)).norm() < 1e-9);
+    }
+
+    #[test]
+    fn read_biomt_returns_empty_without_records() {
+        let pdb =
+            "ATOM      1  N   GLY A   1       0.000   0.000   0.000  1.00 20.00           N\n";
+        assert!(read_biomt(Cursor::new(pdb)).unwrap().is_empty());
+    }
+
+    #[test]
+    fn read_biomt_rejects_incomplete_operator() {
+        let pdb = "\
+REMARK 350   BIOMT1   1  1.000000  0.000000  0.000000        0.00000
+REMARK 350   BIOMT2   1  0.000000  1.000000  0.000000        0.00000
+";
+        let err = read_biomt(Cursor::new(pdb)).unwrap_err();
+        assert!(matches!(err, Error::InconsistentData { .. }));
+    }
+
+    #[test]
+    fn read_biomt_reports_invalid_numbers() {
+        let pdb = "REMARK 350   BIOMT1   1  1.0000xx  0.000000  0.000000        0.00000\n";
+        let err = read_biomt(Cursor::new(pdb)).unwrap_err();
+        assert!(matches!(err, Error::Parse { line_number: 1, .. }));
+    }
+
+    #[test]
+    fn read_mtrix_skips_operators_already_applied() {
+        let pdb = "\
+MTRIX1   1  1.000000  0.000000  0.000000        0.00000    1
+MTRIX2   1  0.000000  1.000000  0.000000        0.00000    1
+MTRIX3   1  0.000000  0.000000  1.000000        0.00000    1
+MTRIX1   2  0.000000 -1.000000  0.000000        1.00000
+MTRIX2   2  1.000000  0.000000  0.000000        2.00000
+MTRIX3   2  0.000000  0.000000  1.000000        3.00000
+";
+        let ops = read_mtrix(Cursor::new(pdb)).unwrap();
+
+        assert_eq!(ops.len(), 1);
+        let moved = ops[0] * Point::new(1.0, 0.0, 0.0);
+        assert!((moved - Point::new(1.0, 3.0, 3.0)).norm() < 1e-9);
+    }
+}
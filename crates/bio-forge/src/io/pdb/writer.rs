@@ -1,35 +1,94 @@
 //! PDB writer utilities that serialize structures and topologies into fixed-width records.
 //!
 //! The module handles optional unit-cell information, deterministic atom serial numbering,
-//! TER record emission, and `CONECT` reconstruction from a [`Topology`] to ensure round-trip
-//! compatibility with downstream crystallography tools.
+//! `ANISOU` records, TER record emission, and `CONECT` reconstruction from a [`Topology`] to
+//! ensure round-trip compatibility with downstream crystallography tools. Line endings and
+//! trailing padding are configurable through [`PdbWriteConfig`] for readers with stricter
+//! expectations.
 
 use crate::io::error::Error;
+use crate::io::selection::{select_structure, select_topology};
 use crate::model::{
-    atom::Atom, residue::Residue, structure::Structure, topology::Topology, types::ResidueCategory,
+    atom::Atom, chain::Chain, residue::Residue, structure::Structure, topology::Topology,
+    types::BondOrder,
 };
 use std::collections::HashMap;
 use std::io::Write;
 
+/// Line terminator appended to every record written by the PDB writers.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum LineEnding {
+    /// Unix line feed (`\n`).
+    #[default]
+    Lf,
+    /// Windows carriage return plus line feed (`\r\n`), expected by some legacy readers.
+    CrLf,
+}
+
+impl LineEnding {
+    /// Returns the terminator characters.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            LineEnding::Lf => "\n",
+            LineEnding::CrLf => "\r\n",
+        }
+    }
+}
+
+/// Formatting options applied by the PDB writers.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PdbWriteConfig {
+    /// Whether to emit a `TER` record after the last polymer residue of each chain.
+    pub ter_records: bool,
+    /// Line terminator used for every record.
+    pub line_ending: LineEnding,
+    /// Whether to strip trailing spaces left by fixed-width column padding.
+    pub trim_trailing: bool,
+    /// Whether `CONECT` records include [`BondOrder::Coordination`] bonds to metals.
+    pub conect_coordination: bool,
+}
+
+impl Default for PdbWriteConfig {
+    /// Emits `TER` records and LF-terminated, fully padded records, matching conventional
+    /// PDB output. Coordination bonds are left out of `CONECT` so that readers treating
+    /// every `CONECT` entry as covalent do not bond metals to their ligands.
+    fn default() -> Self {
+        Self {
+            ter_records: true,
+            line_ending: LineEnding::Lf,
+            trim_trailing: false,
+            conect_coordination: false,
+        }
+    }
+}
+
 /// Writes a [`Structure`] to PDB format, including optional CRYST1 and TER records.
 ///
 /// The function traverses chains in their stored order, emits `ATOM` records for polymeric
 /// residues, `HETATM` records for everything else, and appends a final `END` line so the file
-/// is ready for legacy toolchains.
+/// is ready for legacy toolchains. Two-character chain identifiers extend into column 21, the
+/// convention used by several tools for large assemblies.
 ///
 /// # Arguments
 ///
 /// * `writer` - Destination implementing [`Write`], such as a file or in-memory buffer.
 /// * `structure` - Source structure whose chains and atoms are serialized.
+/// * `config` - Formatting options such as `TER` record emission.
 ///
 /// # Returns
 ///
 /// [`Ok`] if writing succeeded; [`Error`] if IO failures occur.
 ///
+/// # Errors
+///
+/// Returns [`Error::InconsistentData`] before anything is written when a chain identifier is
+/// longer than two characters, or a formal charge lies outside `-9..=9`, and therefore cannot
+/// be represented.
+///
 /// # Examples
 ///
 /// ```
-/// use bio_forge::io::{write_pdb_structure, IoContext, read_pdb_structure};
+/// use bio_forge::io::{write_pdb_structure, IoContext, PdbWriteConfig, read_pdb_structure};
 /// use std::io::Cursor;
 ///
 /// // Parse a minimal PDB and immediately write it back out.
@@ -40,11 +99,18 @@ use std::io::Write;
 /// let mut cursor = Cursor::new(pdb.as_bytes());
 /// let structure = read_pdb_structure(&mut cursor, &context).unwrap();
 /// let mut out = Vec::new();
-/// write_pdb_structure(&mut out, &structure).unwrap();
+/// write_pdb_structure(&mut out, &structure, &PdbWriteConfig::default()).unwrap();
 /// assert!(String::from_utf8(out).unwrap().contains("END"));
 /// ```
-pub fn write_structure<W: Write>(writer: W, structure: &Structure) -> Result<(), Error> {
-    let mut ctx = WriterContext::new(writer);
+pub fn write_structure<W: Write>(
+    writer: W,
+    structure: &Structure,
+    config: &PdbWriteConfig,
+) -> Result<(), Error> {
+    check_chain_ids(structure)?;
+    check_formal_charges(structure)?;
+
+    let mut ctx = WriterContext::new(writer, config);
 
     ctx.write_cryst1(structure.box_vectors)?;
 
@@ -65,16 +131,22 @@ pub fn write_structure<W: Write>(writer: W, structure: &Structure) -> Result<(),
 ///
 /// * `writer` - Output sink implementing [`Write`].
 /// * `topology` - Source topology whose structure and bonds are serialized.
+/// * `config` - Formatting options such as `TER` record emission.
 ///
 /// # Returns
 ///
 /// [`Ok`] if writing succeeded; [`Error`] if serialization or IO steps fail.
 ///
+/// # Errors
+///
+/// Returns [`Error::InconsistentData`] when a chain identifier or formal charge cannot be
+/// represented, as described for [`write_structure`].
+///
 /// # Examples
 ///
 /// ```
 /// use bio_forge::{Bond, BondOrder, Topology};
-/// use bio_forge::io::{write_pdb_topology, IoContext, read_pdb_structure};
+/// use bio_forge::io::{write_pdb_topology, IoContext, PdbWriteConfig, read_pdb_structure};
 /// use std::io::Cursor;
 ///
 /// let pdb = "\
@@ -89,12 +161,19 @@ pub fn write_structure<W: Write>(writer: W, structure: &Structure) -> Result<(),
 ///     vec![Bond::new(0, 1, BondOrder::Single)],
 /// );
 /// let mut out = Vec::new();
-/// write_pdb_topology(&mut out, &topo).unwrap();
+/// write_pdb_topology(&mut out, &topo, &PdbWriteConfig::default()).unwrap();
 /// assert!(String::from_utf8(out).unwrap().contains("CONECT"));
 /// ```
-pub fn write_topology<W: Write>(writer: W, topology: &Topology) -> Result<(), Error> {
-    let mut ctx = WriterContext::new(writer);
+pub fn write_topology<W: Write>(
+    writer: W,
+    topology: &Topology,
+    config: &PdbWriteConfig,
+) -> Result<(), Error> {
     let structure = topology.structure();
+    check_chain_ids(structure)?;
+    check_formal_charges(structure)?;
+
+    let mut ctx = WriterContext::new(writer, config);
 
     ctx.write_cryst1(structure.box_vectors)?;
 
@@ -107,8 +186,154 @@ pub fn write_topology<W: Write>(writer: W, topology: &Topology) -> Result<(), Er
     Ok(())
 }
 
+/// Writes a subset of a [`Structure`]'s atoms to PDB format.
+///
+/// Only the listed global atom indices (in [`Structure::iter_atoms`] order) are emitted;
+/// chain and residue grouping is kept and serial numbers are assigned afresh. Indices beyond
+/// the atom count are ignored. The structure itself is left untouched.
+///
+/// # Arguments
+///
+/// * `writer` - Destination implementing [`Write`].
+/// * `structure` - Source structure.
+/// * `indices` - Global atom indices to write.
+/// * `config` - Formatting options such as `TER` record emission.
+///
+/// # Returns
+///
+/// [`Ok`] if writing succeeded; [`Error`] if IO failures occur.
+///
+/// # Errors
+///
+/// Returns [`Error::InconsistentData`] when a selected chain identifier or formal charge
+/// cannot be represented, as described for [`write_structure`].
+pub fn write_selection<W: Write>(
+    writer: W,
+    structure: &Structure,
+    indices: &[usize],
+    config: &PdbWriteConfig,
+) -> Result<(), Error> {
+    write_structure(writer, &select_structure(structure, indices), config)
+}
+
+/// Writes a subset of a [`Topology`] to PDB format, including `CONECT` records.
+///
+/// Atoms are selected as in [`write_selection`]; a bond is written only when both of its
+/// endpoints are selected.
+///
+/// # Arguments
+///
+/// * `writer` - Output sink implementing [`Write`].
+/// * `topology` - Source topology.
+/// * `indices` - Global atom indices to write.
+/// * `config` - Formatting options such as `TER` record emission.
+///
+/// # Returns
+///
+/// [`Ok`] if writing succeeded; [`Error`] if serialization or IO steps fail.
+///
+/// # Errors
+///
+/// Returns [`Error::InconsistentData`] when a selected chain identifier or formal charge
+/// cannot be represented, as described for [`write_structure`].
+pub fn write_topology_selection<W: Write>(
+    writer: W,
+    topology: &Topology,
+    indices: &[usize],
+    config: &PdbWriteConfig,
+) -> Result<(), Error> {
+    write_topology(writer, &select_topology(topology, indices), config)
+}
+
+/// Maximum chain identifier width supported by the fixed-column layout.
+const MAX_CHAIN_ID_WIDTH: usize = 2;
+
+/// Ensures every chain identifier fits into the PDB chain columns.
+///
+/// # Arguments
+///
+/// * `structure` - Structure whose chain identifiers are validated.
+///
+/// # Returns
+///
+/// [`Ok`] when all identifiers fit; [`Error::InconsistentData`] naming the first offender.
+fn check_chain_ids(structure: &Structure) -> Result<(), Error> {
+    match structure
+        .iter_chains()
+        .find(|chain| chain.id.chars().count() > MAX_CHAIN_ID_WIDTH)
+    {
+        Some(chain) => Err(Error::inconsistent_data(
+            "PDB",
+            None,
+            format!(
+                "chain identifier '{}' exceeds {MAX_CHAIN_ID_WIDTH} characters and cannot be written",
+                chain.id
+            ),
+        )),
+        None => Ok(()),
+    }
+}
+
+/// Largest formal charge magnitude that fits into the two charge columns.
+const MAX_FORMAL_CHARGE: u8 = 9;
+
+/// Ensures every formal charge fits into the single-digit PDB charge columns.
+///
+/// # Arguments
+///
+/// * `structure` - Structure whose atomic formal charges are validated.
+///
+/// # Returns
+///
+/// [`Ok`] when all charges fit; [`Error::InconsistentData`] naming the first offender.
+fn check_formal_charges(structure: &Structure) -> Result<(), Error> {
+    let offender = structure.iter_chains().find_map(|chain| {
+        chain.iter_residues().find_map(|residue| {
+            residue
+                .iter_atoms()
+                .find(|atom| {
+                    atom.formal_charge
+                        .is_some_and(|c| c.unsigned_abs() > MAX_FORMAL_CHARGE)
+                })
+                .map(|atom| (chain, residue, atom))
+        })
+    });
+    match offender {
+        Some((chain, residue, atom)) => Err(Error::inconsistent_data(
+            "PDB",
+            None,
+            format!(
+                "formal charge {} on atom '{}' of residue {}{} in chain '{}' exceeds \
+                 {MAX_FORMAL_CHARGE} in magnitude and cannot be written",
+                atom.formal_charge.unwrap_or_default(),
+                atom.name,
+                residue.id,
+                residue.insertion_code.map(String::from).unwrap_or_default(),
+                chain.id
+            ),
+        )),
+        None => Ok(()),
+    }
+}
+
+/// Formats a chain identifier into the two columns preceding the residue number.
+///
+/// Single-character identifiers keep the standard layout with a blank column 21, while
+/// two-character identifiers occupy both columns.
+fn format_chain_id(chain_id: &str) -> String {
+    match chain_id.chars().count() {
+        0 => "  ".to_string(),
+        1 => format!(" {chain_id}"),
+        _ => chain_id.chars().take(MAX_CHAIN_ID_WIDTH).collect(),
+    }
+}
+
 struct WriterContext<W> {
     writer: W,
+    ter_records: bool,
+    line_ending: LineEnding,
+    trim_trailing: bool,
+    conect_coordination: bool,
     current_serial: usize,
     atom_index_to_serial: HashMap<usize, usize>,
 }
@@ -119,14 +344,37 @@ impl<W: Write> WriterContext<W> {
     /// # Arguments
     ///
     /// * `writer` - Output sink that will receive the generated PDB text.
-    fn new(writer: W) -> Self {
+    /// * `config` - Formatting options controlling optional records.
+    fn new(writer: W, config: &PdbWriteConfig) -> Self {
         Self {
             writer,
+            ter_records: config.ter_records,
+            line_ending: config.line_ending,
+            trim_trailing: config.trim_trailing,
+            conect_coordination: config.conect_coordination,
             current_serial: 1,
             atom_index_to_serial: HashMap::new(),
         }
     }
 
+    /// Writes one record followed by the configured line ending.
+    ///
+    /// # Arguments
+    ///
+    /// * `line` - Record text without a terminator; trailing spaces are removed when
+    ///   trimming is enabled.
+    fn write_line(&mut self, line: &str) -> Result<(), Error> {
+        let line = if self.trim_trailing {
+            line.trim_end_matches(' ')
+        } else {
+            line
+        };
+        self.writer
+            .write_all(line.as_bytes())
+            .and_then(|()| self.writer.write_all(self.line_ending.as_str().as_bytes()))
+            .map_err(|e| Error::from_io(e, None))
+    }
+
     /// Outputs a `CRYST1` record if orthogonal vectors are available.
     ///
     /// # Arguments
@@ -150,26 +398,38 @@ impl<W: Write> WriterContext<W> {
             let beta = v1.angle(&v3).to_degrees();
             let gamma = v1.angle(&v2).to_degrees();
 
-            writeln!(
-                self.writer,
+            self.write_line(&format!(
                 "CRYST1{:9.3}{:9.3}{:9.3}{:7.2}{:7.2}{:7.2} P 1           1",
                 a, b, c, alpha, beta, gamma
-            )
-            .map_err(|e| Error::from_io(e, None))?;
+            ))?;
         }
         Ok(())
     }
 
-    /// Writes all chain atoms and inserts `TER` records after the final standard residue.
+    /// Writes all chain atoms and inserts `TER` records after the final polymer residue.
+    ///
+    /// The `TER` record follows the atoms of the last polymer residue directly, so trailing
+    /// caps, ligands, ions, and waters of the same chain are written after it. Chains without
+    /// amino acid or nucleotide residues never receive a `TER` record, and none are written
+    /// when `TER` emission is disabled.
     ///
     /// # Arguments
     ///
     /// * `structure` - Source structure providing chains, residues, and atoms.
     fn write_atoms(&mut self, structure: &Structure) -> Result<(), Error> {
         let mut global_idx = 0;
+        self.atom_index_to_serial.reserve(structure.atom_count());
 
         for chain in structure.iter_chains() {
-            for residue in chain.iter_residues() {
+            let last_polymer = chain
+                .iter_residues()
+                .rposition(|res| {
+                    res.standard_name
+                        .is_some_and(|std| std.is_protein() || std.is_nucleic())
+                })
+                .filter(|_| self.ter_records);
+
+            for (res_idx, residue) in chain.iter_residues().enumerate() {
                 for atom in residue.iter_atoms() {
                     let record_type = match residue.standard_name {
                         Some(std) if std.is_protein() || std.is_nucleic() => "ATOM  ",
@@ -180,21 +440,17 @@ impl<W: Write> WriterContext<W> {
 
                     self.atom_index_to_serial.insert(global_idx, serial);
 
-                    self.write_atom_record(record_type, serial, atom, residue, &chain.id)?;
+                    self.write_atom_record(record_type, serial, atom, residue, chain)?;
 
                     self.current_serial += 1;
                     global_idx += 1;
                 }
-            }
 
-            if let Some(last_standard) = chain
-                .iter_residues()
-                .rev()
-                .find(|res| res.category == ResidueCategory::Standard)
-            {
-                let serial = self.current_serial;
-                self.write_ter_record(serial, last_standard, &chain.id)?;
-                self.current_serial += 1;
+                if last_polymer == Some(res_idx) {
+                    let serial = self.current_serial;
+                    self.write_ter_record(serial, residue, &chain.id)?;
+                    self.current_serial += 1;
+                }
             }
         }
         Ok(())
@@ -202,20 +458,23 @@ impl<W: Write> WriterContext<W> {
 
     /// Formats a single `ATOM` or `HETATM` entry using the fixed-width PDB layout.
     ///
+    /// Atoms carrying anisotropic displacement parameters are followed by an `ANISOU`
+    /// record sharing the same serial and identification columns.
+    ///
     /// # Arguments
     ///
     /// * `record_type` - Either `"ATOM  "` or `"HETATM"` depending on residue type.
     /// * `serial` - Sequential atom serial number.
     /// * `atom` - Atom instance providing coordinates and element.
     /// * `residue` - Parent residue containing residue identifiers.
-    /// * `chain_id` - Author chain identifier string.
+    /// * `chain` - Parent chain providing the chain identifier.
     fn write_atom_record(
         &mut self,
         record_type: &str,
         serial: usize,
         atom: &Atom,
         residue: &Residue,
-        chain_id: &str,
+        chain: &Chain,
     ) -> Result<(), Error> {
         let atom_name = if atom.name.len() >= 4 {
             format!("{:<4}", &atom.name[0..4])
@@ -231,25 +490,50 @@ impl<W: Write> WriterContext<W> {
 
         let element_str = format!("{:>2}", atom.element.symbol().to_uppercase());
 
-        writeln!(
-            self.writer,
-            "{:6}{:5} {:4}{:1}{:3} {:1}{:4}{:1}   {:8.3}{:8.3}{:8.3}{:6.2}{:6.2}          {:2}",
-            record_type,
+        let segid: String = residue
+            .segid
+            .iter()
+            .flat_map(|s| s.chars())
+            .take(4)
+            .collect();
+        let charge = match atom.formal_charge {
+            Some(c) if c != 0 => format!("{}{}", c.unsigned_abs(), if c > 0 { '+' } else { '-' }),
+            _ => String::new(),
+        };
+
+        let ident = format!(
+            "{:5} {:4}{:1}{:3}{:2}{:4}{:1}",
             serial % 100000,
             atom_name,
-            ' ',
+            atom.alt_loc.unwrap_or(' '),
             res_name,
-            chain_id.chars().next().unwrap_or(' '),
+            format_chain_id(&chain.id),
             residue.id % 10000,
             residue.insertion_code.unwrap_or(' '),
+        );
+
+        self.write_line(&format!(
+            "{:6}{}   {:8.3}{:8.3}{:8.3}{:6.2}{:6.2}      {:<4}{:2}{}",
+            record_type,
+            ident,
             atom.pos.x,
             atom.pos.y,
             atom.pos.z,
-            1.00,
-            0.00,
-            element_str
-        )
-        .map_err(|e| Error::from_io(e, None))
+            atom.occupancy,
+            atom.b_factor,
+            segid,
+            element_str,
+            charge
+        ))?;
+
+        let Some(anisou) = atom.anisou else {
+            return Ok(());
+        };
+        let [u11, u22, u33, u12, u13, u23] = anisou.map(|u| (u * 1.0e4).round() as i64);
+        self.write_line(&format!(
+            "ANISOU{} {:7}{:7}{:7}{:7}{:7}{:7}  {:<4}{:2}{}",
+            ident, u11, u22, u33, u12, u13, u23, segid, element_str, charge
+        ))
     }
 
     /// Emits a `TER` record to terminate the current polymer chain.
@@ -271,20 +555,20 @@ impl<W: Write> WriterContext<W> {
             &residue.name
         };
 
-        writeln!(
-            self.writer,
-            "TER   {:5}      {:3} {:1}{:4}{:1}",
+        self.write_line(&format!(
+            "TER   {:5}      {:3}{:2}{:4}{:1}",
             serial % 100000,
             res_name,
-            chain_id.chars().next().unwrap_or(' '),
+            format_chain_id(chain_id),
             residue.id % 10000,
             residue.insertion_code.unwrap_or(' ')
-        )
-        .map_err(|e| Error::from_io(e, None))
+        ))
     }
 
     /// Serializes topology bonds into grouped `CONECT` records with deduplicated targets.
     ///
+    /// Coordination bonds are skipped unless the configuration asks for them.
+    ///
     /// # Arguments
     ///
     /// * `topology` - Topology providing bond definitions that should be written.
@@ -297,6 +581,9 @@ impl<W: Write> WriterContext<W> {
         let mut adjacency: HashMap<usize, Vec<usize>> = HashMap::new();
 
         for bond in topology.bonds() {
+            if bond.order == BondOrder::Coordination && !self.conect_coordination {
+                continue;
+            }
             let s1 = *self.atom_index_to_serial.get(&bond.a1_idx).ok_or_else(|| {
                 Error::inconsistent_data(
                     "PDB",
@@ -332,12 +619,11 @@ impl<W: Write> WriterContext<W> {
             targets.dedup();
 
             for chunk in targets.chunks(4) {
-                write!(self.writer, "CONECT{:5}", src_serial)
-                    .map_err(|e| Error::from_io(e, None))?;
+                let mut line = format!("CONECT{:5}", src_serial);
                 for target in chunk {
-                    write!(self.writer, "{:5}", target).map_err(|e| Error::from_io(e, None))?;
+                    line.push_str(&format!("{:5}", target));
                 }
-                writeln!(self.writer).map_err(|e| Error::from_io(e, None))?;
+                self.write_line(&line)?;
             }
         }
 
@@ -350,7 +636,7 @@ impl<W: Write> WriterContext<W> {
     ///
     /// [`Ok`] when the line is written; [`Error`] if the underlying writer fails.
     fn write_end(&mut self) -> Result<(), Error> {
-        writeln!(self.writer, "END   ").map_err(|e| Error::from_io(e, None))
+        self.write_line("END   ")
     }
 }
 
@@ -374,6 +660,7 @@ mod tests {
         assert!((parse_float(&line[47..54]) - gamma).abs() < 1e-2);
     }
 
+    #[allow(clippy::too_many_arguments)]
     fn assert_atom_line(
         line: &str,
         record: &str,
@@ -457,7 +744,8 @@ mod tests {
         structure.add_chain(chain);
 
         let mut buffer = Vec::new();
-        write_structure(&mut buffer, &structure).expect("writer should succeed");
+        write_structure(&mut buffer, &structure, &PdbWriteConfig::default())
+            .expect("writer should succeed");
 
         let output = String::from_utf8(buffer).expect("valid UTF-8");
         let lines: Vec<&str> = output.lines().collect();
@@ -488,10 +776,11 @@ mod tests {
             (1.5, 2.5, 3.5),
             "C",
         );
+        assert_ter_line(lines[3], 3, "GLY", 'A', 1, ' ');
         assert_atom_line(
-            lines[3],
+            lines[4],
             "HETATM",
-            3,
+            4,
             "C1",
             "LIG",
             'A',
@@ -500,10 +789,54 @@ mod tests {
             (4.0, 5.0, 6.0),
             "C",
         );
-        assert_ter_line(lines[4], 4, "GLY", 'A', 1, ' ');
         assert_eq!(lines[5], "END   ");
     }
 
+    #[test]
+    fn write_structure_labels_alternate_conformers_in_column_17() {
+        let mut gly = Residue::new(
+            1,
+            None,
+            "GLY",
+            Some(StandardResidue::GLY),
+            ResidueCategory::Standard,
+        );
+        gly.add_atom(Atom::new("N", Element::N, Point::new(0.0, 0.0, 0.0)));
+        for (label, x) in [('A', 1.0), ('B', 2.0)] {
+            let mut ca = Atom::new("CA", Element::C, Point::new(x, 0.0, 0.0));
+            ca.alt_loc = Some(label);
+            gly.add_atom(ca);
+        }
+        let mut chain = Chain::new("A");
+        chain.add_residue(gly);
+        let mut structure = Structure::new();
+        structure.add_chain(chain);
+
+        let mut buffer = Vec::new();
+        write_structure(&mut buffer, &structure, &PdbWriteConfig::default()).unwrap();
+        let output = String::from_utf8(buffer).unwrap();
+
+        let columns: Vec<char> = output
+            .lines()
+            .filter(|l| l.starts_with("ATOM"))
+            .map(|l| l.chars().nth(16).unwrap())
+            .collect();
+        assert_eq!(columns, vec![' ', 'A', 'B']);
+
+        let options = crate::io::ReadOptions {
+            keep_alt_locs: true,
+            ..Default::default()
+        };
+        let reread = crate::io::pdb::reader::read_with_options(
+            output.as_bytes(),
+            &crate::io::IoContext::new_default(),
+            &options,
+        )
+        .unwrap();
+        let labels: Vec<_> = reread.iter_atoms().map(|a| a.alt_loc).collect();
+        assert_eq!(labels, vec![Some('A'), Some('B'), None]);
+    }
+
     #[test]
     fn write_structure_without_box_starts_with_atom_records() {
         let mut structure = Structure::new();
@@ -521,7 +854,8 @@ mod tests {
         structure.add_chain(chain);
 
         let mut buffer = Vec::new();
-        write_structure(&mut buffer, &structure).expect("writer should succeed");
+        write_structure(&mut buffer, &structure, &PdbWriteConfig::default())
+            .expect("writer should succeed");
 
         let output = String::from_utf8(buffer).expect("valid UTF-8");
         let mut lines = output.lines();
@@ -548,7 +882,8 @@ mod tests {
         structure.add_chain(chain);
 
         let mut buffer = Vec::new();
-        write_structure(&mut buffer, &structure).expect("writer should succeed");
+        write_structure(&mut buffer, &structure, &PdbWriteConfig::default())
+            .expect("writer should succeed");
 
         let output = String::from_utf8(buffer).expect("valid UTF-8");
         let first_line = output.lines().next().expect("at least one line");
@@ -577,7 +912,8 @@ mod tests {
         let topology = Topology::new(structure.clone(), vec![Bond::new(0, 1, BondOrder::Single)]);
 
         let mut buffer = Vec::new();
-        write_topology(&mut buffer, &topology).expect("topology writer succeeds");
+        write_topology(&mut buffer, &topology, &PdbWriteConfig::default())
+            .expect("topology writer succeeds");
 
         let output = String::from_utf8(buffer).expect("valid UTF-8");
         let conect_lines: Vec<&str> = output
@@ -590,6 +926,100 @@ mod tests {
         assert_conect_line(conect_lines[1], 2, &[1]);
     }
 
+    #[test]
+    fn write_topology_omits_coordination_bonds_from_conect_unless_configured() {
+        let mut chain = single_gly_chain("A");
+        let mut zn = Residue::new(2, None, "ZN", None, ResidueCategory::Ion);
+        zn.add_atom(Atom::new("ZN", Element::Zn, Point::new(0.0, 2.1, 0.0)));
+        chain.add_residue(zn);
+        let mut structure = Structure::new();
+        structure.add_chain(chain);
+        let topology = Topology::new(structure, vec![Bond::new(0, 1, BondOrder::Coordination)]);
+        let conect_lines = |config: &PdbWriteConfig| {
+            let mut buffer = Vec::new();
+            write_topology(&mut buffer, &topology, config).expect("topology writer succeeds");
+            String::from_utf8(buffer)
+                .unwrap()
+                .lines()
+                .filter(|line| line.starts_with("CONECT"))
+                .map(str::to_string)
+                .collect::<Vec<_>>()
+        };
+
+        assert!(conect_lines(&PdbWriteConfig::default()).is_empty());
+
+        let config = PdbWriteConfig {
+            conect_coordination: true,
+            ..PdbWriteConfig::default()
+        };
+        let lines = conect_lines(&config);
+        assert_eq!(lines.len(), 2);
+        assert_conect_line(&lines[0], 1, &[3]);
+    }
+
+    #[test]
+    fn write_topology_selection_renumbers_serials_and_filters_bonds() {
+        let mut structure = Structure::new();
+        let mut chain = Chain::new("C");
+        let mut ala = Residue::new(
+            10,
+            None,
+            "ALA",
+            Some(StandardResidue::ALA),
+            ResidueCategory::Standard,
+        );
+        ala.add_atom(Atom::new("N", Element::N, Point::new(0.0, 0.0, 0.0)));
+        ala.add_atom(Atom::new("CA", Element::C, Point::new(1.0, 0.0, 0.0)));
+        ala.add_atom(Atom::new("C", Element::C, Point::new(2.0, 0.0, 0.0)));
+        chain.add_residue(ala);
+        structure.add_chain(chain);
+
+        let topology = Topology::new(
+            structure.clone(),
+            vec![
+                Bond::new(0, 1, BondOrder::Single),
+                Bond::new(1, 2, BondOrder::Single),
+            ],
+        );
+
+        let mut buffer = Vec::new();
+        write_topology_selection(&mut buffer, &topology, &[1, 2], &PdbWriteConfig::default())
+            .expect("selection writer succeeds");
+
+        let output = String::from_utf8(buffer).expect("valid UTF-8");
+        let atom_lines: Vec<&str> = output
+            .lines()
+            .filter(|line| line.starts_with("ATOM"))
+            .collect();
+        assert_eq!(atom_lines.len(), 2);
+        assert_atom_line(
+            atom_lines[0],
+            "ATOM  ",
+            1,
+            "CA",
+            "ALA",
+            'C',
+            10,
+            ' ',
+            (1.0, 0.0, 0.0),
+            "C",
+        );
+
+        let conect_lines: Vec<&str> = output
+            .lines()
+            .filter(|line| line.starts_with("CONECT"))
+            .collect();
+        assert_eq!(conect_lines.len(), 2);
+        assert_conect_line(conect_lines[0], 1, &[2]);
+        assert_eq!(structure.atom_count(), 3);
+
+        let mut buffer = Vec::new();
+        write_selection(&mut buffer, &structure, &[0], &PdbWriteConfig::default())
+            .expect("selection writer succeeds");
+        let output = String::from_utf8(buffer).expect("valid UTF-8");
+        assert_eq!(output.lines().filter(|l| l.starts_with("ATOM")).count(), 1);
+    }
+
     #[test]
     fn write_connects_returns_error_when_serial_missing() {
         let mut structure = Structure::new();
@@ -608,7 +1038,7 @@ mod tests {
 
         let topology = Topology::new(structure.clone(), vec![Bond::new(0, 1, BondOrder::Single)]);
 
-        let mut ctx = WriterContext::new(Vec::new());
+        let mut ctx = WriterContext::new(Vec::new(), &PdbWriteConfig::default());
         ctx.write_atoms(&structure).expect("atoms should write");
         ctx.atom_index_to_serial.clear();
 
@@ -623,4 +1053,347 @@ mod tests {
             other => panic!("unexpected error: {other:?}"),
         }
     }
+
+    #[test]
+    fn write_structure_emits_atom_occupancy() {
+        let mut structure = Structure::new();
+        let mut chain = Chain::new("A");
+        let mut gly = Residue::new(
+            1,
+            None,
+            "GLY",
+            Some(StandardResidue::GLY),
+            ResidueCategory::Standard,
+        );
+        let mut atom = Atom::new("CA", Element::C, Point::new(0.0, 0.0, 0.0));
+        atom.occupancy = 0.25;
+        atom.b_factor = 42.5;
+        gly.add_atom(atom);
+        chain.add_residue(gly);
+        structure.add_chain(chain);
+
+        let mut buffer = Vec::new();
+        write_structure(&mut buffer, &structure, &PdbWriteConfig::default())
+            .expect("writer should succeed");
+
+        let output = String::from_utf8(buffer).expect("valid UTF-8");
+        let atom_line = output.lines().next().expect("atom line");
+        assert_eq!(&atom_line[54..60], "  0.25");
+        assert_eq!(&atom_line[60..66], " 42.50");
+    }
+
+    fn single_gly_chain(id: &str) -> Chain {
+        let mut chain = Chain::new(id);
+        let mut gly = Residue::new(
+            1,
+            None,
+            "GLY",
+            Some(StandardResidue::GLY),
+            ResidueCategory::Standard,
+        );
+        gly.add_atom(Atom::new("CA", Element::C, Point::new(0.0, 0.0, 0.0)));
+        chain.add_residue(gly);
+        chain
+    }
+
+    #[test]
+    fn write_structure_emits_two_character_chain_ids() {
+        let mut structure = Structure::new();
+        structure.add_chain(single_gly_chain("A"));
+        structure.add_chain(single_gly_chain("AB"));
+
+        let mut buffer = Vec::new();
+        write_structure(&mut buffer, &structure, &PdbWriteConfig::default())
+            .expect("writer should succeed");
+
+        let output = String::from_utf8(buffer).expect("valid UTF-8");
+        let lines: Vec<&str> = output.lines().collect();
+        assert_eq!(&lines[0][20..22], " A");
+        assert_eq!(&lines[1][20..22], " A");
+        assert_eq!(&lines[2][20..22], "AB");
+        assert_eq!(&lines[3][20..22], "AB");
+        assert!(lines[3].starts_with("TER"));
+    }
+
+    #[test]
+    fn write_structure_rejects_chain_ids_that_cannot_be_represented() {
+        let mut structure = Structure::new();
+        structure.add_chain(single_gly_chain("ABCD"));
+
+        let mut buffer = Vec::new();
+        let err = write_structure(&mut buffer, &structure, &PdbWriteConfig::default())
+            .expect_err("id too long");
+
+        assert!(matches!(err, Error::InconsistentData { details, .. } if details.contains("ABCD")));
+        assert!(buffer.is_empty(), "nothing should be written on error");
+    }
+
+    #[test]
+    fn write_structure_places_ter_after_last_polymer_residue() {
+        let mut chain = single_gly_chain("A");
+        let mut cap = Residue::new(2, None, "NME", None, ResidueCategory::Hetero);
+        cap.add_atom(Atom::new("N", Element::N, Point::new(1.0, 0.0, 0.0)));
+        chain.add_residue(cap);
+        let mut water = Residue::new(
+            3,
+            None,
+            "HOH",
+            Some(StandardResidue::HOH),
+            ResidueCategory::Standard,
+        );
+        water.add_atom(Atom::new("O", Element::O, Point::new(5.0, 0.0, 0.0)));
+        chain.add_residue(water);
+        let mut structure = Structure::new();
+        structure.add_chain(chain);
+
+        let mut buffer = Vec::new();
+        write_structure(&mut buffer, &structure, &PdbWriteConfig::default())
+            .expect("writer should succeed");
+
+        let output = String::from_utf8(buffer).expect("valid UTF-8");
+        let lines: Vec<&str> = output.lines().collect();
+        let ter_lines: Vec<&str> = lines
+            .iter()
+            .copied()
+            .filter(|l| l.starts_with("TER"))
+            .collect();
+        assert_eq!(ter_lines.len(), 1);
+        assert_ter_line(ter_lines[0], 2, "GLY", 'A', 1, ' ');
+        let ter_pos = lines.iter().position(|l| l.starts_with("TER")).unwrap();
+        let hetatm: Vec<usize> = lines
+            .iter()
+            .enumerate()
+            .filter(|(_, l)| l.starts_with("HETATM"))
+            .map(|(i, _)| i)
+            .collect();
+        assert_eq!(hetatm.len(), 2);
+        assert!(hetatm.iter().all(|&i| i > ter_pos));
+        assert_eq!(&lines[hetatm[0]][17..20], "NME");
+        assert_eq!(&lines[hetatm[1]][17..20], "HOH");
+        assert_eq!(&lines[hetatm[0]][6..11], "    3");
+    }
+
+    #[test]
+    fn write_structure_omits_ter_records_when_disabled() {
+        let mut structure = Structure::new();
+        structure.add_chain(single_gly_chain("A"));
+        let config = PdbWriteConfig {
+            ter_records: false,
+            ..PdbWriteConfig::default()
+        };
+
+        let mut buffer = Vec::new();
+        write_structure(&mut buffer, &structure, &config).expect("writer should succeed");
+
+        let output = String::from_utf8(buffer).expect("valid UTF-8");
+        assert!(!output.lines().any(|l| l.starts_with("TER")));
+        assert_eq!(output.lines().count(), 2);
+    }
+
+    #[test]
+    fn write_structure_emits_formal_charge_columns() {
+        let mut chain = Chain::new("A");
+        let mut mg = Residue::new(1, None, "MG", None, ResidueCategory::Ion);
+        let mut atom = Atom::new("MG", Element::Mg, Point::origin());
+        atom.formal_charge = Some(2);
+        mg.add_atom(atom);
+        let mut cl = Residue::new(2, None, "CL", None, ResidueCategory::Ion);
+        let mut atom = Atom::new("CL", Element::Cl, Point::new(3.0, 0.0, 0.0));
+        atom.formal_charge = Some(-1);
+        cl.add_atom(atom);
+        chain.add_residue(mg);
+        chain.add_residue(cl);
+        chain.add_residue({
+            let mut na = Residue::new(3, None, "NA", None, ResidueCategory::Ion);
+            na.add_atom(Atom::new("NA", Element::Na, Point::new(6.0, 0.0, 0.0)));
+            na
+        });
+        let mut structure = Structure::new();
+        structure.add_chain(chain);
+
+        let mut buffer = Vec::new();
+        write_structure(&mut buffer, &structure, &PdbWriteConfig::default())
+            .expect("writer should succeed");
+
+        let output = String::from_utf8(buffer).expect("valid UTF-8");
+        let lines: Vec<&str> = output.lines().collect();
+        assert_eq!(&lines[0][76..], "MG2+");
+        assert_eq!(&lines[1][76..], "CL1-");
+        assert_eq!(lines[2].len(), 78);
+    }
+
+    #[test]
+    fn write_structure_rejects_formal_charges_beyond_one_digit() {
+        let mut chain = Chain::new("A");
+        let mut residue = Residue::new(7, Some('B'), "CLU", None, ResidueCategory::Hetero);
+        let mut atom = Atom::new("FE1", Element::Fe, Point::origin());
+        atom.formal_charge = Some(-12);
+        residue.add_atom(atom);
+        chain.add_residue(residue);
+        let mut structure = Structure::new();
+        structure.add_chain(chain);
+
+        let mut buffer = Vec::new();
+        let err = write_structure(&mut buffer, &structure, &PdbWriteConfig::default())
+            .expect_err("two-digit charges cannot be written");
+
+        assert!(matches!(
+            err,
+            Error::InconsistentData { details, .. }
+                if details.contains("-12") && details.contains("FE1") && details.contains("7B")
+        ));
+        assert!(buffer.is_empty());
+    }
+
+    #[test]
+    fn write_structure_emits_anisou_after_atom_and_round_trips() {
+        let mut chain = single_gly_chain("A");
+        let anisou = [0.2406, 0.1892, -0.1262, 0.0044, -0.0121, -0.0181];
+        chain
+            .iter_residues_mut()
+            .next()
+            .unwrap()
+            .iter_atoms_mut()
+            .next()
+            .unwrap()
+            .anisou = Some(anisou);
+        let mut structure = Structure::new();
+        structure.add_chain(chain);
+
+        let mut buffer = Vec::new();
+        write_structure(&mut buffer, &structure, &PdbWriteConfig::default())
+            .expect("writer should succeed");
+
+        let output = String::from_utf8(buffer).expect("valid UTF-8");
+        let lines: Vec<&str> = output.lines().collect();
+        assert_eq!(&lines[0][6..27], &lines[1][6..27]);
+        assert_eq!(
+            &lines[1][..70],
+            "ANISOU    1  CA  GLY A   1     2406   1892  -1262     44   -121   -181"
+        );
+        assert!(lines[2].starts_with("TER"));
+        assert_eq!(output.matches("ANISOU").count(), 1);
+
+        let parsed = crate::io::read_pdb_structure(
+            std::io::Cursor::new(output.as_bytes()),
+            &crate::io::IoContext::new_default(),
+        )
+        .expect("round trip");
+        let ca = parsed.iter_atoms().find(|a| a.name == "CA").unwrap();
+        for (actual, expected) in ca.anisou.expect("anisou").iter().zip(anisou) {
+            assert!((actual - expected).abs() < 1e-9);
+        }
+    }
+
+    #[test]
+    fn write_structure_emits_segid_columns() {
+        let mut chain = single_gly_chain("A");
+        for residue in chain.iter_residues_mut() {
+            residue.segid = Some("PROTA".to_string());
+        }
+        let mut structure = Structure::new();
+        structure.add_chain(chain);
+        structure.add_chain(single_gly_chain("B"));
+
+        let mut buffer = Vec::new();
+        write_structure(&mut buffer, &structure, &PdbWriteConfig::default())
+            .expect("writer should succeed");
+
+        let output = String::from_utf8(buffer).expect("valid UTF-8");
+        let lines: Vec<&str> = output.lines().collect();
+        assert_eq!(&lines[0][72..76], "PROT");
+        assert_eq!(&lines[0][76..78], " C");
+        assert_eq!(&lines[2][72..76], "    ");
+    }
+
+    #[test]
+    fn write_structure_round_trips_segids_sharing_a_blank_chain_id() {
+        let pdb = "\
+ATOM      1  CA  GLY     1       0.000   0.000   0.000  1.00 20.00      PROA C
+HETATM    2  O   HOH     2       3.000   0.000   0.000  1.00 20.00      SOLV O
+HETATM    3  O   HOH     3       6.000   0.000   0.000  1.00 20.00      SOLV O
+";
+        let structure = crate::io::read_pdb_structure(
+            std::io::Cursor::new(pdb),
+            &crate::io::IoContext::new_default(),
+        )
+        .expect("reader should succeed");
+        let segids = |s: &Structure| -> Vec<Option<String>> {
+            s.iter_chains()
+                .flat_map(|c| c.iter_residues())
+                .map(|r| r.segid.clone())
+                .collect()
+        };
+        assert_eq!(structure.chain_count(), 1);
+        assert_eq!(
+            segids(&structure),
+            vec![
+                Some("PROA".to_string()),
+                Some("SOLV".to_string()),
+                Some("SOLV".to_string())
+            ]
+        );
+
+        let mut buffer = Vec::new();
+        write_structure(&mut buffer, &structure, &PdbWriteConfig::default())
+            .expect("writer should succeed");
+        let output = String::from_utf8(buffer).expect("valid UTF-8");
+        let columns: Vec<&str> = output
+            .lines()
+            .filter(|l| l.starts_with("ATOM") || l.starts_with("HETATM"))
+            .map(|l| &l[72..76])
+            .collect();
+        assert_eq!(columns, vec!["PROA", "SOLV", "SOLV"]);
+
+        let reread = crate::io::read_pdb_structure(
+            std::io::Cursor::new(output),
+            &crate::io::IoContext::new_default(),
+        )
+        .expect("round trip should parse");
+        assert_eq!(segids(&reread), segids(&structure));
+    }
+
+    #[test]
+    fn write_structure_uses_crlf_line_endings_when_configured() {
+        let mut structure = Structure::new();
+        structure.add_chain(single_gly_chain("A"));
+        let config = PdbWriteConfig {
+            line_ending: LineEnding::CrLf,
+            ..PdbWriteConfig::default()
+        };
+
+        let mut buffer = Vec::new();
+        write_structure(&mut buffer, &structure, &config).expect("writer should succeed");
+        let text = String::from_utf8(buffer).unwrap();
+
+        assert!(text.ends_with("END   \r\n"));
+        assert_eq!(text.matches("\r\n").count(), text.matches('\n').count());
+    }
+
+    #[test]
+    fn write_topology_trims_trailing_whitespace_when_configured() {
+        let mut chain = single_gly_chain("A");
+        let gly = chain.iter_residues_mut().next().unwrap();
+        gly.add_atom(Atom::new("C", Element::C, Point::new(1.5, 0.0, 0.0)));
+        let mut structure = Structure::new();
+        structure.add_chain(chain);
+        let topology = Topology::new(structure, vec![Bond::new(0, 1, BondOrder::Single)]);
+        let config = PdbWriteConfig {
+            trim_trailing: true,
+            ..PdbWriteConfig::default()
+        };
+
+        let mut padded = Vec::new();
+        write_topology(&mut padded, &topology, &PdbWriteConfig::default()).unwrap();
+        let padded = String::from_utf8(padded).unwrap();
+        let mut trimmed = Vec::new();
+        write_topology(&mut trimmed, &topology, &config).unwrap();
+        let trimmed = String::from_utf8(trimmed).unwrap();
+
+        assert!(padded.lines().any(|l| l.ends_with(' ')));
+        assert!(trimmed.lines().all(|l| !l.ends_with(' ')));
+        assert!(trimmed.lines().any(|l| l == "END"));
+        let padded_lines: Vec<&str> = padded.lines().map(str::trim_end).collect();
+        assert_eq!(trimmed.lines().collect::<Vec<_>>(), padded_lines);
+    }
 }
@@ -0,0 +1,220 @@
+//! MDL MOL/SDF (V2000) writer for single-molecule topologies.
+//!
+//! Small-molecule toolkits such as RDKit and Open Babel exchange ligands through the MDL
+//! connection table format. The writer emits one V2000 MOL block per topology, with atoms in
+//! the topology's global order, and terminates it with the `$$$$` SDF record separator.
+
+use crate::io::error::Error;
+use crate::model::{
+    topology::Topology,
+    types::{BondOrder, Element},
+};
+use std::io::Write;
+
+/// Largest atom or bond count representable in the three-column V2000 counts line.
+const V2000_MAX_ENTRIES: usize = 999;
+
+/// Writes a [`Topology`] as a V2000 MOL block followed by the SDF `$$$$` delimiter.
+///
+/// The header name is taken from the first residue, coordinates use the `%10.4f` layout,
+/// and bond orders map to MDL codes (`1` single, `2` double, `3` triple, `4` aromatic).
+/// Atom numbers in the bond block are 1-based global indices of the topology.
+///
+/// # Arguments
+///
+/// * `writer` - Destination that implements [`Write`].
+/// * `topology` - Molecule whose atoms and bonds are serialized.
+///
+/// # Returns
+///
+/// [`Ok`] on success or [`Error`] if any IO operation fails.
+///
+/// # Errors
+///
+/// Returns [`Error::InconsistentData`] when the topology has more than 999 atoms or bonds,
+/// which the V2000 counts line cannot represent.
+///
+/// # Examples
+///
+/// ```
+/// use bio_forge::{Atom, Bond, BondOrder, Chain, Element, Point, Residue, ResidueCategory};
+/// use bio_forge::{Structure, Topology};
+/// use bio_forge::io::write_sdf_topology;
+///
+/// let mut residue = Residue::new(1, None, "CO", None, ResidueCategory::Hetero);
+/// residue.add_atom(Atom::new("C", Element::C, Point::new(0.0, 0.0, 0.0)));
+/// residue.add_atom(Atom::new("O", Element::O, Point::new(1.2, 0.0, 0.0)));
+/// let mut chain = Chain::new("A");
+/// chain.add_residue(residue);
+/// let structure: Structure = std::iter::once(chain).collect();
+/// let topology = Topology::new(structure, vec![Bond::new(0, 1, BondOrder::Double)]);
+///
+/// let mut out = Vec::new();
+/// write_sdf_topology(&mut out, &topology).unwrap();
+/// assert!(String::from_utf8(out).unwrap().ends_with("$$$$\n"));
+/// ```
+pub fn write_topology<W: Write>(mut writer: W, topology: &Topology) -> Result<(), Error> {
+    let structure = topology.structure();
+    let atom_count = structure.atom_count();
+    let bond_count = topology.bond_count();
+
+    if atom_count > V2000_MAX_ENTRIES || bond_count > V2000_MAX_ENTRIES {
+        return Err(Error::inconsistent_data(
+            "SDF",
+            None,
+            format!(
+                "V2000 supports at most {V2000_MAX_ENTRIES} atoms and bonds (got {atom_count} atoms, {bond_count} bonds)"
+            ),
+        ));
+    }
+
+    let name = structure
+        .iter_chains()
+        .flat_map(|c| c.iter_residues())
+        .next()
+        .map(|r| r.name.to_string())
+        .unwrap_or_default();
+    let io = |e| Error::from_io(e, None);
+
+    writeln!(writer, "{name}").map_err(io)?;
+    writeln!(writer, "  bio-forge         3D").map_err(io)?;
+    writeln!(writer).map_err(io)?;
+    writeln!(
+        writer,
+        "{atom_count:3}{bond_count:3}  0  0  0  0  0  0  0  0999 V2000"
+    )
+    .map_err(io)?;
+
+    for atom in structure.iter_atoms() {
+        writeln!(
+            writer,
+            "{:10.4}{:10.4}{:10.4} {:<3} 0  0  0  0  0  0  0  0  0  0  0  0",
+            atom.pos.x,
+            atom.pos.y,
+            atom.pos.z,
+            element_symbol(atom.element)
+        )
+        .map_err(io)?;
+    }
+
+    for bond in topology.bonds() {
+        writeln!(
+            writer,
+            "{:3}{:3}{:3}  0",
+            bond.a1_idx + 1,
+            bond.a2_idx + 1,
+            mdl_bond_code(bond.order)
+        )
+        .map_err(io)?;
+    }
+
+    writeln!(writer, "M  END").map_err(io)?;
+    writeln!(writer, "$$$$").map_err(io)?;
+
+    Ok(())
+}
+
+/// Maps an element onto its MDL atom symbol, using `*` for unknown atoms.
+fn element_symbol(element: Element) -> &'static str {
+    match element {
+        Element::Unknown => "*",
+        other => other.symbol(),
+    }
+}
+
+/// Converts a [`BondOrder`] into the MDL bond type code.
+///
+/// V2000 has no dative bond type, so coordination bonds are written as type 8 ("any").
+fn mdl_bond_code(order: BondOrder) -> u8 {
+    match order {
+        BondOrder::Single => 1,
+        BondOrder::Double => 2,
+        BondOrder::Triple => 3,
+        BondOrder::Aromatic => 4,
+        BondOrder::Coordination => 8,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::{
+        atom::Atom,
+        chain::Chain,
+        residue::Residue,
+        structure::Structure,
+        topology::Bond,
+        types::{Point, ResidueCategory},
+    };
+
+    fn ethanal() -> Topology {
+        let mut residue = Residue::new(1, None, "ACD", None, ResidueCategory::Hetero);
+        residue.add_atom(Atom::new("C1", Element::C, Point::new(0.0, 0.0, 0.0)));
+        residue.add_atom(Atom::new("C2", Element::C, Point::new(1.5, 0.0, 0.0)));
+        residue.add_atom(Atom::new("O", Element::O, Point::new(2.1, -1.0512, 0.25)));
+        let mut chain = Chain::new("A");
+        chain.add_residue(residue);
+        let structure: Structure = std::iter::once(chain).collect();
+        Topology::new(
+            structure,
+            vec![
+                Bond::new(0, 1, BondOrder::Single),
+                Bond::new(2, 1, BondOrder::Double),
+            ],
+        )
+    }
+
+    #[test]
+    fn write_topology_emits_v2000_blocks() {
+        let mut buffer = Vec::new();
+        write_topology(&mut buffer, &ethanal()).expect("writer should succeed");
+
+        let output = String::from_utf8(buffer).expect("valid UTF-8");
+        let lines: Vec<&str> = output.lines().collect();
+
+        assert_eq!(lines[0], "ACD");
+        assert_eq!(lines.len(), 11);
+        assert!(lines[3].starts_with("  3  2"));
+        assert!(lines[3].ends_with("V2000"));
+        assert_eq!(&lines[6][..30], "    2.1000   -1.0512    0.2500");
+        assert_eq!(&lines[6][31..34], "O  ");
+        assert_eq!(lines[7], "  1  2  1  0");
+        assert_eq!(lines[8], "  2  3  2  0");
+        assert_eq!(lines[9], "M  END");
+        assert_eq!(lines[10], "$$$$");
+    }
+
+    #[test]
+    fn write_topology_maps_aromatic_bonds_and_unknown_elements() {
+        let mut residue = Residue::new(1, None, "UNK", None, ResidueCategory::Hetero);
+        residue.add_atom(Atom::new("X1", Element::Unknown, Point::origin()));
+        residue.add_atom(Atom::new("C1", Element::C, Point::new(1.4, 0.0, 0.0)));
+        let mut chain = Chain::new("A");
+        chain.add_residue(residue);
+        let structure: Structure = std::iter::once(chain).collect();
+        let topology = Topology::new(structure, vec![Bond::new(0, 1, BondOrder::Aromatic)]);
+
+        let mut buffer = Vec::new();
+        write_topology(&mut buffer, &topology).expect("writer should succeed");
+
+        let output = String::from_utf8(buffer).expect("valid UTF-8");
+        let lines: Vec<&str> = output.lines().collect();
+        assert_eq!(&lines[4][31..34], "*  ");
+        assert_eq!(lines[6], "  1  2  4  0");
+    }
+
+    #[test]
+    fn write_topology_rejects_counts_beyond_v2000_limits() {
+        let mut chain = Chain::new("A");
+        for id in 0..1000 {
+            let mut residue = Residue::new(id, None, "AR", None, ResidueCategory::Ion);
+            residue.add_atom(Atom::new("AR", Element::Ar, Point::origin()));
+            chain.add_residue(residue);
+        }
+        let structure: Structure = std::iter::once(chain).collect();
+        let topology = Topology::new(structure, Vec::new());
+
+        let err = write_topology(Vec::new(), &topology).expect_err("too many atoms");
+        assert!(matches!(err, Error::InconsistentData { format: "SDF", .. }));
+    }
+}
@@ -0,0 +1,145 @@
+//! Atom-subset extraction shared by the selection writers.
+//!
+//! Selections are global atom indices in [`Structure::iter_atoms`] order. The helpers copy
+//! the selected atoms into a standalone structure (keeping chain and residue grouping) and
+//! remap topology bonds onto it, so the regular writers can serialize the subset with fresh
+//! serial numbers.
+
+use crate::model::{
+    structure::Structure,
+    topology::{Bond, Topology},
+};
+
+/// Copies the selected atoms into a new structure.
+///
+/// Indices beyond the atom count are ignored and duplicates collapse. Residues and chains
+/// left without atoms are dropped; box vectors are preserved.
+///
+/// # Arguments
+///
+/// * `structure` - Source structure.
+/// * `indices` - Global atom indices to keep.
+///
+/// # Returns
+///
+/// A structure containing only the selected atoms, in their original order.
+pub(crate) fn select_structure(structure: &Structure, indices: &[usize]) -> Structure {
+    let mask = structure.selection_mask(indices);
+    let mut selected = structure.clone();
+
+    let mut global_idx = 0;
+    selected.retain_residues_mut(|_, residue| {
+        residue.retain_atoms(|_| {
+            let keep = mask[global_idx];
+            global_idx += 1;
+            keep
+        });
+        !residue.is_empty()
+    });
+    selected.prune_empty_chains();
+
+    selected
+}
+
+/// Copies the selected atoms and the bonds between them into a new topology.
+///
+/// Bonds are kept only when both endpoints are selected and are renumbered to the new
+/// atom order.
+///
+/// # Arguments
+///
+/// * `topology` - Source topology.
+/// * `indices` - Global atom indices to keep.
+///
+/// # Returns
+///
+/// A topology over the selected atoms, following [`select_structure`] semantics.
+pub(crate) fn select_topology(topology: &Topology, indices: &[usize]) -> Topology {
+    let structure = topology.structure();
+    let mask = structure.selection_mask(indices);
+
+    let mut new_index = vec![None; mask.len()];
+    let kept = mask.iter().enumerate().filter(|(_, keep)| **keep);
+    for (new, (old, _)) in kept.enumerate() {
+        new_index[old] = Some(new);
+    }
+
+    let bonds = topology
+        .bonds()
+        .iter()
+        .filter_map(|bond| {
+            let a1 = new_index.get(bond.a1_idx).copied().flatten()?;
+            let a2 = new_index.get(bond.a2_idx).copied().flatten()?;
+            Some(Bond::new(a1, a2, bond.order))
+        })
+        .collect();
+
+    Topology::new(select_structure(structure, indices), bonds)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::{
+        atom::Atom,
+        chain::Chain,
+        residue::Residue,
+        types::{BondOrder, Element, Point, ResidueCategory, StandardResidue},
+    };
+
+    fn sample_topology() -> Topology {
+        let mut chain_a = Chain::new("A");
+        let mut gly = Residue::new(
+            1,
+            None,
+            "GLY",
+            Some(StandardResidue::GLY),
+            ResidueCategory::Standard,
+        );
+        gly.add_atom(Atom::new("N", Element::N, Point::new(0.0, 0.0, 0.0)));
+        gly.add_atom(Atom::new("CA", Element::C, Point::new(1.5, 0.0, 0.0)));
+        gly.add_atom(Atom::new("C", Element::C, Point::new(3.0, 0.0, 0.0)));
+        chain_a.add_residue(gly);
+
+        let mut chain_b = Chain::new("B");
+        let mut ion = Residue::new(1, None, "NA", None, ResidueCategory::Ion);
+        ion.add_atom(Atom::new("NA", Element::Na, Point::new(9.0, 0.0, 0.0)));
+        chain_b.add_residue(ion);
+
+        let mut structure = Structure::new();
+        structure.add_chain(chain_a);
+        structure.add_chain(chain_b);
+        structure.box_vectors = Some([[10.0, 0.0, 0.0], [0.0, 10.0, 0.0], [0.0, 0.0, 10.0]]);
+
+        Topology::new(
+            structure,
+            vec![
+                Bond::new(0, 1, BondOrder::Single),
+                Bond::new(1, 2, BondOrder::Single),
+            ],
+        )
+    }
+
+    #[test]
+    fn select_structure_keeps_grouping_and_drops_empty_containers() {
+        let topology = sample_topology();
+
+        let selected = select_structure(topology.structure(), &[2, 1, 1, 99]);
+
+        assert_eq!(selected.chain_count(), 1);
+        let names: Vec<_> = selected.iter_atoms().map(|a| a.name.to_string()).collect();
+        assert_eq!(names, vec!["CA", "C"]);
+        assert!(selected.box_vectors.is_some());
+    }
+
+    #[test]
+    fn select_topology_remaps_bonds_with_both_endpoints_selected() {
+        let topology = sample_topology();
+
+        let selected = select_topology(&topology, &[1, 2, 3]);
+
+        assert_eq!(selected.atom_count(), 3);
+        assert_eq!(selected.bonds(), &[Bond::new(0, 1, BondOrder::Single)]);
+        assert_eq!(selected.structure().chain_count(), 2);
+    }
+}
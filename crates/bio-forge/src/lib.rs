@@ -16,6 +16,7 @@
 //! - **Topology reconstruction** – `Topology` and `Bond` exports allow downstream force-field assignment or connectivity validation without re-parsing raw coordinates.
 
 mod db;
+mod error;
 mod model;
 mod utils;
 
@@ -23,13 +24,20 @@ pub mod io;
 pub mod ops;
 pub mod templates;
 
+pub use error::Error;
 pub use model::atom::Atom;
+pub use model::builder::{BuildError, StructureBuilder};
 pub use model::chain::Chain;
+pub use model::geometry;
 pub use model::grid::{Grid, GridNeighborhood};
+pub use model::pbc;
+pub use model::properties::ElementProperties;
 pub use model::residue::Residue;
-pub use model::structure::Structure;
+pub use model::structure::{
+    AtomRecord, ChainBreak, ResidueDiff, ResidueOrderIssue, Structure, ValidationIssue,
+};
 pub use model::template::Template;
-pub use model::topology::{Bond, Topology};
+pub use model::topology::{Bond, Interaction, InteractionKind, Topology};
 pub use model::types::{
     BondOrder, Element, Point, ResidueCategory, ResiduePosition, StandardResidue,
 };
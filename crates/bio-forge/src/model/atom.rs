@@ -15,13 +15,36 @@ use std::fmt;
 /// metadata close to the coordinate allows downstream algorithms (e.g., heavy-atom filters
 /// or hydrogen placement) to reason locally without traversing additional tables.
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Atom {
     /// Atom name as it appears in crystallographic or modeling files (e.g., `CA`).
     pub name: SmolStr,
     /// Chemical element derived from the periodic table definitions.
     pub element: Element,
     /// Cartesian coordinates measured in ångströms.
+    #[cfg_attr(feature = "serde", serde(with = "super::types::point_serde"))]
     pub pos: Point,
+    /// Crystallographic occupancy in the range `0.0..=1.0`; defaults to `1.0`.
+    pub occupancy: f64,
+    /// Isotropic temperature factor (B-factor, or pLDDT in predicted models); defaults to `0.0`.
+    pub b_factor: f64,
+    /// Force-field partial charge in electrons, when assigned.
+    pub partial_charge: Option<f64>,
+    /// Integer formal charge (e.g. `+2` for Mg²⁺), when known.
+    pub formal_charge: Option<i8>,
+    /// Anisotropic displacement parameters `[U11, U22, U33, U12, U13, U23]` in Å², when
+    /// refined.
+    pub anisou: Option<[f64; 6]>,
+    /// Whether the atom was built by `bio-forge` rather than read from an input file;
+    /// defaults to `false`. Set for hydrogens placed by hydrogenation, heavy atoms (including
+    /// OXT and OP3) rebuilt by repair, and waters and ions added by solvation.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub generated: bool,
+    /// Alternate-location label (e.g. `'A'`) of the conformer this atom belongs to; `None`
+    /// for atoms shared by every conformer. Readers only set it when asked to keep
+    /// conformers, see [`ReadOptions::keep_alt_locs`](crate::io::ReadOptions::keep_alt_locs).
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub alt_loc: Option<char>,
 }
 
 impl Atom {
@@ -44,6 +67,13 @@ impl Atom {
             name: SmolStr::new(name),
             element,
             pos,
+            occupancy: 1.0,
+            b_factor: 0.0,
+            partial_charge: None,
+            formal_charge: None,
+            anisou: None,
+            generated: false,
+            alt_loc: None,
         }
     }
 
@@ -114,6 +144,9 @@ mod tests {
         assert_eq!(atom.name, "C1");
         assert_eq!(atom.element, Element::C);
         assert_eq!(atom.pos, pos);
+        assert_eq!(atom.occupancy, 1.0);
+        assert_eq!(atom.partial_charge, None);
+        assert!(!atom.generated);
     }
 
     #[test]
@@ -0,0 +1,355 @@
+//! Fluent construction of [`Structure`] instances with eager validation.
+//!
+//! Building structures by hand requires wiring chains, residues, and atoms through the
+//! `add_*` methods, whose uniqueness checks are debug assertions only. [`StructureBuilder`]
+//! reports duplicate identifiers as errors at the point they are introduced, infers residue
+//! metadata from names, and annotates terminal positions when the model is finished.
+
+use super::atom::Atom;
+use super::chain::Chain;
+use super::residue::Residue;
+use super::structure::Structure;
+use super::types::{Element, Point, ResidueCategory, StandardResidue};
+use std::str::FromStr;
+use thiserror::Error;
+
+/// Errors raised while assembling a structure with [`StructureBuilder`].
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum BuildError {
+    /// A chain with the same identifier was already started.
+    #[error("duplicate chain '{chain_id}'")]
+    DuplicateChain { chain_id: String },
+
+    /// A residue with the same identifier already exists in the current chain.
+    #[error("duplicate residue {res_id}{} in chain '{chain_id}'", insertion_code.map(String::from).unwrap_or_default())]
+    DuplicateResidue {
+        chain_id: String,
+        res_id: i32,
+        insertion_code: Option<char>,
+    },
+
+    /// An atom with the same name already exists in the current residue.
+    #[error("duplicate atom '{atom_name}' in residue '{res_name}' ({res_id})")]
+    DuplicateAtom {
+        res_name: String,
+        res_id: i32,
+        atom_name: String,
+    },
+
+    /// A residue was added before any chain was started.
+    #[error("residue added before any chain was started")]
+    NoActiveChain,
+
+    /// An atom was added before any residue was started in the current chain.
+    #[error("atom added before any residue was started")]
+    NoActiveResidue,
+}
+
+/// Fluent builder that assembles chains, residues, and atoms into a [`Structure`].
+///
+/// Each call extends the most recently started chain or residue. Residue names are mapped
+/// onto [`StandardResidue`] when they match a canonical name, categories are inferred via
+/// [`Residue::infer_category`], and [`StructureBuilder::build`] marks the first and last
+/// polymer residues of every chain as termini.
+///
+/// # Examples
+///
+/// ```
+/// use bio_forge::{Element, Point, StructureBuilder};
+///
+/// let structure = StructureBuilder::new()
+///     .chain("A")?
+///     .residue(1, "ALA")?
+///     .atom("CA", Element::C, Point::new(0.0, 0.0, 0.0))?
+///     .residue(2, "GLY")?
+///     .atom("CA", Element::C, Point::new(3.8, 0.0, 0.0))?
+///     .build();
+///
+/// assert_eq!(structure.residue_count(), 2);
+/// # Ok::<(), bio_forge::BuildError>(())
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct StructureBuilder {
+    chains: Vec<Chain>,
+    box_vectors: Option<[[f64; 3]; 3]>,
+}
+
+impl StructureBuilder {
+    /// Creates an empty builder.
+    ///
+    /// # Returns
+    ///
+    /// A builder with no chains and no box vectors.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Starts a new chain that subsequent residues are appended to.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - Chain identifier, unique within the structure.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`BuildError::DuplicateChain`] if the identifier was already used.
+    pub fn chain(mut self, id: &str) -> Result<Self, BuildError> {
+        if self.chains.iter().any(|c| c.id == id) {
+            return Err(BuildError::DuplicateChain {
+                chain_id: id.to_string(),
+            });
+        }
+        self.chains.push(Chain::new(id));
+        Ok(self)
+    }
+
+    /// Starts a new residue without an insertion code in the current chain.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - Residue sequence number.
+    /// * `name` - Residue name, mapped onto [`StandardResidue`] when canonical.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`BuildError::NoActiveChain`] when no chain was started, or
+    /// [`BuildError::DuplicateResidue`] if the chain already holds residue `id`.
+    pub fn residue(self, id: i32, name: &str) -> Result<Self, BuildError> {
+        self.residue_with_insertion_code(id, None, name)
+    }
+
+    /// Starts a new residue with an optional insertion code in the current chain.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - Residue sequence number.
+    /// * `insertion_code` - Optional insertion code distinguishing residues sharing `id`.
+    /// * `name` - Residue name, mapped onto [`StandardResidue`] when canonical.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`BuildError::NoActiveChain`] when no chain was started, or
+    /// [`BuildError::DuplicateResidue`] if the `(id, insertion_code)` pair already exists.
+    pub fn residue_with_insertion_code(
+        mut self,
+        id: i32,
+        insertion_code: Option<char>,
+        name: &str,
+    ) -> Result<Self, BuildError> {
+        let chain = self.chains.last_mut().ok_or(BuildError::NoActiveChain)?;
+        if chain.residue(id, insertion_code).is_some() {
+            return Err(BuildError::DuplicateResidue {
+                chain_id: chain.id.to_string(),
+                res_id: id,
+                insertion_code,
+            });
+        }
+
+        let standard_name = StandardResidue::from_str(name).ok();
+        let category = if standard_name.is_some() {
+            ResidueCategory::Standard
+        } else {
+            ResidueCategory::Hetero
+        };
+        chain.add_residue(Residue::new(
+            id,
+            insertion_code,
+            name,
+            standard_name,
+            category,
+        ));
+        Ok(self)
+    }
+
+    /// Adds an atom to the current residue.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - Atom name, unique within the residue.
+    /// * `element` - Chemical element of the atom.
+    /// * `pos` - Cartesian position in ångströms.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`BuildError::NoActiveResidue`] when the current chain has no residue, or
+    /// [`BuildError::DuplicateAtom`] if the residue already holds an atom named `name`.
+    pub fn atom(mut self, name: &str, element: Element, pos: Point) -> Result<Self, BuildError> {
+        let residue = self
+            .chains
+            .last_mut()
+            .and_then(|c| c.iter_residues_mut().next_back())
+            .ok_or(BuildError::NoActiveResidue)?;
+        if residue.atom(name).is_some() {
+            return Err(BuildError::DuplicateAtom {
+                res_name: residue.name.to_string(),
+                res_id: residue.id,
+                atom_name: name.to_string(),
+            });
+        }
+        residue.add_atom(Atom::new(name, element, pos));
+        Ok(self)
+    }
+
+    /// Sets the periodic box vectors of the resulting structure.
+    ///
+    /// # Arguments
+    ///
+    /// * `box_vectors` - Crystallographic basis vectors.
+    pub fn box_vectors(mut self, box_vectors: [[f64; 3]; 3]) -> Self {
+        self.box_vectors = Some(box_vectors);
+        self
+    }
+
+    /// Finalizes the structure, inferring categories and terminal positions.
+    ///
+    /// Categories come from [`Structure::reclassify_categories`] and residue positions from
+    /// [`Structure::assign_positions`].
+    ///
+    /// # Returns
+    ///
+    /// The assembled [`Structure`].
+    pub fn build(self) -> Structure {
+        let mut structure: Structure = self.chains.into_iter().collect();
+        structure.box_vectors = self.box_vectors;
+        structure.reclassify_categories();
+
+        structure.assign_positions();
+
+        structure
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::types::ResiduePosition;
+
+    #[test]
+    fn build_assembles_chains_residues_and_atoms() {
+        let structure = StructureBuilder::new()
+            .chain("A")
+            .unwrap()
+            .residue(1, "ALA")
+            .unwrap()
+            .atom("N", Element::N, Point::new(0.0, 0.0, 0.0))
+            .unwrap()
+            .atom("CA", Element::C, Point::new(1.5, 0.0, 0.0))
+            .unwrap()
+            .chain("B")
+            .unwrap()
+            .residue(1, "NA")
+            .unwrap()
+            .atom("NA", Element::Na, Point::new(5.0, 5.0, 5.0))
+            .unwrap()
+            .box_vectors([[10.0, 0.0, 0.0], [0.0, 10.0, 0.0], [0.0, 0.0, 10.0]])
+            .build();
+
+        assert_eq!(structure.chain_count(), 2);
+        assert_eq!(structure.atom_count(), 3);
+        assert!(structure.box_vectors.is_some());
+
+        let ala = structure.find_residue("A", 1, None).unwrap();
+        assert_eq!(ala.standard_name, Some(StandardResidue::ALA));
+        assert_eq!(ala.category, ResidueCategory::Standard);
+        let ion = structure.find_residue("B", 1, None).unwrap();
+        assert_eq!(ion.category, ResidueCategory::Ion);
+        assert_eq!(ion.position, ResiduePosition::None);
+    }
+
+    #[test]
+    fn build_marks_terminal_positions_per_chain() {
+        let structure = StructureBuilder::new()
+            .chain("A")
+            .unwrap()
+            .residue(1, "MET")
+            .unwrap()
+            .residue(2, "GLY")
+            .unwrap()
+            .residue(3, "LYS")
+            .unwrap()
+            .residue(4, "HOH")
+            .unwrap()
+            .chain("N")
+            .unwrap()
+            .residue(1, "DA")
+            .unwrap()
+            .residue(2, "DT")
+            .unwrap()
+            .build();
+
+        let positions = |chain: &str| -> Vec<ResiduePosition> {
+            structure
+                .chain(chain)
+                .unwrap()
+                .iter_residues()
+                .map(|r| r.position)
+                .collect()
+        };
+        assert_eq!(
+            positions("A"),
+            vec![
+                ResiduePosition::NTerminal,
+                ResiduePosition::Internal,
+                ResiduePosition::CTerminal,
+                ResiduePosition::None,
+            ]
+        );
+        assert_eq!(
+            positions("N"),
+            vec![ResiduePosition::FivePrime, ResiduePosition::ThreePrime]
+        );
+    }
+
+    #[test]
+    fn builder_rejects_duplicate_identifiers() {
+        let err = StructureBuilder::new()
+            .chain("A")
+            .and_then(|b| b.chain("A"))
+            .unwrap_err();
+        assert_eq!(
+            err,
+            BuildError::DuplicateChain {
+                chain_id: "A".to_string()
+            }
+        );
+
+        let err = StructureBuilder::new()
+            .chain("A")
+            .and_then(|b| b.residue(5, "ALA"))
+            .and_then(|b| b.residue(5, "GLY"))
+            .unwrap_err();
+        assert!(matches!(
+            err,
+            BuildError::DuplicateResidue { res_id: 5, .. }
+        ));
+
+        let builder = StructureBuilder::new()
+            .chain("A")
+            .and_then(|b| b.residue_with_insertion_code(5, None, "ALA"))
+            .and_then(|b| b.residue_with_insertion_code(5, Some('A'), "ALA"));
+        assert!(builder.is_ok());
+
+        let err = StructureBuilder::new()
+            .chain("A")
+            .and_then(|b| b.residue(1, "ALA"))
+            .and_then(|b| b.atom("CA", Element::C, Point::origin()))
+            .and_then(|b| b.atom("CA", Element::C, Point::origin()))
+            .unwrap_err();
+        assert!(matches!(err, BuildError::DuplicateAtom { atom_name, .. } if atom_name == "CA"));
+    }
+
+    #[test]
+    fn builder_requires_active_chain_and_residue() {
+        assert_eq!(
+            StructureBuilder::new().residue(1, "ALA").unwrap_err(),
+            BuildError::NoActiveChain
+        );
+        assert_eq!(
+            StructureBuilder::new()
+                .chain("A")
+                .and_then(|b| b.atom("CA", Element::C, Point::origin()))
+                .unwrap_err(),
+            BuildError::NoActiveResidue
+        );
+    }
+}
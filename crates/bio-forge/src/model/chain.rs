@@ -16,6 +16,7 @@ use std::fmt;
 /// structure files (e.g., `"A"`), and provides iteration helpers for both residues and
 /// atoms while keeping the internal storage private.
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Chain {
     /// Chain identifier matching the source structure (usually a single character).
     pub id: SmolStr,
@@ -63,6 +64,30 @@ impl Chain {
         self.residues.push(residue);
     }
 
+    /// Inserts a residue at a specific index, shifting subsequent residues back.
+    ///
+    /// Duplicate `(id, insertion_code)` pairs are rejected during debug builds, mirroring
+    /// [`Chain::add_residue`].
+    ///
+    /// # Arguments
+    ///
+    /// * `index` - Position at which the residue is inserted.
+    /// * `residue` - The residue to insert.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index > residue_count()`.
+    pub fn insert_residue(&mut self, index: usize, residue: Residue) {
+        debug_assert!(
+            self.residue(residue.id, residue.insertion_code).is_none(),
+            "Attempted to insert a duplicate residue ID '{}' (ic: {:?}) into chain '{}'",
+            residue.id,
+            residue.insertion_code,
+            self.id
+        );
+        self.residues.insert(index, residue);
+    }
+
     /// Reserves capacity for at least `additional` more residues to be inserted.
     ///
     /// Use this to avoid frequent reallocations when adding a known number of residues.
@@ -135,6 +160,41 @@ impl Chain {
         self.residues.iter().map(|r| r.atom_count()).sum()
     }
 
+    /// Computes the mean B-factor over every atom in the chain.
+    ///
+    /// For predicted models the B-factor column usually carries pLDDT, making this the
+    /// average per-atom confidence of the chain.
+    ///
+    /// # Returns
+    ///
+    /// `Some(mean)` when the chain contains atoms, otherwise `None`.
+    pub fn mean_bfactor(&self) -> Option<f64> {
+        let count = self.atom_count();
+        if count == 0 {
+            return None;
+        }
+        let sum: f64 = self.iter_atoms().map(|a| a.b_factor).sum();
+        Some(sum / count as f64)
+    }
+
+    /// Builds the one-letter sequence of the chain's polymer residues.
+    ///
+    /// Only residues mapped to a protein or nucleic acid [`StandardResidue`] contribute;
+    /// waters, ions, and heterogens are skipped.
+    ///
+    /// # Returns
+    ///
+    /// The polymer sequence in residue order, empty for non-polymer chains.
+    ///
+    /// [`StandardResidue`]: super::types::StandardResidue
+    pub fn sequence_one_letter(&self) -> String {
+        self.residues
+            .iter()
+            .filter_map(|r| r.standard_name)
+            .filter_map(|s| s.one_letter_code())
+            .collect()
+    }
+
     /// Indicates whether the chain contains no residues.
     ///
     /// # Returns
@@ -242,6 +302,14 @@ impl Chain {
         self.residues.retain_mut(|residue| f(residue));
     }
 
+    /// Stable-sorts residues by residue number, then insertion code.
+    ///
+    /// Residues without an insertion code precede coded ones sharing the same number, so
+    /// `52`, `52A`, `52B`, `53` is the resulting order.
+    pub fn sort_residues(&mut self) {
+        self.residues.sort_by_key(|r| (r.id, r.insertion_code));
+    }
+
     /// Removes a residue by identifier and returns ownership if found.
     ///
     /// # Arguments
@@ -318,6 +386,35 @@ mod tests {
         assert_eq!(chain.residue(1, None).unwrap().name, "ALA");
     }
 
+    #[test]
+    fn chain_insert_residue_places_residue_at_index() {
+        let mut chain = Chain::new("A");
+        for id in [1, 3] {
+            chain.add_residue(Residue::new(
+                id,
+                None,
+                "ALA",
+                Some(StandardResidue::ALA),
+                ResidueCategory::Standard,
+            ));
+        }
+
+        chain.insert_residue(
+            1,
+            Residue::new(
+                2,
+                None,
+                "GLY",
+                Some(StandardResidue::GLY),
+                ResidueCategory::Standard,
+            ),
+        );
+
+        let ids: Vec<i32> = chain.iter_residues().map(|r| r.id).collect();
+        assert_eq!(ids, vec![1, 2, 3]);
+        assert_eq!(chain.residues()[1].name, "GLY");
+    }
+
     #[test]
     fn chain_reserve_increases_capacity() {
         let mut chain = Chain::new("A");
@@ -779,6 +876,43 @@ mod tests {
         assert_eq!(chain.residue(3, None).unwrap().name, "SER_MOD");
     }
 
+    #[test]
+    fn chain_mean_bfactor_averages_all_atoms() {
+        let mut chain = Chain::new("A");
+        assert_eq!(chain.mean_bfactor(), None);
+
+        let mut residue = sample_residue(1, "ALA");
+        for (name, b) in [("N", 10.0), ("CA", 20.0), ("C", 60.0)] {
+            let mut atom = Atom::new(name, Element::C, Point::origin());
+            atom.b_factor = b;
+            residue.add_atom(atom);
+        }
+        chain.add_residue(residue);
+
+        assert_eq!(chain.mean_bfactor(), Some(30.0));
+    }
+
+    #[test]
+    fn chain_sequence_one_letter_skips_non_polymer_residues() {
+        let mut chain = Chain::new("A");
+        for (id, name, std) in [
+            (1, "MET", Some(StandardResidue::MET)),
+            (2, "LIG", None),
+            (3, "TRP", Some(StandardResidue::TRP)),
+            (4, "HOH", Some(StandardResidue::HOH)),
+        ] {
+            let category = if std.is_some() {
+                ResidueCategory::Standard
+            } else {
+                ResidueCategory::Hetero
+            };
+            chain.add_residue(Residue::new(id, None, name, std, category));
+        }
+
+        assert_eq!(chain.sequence_one_letter(), "MW");
+        assert_eq!(Chain::new("B").sequence_one_letter(), "");
+    }
+
     #[test]
     fn chain_remove_residue_returns_removed_value() {
         let mut chain = Chain::new("A");
@@ -793,6 +927,39 @@ mod tests {
         assert_eq!(chain.residue_count(), 1);
     }
 
+    #[test]
+    fn chain_sort_residues_orders_by_id_then_insertion_code() {
+        let mut chain = Chain::new("A");
+        for (id, code) in [
+            (53, None),
+            (52, Some('B')),
+            (52, None),
+            (51, None),
+            (52, Some('A')),
+        ] {
+            let mut residue = sample_residue(id, "ALA");
+            residue.insertion_code = code;
+            chain.add_residue(residue);
+        }
+
+        chain.sort_residues();
+
+        let order: Vec<_> = chain
+            .iter_residues()
+            .map(|r| (r.id, r.insertion_code))
+            .collect();
+        assert_eq!(
+            order,
+            vec![
+                (51, None),
+                (52, None),
+                (52, Some('A')),
+                (52, Some('B')),
+                (53, None)
+            ]
+        );
+    }
+
     #[test]
     fn chain_remove_residue_returns_none_for_missing_entry() {
         let mut chain = Chain::new("A");
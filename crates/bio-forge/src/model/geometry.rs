@@ -0,0 +1,289 @@
+//! Bond angles, torsions, and improper dihedrals between atom positions.
+//!
+//! All functions take [`Point`] references and return radians, with `_deg` wrappers for
+//! reporting. Dihedrals follow the IUPAC sign convention and are evaluated with `atan2`, which
+//! stays accurate near 0° and 180° where an `acos`-based formula loses precision. The module
+//! also hosts the crate-internal Kabsch superposition shared by template fitting and
+//! structure comparison.
+
+use super::types::Point;
+use nalgebra::{Matrix3, Rotation3, Vector3};
+
+/// Computes the angle `a`-`b`-`c` at vertex `b`.
+///
+/// # Arguments
+///
+/// * `a` - First outer point.
+/// * `b` - Vertex point.
+/// * `c` - Second outer point.
+///
+/// # Returns
+///
+/// The angle in radians within `[0, π]`, or `0.0` when `a` or `c` coincides with `b`.
+pub fn angle(a: &Point, b: &Point, c: &Point) -> f64 {
+    (a - b).angle(&(c - b))
+}
+
+/// Computes the torsion angle `a`-`b`-`c`-`d` about the `b`-`c` axis.
+///
+/// Following IUPAC, the angle is positive when, looking along `b` → `c`, the `b`-`a` bond
+/// must rotate clockwise to eclipse the `c`-`d` bond. A cis arrangement gives `0` and a
+/// trans arrangement gives `π`.
+///
+/// # Arguments
+///
+/// * `a` - First point, bonded to `b`.
+/// * `b` - Start of the rotation axis.
+/// * `c` - End of the rotation axis.
+/// * `d` - Last point, bonded to `c`.
+///
+/// # Returns
+///
+/// The dihedral in radians within `[-π, π]`; `0.0` when three consecutive points are
+/// collinear and the angle is undefined.
+pub fn dihedral(a: &Point, b: &Point, c: &Point, d: &Point) -> f64 {
+    let b1 = b - a;
+    let b2 = c - b;
+    let b3 = d - c;
+
+    let n1 = b1.cross(&b2);
+    let n2 = b2.cross(&b3);
+    let y = b2.norm() * b1.dot(&n2);
+    let x = n1.dot(&n2);
+    y.atan2(x)
+}
+
+/// Computes the improper dihedral measuring how far `center` sits out of the plane of its
+/// three neighbors.
+///
+/// Uses the CHARMM ordering, i.e. the torsion `center`-`a`-`b`-`c`. The value is `0` when
+/// all four points are coplanar and changes sign when `center` crosses the plane.
+///
+/// # Arguments
+///
+/// * `center` - Central atom bonded to the three others.
+/// * `a` - First neighbor.
+/// * `b` - Second neighbor.
+/// * `c` - Third neighbor.
+///
+/// # Returns
+///
+/// The improper dihedral in radians within `[-π, π]`.
+pub fn improper_dihedral(center: &Point, a: &Point, b: &Point, c: &Point) -> f64 {
+    dihedral(center, a, b, c)
+}
+
+/// Computes the angle `a`-`b`-`c` in degrees.
+///
+/// # Arguments
+///
+/// * `a` - First outer point.
+/// * `b` - Vertex point.
+/// * `c` - Second outer point.
+///
+/// # Returns
+///
+/// [`angle`] converted to degrees, within `[0, 180]`.
+pub fn angle_deg(a: &Point, b: &Point, c: &Point) -> f64 {
+    angle(a, b, c).to_degrees()
+}
+
+/// Computes the torsion angle `a`-`b`-`c`-`d` in degrees.
+///
+/// # Arguments
+///
+/// * `a` - First point, bonded to `b`.
+/// * `b` - Start of the rotation axis.
+/// * `c` - End of the rotation axis.
+/// * `d` - Last point, bonded to `c`.
+///
+/// # Returns
+///
+/// [`dihedral`] converted to degrees, within `[-180, 180]`.
+pub fn dihedral_deg(a: &Point, b: &Point, c: &Point, d: &Point) -> f64 {
+    dihedral(a, b, c, d).to_degrees()
+}
+
+/// Computes the improper dihedral of `center` and its three neighbors in degrees.
+///
+/// # Arguments
+///
+/// * `center` - Central atom bonded to the three others.
+/// * `a` - First neighbor.
+/// * `b` - Second neighbor.
+/// * `c` - Third neighbor.
+///
+/// # Returns
+///
+/// [`improper_dihedral`] converted to degrees, within `[-180, 180]`.
+pub fn improper_dihedral_deg(center: &Point, a: &Point, b: &Point, c: &Point) -> f64 {
+    improper_dihedral(center, a, b, c).to_degrees()
+}
+
+/// Computes the rigid transform that best maps mobile points onto target points.
+///
+/// Uses Kabsch alignment for three or more pairs, a single-axis rotation for two pairs, and
+/// a pure translation for one pair. Reflections are excluded, so the rotation is proper.
+///
+/// # Arguments
+///
+/// * `pairs` - `(target, mobile)` coordinate pairs.
+///
+/// # Returns
+///
+/// Rotation matrix and translation vector such that `rotation * mobile + translation`
+/// approximates `target`; `None` when `pairs` is empty or the SVD fails to converge.
+pub(crate) fn superposition(pairs: &[(Point, Point)]) -> Option<(Matrix3<f64>, Vector3<f64>)> {
+    if pairs.is_empty() {
+        return None;
+    }
+
+    let n = pairs.len() as f64;
+    let target_center = pairs.iter().map(|p| p.0.coords).sum::<Vector3<f64>>() / n;
+    let mobile_center = pairs.iter().map(|p| p.1.coords).sum::<Vector3<f64>>() / n;
+
+    let rotation = match pairs.len() {
+        1 => Matrix3::identity(),
+        2 => {
+            let v_target = pairs[1].0 - pairs[0].0;
+            let v_mobile = pairs[1].1 - pairs[0].1;
+            Rotation3::rotation_between(&v_mobile, &v_target)
+                .unwrap_or_else(Rotation3::identity)
+                .into_inner()
+        }
+        _ => {
+            let mut cov = Matrix3::zeros();
+            for (p_target, p_mobile) in pairs {
+                cov += (p_target.coords - target_center)
+                    * (p_mobile.coords - mobile_center).transpose();
+            }
+
+            let svd = cov.svd(true, true);
+            let (u, v_t) = (svd.u?, svd.v_t?);
+            let mut rotation = u * v_t;
+            if rotation.determinant() < 0.0 {
+                let mut correction = Matrix3::identity();
+                correction[(2, 2)] = -1.0;
+                rotation = u * correction * v_t;
+            }
+            rotation
+        }
+    };
+
+    Some((rotation, target_center - rotation * mobile_center))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::f64::consts::{FRAC_PI_2, PI};
+
+    fn assert_close(actual: f64, expected: f64) {
+        assert!(
+            (actual - expected).abs() < 1e-9,
+            "expected {expected}, got {actual}"
+        );
+    }
+
+    #[test]
+    fn angle_measures_vertex_angle() {
+        let b = Point::origin();
+
+        assert_close(
+            angle(&Point::new(1.0, 0.0, 0.0), &b, &Point::new(0.0, 2.0, 0.0)),
+            FRAC_PI_2,
+        );
+        assert_close(
+            angle_deg(&Point::new(1.0, 0.0, 0.0), &b, &Point::new(-3.0, 0.0, 0.0)),
+            180.0,
+        );
+        assert_close(angle(&b, &b, &Point::new(1.0, 0.0, 0.0)), 0.0);
+    }
+
+    #[test]
+    fn dihedral_follows_iupac_sign_convention() {
+        let b = Point::origin();
+        let c = Point::new(0.0, 0.0, 1.5);
+        let a = Point::new(1.0, 0.0, 0.0);
+
+        assert_close(dihedral(&a, &b, &c, &Point::new(0.0, 1.0, 1.5)), FRAC_PI_2);
+        assert_close(
+            dihedral(&a, &b, &c, &Point::new(0.0, -1.0, 1.5)),
+            -FRAC_PI_2,
+        );
+        assert_close(dihedral_deg(&a, &b, &c, &Point::new(2.0, 0.0, 1.5)), 0.0);
+    }
+
+    #[test]
+    fn dihedral_is_stable_near_trans() {
+        let a = Point::new(1.0, 0.0, 0.0);
+        let b = Point::origin();
+        let c = Point::new(0.0, 0.0, 1.5);
+
+        assert_close(dihedral(&a, &b, &c, &Point::new(-1.0, 0.0, 1.5)).abs(), PI);
+
+        let eps = 1e-7;
+        let nearly_trans = dihedral(&a, &b, &c, &Point::new(-1.0, eps, 1.5));
+        assert!((PI - eps - nearly_trans).abs() < 1e-12);
+    }
+
+    #[test]
+    fn dihedral_is_zero_for_collinear_points() {
+        let points = [0.0, 1.0, 2.0, 3.0].map(|x| Point::new(x, 0.0, 0.0));
+
+        assert_close(
+            dihedral(&points[0], &points[1], &points[2], &points[3]),
+            0.0,
+        );
+    }
+
+    #[test]
+    fn improper_dihedral_vanishes_for_planar_center_and_flips_sign_across_plane() {
+        let a = Point::new(1.0, 0.0, 0.0);
+        let b = Point::new(-0.5, 0.8, 0.0);
+        let c = Point::new(-0.5, -0.8, 0.0);
+
+        assert_close(improper_dihedral(&Point::origin(), &a, &b, &c), 0.0);
+
+        let above = improper_dihedral_deg(&Point::new(0.0, 0.0, 0.3), &a, &b, &c);
+        let below = improper_dihedral_deg(&Point::new(0.0, 0.0, -0.3), &a, &b, &c);
+        assert!(above.abs() > 1.0);
+        assert_close(above, -below);
+    }
+
+    #[test]
+    fn superposition_recovers_rigid_transform() {
+        let rotation = Rotation3::from_euler_angles(0.4, -1.1, 2.3);
+        let shift = Vector3::new(3.0, -2.0, 7.5);
+        let mobile = [
+            Point::new(0.0, 0.0, 0.0),
+            Point::new(1.5, 0.0, 0.0),
+            Point::new(0.0, 2.0, 0.0),
+            Point::new(0.3, 0.4, 1.2),
+        ];
+        let pairs: Vec<(Point, Point)> =
+            mobile.iter().map(|p| (rotation * p + shift, *p)).collect();
+
+        let (rot, trans) = superposition(&pairs).unwrap();
+
+        assert!((rot - rotation.into_inner()).norm() < 1e-9);
+        assert!((trans - shift).norm() < 1e-9);
+        assert!(superposition(&[]).is_none());
+    }
+
+    #[test]
+    fn superposition_handles_one_and_two_pairs() {
+        let single = [(Point::new(1.0, 2.0, 3.0), Point::origin())];
+        let (rot, trans) = superposition(&single).unwrap();
+        assert_eq!(rot, Matrix3::identity());
+        assert!((trans - Vector3::new(1.0, 2.0, 3.0)).norm() < 1e-12);
+
+        let pair = [
+            (Point::new(0.0, 0.0, 0.0), Point::new(0.0, 0.0, 0.0)),
+            (Point::new(0.0, 2.0, 0.0), Point::new(2.0, 0.0, 0.0)),
+        ];
+        let (rot, trans) = superposition(&pair).unwrap();
+        let moved = Point::from(rot * pair[1].1.coords + trans);
+        assert!((moved - pair[1].0).norm() < 1e-9);
+    }
+}
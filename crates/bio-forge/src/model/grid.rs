@@ -2,17 +2,30 @@
 //!
 //! This module provides a [`Grid`] structure that partitions 3D space into uniform cells,
 //! enabling **O(1)** average-case lookups for neighbor searches, collision detection, and
-//! range queries.
+//! range queries. Cells are cubic by default; [`Grid::new_anisotropic`] accepts a separate
+//! edge length per axis for slab-like systems.
+//!
+//! Atom searches usually store global atom indices as the payload; build such grids with
+//! [`Structure::build_atom_grid`] so the stored `usize` is always an index into
+//! [`Structure::iter_atoms`] rather than a position inside the grid.
 
+use super::atom::Atom;
+use super::structure::Structure;
 use super::types::Point;
 use nalgebra::Vector3;
 
 /// Sentinel value indicating the end of a linked list.
 const SENTINEL: u32 = u32::MAX;
 
-/// A uniform spatial grid that bins items into cubic cells.
+/// Average number of items per cell targeted by [`Grid::with_auto_cell`].
+const AUTO_ITEMS_PER_CELL: f64 = 1.5;
+
+/// Lower bound (in ångströms) on cell sizes and box extents chosen by [`Grid::with_auto_cell`].
+const AUTO_MIN_CELL_SIZE: f64 = 1.0;
+
+/// A uniform spatial grid that bins items into box-shaped cells.
 ///
-/// The grid is defined by a bounding box and a cell size. Items are mapped to cells
+/// The grid is defined by a bounding box and per-axis cell edge lengths. Items are mapped to cells
 /// based on their coordinates. This structure is optimized for "fixed-radius" queries,
 /// where the search radius is comparable to the cell size.
 ///
@@ -22,8 +35,8 @@ const SENTINEL: u32 = u32::MAX;
 /// - Neighbor queries: **O(1)** average-case per query, assuming uniform distribution.
 #[derive(Debug, Clone)]
 pub struct Grid<T> {
-    /// Side length of each cubic cell.
-    cell_size: f64,
+    /// Edge length of each cell along the x, y, and z axes.
+    cell: Vector3<f64>,
     /// Minimum coordinate of the grid's bounding box.
     origin: Point,
     /// Number of cells along each dimension (x, y, z).
@@ -52,13 +65,35 @@ impl<T> Grid<T> {
     /// Panics if `cell_size` is non-positive.
     pub fn new(items: impl IntoIterator<Item = (Point, T)>, cell_size: f64) -> Self {
         assert!(cell_size > 0.0, "Cell size must be positive");
+        Self::new_anisotropic(items, Vector3::repeat(cell_size))
+    }
+
+    /// Creates a new grid whose cells have an independent edge length along each axis.
+    ///
+    /// Thin slabs or elongated boxes can use coarse cells along the short axis instead of
+    /// allocating many nearly empty cubic cells. Neighbor queries expand by
+    /// `ceil(radius / cell_axis)` cells per axis, so results match a cubic grid.
+    ///
+    /// # Arguments
+    ///
+    /// * `items` - Iterator yielding `(position, item)` pairs.
+    /// * `cell` - Cell edge lengths along the x, y, and z axes.
+    ///
+    /// # Panics
+    ///
+    /// Panics if any component of `cell` is non-positive.
+    pub fn new_anisotropic(
+        items: impl IntoIterator<Item = (Point, T)>,
+        cell: Vector3<f64>,
+    ) -> Self {
+        assert!(cell.iter().all(|&c| c > 0.0), "Cell size must be positive");
 
-        let input_items: Vec<_> = items.into_iter().collect();
-        let num_items = input_items.len();
+        let items: Vec<_> = items.into_iter().collect();
+        let num_items = items.len();
 
         if num_items == 0 {
             return Self {
-                cell_size,
+                cell,
                 origin: Point::origin(),
                 dims: Vector3::zeros(),
                 head: Vec::new(),
@@ -70,7 +105,7 @@ impl<T> Grid<T> {
         let mut min = Point::new(f64::MAX, f64::MAX, f64::MAX);
         let mut max = Point::new(f64::MIN, f64::MIN, f64::MIN);
 
-        for (pos, _) in &input_items {
+        for (pos, _) in &items {
             min = min.inf(pos);
             max = max.sup(pos);
         }
@@ -80,33 +115,218 @@ impl<T> Grid<T> {
 
         let extent = max - min;
         let dims = Vector3::new(
-            (extent.x / cell_size).ceil() as usize,
-            (extent.y / cell_size).ceil() as usize,
-            (extent.z / cell_size).ceil() as usize,
+            (extent.x / cell.x).ceil() as usize,
+            (extent.y / cell.y).ceil() as usize,
+            (extent.z / cell.z).ceil() as usize,
         );
 
         let total_cells = dims.x * dims.y * dims.z;
 
         let mut head = vec![SENTINEL; total_cells];
         let mut next = vec![SENTINEL; num_items];
-        let mut stored_items = Vec::with_capacity(num_items);
 
-        for (i, (pos, item)) in input_items.into_iter().enumerate() {
-            stored_items.push((pos, item));
-
-            if let Some(cell_idx) = Self::get_cell_index_static(&pos, dims, min, cell_size) {
+        for (i, (pos, _)) in items.iter().enumerate() {
+            if let Some(cell_idx) = Self::get_cell_index_static(pos, dims, min, cell) {
                 next[i] = head[cell_idx];
                 head[cell_idx] = i as u32;
             }
         }
 
         Self {
-            cell_size,
+            cell,
             origin: min,
             dims,
             head,
             next,
-            items: stored_items,
+            items,
+        }
+    }
+
+    /// Creates a grid directly from the atoms of a structure.
+    ///
+    /// Atoms are streamed in [`Structure::iter_atoms`] order into the grid's own storage, so
+    /// no intermediate position list is built. The payload closure receives each atom's
+    /// global index and may return `None` to leave the atom out of the grid.
+    ///
+    /// # Arguments
+    ///
+    /// * `structure` - Structure whose atoms are indexed.
+    /// * `cell_size` - The side length of each spatial bin.
+    /// * `payload` - Maps `(global_index, atom)` to the stored item, or `None` to skip it.
+    ///
+    /// # Returns
+    ///
+    /// A grid holding one entry per accepted atom at the atom's position.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `cell_size` is non-positive.
+    pub fn from_structure<F>(structure: &Structure, cell_size: f64, mut payload: F) -> Self
+    where
+        F: FnMut(usize, &Atom) -> Option<T>,
+    {
+        Self::new(
+            structure
+                .iter_atoms()
+                .enumerate()
+                .filter_map(|(idx, atom)| payload(idx, atom).map(|item| (atom.pos, item))),
+            cell_size,
+        )
+    }
+
+    /// Creates a new grid with a cell size derived from the item density.
+    ///
+    /// The heuristic divides the bounding-box volume by the item count and picks the cell
+    /// edge whose volume holds about 1.5 items on average. Flat or linear point sets have
+    /// their degenerate box extents raised to 1 Å, and the chosen cell size never drops
+    /// below 1 Å. Use [`Grid::new`] instead when the query radius is known, since matching
+    /// the cell size to that radius usually gives the fastest lookups.
+    ///
+    /// # Arguments
+    ///
+    /// * `items` - Iterator yielding `(position, item)` pairs.
+    ///
+    /// # Returns
+    ///
+    /// A grid indexing every item; [`Grid::cell_size`] reports the chosen size.
+    pub fn with_auto_cell(items: impl IntoIterator<Item = (Point, T)>) -> Self {
+        let items: Vec<_> = items.into_iter().collect();
+        let cell_size = Self::auto_cell_size(&items);
+        Self::new(items, cell_size)
+    }
+
+    /// Computes the density-based cell size used by [`Grid::with_auto_cell`].
+    fn auto_cell_size(items: &[(Point, T)]) -> f64 {
+        if items.is_empty() {
+            return AUTO_MIN_CELL_SIZE;
+        }
+
+        let mut min = Point::new(f64::MAX, f64::MAX, f64::MAX);
+        let mut max = Point::new(f64::MIN, f64::MIN, f64::MIN);
+        for (pos, _) in items {
+            min = min.inf(pos);
+            max = max.sup(pos);
+        }
+
+        let volume: f64 = (max - min)
+            .iter()
+            .map(|extent| extent.max(AUTO_MIN_CELL_SIZE))
+            .product();
+        let cell_volume = volume * AUTO_ITEMS_PER_CELL / items.len() as f64;
+
+        cell_volume.cbrt().max(AUTO_MIN_CELL_SIZE)
+    }
+
+    /// Returns the side length of each cell.
+    ///
+    /// # Returns
+    ///
+    /// The cell size in the same units as the item positions; for anisotropic grids, the
+    /// longest edge.
+    pub fn cell_size(&self) -> f64 {
+        self.cell.max()
+    }
+
+    /// Returns the cell edge lengths along each axis.
+    ///
+    /// # Returns
+    ///
+    /// The x, y, and z edge lengths, all equal for grids built with [`Grid::new`].
+    pub fn cell_dimensions(&self) -> Vector3<f64> {
+        self.cell
+    }
+
+    /// Returns the number of stored items, including any left unindexed by [`Grid::insert`].
+    ///
+    /// # Returns
+    ///
+    /// Total item count.
+    pub fn len(&self) -> usize {
+        self.items.len()
+    }
+
+    /// Reports whether the grid stores no items.
+    ///
+    /// # Returns
+    ///
+    /// `true` when [`Grid::len`] is zero.
+    pub fn is_empty(&self) -> bool {
+        self.items.is_empty()
+    }
+
+    /// Returns the number of cells spanned by the grid.
+    ///
+    /// # Returns
+    ///
+    /// Product of the cell counts along each axis.
+    pub fn cell_count(&self) -> usize {
+        self.head.len()
+    }
+
+    /// Summarizes how indexed items are distributed across cells.
+    ///
+    /// Useful for diagnosing slow queries: a large maximum relative to the mean points to
+    /// clustering that a smaller cell size would break up.
+    ///
+    /// # Returns
+    ///
+    /// A `(max_per_cell, mean_per_cell, empty_cells)` tuple. The mean is taken over all
+    /// cells and is `0.0` for a grid without cells.
+    pub fn occupancy_stats(&self) -> (usize, f64, usize) {
+        let mut max_per_cell = 0;
+        let mut empty_cells = 0;
+        let mut indexed = 0;
+
+        for &first in &self.head {
+            let mut count = 0;
+            let mut idx = first;
+            while idx != SENTINEL {
+                count += 1;
+                idx = self.next[idx as usize];
+            }
+            if count == 0 {
+                empty_cells += 1;
+            }
+            max_per_cell = max_per_cell.max(count);
+            indexed += count;
+        }
+
+        let mean_per_cell = if self.head.is_empty() {
+            0.0
+        } else {
+            indexed as f64 / self.head.len() as f64
+        };
+
+        (max_per_cell, mean_per_cell, empty_cells)
+    }
+
+    /// Inserts an item into the existing grid without rebuilding it.
+    ///
+    /// The item is linked into the cell containing `pos` in **O(1)**. The bounding box is
+    /// fixed at construction time: items whose position falls outside it are still stored
+    /// but remain unindexed, so neighbor queries will not return them until the grid is
+    /// rebuilt with [`Grid::new`].
+    ///
+    /// # Arguments
+    ///
+    /// * `pos` - Position of the new item.
+    /// * `item` - Payload to store.
+    ///
+    /// # Returns
+    ///
+    /// `true` if the item was indexed, `false` if it lies outside the grid bounds.
+    pub fn insert(&mut self, pos: Point, item: T) -> bool {
+        let idx = self.items.len();
+        self.items.push((pos, item));
+        self.next.push(SENTINEL);
+
+        match Self::get_cell_index_static(&pos, self.dims, self.origin, self.cell) {
+            Some(cell_idx) => {
+                self.next[idx] = self.head[cell_idx];
+                self.head[cell_idx] = idx as u32;
+                true
+            }
+            None => false,
         }
     }
 
@@ -115,16 +335,16 @@ impl<T> Grid<T> {
         pos: &Point,
         dims: Vector3<usize>,
         origin: Point,
-        cell_size: f64,
+        cell: Vector3<f64>,
     ) -> Option<usize> {
         if pos.x < origin.x || pos.y < origin.y || pos.z < origin.z {
             return None;
         }
 
         let offset = pos - origin;
-        let x = (offset.x / cell_size).floor() as usize;
-        let y = (offset.y / cell_size).floor() as usize;
-        let z = (offset.z / cell_size).floor() as usize;
+        let x = (offset.x / cell.x).floor() as usize;
+        let y = (offset.y / cell.y).floor() as usize;
+        let z = (offset.z / cell.z).floor() as usize;
 
         if x >= dims.x || y >= dims.y || z >= dims.z {
             return None;
@@ -143,7 +363,7 @@ impl<T> Grid<T> {
     /// * `center` - Center of the search sphere.
     /// * `radius` - Radius of the search sphere.
     pub fn neighbors<'a>(&'a self, center: &Point, radius: f64) -> GridNeighborhood<'a, T> {
-        if self.items.is_empty() {
+        if self.head.is_empty() {
             return GridNeighborhood {
                 grid: self,
                 min_x: 0,
@@ -182,12 +402,78 @@ impl<T> Grid<T> {
         }
     }
 
+    /// Collects the items within `radius` of `center`, nearest first.
+    ///
+    /// Unlike [`GridNeighborhood::exact`], which yields items in cell-traversal order, the
+    /// result is sorted by ascending distance; items at equal distance keep their traversal
+    /// order. Collecting and sorting costs `O(k log k)` for `k` items within the radius.
+    ///
+    /// # Arguments
+    ///
+    /// * `center` - Center of the search sphere.
+    /// * `radius` - Radius of the search sphere.
+    ///
+    /// # Returns
+    ///
+    /// `(item, distance)` pairs for every item within the radius, sorted by distance.
+    pub fn neighbors_sorted(&self, center: &Point, radius: f64) -> Vec<(&T, f64)> {
+        let mut found: Vec<(&T, f64)> = self
+            .neighbors(center, radius)
+            .exact_with_positions()
+            .map(|(pos, item)| (item, nalgebra::distance(pos, center)))
+            .collect();
+        found.sort_by(|a, b| a.1.total_cmp(&b.1));
+        found
+    }
+
+    /// Finds the item closest to a point, wherever it lies.
+    ///
+    /// The search starts with a sphere one cell wide and doubles its radius until an item
+    /// falls inside, so queries near the stored items touch only a few cells. Points far
+    /// outside the grid fall back to a sphere enclosing the whole bounding box.
+    ///
+    /// # Arguments
+    ///
+    /// * `point` - The query point; it may lie outside the grid.
+    ///
+    /// # Returns
+    ///
+    /// The nearest item and its distance, or `None` for an empty grid. Among items at equal
+    /// distance, the first in cell-traversal order wins.
+    pub fn nearest(&self, point: &Point) -> Option<(&T, f64)> {
+        if self.head.is_empty() {
+            return None;
+        }
+
+        let far_corner = self.origin + self.cell.component_mul(&self.dims.cast::<f64>());
+        let max_radius = Vector3::from_fn(|i, _| {
+            (point[i] - self.origin[i])
+                .abs()
+                .max((point[i] - far_corner[i]).abs())
+        })
+        .norm();
+
+        let mut radius = self.cell.min();
+        loop {
+            let radius_used = radius.min(max_radius);
+            let nearest = self
+                .neighbors(point, radius_used)
+                .exact_with_positions()
+                .map(|(pos, item)| (item, nalgebra::distance(pos, point)))
+                .min_by(|a, b| a.1.total_cmp(&b.1));
+            if nearest.is_some() || radius_used >= max_radius {
+                return nearest;
+            }
+            radius *= 2.0;
+        }
+    }
+
     /// Helper to get clamped grid coordinates (x, y, z).
     fn get_grid_coords(&self, pos: &Point) -> (usize, usize, usize) {
         let offset = pos - self.origin;
-        let x = (offset.x / self.cell_size).floor() as isize;
-        let y = (offset.y / self.cell_size).floor() as isize;
-        let z = (offset.z / self.cell_size).floor() as isize;
+        let x = (offset.x / self.cell.x).floor() as isize;
+        let y = (offset.y / self.cell.y).floor() as isize;
+        let z = (offset.z / self.cell.z).floor() as isize;
 
         (
             x.clamp(0, (self.dims.x as isize) - 1) as usize,
@@ -245,6 +531,33 @@ impl<'a, T> GridNeighborhood<'a, T> {
     pub fn exact(self) -> impl Iterator<Item = (Point, &'a T)> + 'a {
         ExactGridNeighborhood { inner: self }
     }
+
+    /// Returns an iterator that pairs each candidate item with its stored position.
+    ///
+    /// Like the plain iterator, this yields every item in the overlapping cells without a
+    /// distance check, so callers can compute or weight by the actual distance themselves.
+    ///
+    /// # Returns
+    ///
+    /// Iterator over `(position, item)` references into the grid.
+    pub fn with_positions(mut self) -> impl Iterator<Item = (&'a Point, &'a T)> + 'a {
+        std::iter::from_fn(move || self.next_entry().map(|(pos, item)| (pos, item)))
+    }
+
+    /// Returns an iterator over items strictly within the radius, with their stored positions.
+    ///
+    /// This is the borrowing counterpart of [`GridNeighborhood::exact`].
+    ///
+    /// # Returns
+    ///
+    /// Iterator over `(position, item)` references whose distance to the center is at most
+    /// the query radius.
+    pub fn exact_with_positions(self) -> impl Iterator<Item = (&'a Point, &'a T)> + 'a {
+        let center = self.center;
+        let radius_sq = self.radius_sq;
+        self.with_positions()
+            .filter(move |(pos, _)| nalgebra::distance_squared(*pos, &center) <= radius_sq)
+    }
 }
 
 /// Iterator that yields items strictly within the Euclidean radius.
@@ -260,51 +573,23 @@ impl<'a, T> Iterator for ExactGridNeighborhood<'a, T> {
     type Item = (Point, &'a T);
 
     fn next(&mut self) -> Option<Self::Item> {
-        loop {
-            if self.inner.curr_item_idx != SENTINEL {
-                let (pos, item) = &self.inner.grid.items[self.inner.curr_item_idx as usize];
-                self.inner.curr_item_idx = self.inner.grid.next[self.inner.curr_item_idx as usize];
-
-                if nalgebra::distance_squared(pos, &self.inner.center) <= self.inner.radius_sq {
-                    return Some((*pos, item));
-                }
-                continue;
-            }
-
-            if self.inner.curr_x > self.inner.max_x {
-                self.inner.curr_x = self.inner.min_x;
-                self.inner.curr_y += 1;
-            }
-            if self.inner.curr_y > self.inner.max_y {
-                self.inner.curr_y = self.inner.min_y;
-                self.inner.curr_z += 1;
-            }
-            if self.inner.curr_z > self.inner.max_z {
-                return None;
-            }
-
-            let cell_idx = self.inner.curr_x
-                + self.inner.curr_y * self.inner.grid.dims.x
-                + self.inner.curr_z * self.inner.grid.dims.x * self.inner.grid.dims.y;
-
-            self.inner.curr_x += 1;
-
-            if cell_idx < self.inner.grid.head.len() {
-                self.inner.curr_item_idx = self.inner.grid.head[cell_idx];
+        while let Some((pos, item)) = self.inner.next_entry() {
+            if nalgebra::distance_squared(pos, &self.inner.center) <= self.inner.radius_sq {
+                return Some((*pos, item));
             }
         }
+        None
     }
 }
 
-impl<'a, T> Iterator for GridNeighborhood<'a, T> {
-    type Item = &'a T;
-
-    fn next(&mut self) -> Option<Self::Item> {
+impl<'a, T> GridNeighborhood<'a, T> {
+    /// Advances to the next stored `(position, item)` entry in the candidate cells.
+    fn next_entry(&mut self) -> Option<&'a (Point, T)> {
         loop {
             if self.curr_item_idx != SENTINEL {
-                let (_, item) = &self.grid.items[self.curr_item_idx as usize];
+                let entry = &self.grid.items[self.curr_item_idx as usize];
                 self.curr_item_idx = self.grid.next[self.curr_item_idx as usize];
-                return Some(item);
+                return Some(entry);
             }
 
             if self.curr_x > self.max_x {
@@ -332,6 +617,14 @@ impl<'a, T> Iterator for GridNeighborhood<'a, T> {
     }
 }
 
+impl<'a, T> Iterator for GridNeighborhood<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.next_entry().map(|(_, item)| item)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -355,6 +648,123 @@ mod tests {
         assert!(!neighbors.contains(&&2));
     }
 
+    #[test]
+    fn grid_anisotropic_cells_match_cubic_neighbor_results() {
+        let points: Vec<_> = (0..20)
+            .flat_map(|x| (0..20).map(move |y| (x, y)))
+            .flat_map(|(x, y)| {
+                [0.0, 1.5].map(|z| {
+                    (
+                        Point::new(x as f64 * 1.7, y as f64 * 1.3, z),
+                        (x, y, z > 0.0),
+                    )
+                })
+            })
+            .collect();
+
+        let cubic = Grid::new(points.clone(), 1.0);
+        let slab = Grid::new_anisotropic(points, Vector3::new(2.5, 1.0, 10.0));
+
+        assert_eq!(slab.dims.z, 1);
+        assert!(slab.cell_count() < cubic.cell_count());
+        assert_eq!(slab.cell_dimensions(), Vector3::new(2.5, 1.0, 10.0));
+        assert_eq!(slab.cell_size(), 10.0);
+
+        for center in [
+            Point::new(10.0, 10.0, 0.5),
+            Point::new(0.0, 0.0, 0.0),
+            Point::new(32.3, 24.7, 1.5),
+        ] {
+            let mut expected: Vec<_> = cubic
+                .neighbors(&center, 3.2)
+                .exact()
+                .map(|(_, v)| *v)
+                .collect();
+            let mut actual: Vec<_> = slab
+                .neighbors(&center, 3.2)
+                .exact()
+                .map(|(_, v)| *v)
+                .collect();
+            expected.sort_unstable();
+            actual.sort_unstable();
+            assert!(!expected.is_empty());
+            assert_eq!(actual, expected);
+        }
+    }
+
+    #[test]
+    fn grid_with_auto_cell_targets_item_density() {
+        let points: Vec<_> = (0..10)
+            .flat_map(|x| (0..10).flat_map(move |y| (0..10).map(move |z| (x, y, z))))
+            .map(|(x, y, z)| (Point::new(x as f64, y as f64, z as f64) * 3.0, (x, y, z)))
+            .collect();
+
+        let grid = Grid::with_auto_cell(points);
+
+        let expected = (27.0f64.powi(3) * AUTO_ITEMS_PER_CELL / 1000.0).cbrt();
+        assert!((grid.cell_size() - expected).abs() < 1e-9);
+        let found: Vec<_> = grid
+            .neighbors(&Point::new(9.0, 9.0, 9.0), 0.1)
+            .exact()
+            .map(|(_, item)| *item)
+            .collect();
+        assert_eq!(found, vec![(3, 3, 3)]);
+    }
+
+    #[test]
+    fn grid_with_auto_cell_handles_degenerate_inputs() {
+        let empty: Grid<i32> = Grid::with_auto_cell(Vec::new());
+        assert_eq!(empty.cell_size(), AUTO_MIN_CELL_SIZE);
+
+        let single = Grid::with_auto_cell(vec![(Point::new(1.0, 2.0, 3.0), 7)]);
+        assert!(single.cell_size() >= AUTO_MIN_CELL_SIZE);
+        assert_eq!(single.neighbors(&Point::new(1.0, 2.0, 3.0), 0.5).count(), 1);
+
+        let line: Vec<_> = (0..100)
+            .map(|i| (Point::new(i as f64 * 0.1, 0.0, 0.0), i))
+            .collect();
+        let grid = Grid::with_auto_cell(line);
+        assert!(grid.cell_size() >= AUTO_MIN_CELL_SIZE);
+        assert_eq!(grid.neighbors(&Point::origin(), 0.05).exact().count(), 1);
+    }
+
+    #[test]
+    fn grid_insert_indexes_items_inside_bounds() {
+        let mut grid = Grid::new(
+            vec![
+                (Point::new(0.0, 0.0, 0.0), 1),
+                (Point::new(4.0, 4.0, 4.0), 2),
+            ],
+            1.0,
+        );
+
+        assert!(grid.insert(Point::new(2.2, 2.1, 2.0), 3));
+
+        let found: Vec<_> = grid
+            .neighbors(&Point::new(2.0, 2.0, 2.0), 0.5)
+            .exact()
+            .map(|(_, item)| *item)
+            .collect();
+        assert_eq!(found, vec![3]);
+        let corner: Vec<_> = grid.neighbors(&Point::origin(), 0.5).collect();
+        assert_eq!(corner, vec![&1]);
+    }
+
+    #[test]
+    fn grid_insert_leaves_out_of_bounds_items_unindexed() {
+        let mut grid = Grid::new(vec![(Point::new(0.0, 0.0, 0.0), 1)], 1.0);
+
+        assert!(!grid.insert(Point::new(10.0, 0.0, 0.0), 2));
+        assert!(!grid.insert(Point::new(-1.0, 0.0, 0.0), 3));
+
+        let found: Vec<_> = grid.neighbors(&Point::new(10.0, 0.0, 0.0), 1.0).collect();
+        assert!(!found.contains(&&2));
+
+        let mut empty: Grid<i32> = Grid::new(Vec::new(), 1.0);
+        assert!(!empty.insert(Point::origin(), 1));
+        assert_eq!(empty.neighbors(&Point::origin(), 1.0).count(), 0);
+    }
+
     #[test]
     fn grid_neighbors_returns_nearby_items() {
         let points = vec![
@@ -369,6 +779,60 @@ mod tests {
         assert_eq!(*neighbors[0], "A");
     }
 
+    #[test]
+    fn grid_neighbors_sorted_orders_items_by_distance() {
+        let points = vec![
+            (Point::new(3.0, 0.0, 0.0), "far"),
+            (Point::new(0.5, 0.0, 0.0), "near"),
+            (Point::new(0.0, 2.0, 0.0), "mid"),
+            (Point::new(9.0, 9.0, 9.0), "outside"),
+        ];
+        let grid = Grid::new(points, 1.0);
+
+        let sorted = grid.neighbors_sorted(&Point::origin(), 3.0);
+
+        let items: Vec<&str> = sorted.iter().map(|(item, _)| **item).collect();
+        assert_eq!(items, vec!["near", "mid", "far"]);
+        let distances: Vec<f64> = sorted.iter().map(|(_, d)| *d).collect();
+        assert_eq!(distances, vec![0.5, 2.0, 3.0]);
+        assert!(grid.neighbors_sorted(&Point::origin(), 0.1).is_empty());
+    }
+
+    #[test]
+    fn grid_nearest_matches_linear_scan_inside_and_outside_the_grid() {
+        let points: Vec<(Point, usize)> = (0..50)
+            .map(|i| {
+                let t = i as f64;
+                (
+                    Point::new((t * 1.7) % 9.0, (t * 2.3) % 7.0, (t * 0.9) % 5.0),
+                    i,
+                )
+            })
+            .collect();
+        let grid = Grid::new(points.clone(), 1.0);
+
+        for query in [
+            Point::new(4.2, 3.3, 2.1),
+            Point::new(-20.0, 3.0, 1.0),
+            Point::new(100.0, -50.0, 80.0),
+        ] {
+            let expected = points
+                .iter()
+                .map(|(pos, _)| nalgebra::distance(pos, &query))
+                .fold(f64::MAX, f64::min);
+
+            let (item, distance) = grid.nearest(&query).expect("grid is not empty");
+
+            assert!((distance - expected).abs() < 1e-12);
+            assert!((nalgebra::distance(&points[*item].0, &query) - expected).abs() < 1e-12);
+        }
+        assert!(
+            Grid::<usize>::new(Vec::new(), 1.0)
+                .nearest(&Point::origin())
+                .is_none()
+        );
+    }
+
     #[test]
     fn grid_handles_empty_input() {
         let points: Vec<(Point, i32)> = vec![];
@@ -451,4 +915,89 @@ mod tests {
         let count = grid.neighbors(&center, 0.99).exact().count();
         assert_eq!(count, 0);
     }
+
+    #[test]
+    fn grid_with_positions_yields_stored_coordinates() {
+        let points = vec![
+            (Point::new(0.0, 0.0, 0.0), "Center"),
+            (Point::new(0.9, 0.0, 0.0), "Inside"),
+            (Point::new(1.8, 0.0, 0.0), "Outside"),
+        ];
+        let grid = Grid::new(points, 2.0);
+        let center = Point::origin();
+
+        let mut candidates: Vec<_> = grid.neighbors(&center, 1.0).with_positions().collect();
+        candidates.sort_by(|a, b| a.0.x.total_cmp(&b.0.x));
+        assert_eq!(candidates.len(), 3);
+        assert_eq!(candidates[2], (&Point::new(1.8, 0.0, 0.0), &"Outside"));
+
+        let exact: Vec<_> = grid
+            .neighbors(&center, 1.0)
+            .exact_with_positions()
+            .map(|(pos, item)| ((pos - center).norm(), *item))
+            .collect();
+        assert_eq!(exact.len(), 2);
+        assert!(exact.contains(&(0.0, "Center")));
+        assert!(exact.contains(&(0.9, "Inside")));
+    }
+
+    #[test]
+    fn grid_reports_size_and_occupancy() {
+        let points = vec![
+            (Point::new(0.5, 0.5, 0.5), 1),
+            (Point::new(0.6, 0.5, 0.5), 2),
+            (Point::new(0.7, 0.5, 0.5), 3),
+            (Point::new(1.5, 1.5, 0.5), 4),
+        ];
+        let mut grid = Grid::new(points, 1.0);
+
+        assert_eq!(grid.len(), 4);
+        assert!(!grid.is_empty());
+        assert_eq!(grid.cell_count(), 4);
+        assert_eq!(grid.occupancy_stats(), (3, 1.0, 2));
+
+        assert!(!grid.insert(Point::new(10.0, 0.0, 0.0), 5));
+        assert_eq!(grid.len(), 5);
+        assert_eq!(grid.occupancy_stats(), (3, 1.0, 2));
+    }
+
+    #[test]
+    fn grid_occupancy_stats_handles_empty_grid() {
+        let grid: Grid<i32> = Grid::new(Vec::new(), 1.0);
+
+        assert!(grid.is_empty());
+        assert_eq!(grid.cell_count(), 0);
+        assert_eq!(grid.occupancy_stats(), (0, 0.0, 0));
+    }
+
+    #[test]
+    fn grid_from_structure_streams_atoms_with_payloads() {
+        use crate::model::{
+            chain::Chain,
+            residue::Residue,
+            types::{Element, ResidueCategory},
+        };
+
+        let mut residue = Residue::new(1, None, "LIG", None, ResidueCategory::Hetero);
+        residue.add_atom(Atom::new("C1", Element::C, Point::new(0.0, 0.0, 0.0)));
+        residue.add_atom(Atom::new("H1", Element::H, Point::new(1.0, 0.0, 0.0)));
+        residue.add_atom(Atom::new("O1", Element::O, Point::new(2.0, 0.0, 0.0)));
+        let mut chain = Chain::new("A");
+        chain.add_residue(residue);
+        let mut structure = Structure::new();
+        structure.add_chain(chain);
+
+        let grid = Grid::from_structure(&structure, 1.5, |idx, atom| {
+            atom.element.is_heavy_atom().then_some(idx)
+        });
+
+        assert_eq!(grid.len(), 2);
+        let mut found: Vec<_> = grid
+            .neighbors(&Point::new(1.0, 0.0, 0.0), 1.0)
+            .exact()
+            .map(|(_, idx)| *idx)
+            .collect();
+        found.sort_unstable();
+        assert_eq!(found, vec![0, 2]);
+    }
 }
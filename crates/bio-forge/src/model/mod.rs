@@ -1,13 +1,19 @@
 //! Core data structures modeling biological macromolecules.
 //!
 //! This module defines the foundational types for representing atoms, residues, chains,
-//! structures, and topologies. These types form the backbone of `bio-forge` and are
-//! consumed and mutated by I/O parsers, operations pipelines, and export routines.
+//! structures, and topologies, together with geometry and periodic-boundary helpers. These
+//! types form the backbone of `bio-forge` and are consumed and mutated by I/O parsers,
+//! operations pipelines, and export routines.
 
 pub mod atom;
+pub mod builder;
 pub mod chain;
+pub mod geometry;
 pub mod grid;
+pub mod pbc;
+pub mod properties;
 pub mod residue;
+pub mod sasa;
 pub mod structure;
 pub mod template;
 pub mod topology;
@@ -0,0 +1,225 @@
+//! Periodic boundary helpers for orthorhombic and triclinic simulation boxes.
+//!
+//! Box vectors follow the [`Structure::box_vectors`](super::structure::Structure::box_vectors)
+//! convention: each row is one lattice vector (`a`, `b`, `c`) in ångströms. Distances are
+//! computed by mapping the separation vector into fractional coordinates, shifting it to the
+//! nearest lattice image, and then checking the surrounding images so strongly skewed
+//! triclinic cells still yield the true minimum.
+
+use super::types::Point;
+use nalgebra::{Matrix3, Vector3};
+
+/// Computes the minimum-image distance between two points in a periodic box.
+///
+/// # Arguments
+///
+/// * `a` - First point.
+/// * `b` - Second point.
+/// * `box_vectors` - Lattice vectors of the periodic cell, one per row.
+///
+/// # Returns
+///
+/// The shortest distance between `a` and any periodic image of `b`. Degenerate boxes
+/// (zero volume) fall back to the plain Euclidean distance.
+pub fn minimum_image_distance(a: &Point, b: &Point, box_vectors: &[[f64; 3]; 3]) -> f64 {
+    minimum_image_distance_squared(a, b, box_vectors).sqrt()
+}
+
+/// Computes the squared minimum-image distance between two points in a periodic box.
+///
+/// Prefer this variant for cutoff comparisons to avoid the square root.
+///
+/// # Arguments
+///
+/// * `a` - First point.
+/// * `b` - Second point.
+/// * `box_vectors` - Lattice vectors of the periodic cell, one per row.
+///
+/// # Returns
+///
+/// The squared shortest distance between `a` and any periodic image of `b`, or the plain
+/// squared Euclidean distance for degenerate boxes.
+pub fn minimum_image_distance_squared(a: &Point, b: &Point, box_vectors: &[[f64; 3]; 3]) -> f64 {
+    minimum_image_vector(a, b, box_vectors).norm_squared()
+}
+
+/// Computes the shortest separation vector from `a` to any periodic image of `b`.
+///
+/// # Arguments
+///
+/// * `a` - Reference point.
+/// * `b` - Point whose nearest image is sought.
+/// * `box_vectors` - Lattice vectors of the periodic cell, one per row.
+///
+/// # Returns
+///
+/// The vector `b' - a`, where `b'` is the image of `b` closest to `a`; the unwrapped
+/// `b - a` for degenerate boxes.
+pub fn minimum_image_vector(a: &Point, b: &Point, box_vectors: &[[f64; 3]; 3]) -> Vector3<f64> {
+    let delta = b - a;
+    let cell = cell_matrix(box_vectors);
+    let Some(inverse) = cell.try_inverse() else {
+        return delta;
+    };
+
+    let fractional = inverse * delta;
+    let wrapped = cell * fractional.map(|f| f - f.round());
+
+    let mut best = wrapped;
+    let mut best_norm = wrapped.norm_squared();
+    for i in -1..=1 {
+        for j in -1..=1 {
+            for k in -1..=1 {
+                let shift = Vector3::new(f64::from(i), f64::from(j), f64::from(k));
+                let candidate = wrapped + cell * shift;
+                let norm = candidate.norm_squared();
+                if norm < best_norm {
+                    best = candidate;
+                    best_norm = norm;
+                }
+            }
+        }
+    }
+    best
+}
+
+/// Maps a point into the primary periodic cell spanned by the box vectors.
+///
+/// # Arguments
+///
+/// * `point` - Point to wrap.
+/// * `box_vectors` - Lattice vectors of the periodic cell, one per row.
+///
+/// # Returns
+///
+/// The image of `point` whose fractional coordinates lie in `[0, 1)`; the point unchanged
+/// for degenerate boxes.
+pub fn wrap_into_cell(point: &Point, box_vectors: &[[f64; 3]; 3]) -> Point {
+    let cell = cell_matrix(box_vectors);
+    let Some(inverse) = cell.try_inverse() else {
+        return *point;
+    };
+
+    let fractional = inverse * point.coords;
+    Point::from(cell * fractional.map(|f| f - f.floor()))
+}
+
+/// Enumerates the lattice translations to the 27 cells surrounding and including the origin.
+///
+/// # Arguments
+///
+/// * `box_vectors` - Lattice vectors of the periodic cell, one per row.
+///
+/// # Returns
+///
+/// An iterator over `i·a + j·b + k·c` for `i, j, k ∈ {-1, 0, 1}`.
+pub fn neighbor_translations(box_vectors: &[[f64; 3]; 3]) -> impl Iterator<Item = Vector3<f64>> {
+    let cell = cell_matrix(box_vectors);
+    (-1..=1).flat_map(move |i| {
+        (-1..=1).flat_map(move |j| {
+            (-1..=1).map(move |k| cell * Vector3::new(f64::from(i), f64::from(j), f64::from(k)))
+        })
+    })
+}
+
+/// Builds the cell matrix whose columns are the lattice vectors.
+fn cell_matrix(box_vectors: &[[f64; 3]; 3]) -> Matrix3<f64> {
+    let [a, b, c] = box_vectors.map(Vector3::from);
+    Matrix3::from_columns(&[a, b, c])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const CUBIC: [[f64; 3]; 3] = [[10.0, 0.0, 0.0], [0.0, 10.0, 0.0], [0.0, 0.0, 10.0]];
+    const SKEWED: [[f64; 3]; 3] = [[10.0, 0.0, 0.0], [8.0, 3.0, 0.0], [2.0, 1.5, 4.0]];
+
+    fn brute_force_squared(a: &Point, b: &Point, box_vectors: &[[f64; 3]; 3]) -> f64 {
+        let cell = cell_matrix(box_vectors);
+        let mut best = f64::MAX;
+        for i in -4..=4 {
+            for j in -4..=4 {
+                for k in -4..=4 {
+                    let shift = cell * Vector3::new(f64::from(i), f64::from(j), f64::from(k));
+                    best = best.min((b - a + shift).norm_squared());
+                }
+            }
+        }
+        best
+    }
+
+    #[test]
+    fn minimum_image_distance_wraps_across_orthorhombic_faces() {
+        let a = Point::new(1.0, 1.0, 1.0);
+        let b = Point::new(9.0, 9.0, 9.0);
+
+        assert!((minimum_image_distance(&a, &b, &CUBIC) - 12f64.sqrt()).abs() < 1e-12);
+        assert!((minimum_image_distance_squared(&a, &b, &CUBIC) - 12.0).abs() < 1e-12);
+
+        let near = Point::new(3.0, 1.0, 1.0);
+        assert!((minimum_image_distance(&a, &near, &CUBIC) - 2.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn minimum_image_distance_handles_hexagonal_cell() {
+        let hexagonal = [[10.0, 0.0, 0.0], [5.0, 75f64.sqrt(), 0.0], [0.0, 0.0, 10.0]];
+        let origin = Point::origin();
+        let b = Point::new(5.0, 75f64.sqrt() - 0.5, 0.0);
+
+        assert!((minimum_image_distance(&origin, &b, &hexagonal) - 0.5).abs() < 1e-9);
+        let vector = minimum_image_vector(&origin, &b, &hexagonal);
+        assert!((vector - Vector3::new(0.0, -0.5, 0.0)).norm() < 1e-9);
+    }
+
+    #[test]
+    fn minimum_image_distance_matches_brute_force_in_skewed_triclinic_cell() {
+        let a = Point::new(0.3, -0.7, 1.1);
+        for x in 0..6 {
+            for y in 0..6 {
+                for z in 0..4 {
+                    let b = Point::new(f64::from(x) * 2.9, f64::from(y) * 1.7, f64::from(z) * 2.3);
+                    let expected = brute_force_squared(&a, &b, &SKEWED);
+
+                    let actual = minimum_image_distance_squared(&a, &b, &SKEWED);
+
+                    assert!(
+                        (actual - expected).abs() < 1e-9,
+                        "{b:?}: {actual} vs {expected}"
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn wrap_into_cell_maps_points_to_fractional_unit_range() {
+        let wrapped = wrap_into_cell(&Point::new(-1.0, 23.0, 10.0), &CUBIC);
+        assert!((wrapped - Point::new(9.0, 3.0, 0.0)).norm() < 1e-12);
+
+        let skewed = wrap_into_cell(&Point::new(-7.0, 4.0, 9.0), &SKEWED);
+        let cell = cell_matrix(&SKEWED);
+        let fractional = cell.try_inverse().unwrap() * skewed.coords;
+        assert!(fractional.iter().all(|f| (0.0..1.0).contains(f)));
+        let delta = minimum_image_distance(&skewed, &Point::new(-7.0, 4.0, 9.0), &SKEWED);
+        assert!(delta < 1e-9);
+    }
+
+    #[test]
+    fn neighbor_translations_cover_surrounding_cells() {
+        let shifts: Vec<Vector3<f64>> = neighbor_translations(&CUBIC).collect();
+
+        assert_eq!(shifts.len(), 27);
+        assert!(shifts.contains(&Vector3::zeros()));
+        assert!(shifts.contains(&Vector3::new(-10.0, 10.0, 0.0)));
+    }
+
+    #[test]
+    fn minimum_image_distance_falls_back_to_euclidean_for_degenerate_box() {
+        let flat = [[10.0, 0.0, 0.0], [0.0, 10.0, 0.0], [0.0, 0.0, 0.0]];
+        let a = Point::origin();
+        let b = Point::new(9.0, 0.0, 7.0);
+
+        assert!((minimum_image_distance_squared(&a, &b, &flat) - 130.0).abs() < 1e-12);
+    }
+}
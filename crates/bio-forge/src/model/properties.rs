@@ -0,0 +1,296 @@
+//! Per-element physical parameters with runtime overrides.
+//!
+//! [`ElementProperties`] bundles atomic masses, van der Waals radii, and covalent radii.
+//! The default instance reproduces the built-in tables; callers working with non-standard
+//! parameter sets (for example united-atom force fields) override individual elements
+//! without patching the crate. Mass-weighted routines such as
+//! [`Structure::center_of_mass_with`](super::structure::Structure::center_of_mass_with) and
+//! [`Transform::center_mass_with`](crate::ops::Transform::center_mass_with) consume it.
+
+use super::atom::Atom;
+use super::types::{Element, Point};
+use std::collections::HashMap;
+
+/// Van der Waals radius (Å) used for elements missing from the built-in table.
+const FALLBACK_VDW_RADIUS: f64 = 2.0;
+
+/// Covalent radius (Å) used for elements missing from the built-in table.
+const FALLBACK_COVALENT_RADIUS: f64 = 1.5;
+
+/// Element parameter tables with optional per-element overrides.
+///
+/// Lookups consult the overrides first and fall back to the built-in values: IUPAC atomic
+/// masses, Bondi van der Waals radii, and Cordero covalent radii.
+///
+/// # Examples
+///
+/// ```
+/// use bio_forge::{Atom, Chain, Element, ElementProperties, Point, Residue, ResidueCategory};
+/// use bio_forge::Structure;
+///
+/// let mut residue = Residue::new(1, None, "DOD", None, ResidueCategory::Hetero);
+/// residue.add_atom(Atom::new("O", Element::O, Point::origin()));
+/// residue.add_atom(Atom::new("D1", Element::H, Point::new(0.96, 0.0, 0.0)));
+/// let mut chain = Chain::new("A");
+/// chain.add_residue(residue);
+/// let structure: Structure = std::iter::once(chain).collect();
+///
+/// let deuterium = ElementProperties::new().with_mass(Element::H, 2.014);
+/// assert!((deuterium.mass(Element::H) - 2.014).abs() < 1e-12);
+/// assert!(structure.total_mass_with(&deuterium) > structure.total_mass());
+/// ```
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ElementProperties {
+    masses: HashMap<Element, f64>,
+    vdw_radii: HashMap<Element, f64>,
+    covalent_radii: HashMap<Element, f64>,
+}
+
+impl ElementProperties {
+    /// Creates a parameter set matching the built-in tables.
+    ///
+    /// # Returns
+    ///
+    /// An `ElementProperties` without overrides.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Overrides the atomic mass of an element.
+    ///
+    /// # Arguments
+    ///
+    /// * `element` - Element whose mass is replaced.
+    /// * `mass` - Mass in daltons.
+    ///
+    /// # Returns
+    ///
+    /// The updated parameter set for chaining.
+    pub fn with_mass(mut self, element: Element, mass: f64) -> Self {
+        self.masses.insert(element, mass);
+        self
+    }
+
+    /// Overrides the van der Waals radius of an element.
+    ///
+    /// # Arguments
+    ///
+    /// * `element` - Element whose radius is replaced.
+    /// * `radius` - Radius in ångströms.
+    ///
+    /// # Returns
+    ///
+    /// The updated parameter set for chaining.
+    pub fn with_vdw_radius(mut self, element: Element, radius: f64) -> Self {
+        self.vdw_radii.insert(element, radius);
+        self
+    }
+
+    /// Overrides the covalent radius of an element.
+    ///
+    /// # Arguments
+    ///
+    /// * `element` - Element whose radius is replaced.
+    /// * `radius` - Radius in ångströms.
+    ///
+    /// # Returns
+    ///
+    /// The updated parameter set for chaining.
+    pub fn with_covalent_radius(mut self, element: Element, radius: f64) -> Self {
+        self.covalent_radii.insert(element, radius);
+        self
+    }
+
+    /// Looks up the atomic mass of an element.
+    ///
+    /// # Arguments
+    ///
+    /// * `element` - Element to query.
+    ///
+    /// # Returns
+    ///
+    /// The overridden mass, or [`Element::atomic_mass`] when none is set.
+    pub fn mass(&self, element: Element) -> f64 {
+        self.masses
+            .get(&element)
+            .copied()
+            .unwrap_or_else(|| element.atomic_mass())
+    }
+
+    /// Looks up the van der Waals radius of an element.
+    ///
+    /// # Arguments
+    ///
+    /// * `element` - Element to query.
+    ///
+    /// # Returns
+    ///
+    /// The overridden radius, or the Bondi radius (2.0 Å for elements without one).
+    pub fn vdw_radius(&self, element: Element) -> f64 {
+        self.vdw_radii
+            .get(&element)
+            .copied()
+            .unwrap_or_else(|| builtin_vdw_radius(element).unwrap_or(FALLBACK_VDW_RADIUS))
+    }
+
+    /// Looks up the covalent radius of an element.
+    ///
+    /// # Arguments
+    ///
+    /// * `element` - Element to query.
+    ///
+    /// # Returns
+    ///
+    /// The overridden radius, or the Cordero radius (1.5 Å for elements without one).
+    pub fn covalent_radius(&self, element: Element) -> f64 {
+        self.covalent_radii
+            .get(&element)
+            .copied()
+            .unwrap_or_else(|| builtin_covalent_radius(element).unwrap_or(FALLBACK_COVALENT_RADIUS))
+    }
+
+    /// Computes the mass-weighted center of a set of atoms.
+    ///
+    /// # Arguments
+    ///
+    /// * `atoms` - Atoms contributing to the center.
+    ///
+    /// # Returns
+    ///
+    /// `Some(Point)` at the center of mass, or `None` when the total mass is below
+    /// numerical tolerance.
+    pub(crate) fn center_of_mass<'a>(
+        &self,
+        atoms: impl IntoIterator<Item = &'a Atom>,
+    ) -> Option<Point> {
+        let mut total_mass = 0.0;
+        let mut weighted_sum = nalgebra::Vector3::zeros();
+
+        for atom in atoms {
+            let mass = self.mass(atom.element);
+            weighted_sum += atom.pos.coords * mass;
+            total_mass += mass;
+        }
+
+        (total_mass > 1e-9).then(|| Point::from(weighted_sum / total_mass))
+    }
+}
+
+/// Bondi van der Waals radii in ångströms.
+fn builtin_vdw_radius(element: Element) -> Option<f64> {
+    let radius = match element {
+        Element::H => 1.20,
+        Element::He => 1.40,
+        Element::Li => 1.82,
+        Element::C => 1.70,
+        Element::N => 1.55,
+        Element::O => 1.52,
+        Element::F => 1.47,
+        Element::Ne => 1.54,
+        Element::Na => 2.27,
+        Element::Mg => 1.73,
+        Element::Si => 2.10,
+        Element::P => 1.80,
+        Element::S => 1.80,
+        Element::Cl => 1.75,
+        Element::Ar => 1.88,
+        Element::K => 2.75,
+        Element::Ni => 1.63,
+        Element::Cu => 1.40,
+        Element::Zn => 1.39,
+        Element::Ga => 1.87,
+        Element::As => 1.85,
+        Element::Se => 1.90,
+        Element::Br => 1.85,
+        Element::Kr => 2.02,
+        Element::Pd => 1.63,
+        Element::Ag => 1.72,
+        Element::Cd => 1.58,
+        Element::In => 1.93,
+        Element::Sn => 2.17,
+        Element::Te => 2.06,
+        Element::I => 1.98,
+        Element::Xe => 2.16,
+        Element::Pt => 1.72,
+        Element::Au => 1.66,
+        Element::Hg => 1.55,
+        Element::Tl => 1.96,
+        Element::Pb => 2.02,
+        Element::U => 1.86,
+        _ => return None,
+    };
+    Some(radius)
+}
+
+/// Cordero covalent radii in ångströms (sp3 carbon, low-spin transition metals).
+fn builtin_covalent_radius(element: Element) -> Option<f64> {
+    let radius = match element {
+        Element::H => 0.31,
+        Element::Li => 1.28,
+        Element::B => 0.84,
+        Element::C => 0.76,
+        Element::N => 0.71,
+        Element::O => 0.66,
+        Element::F => 0.57,
+        Element::Na => 1.66,
+        Element::Mg => 1.41,
+        Element::Si => 1.11,
+        Element::P => 1.07,
+        Element::S => 1.05,
+        Element::Cl => 1.02,
+        Element::K => 2.03,
+        Element::Ca => 1.76,
+        Element::Mn => 1.39,
+        Element::Fe => 1.32,
+        Element::Co => 1.26,
+        Element::Ni => 1.24,
+        Element::Cu => 1.32,
+        Element::Zn => 1.22,
+        Element::As => 1.19,
+        Element::Se => 1.20,
+        Element::Br => 1.20,
+        Element::Mo => 1.54,
+        Element::Ag => 1.45,
+        Element::Cd => 1.44,
+        Element::I => 1.39,
+        Element::Pt => 1.36,
+        Element::Au => 1.36,
+        Element::Hg => 1.32,
+        _ => return None,
+    };
+    Some(radius)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn default_properties_match_builtin_tables() {
+        let props = ElementProperties::default();
+
+        assert_eq!(props.mass(Element::C), Element::C.atomic_mass());
+        assert_eq!(props.vdw_radius(Element::C), 1.70);
+        assert_eq!(props.covalent_radius(Element::S), 1.05);
+        assert_eq!(props.vdw_radius(Element::Unknown), FALLBACK_VDW_RADIUS);
+        assert_eq!(
+            props.covalent_radius(Element::Unknown),
+            FALLBACK_COVALENT_RADIUS
+        );
+    }
+
+    #[test]
+    fn overrides_replace_only_the_selected_element() {
+        let props = ElementProperties::new()
+            .with_mass(Element::C, 15.035)
+            .with_vdw_radius(Element::C, 2.0)
+            .with_covalent_radius(Element::N, 0.75);
+
+        assert_eq!(props.mass(Element::C), 15.035);
+        assert_eq!(props.vdw_radius(Element::C), 2.0);
+        assert_eq!(props.covalent_radius(Element::N), 0.75);
+        assert_eq!(props.mass(Element::N), Element::N.atomic_mass());
+        assert_eq!(props.vdw_radius(Element::N), 1.55);
+        assert_eq!(props.covalent_radius(Element::C), 0.76);
+    }
+}
@@ -5,7 +5,9 @@
 //! this module to inspect or mutate residues while preserving biochemical context.
 
 use super::atom::Atom;
-use super::types::{ResidueCategory, ResiduePosition, StandardResidue};
+use super::properties::ElementProperties;
+use super::template::Template;
+use super::types::{BondOrder, Element, Point, ResidueCategory, ResiduePosition, StandardResidue};
 use smol_str::SmolStr;
 use std::fmt;
 
@@ -15,10 +17,20 @@ use std::fmt;
 /// original and normalized names, classification metadata, and the atoms that belong to the
 /// residue. They are the primary unit passed between IO routines, topology builders, and
 /// structure editing operations.
+///
+/// The derived `PartialEq` compares atoms positionally, so residues holding the same atoms
+/// in a different order are unequal; use [`Residue::same_atoms`] for an order-independent
+/// check.
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Residue {
     /// Author-provided sequence identifier (can be negative for certain files).
     pub id: i32,
+    /// Author sequence number to publish instead of `id` in formats that carry it separately
+    /// (mmCIF `auth_seq_id`). Readers store the author number in `id` and leave this unset,
+    /// so renumbering `id` shows up on output; set it only when the published author
+    /// numbering must differ from `id`.
+    pub auth_id: Option<i32>,
     /// Optional insertion code differentiating records that share the same `id`.
     pub insertion_code: Option<char>,
     /// Original residue name as encountered during parsing.
@@ -29,6 +41,12 @@ pub struct Residue {
     pub category: ResidueCategory,
     /// Chain position annotation (terminus, internal, or nucleic end).
     pub position: ResiduePosition,
+    /// Optional segment identifier (PDB columns 73-76) used by CHARMM and NAMD workflows.
+    ///
+    /// Kept per residue because a single chain identifier, often blank, may span several
+    /// segments such as a protein and its solvent.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub segid: Option<String>,
     /// Owned atoms that comprise the residue.
     atoms: Vec<Atom>,
 }
@@ -59,11 +77,13 @@ impl Residue {
     ) -> Self {
         Self {
             id,
+            auth_id: None,
             insertion_code,
             name: SmolStr::new(name),
             standard_name,
             category,
             position: ResiduePosition::None,
+            segid: None,
             atoms: Vec::new(),
         }
     }
@@ -77,10 +97,69 @@ impl Residue {
         self.standard_name.is_some()
     }
 
+    /// Derives the residue category from its name, standard mapping, and atom content.
+    ///
+    /// The residue name is resolved first: residues with a standard mapping, or whose name
+    /// maps onto a [`StandardResidue`] via [`StandardResidue::from_name`] (including water),
+    /// are [`ResidueCategory::Standard`]. Single-atom residues are [`ResidueCategory::Ion`]
+    /// when their atom's element is a known ion, or when the element is unknown and the
+    /// residue name is a known ion, so a lone carbon named `CA` is not mistaken for calcium.
+    /// Everything else is [`ResidueCategory::Hetero`].
+    ///
+    /// # Returns
+    ///
+    /// The inferred category; the stored `category` is not modified.
+    pub fn infer_category(&self) -> ResidueCategory {
+        if self
+            .standard_name
+            .or_else(|| StandardResidue::from_name(&self.name))
+            .is_some()
+        {
+            return ResidueCategory::Standard;
+        }
+        match self.atoms.as_slice() {
+            [atom] if atom.element == Element::Unknown => {
+                if ResidueCategory::is_ion_name(&self.name) {
+                    ResidueCategory::Ion
+                } else {
+                    ResidueCategory::Hetero
+                }
+            }
+            [atom] if ResidueCategory::is_ion_name(atom.element.symbol()) => ResidueCategory::Ion,
+            _ => ResidueCategory::Hetero,
+        }
+    }
+
+    /// Updates the insertion code that disambiguates residues sharing the same `id`.
+    ///
+    /// Callers must ensure the new `(id, insertion_code)` pair stays unique within the
+    /// owning chain.
+    ///
+    /// # Arguments
+    ///
+    /// * `insertion_code` - New insertion code, or `None` to clear it.
+    pub fn set_insertion_code(&mut self, insertion_code: Option<char>) {
+        self.insertion_code = insertion_code;
+    }
+
+    /// Updates the canonical standard residue assignment.
+    ///
+    /// The residue `name` and `category` are left untouched so callers can relabel
+    /// residues independently from their canonical mapping.
+    ///
+    /// # Arguments
+    ///
+    /// * `standard_name` - New canonical assignment, or `None` for non-standard residues.
+    pub fn set_standard_name(&mut self, standard_name: Option<StandardResidue>) {
+        self.standard_name = standard_name;
+    }
+
     /// Appends an atom to the residue.
     ///
     /// Duplicate atom names are guarded with a debug assertion to prevent inconsistent
-    /// topologies while still allowing release builds to proceed.
+    /// topologies while still allowing release builds to proceed. Atoms sharing a name are
+    /// accepted when their [`Atom::alt_loc`] labels differ, since they are alternate
+    /// conformers of the same site.
     ///
     /// # Arguments
     ///
@@ -88,10 +167,13 @@ impl Residue {
     ///
     /// # Panics
     ///
-    /// Panics in debug builds if an atom with the same `name` already exists.
+    /// Panics in debug builds if an atom with the same `name` and `alt_loc` already exists.
     pub fn add_atom(&mut self, atom: Atom) {
         debug_assert!(
-            self.atom(&atom.name).is_none(),
+            !self
+                .atoms
+                .iter()
+                .any(|a| a.name == atom.name && a.alt_loc == atom.alt_loc),
             "Attempted to add a duplicate atom name '{}' to residue '{}'",
             atom.name,
             self.name
@@ -129,6 +211,28 @@ impl Residue {
         self.atoms.iter().find(|a| a.name == name)
     }
 
+    /// Retrieves an atom by name, ignoring ASCII case and surrounding whitespace.
+    ///
+    /// Helps diagnose inputs whose atom names are inconsistently cased or padded (`"Ca"`,
+    /// `" CA "`), which [`Residue::atom`] does not match. An exact match is preferred when
+    /// several atoms differ only in case.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - Atom name to search for.
+    ///
+    /// # Returns
+    ///
+    /// `Some(&Atom)` when a matching atom exists, otherwise `None`.
+    pub fn atom_ci(&self, name: &str) -> Option<&Atom> {
+        let name = name.trim();
+        self.atom(name).or_else(|| {
+            self.atoms
+                .iter()
+                .find(|a| a.name.trim().eq_ignore_ascii_case(name))
+        })
+    }
+
     /// Retrieves a mutable reference to an atom by name.
     ///
     /// # Arguments
@@ -155,6 +259,32 @@ impl Residue {
         self.atom(name).is_some()
     }
 
+    /// Lists the backbone atoms this residue is expected to carry but lacks.
+    ///
+    /// Amino acids require `N`, `CA`, and `C`; nucleotides require `P`, `O5'`, and `O3'`,
+    /// except that `P` is optional at the 5' terminus. Residues that are not standard
+    /// polymer residues have no backbone expectations.
+    ///
+    /// # Returns
+    ///
+    /// Names of the missing backbone atoms in canonical order; empty when complete.
+    pub fn missing_backbone_atoms(&self) -> Vec<&'static str> {
+        let expected: &[&'static str] = match self.standard_name {
+            Some(std) if std.is_protein() => &["N", "CA", "C"],
+            Some(std) if std.is_nucleic() && self.position == ResiduePosition::FivePrime => {
+                &["O5'", "O3'"]
+            }
+            Some(std) if std.is_nucleic() => &["P", "O5'", "O3'"],
+            _ => &[],
+        };
+
+        expected
+            .iter()
+            .copied()
+            .filter(|name| !self.has_atom(name))
+            .collect()
+    }
+
     /// Returns an immutable slice of all atoms contained in the residue.
     ///
     /// Enables zero-copy iteration when only read-only access is required.
@@ -166,6 +296,28 @@ impl Residue {
         &self.atoms
     }
 
+    /// Compares the atom inventories of two residues regardless of atom order.
+    ///
+    /// Atoms are matched by name and must agree exactly in element and position. Residue
+    /// identifiers and other atom properties are not compared.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - Residue to compare against.
+    ///
+    /// # Returns
+    ///
+    /// `true` when both residues contain the same named atoms with identical elements and
+    /// coordinates.
+    pub fn same_atoms(&self, other: &Residue) -> bool {
+        self.atoms.len() == other.atoms.len()
+            && self.atoms.iter().all(|atom| {
+                other
+                    .atom(&atom.name)
+                    .is_some_and(|o| o.element == atom.element && o.pos == atom.pos)
+            })
+    }
+
     /// Counts the number of atoms owned by the residue.
     ///
     /// # Returns
@@ -247,6 +399,18 @@ impl Residue {
         self.atoms.par_iter_mut()
     }
 
+    /// Retains only the atoms that satisfy a predicate, preserving their order.
+    ///
+    /// # Arguments
+    ///
+    /// * `f` - Closure returning `true` to keep the atom.
+    pub fn retain_atoms<F>(&mut self, f: F)
+    where
+        F: FnMut(&Atom) -> bool,
+    {
+        self.atoms.retain(f);
+    }
+
     /// Removes all hydrogen atoms from the residue.
     ///
     /// Used by cleaning operations when preparing structures for solvation or heavy-atom
@@ -255,6 +419,134 @@ impl Residue {
         self.atoms
             .retain(|a| a.element != crate::model::types::Element::H);
     }
+
+    /// Computes the mass-weighted center of the residue's atoms.
+    ///
+    /// # Returns
+    ///
+    /// A `Point` at the center of mass, or the origin for residues without mass.
+    pub fn center_of_mass(&self) -> Point {
+        ElementProperties::default()
+            .center_of_mass(&self.atoms)
+            .unwrap_or_else(Point::origin)
+    }
+
+    /// Computes the electric dipole moment about the residue's center of mass.
+    ///
+    /// Atoms without a partial charge contribute nothing. For residues with a non-zero net
+    /// charge the dipole depends on the chosen origin; use [`Residue::dipole_moment_about`]
+    /// to evaluate it about another point.
+    ///
+    /// # Returns
+    ///
+    /// The dipole vector in e·Å, or the zero vector when no partial charges are assigned.
+    pub fn dipole_moment(&self) -> nalgebra::Vector3<f64> {
+        self.dipole_moment_about(&self.center_of_mass())
+    }
+
+    /// Computes the electric dipole moment about an arbitrary origin.
+    ///
+    /// # Arguments
+    ///
+    /// * `origin` - Reference point the atom positions are measured from.
+    ///
+    /// # Returns
+    ///
+    /// The sum of `q_i * (r_i - origin)` over atoms with partial charges, in e·Å.
+    pub fn dipole_moment_about(&self, origin: &Point) -> nalgebra::Vector3<f64> {
+        self.atoms
+            .iter()
+            .filter_map(|atom| atom.partial_charge.map(|q| (atom.pos - origin) * q))
+            .sum()
+    }
+
+    /// Reports whether every atom of the residue carries a partial charge.
+    ///
+    /// # Returns
+    ///
+    /// `true` when the residue has atoms and none lack a partial charge.
+    pub fn has_partial_charges(&self) -> bool {
+        !self.atoms.is_empty() && self.atoms.iter().all(|a| a.partial_charge.is_some())
+    }
+
+    /// Lists template bonds between atoms present in this residue.
+    ///
+    /// Standard residues resolve their bonds through the internal template database, while
+    /// hetero residues rely on the caller-provided `template`. Ions never carry intra-residue
+    /// bonds. Bonds referencing absent atoms are skipped, so the result doubles as a quick
+    /// completeness check.
+    ///
+    /// # Arguments
+    ///
+    /// * `template` - Optional template used for hetero residues; ignored for standard ones.
+    ///
+    /// # Returns
+    ///
+    /// Vector of `(atom_idx_1, atom_idx_2, order)` triples using local atom indices with
+    /// `atom_idx_1 < atom_idx_2`, in template order.
+    pub fn intra_bonds(&self, template: Option<&Template>) -> Vec<(usize, usize, BondOrder)> {
+        let index_of = |name: &str| self.atoms.iter().position(|a| a.name == name);
+        let resolve = |a1: &str, a2: &str, order: BondOrder| {
+            let (i, j) = (index_of(a1)?, index_of(a2)?);
+            Some((i.min(j), i.max(j), order))
+        };
+
+        match self.category {
+            ResidueCategory::Standard => crate::db::get_template(&self.name)
+                .map(|tmpl| {
+                    tmpl.bonds()
+                        .filter_map(|(a1, a2, order)| resolve(a1, a2, order))
+                        .collect()
+                })
+                .unwrap_or_default(),
+            ResidueCategory::Hetero => template
+                .map(|tmpl| {
+                    tmpl.bonds()
+                        .iter()
+                        .filter_map(|(a1, a2, order)| resolve(a1, a2, *order))
+                        .collect()
+                })
+                .unwrap_or_default(),
+            ResidueCategory::Ion => Vec::new(),
+        }
+    }
+
+    /// Reorders atoms to follow their template's declaration order.
+    ///
+    /// Standard residues use the internal template (heavy atoms, then hydrogens) while hetero
+    /// residues rely on the caller-provided `template`. Atoms missing from the template keep
+    /// their relative order and move to the end; nothing is dropped. Residues without a
+    /// usable template and ions are left unchanged.
+    ///
+    /// # Arguments
+    ///
+    /// * `template` - Optional template used for hetero residues; ignored for standard ones.
+    pub fn canonicalize_atom_order(&mut self, template: Option<&Template>) {
+        let order: Vec<String> = match self.category {
+            ResidueCategory::Standard => match crate::db::get_template(&self.name) {
+                Some(tmpl) => tmpl
+                    .heavy_atoms()
+                    .map(|(name, _, _)| name)
+                    .chain(tmpl.hydrogens().map(|(name, _, _)| name))
+                    .map(str::to_string)
+                    .collect(),
+                None => return,
+            },
+            ResidueCategory::Hetero => match template {
+                Some(tmpl) => tmpl.atom_names().to_vec(),
+                None => return,
+            },
+            ResidueCategory::Ion => return,
+        };
+
+        let rank = |name: &str| {
+            order
+                .iter()
+                .position(|candidate| candidate == name)
+                .unwrap_or(usize::MAX)
+        };
+        self.atoms.sort_by_cached_key(|atom| rank(&atom.name));
+    }
 }
 
 impl fmt::Display for Residue {
@@ -293,7 +585,7 @@ impl fmt::Display for Residue {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::model::types::{Element, Point};
+    use crate::model::types::Point;
 
     #[test]
     fn residue_new_creates_correct_residue() {
@@ -503,6 +795,63 @@ mod tests {
         assert!(!residue.has_atom("NONEXISTENT"));
     }
 
+    #[test]
+    fn residue_atom_ci_ignores_case_and_padding_but_prefers_exact_names() {
+        let mut residue = Residue::new(
+            1,
+            None,
+            "ALA",
+            Some(StandardResidue::ALA),
+            ResidueCategory::Standard,
+        );
+        residue.add_atom(Atom::new(" Ca ", Element::C, Point::origin()));
+        residue.add_atom(Atom::new("cb", Element::C, Point::new(1.0, 0.0, 0.0)));
+        residue.add_atom(Atom::new("CB", Element::C, Point::new(2.0, 0.0, 0.0)));
+
+        assert!(residue.atom("CA").is_none());
+        assert_eq!(residue.atom_ci("CA").map(|a| &*a.name), Some(" Ca "));
+        assert_eq!(residue.atom_ci(" CB").map(|a| a.pos.x), Some(2.0));
+        assert!(residue.atom_ci("N").is_none());
+    }
+
+    #[test]
+    fn missing_backbone_atoms_lists_absent_protein_atoms() {
+        let mut residue = Residue::new(
+            1,
+            None,
+            "GLY",
+            Some(StandardResidue::GLY),
+            ResidueCategory::Standard,
+        );
+        residue.add_atom(Atom::new("CA", Element::C, Point::origin()));
+
+        assert_eq!(residue.missing_backbone_atoms(), vec!["N", "C"]);
+    }
+
+    #[test]
+    fn missing_backbone_atoms_exempts_five_prime_phosphate() {
+        let mut residue = Residue::new(
+            1,
+            None,
+            "DA",
+            Some(StandardResidue::DA),
+            ResidueCategory::Standard,
+        );
+        residue.add_atom(Atom::new("O5'", Element::O, Point::origin()));
+        residue.add_atom(Atom::new("O3'", Element::O, Point::new(5.0, 0.0, 0.0)));
+
+        assert_eq!(residue.missing_backbone_atoms(), vec!["P"]);
+        residue.position = ResiduePosition::FivePrime;
+        assert!(residue.missing_backbone_atoms().is_empty());
+    }
+
+    #[test]
+    fn missing_backbone_atoms_is_empty_for_non_polymer_residues() {
+        let residue = Residue::new(1, None, "LIG", None, ResidueCategory::Hetero);
+
+        assert!(residue.missing_backbone_atoms().is_empty());
+    }
+
     #[test]
     fn residue_atoms_returns_correct_slice() {
         let mut residue = Residue::new(
@@ -679,6 +1028,25 @@ mod tests {
         assert!(!residue.has_atom("HB"));
     }
 
+    #[test]
+    fn residue_retain_atoms_filters_in_order() {
+        let mut residue = Residue::new(
+            1,
+            None,
+            "ALA",
+            Some(StandardResidue::ALA),
+            ResidueCategory::Standard,
+        );
+        for name in ["N", "CA", "CB", "C"] {
+            residue.add_atom(Atom::new(name, Element::C, Point::origin()));
+        }
+
+        residue.retain_atoms(|atom| atom.name != "CB");
+
+        let names: Vec<&str> = residue.iter_atoms().map(|a| &*a.name).collect();
+        assert_eq!(names, vec!["N", "CA", "C"]);
+    }
+
     #[test]
     fn residue_strip_hydrogens_preserves_non_hydrogen_atoms() {
         let mut residue = Residue::new(
@@ -824,4 +1192,169 @@ mod tests {
         assert_eq!(residue1, residue2);
         assert_ne!(residue1, residue3);
     }
+
+    #[test]
+    fn residue_set_insertion_code_updates_field() {
+        let mut residue = Residue::new(
+            5,
+            None,
+            "GLY",
+            Some(StandardResidue::GLY),
+            ResidueCategory::Standard,
+        );
+
+        residue.set_insertion_code(Some('B'));
+        assert_eq!(residue.insertion_code, Some('B'));
+        assert!(residue.to_string().contains("(ic: B)"));
+
+        residue.set_insertion_code(None);
+        assert_eq!(residue.insertion_code, None);
+        assert!(!residue.to_string().contains("ic:"));
+    }
+
+    #[test]
+    fn residue_set_standard_name_updates_field() {
+        let mut residue = Residue::new(1, None, "MSE", None, ResidueCategory::Hetero);
+        assert!(!residue.is_standard());
+
+        residue.set_standard_name(Some(StandardResidue::MET));
+
+        assert!(residue.is_standard());
+        assert_eq!(residue.standard_name, Some(StandardResidue::MET));
+        assert_eq!(residue.name, "MSE");
+        assert!(residue.to_string().contains("\"MSE\" (MET)"));
+    }
+
+    #[test]
+    fn residue_partial_eq_accounts_for_insertion_code_and_standard_name() {
+        let base = Residue::new(
+            1,
+            None,
+            "ALA",
+            Some(StandardResidue::ALA),
+            ResidueCategory::Standard,
+        );
+
+        let mut with_ic = base.clone();
+        with_ic.set_insertion_code(Some('A'));
+        assert_ne!(base, with_ic);
+
+        let mut without_std = base.clone();
+        without_std.set_standard_name(None);
+        assert_ne!(base, without_std);
+    }
+
+    #[test]
+    fn residue_intra_bonds_uses_internal_template_for_standard_residue() {
+        let mut residue = Residue::new(
+            1,
+            None,
+            "GLY",
+            Some(StandardResidue::GLY),
+            ResidueCategory::Standard,
+        );
+        residue.add_atom(Atom::new("N", Element::N, Point::origin()));
+        residue.add_atom(Atom::new("CA", Element::C, Point::new(1.46, 0.0, 0.0)));
+        residue.add_atom(Atom::new("C", Element::C, Point::new(2.0, 1.4, 0.0)));
+
+        let bonds = residue.intra_bonds(None);
+
+        assert!(bonds.contains(&(0, 1, BondOrder::Single)));
+        assert!(bonds.contains(&(1, 2, BondOrder::Single)));
+        assert!(bonds.iter().all(|&(i, j, _)| i < j && j < 3));
+    }
+
+    #[test]
+    fn residue_intra_bonds_uses_supplied_template_for_hetero_residue() {
+        let template = Template::new(
+            "LIG",
+            vec!["C1".into(), "O1".into(), "N1".into()],
+            vec![
+                ("O1".into(), "C1".into(), BondOrder::Double),
+                ("C1".into(), "N1".into(), BondOrder::Single),
+            ],
+        );
+        let mut residue = Residue::new(1, None, "LIG", None, ResidueCategory::Hetero);
+        residue.add_atom(Atom::new("C1", Element::C, Point::origin()));
+        residue.add_atom(Atom::new("O1", Element::O, Point::new(1.2, 0.0, 0.0)));
+
+        assert_eq!(
+            residue.intra_bonds(Some(&template)),
+            vec![(0, 1, BondOrder::Double)]
+        );
+        assert!(residue.intra_bonds(None).is_empty());
+    }
+
+    #[test]
+    fn residue_intra_bonds_is_empty_for_ions() {
+        let mut residue = Residue::new(1, None, "NA", None, ResidueCategory::Ion);
+        residue.add_atom(Atom::new("NA", Element::Na, Point::origin()));
+
+        assert!(residue.intra_bonds(None).is_empty());
+    }
+
+    #[test]
+    fn residue_same_atoms_ignores_atom_order() {
+        let ca = Atom::new("CA", Element::C, Point::new(1.0, 0.0, 0.0));
+        let n = Atom::new("N", Element::N, Point::new(0.0, 0.0, 0.0));
+        let mut forward = Residue::new(1, None, "GLY", None, ResidueCategory::Hetero);
+        forward.add_atom(n.clone());
+        forward.add_atom(ca.clone());
+        let mut reversed = Residue::new(1, None, "GLY", None, ResidueCategory::Hetero);
+        reversed.add_atom(ca);
+        reversed.add_atom(n.clone());
+
+        assert_ne!(forward, reversed);
+        assert!(forward.same_atoms(&reversed));
+
+        let mut moved = reversed.clone();
+        moved.atom_mut("CA").unwrap().pos.x += 0.1;
+        assert!(!forward.same_atoms(&moved));
+
+        let mut partial = Residue::new(1, None, "GLY", None, ResidueCategory::Hetero);
+        partial.add_atom(n);
+        assert!(!forward.same_atoms(&partial));
+        assert!(!partial.same_atoms(&forward));
+    }
+
+    #[test]
+    fn residue_dipole_moment_uses_center_of_mass_origin() {
+        let mut residue = Residue::new(1, None, "LIG", None, ResidueCategory::Hetero);
+        let mut cation = Atom::new("N1", Element::N, Point::new(1.0, 1.0, 0.0));
+        cation.partial_charge = Some(0.5);
+        let mut anion = Atom::new("N2", Element::N, Point::new(-1.0, 1.0, 0.0));
+        anion.partial_charge = Some(-0.5);
+        residue.add_atom(cation);
+        residue.add_atom(anion);
+
+        assert_eq!(residue.center_of_mass(), Point::new(0.0, 1.0, 0.0));
+        assert!(residue.has_partial_charges());
+        assert_eq!(
+            residue.dipole_moment(),
+            nalgebra::Vector3::new(1.0, 0.0, 0.0)
+        );
+
+        residue.add_atom(Atom::new("C1", Element::C, Point::new(0.0, 5.0, 0.0)));
+        assert!(!residue.has_partial_charges());
+        assert_eq!(
+            residue.dipole_moment_about(&Point::origin()),
+            nalgebra::Vector3::new(1.0, 0.0, 0.0)
+        );
+    }
+
+    #[test]
+    fn infer_category_resolves_name_mapping_before_element() {
+        let single = |name: &str, atom: &str, element: Element| {
+            let mut residue = Residue::new(1, None, name, None, ResidueCategory::Hetero);
+            residue.add_atom(Atom::new(atom, element, Point::origin()));
+            residue.infer_category()
+        };
+
+        assert_eq!(single("CA", "CA", Element::C), ResidueCategory::Hetero);
+        assert_eq!(single("CA", "CA", Element::Ca), ResidueCategory::Ion);
+        assert_eq!(single("CA", "CA", Element::Unknown), ResidueCategory::Ion);
+        assert_eq!(single("MG2", "MG", Element::Mg), ResidueCategory::Ion);
+        assert_eq!(single("WAT", "O", Element::O), ResidueCategory::Standard);
+        assert_eq!(single("HIE", "CA", Element::C), ResidueCategory::Standard);
+    }
 }
@@ -0,0 +1,189 @@
+//! Solvent-accessible surface area via the Shrake-Rupley dot method.
+//!
+//! Each atom is inflated by the probe radius and covered with a fixed set of test points;
+//! the fraction of points not buried inside a neighboring inflated sphere gives the atom's
+//! accessible area. Neighbor lookups go through a global atom index [`Grid`], so the cost
+//! grows linearly with the atom count.
+
+use super::grid::Grid;
+use super::structure::Structure;
+use super::types::StandardResidue;
+use crate::utils::parallel::*;
+use nalgebra::Vector3;
+
+/// Number of test points placed on every atom sphere.
+const SPHERE_POINTS: usize = 100;
+
+/// Computes the accessible area of each atom sphere.
+///
+/// # Arguments
+///
+/// * `structure` - Structure supplying the sphere centers.
+/// * `radii` - Sphere radius of every atom in [`Structure::iter_atoms`] order; radii must
+///   already include the probe radius. A radius of zero marks an atom that is skipped and
+///   neither receives area nor buries neighboring points.
+///
+/// # Returns
+///
+/// The accessible area (Å²) of every atom, in [`Structure::iter_atoms`] order.
+pub(crate) fn shrake_rupley(structure: &Structure, radii: &[f64]) -> Vec<f64> {
+    let max_radius = radii.iter().copied().fold(0.0, f64::max);
+    if max_radius <= 0.0 {
+        return vec![0.0; radii.len()];
+    }
+
+    let grid = Grid::from_structure(structure, 2.0 * max_radius, |idx, _| {
+        (radii[idx] > 0.0).then_some(idx)
+    });
+    let centers: Vec<_> = structure.iter_atoms().map(|atom| atom.pos).collect();
+    let unit_points = unit_sphere_points(SPHERE_POINTS);
+
+    centers
+        .par_iter()
+        .zip(radii.par_iter())
+        .enumerate()
+        .map(|(i, (&center, &radius))| {
+            if radius <= 0.0 {
+                return 0.0;
+            }
+            let neighbors: Vec<_> = grid
+                .neighbors(&center, radius + max_radius)
+                .exact()
+                .filter(|(_, j)| **j != i)
+                .map(|(_, &j)| (centers[j], radii[j]))
+                .filter(|(other, r)| (other - center).norm() < radius + r)
+                .collect();
+
+            let exposed = unit_points
+                .iter()
+                .map(|u| center + u * radius)
+                .filter(|p| {
+                    neighbors
+                        .iter()
+                        .all(|(other, r)| (p - other).norm_squared() >= r * r)
+                })
+                .count();
+
+            4.0 * std::f64::consts::PI * radius * radius * exposed as f64 / SPHERE_POINTS as f64
+        })
+        .collect()
+}
+
+/// Returns the maximal accessible area of a residue in an extended Gly-X-Gly tripeptide.
+///
+/// Values are the theoretical maxima of Tien et al. (2013), computed without hydrogens, so
+/// they pair with heavy-atom areas. Protonation variants share the value of their parent
+/// amino acid through [`StandardResidue`].
+///
+/// # Arguments
+///
+/// * `residue` - Standard residue to look up.
+///
+/// # Returns
+///
+/// The reference area in Å², or `None` for nucleotides and water.
+pub(crate) fn max_residue_area(residue: StandardResidue) -> Option<f64> {
+    let area = match residue {
+        StandardResidue::ALA => 129.0,
+        StandardResidue::ARG => 274.0,
+        StandardResidue::ASN => 195.0,
+        StandardResidue::ASP => 193.0,
+        StandardResidue::CYS => 167.0,
+        StandardResidue::GLN => 225.0,
+        StandardResidue::GLU => 223.0,
+        StandardResidue::GLY => 104.0,
+        StandardResidue::HIS => 224.0,
+        StandardResidue::ILE => 197.0,
+        StandardResidue::LEU => 201.0,
+        StandardResidue::LYS => 236.0,
+        StandardResidue::MET => 224.0,
+        StandardResidue::PHE => 240.0,
+        StandardResidue::PRO => 159.0,
+        StandardResidue::SER => 155.0,
+        StandardResidue::THR => 172.0,
+        StandardResidue::TRP => 285.0,
+        StandardResidue::TYR => 263.0,
+        StandardResidue::VAL => 174.0,
+        _ => return None,
+    };
+    Some(area)
+}
+
+/// Distributes `n` nearly uniform points on the unit sphere along a golden-angle spiral.
+fn unit_sphere_points(n: usize) -> Vec<Vector3<f64>> {
+    let golden_angle = std::f64::consts::PI * (3.0 - 5f64.sqrt());
+    (0..n)
+        .map(|k| {
+            let z = 1.0 - (2.0 * k as f64 + 1.0) / n as f64;
+            let r = (1.0 - z * z).sqrt();
+            let phi = golden_angle * k as f64;
+            Vector3::new(r * phi.cos(), r * phi.sin(), z)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::{
+        atom::Atom,
+        chain::Chain,
+        residue::Residue,
+        types::{Element, Point, ResidueCategory},
+    };
+    use std::f64::consts::PI;
+
+    fn carbons_at(positions: &[Point]) -> Structure {
+        let mut residue = Residue::new(1, None, "LIG", None, ResidueCategory::Hetero);
+        for (i, &pos) in positions.iter().enumerate() {
+            residue.add_atom(Atom::new(&format!("C{i}"), Element::C, pos));
+        }
+        let mut chain = Chain::new("A");
+        chain.add_residue(residue);
+        std::iter::once(chain).collect()
+    }
+
+    #[test]
+    fn isolated_sphere_is_fully_exposed() {
+        let areas = shrake_rupley(&carbons_at(&[Point::origin()]), &[3.0]);
+
+        assert!((areas[0] - 4.0 * PI * 9.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn overlapping_spheres_bury_part_of_each_other() {
+        let structure = carbons_at(&[
+            Point::origin(),
+            Point::new(2.0, 0.0, 0.0),
+            Point::new(50.0, 0.0, 0.0),
+        ]);
+
+        let areas = shrake_rupley(&structure, &[2.0, 2.0, 0.0]);
+
+        // Two spheres of radius r at distance r each lose a cap of height r / 2.
+        let expected = 4.0 * PI * 4.0 - 2.0 * PI * 2.0 * 1.0;
+        for area in &areas[..2] {
+            assert!(
+                (area - expected).abs() / expected < 0.05,
+                "{area} vs {expected}"
+            );
+        }
+        assert_eq!(areas[2], 0.0);
+    }
+
+    #[test]
+    fn unit_sphere_points_are_normalized_and_balanced() {
+        let points = unit_sphere_points(SPHERE_POINTS);
+
+        assert!(points.iter().all(|p| (p.norm() - 1.0).abs() < 1e-12));
+        let centroid: Vector3<f64> = points.iter().sum::<Vector3<f64>>() / points.len() as f64;
+        assert!(centroid.norm() < 0.02);
+    }
+
+    #[test]
+    fn max_residue_area_covers_amino_acids_only() {
+        assert_eq!(max_residue_area(StandardResidue::GLY), Some(104.0));
+        assert_eq!(max_residue_area(StandardResidue::TRP), Some(285.0));
+        assert_eq!(max_residue_area(StandardResidue::DA), None);
+    }
+}
@@ -6,10 +6,164 @@
 
 use super::chain::Chain;
 use super::grid::Grid;
+use super::properties::ElementProperties;
 use super::residue::Residue;
-use super::types::Point;
+use super::sasa;
+use super::template::Template;
+use super::types::{Element, Point, ResidueCategory, ResiduePosition, StandardResidue};
 use crate::utils::parallel::*;
+use std::collections::{BTreeMap, HashMap, HashSet};
 use std::fmt;
+use thiserror::Error;
+
+/// Maximum C···N distance (Å) for consecutive amino acids to count as connected.
+const PEPTIDE_LINK_CUTOFF: f64 = 1.5;
+/// Maximum O3'···P distance (Å) for consecutive nucleotides to count as connected.
+const NUCLEIC_LINK_CUTOFF: f64 = 1.8;
+
+/// Discontinuity between two adjacent polymer residues of the same chain.
+///
+/// A break is reported when the backbone link atoms are missing, lie farther apart than
+/// the covalent cutoff, or when the residue numbering skips one or more identifiers.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ChainBreak {
+    /// Identifier of the chain containing the break.
+    pub chain_id: String,
+    /// Residue number preceding the break.
+    pub prev_residue_id: i32,
+    /// Insertion code of the residue preceding the break.
+    pub prev_insertion_code: Option<char>,
+    /// Residue number following the break.
+    pub next_residue_id: i32,
+    /// Insertion code of the residue following the break.
+    pub next_insertion_code: Option<char>,
+    /// Backbone link distance (C···N or O3'···P) when both atoms are present.
+    pub distance: Option<f64>,
+}
+
+impl ChainBreak {
+    /// Counts the residue identifiers skipped between the flanking residues.
+    ///
+    /// # Returns
+    ///
+    /// Number of absent residue numbers; `0` when the numbering is contiguous.
+    pub fn missing_residue_count(&self) -> usize {
+        (self.next_residue_id - self.prev_residue_id - 1).max(0) as usize
+    }
+}
+
+/// Owned, flattened description of a single atom produced by [`Structure::atom_table`].
+///
+/// Records carry no borrows of the originating structure, making them convenient rows for
+/// tabular exports such as CSV files or data frames.
+#[derive(Debug, Clone, PartialEq)]
+pub struct AtomRecord {
+    /// Position of the atom in [`Structure::iter_atoms`] order.
+    pub index: usize,
+    /// Identifier of the chain that owns the atom.
+    pub chain_id: String,
+    /// Residue sequence number.
+    pub residue_id: i32,
+    /// Optional insertion code of the residue.
+    pub insertion_code: Option<char>,
+    /// Name of the residue containing the atom.
+    pub residue_name: String,
+    /// Atom name.
+    pub atom_name: String,
+    /// Chemical element of the atom.
+    pub element: Element,
+    /// Cartesian coordinates in Ångström.
+    pub pos: Point,
+    /// Crystallographic occupancy.
+    pub occupancy: f64,
+    /// Isotropic temperature factor.
+    pub b_factor: f64,
+}
+
+/// Adjacent residues of a chain stored out of `(id, insertion_code)` order.
+///
+/// Reported by [`Structure::residue_order_issues`]; [`Structure::sort_residues`] repairs
+/// the ordering.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ResidueOrderIssue {
+    /// Identifier of the chain containing the misordered pair.
+    pub chain_id: String,
+    /// Residue number and insertion code of the earlier-stored residue.
+    pub prev: (i32, Option<char>),
+    /// Residue number and insertion code of the later-stored residue, which sorts first.
+    pub next: (i32, Option<char>),
+}
+
+/// Atom-level differences between a standard residue and its template.
+///
+/// Reported by [`Structure::template_diff`]. Terminal variants are taken into account, so a
+/// C-terminal `OXT` is neither missing nor extra.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ResidueDiff {
+    /// Identifier of the chain containing the residue.
+    pub chain_id: String,
+    /// Residue sequence number.
+    pub residue_id: i32,
+    /// Optional insertion code of the residue.
+    pub insertion_code: Option<char>,
+    /// Name of the residue.
+    pub residue_name: String,
+    /// Template atoms absent from the residue, in template order.
+    pub missing: Vec<String>,
+    /// Residue atoms unknown to the template, in residue order.
+    pub extra: Vec<String>,
+}
+
+/// Structural invariant violated by a [`Structure`], as reported by [`Structure::validate`].
+///
+/// The `add_*` methods only guard these invariants with debug assertions, so release
+/// builds can assemble structures that break them.
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum ValidationIssue {
+    /// A chain has an empty identifier.
+    #[error("chain at index {chain_index} has an empty identifier")]
+    EmptyChainId { chain_index: usize },
+
+    /// A chain identifier was already used by an earlier chain.
+    #[error("duplicate chain '{chain_id}'")]
+    DuplicateChain { chain_id: String },
+
+    /// A residue number and insertion code were already used earlier in the same chain.
+    #[error(
+        "duplicate residue {residue_id}{} in chain '{chain_id}'",
+        insertion_code.map(String::from).unwrap_or_default()
+    )]
+    DuplicateResidue {
+        chain_id: String,
+        residue_id: i32,
+        insertion_code: Option<char>,
+    },
+
+    /// An atom name was already used earlier in the same residue and alternate location.
+    #[error(
+        "duplicate atom '{atom_name}' in residue {residue_id}{} of chain '{chain_id}'",
+        insertion_code.map(String::from).unwrap_or_default()
+    )]
+    DuplicateAtom {
+        chain_id: String,
+        residue_id: i32,
+        insertion_code: Option<char>,
+        atom_name: String,
+    },
+
+    /// An atom has a NaN or infinite coordinate.
+    #[error(
+        "atom '{atom_name}' in residue {residue_id}{} of chain '{chain_id}' \
+         has a non-finite coordinate",
+        insertion_code.map(String::from).unwrap_or_default()
+    )]
+    NonFiniteCoordinate {
+        chain_id: String,
+        residue_id: i32,
+        insertion_code: Option<char>,
+        atom_name: String,
+    },
+}
 
 /// High-level biomolecular assembly composed of zero or more chains.
 ///
@@ -18,6 +172,7 @@ use std::fmt;
 /// metadata. Builders and operations mutate the structure to clean, solvate, or analyze
 /// biological systems.
 #[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Structure {
     /// Internal collection of polymer chains preserving insertion order.
     chains: Vec<Chain>,
@@ -147,6 +302,121 @@ impl Structure {
         self.chains.sort_by(|a, b| a.id.cmp(&b.id));
     }
 
+    /// Stable-sorts the residues of every chain by residue number, then insertion code.
+    ///
+    /// Topology building links adjacent residues in storage order, so residues appended
+    /// out of order (for example after edits or merges) should be sorted first. Chain order
+    /// is left unchanged.
+    pub fn sort_residues(&mut self) {
+        for chain in &mut self.chains {
+            chain.sort_residues();
+        }
+    }
+
+    /// Checks every structural invariant and collects all violations.
+    ///
+    /// Chain identifiers must be non-empty and unique, residue `(id, insertion_code)` pairs
+    /// unique within their chain, atom names unique within their residue, and coordinates
+    /// finite. Alternate conformers may share a name when their
+    /// [`Atom::alt_loc`](crate::Atom::alt_loc) labels differ. Each repeated identifier is
+    /// reported once per extra occurrence.
+    ///
+    /// # Returns
+    ///
+    /// All [`ValidationIssue`]s in chain, residue, and atom order; empty when valid.
+    pub fn validate(&self) -> Vec<ValidationIssue> {
+        let mut issues = Vec::new();
+        let mut chain_ids = HashSet::new();
+
+        for (chain_index, chain) in self.chains.iter().enumerate() {
+            let chain_id = chain.id.to_string();
+            if chain.id.is_empty() {
+                issues.push(ValidationIssue::EmptyChainId { chain_index });
+            } else if !chain_ids.insert(chain.id.as_str()) {
+                issues.push(ValidationIssue::DuplicateChain {
+                    chain_id: chain_id.clone(),
+                });
+            }
+
+            let mut residue_keys = HashSet::new();
+            for residue in chain.iter_residues() {
+                if !residue_keys.insert((residue.id, residue.insertion_code)) {
+                    issues.push(ValidationIssue::DuplicateResidue {
+                        chain_id: chain_id.clone(),
+                        residue_id: residue.id,
+                        insertion_code: residue.insertion_code,
+                    });
+                }
+
+                let mut atom_names = HashSet::new();
+                for atom in residue.iter_atoms() {
+                    if !atom_names.insert((atom.name.as_str(), atom.alt_loc)) {
+                        issues.push(ValidationIssue::DuplicateAtom {
+                            chain_id: chain_id.clone(),
+                            residue_id: residue.id,
+                            insertion_code: residue.insertion_code,
+                            atom_name: atom.name.to_string(),
+                        });
+                    }
+                    if !atom.pos.iter().all(|c| c.is_finite()) {
+                        issues.push(ValidationIssue::NonFiniteCoordinate {
+                            chain_id: chain_id.clone(),
+                            residue_id: residue.id,
+                            insertion_code: residue.insertion_code,
+                            atom_name: atom.name.to_string(),
+                        });
+                    }
+                }
+            }
+        }
+
+        issues
+    }
+
+    /// Consumes the structure and returns it only if every invariant holds.
+    ///
+    /// Serves as the gate between a freely assembled structure and one that topology
+    /// building, solvation, and the writers can rely on.
+    ///
+    /// # Returns
+    ///
+    /// The unchanged structure when [`Structure::validate`] finds nothing.
+    ///
+    /// # Errors
+    ///
+    /// Returns every [`ValidationIssue`] at once when any invariant is violated.
+    pub fn try_finalize(self) -> Result<Structure, Vec<ValidationIssue>> {
+        let issues = self.validate();
+        if issues.is_empty() {
+            Ok(self)
+        } else {
+            Err(issues)
+        }
+    }
+
+    /// Lists adjacent residue pairs stored out of `(id, insertion_code)` order.
+    ///
+    /// # Returns
+    ///
+    /// One [`ResidueOrderIssue`] per misordered neighbor pair, in chain and residue order;
+    /// empty when every chain is sorted.
+    pub fn residue_order_issues(&self) -> Vec<ResidueOrderIssue> {
+        self.chains
+            .iter()
+            .flat_map(|chain| {
+                chain.residues().windows(2).filter_map(move |pair| {
+                    let prev = (pair[0].id, pair[0].insertion_code);
+                    let next = (pair[1].id, pair[1].insertion_code);
+                    (next < prev).then(|| ResidueOrderIssue {
+                        chain_id: chain.id.to_string(),
+                        prev,
+                        next,
+                    })
+                })
+            })
+            .collect()
+    }
+
     /// Returns the number of chains currently stored.
     ///
     /// # Returns
@@ -165,6 +435,38 @@ impl Structure {
         self.chains.iter().map(|c| c.residue_count()).sum()
     }
 
+    /// Counts heterogen residues such as ligands and modified residues.
+    ///
+    /// Residues with [`ResidueCategory::Hetero`] are counted unless they are water; ions
+    /// and standard polymer residues are excluded.
+    ///
+    /// # Returns
+    ///
+    /// Number of non-water heterogen residues.
+    pub fn hetero_residue_count(&self) -> usize {
+        self.chains
+            .iter()
+            .flat_map(|c| c.iter_residues())
+            .filter(|r| {
+                r.category == ResidueCategory::Hetero
+                    && r.standard_name != Some(StandardResidue::HOH)
+            })
+            .count()
+    }
+
+    /// Counts water residues, including aliases such as `WAT` or `TIP3` recognized as `HOH`.
+    ///
+    /// # Returns
+    ///
+    /// Number of residues whose standard name is [`StandardResidue::HOH`].
+    pub fn water_count(&self) -> usize {
+        self.chains
+            .iter()
+            .flat_map(|c| c.iter_residues())
+            .filter(|r| r.standard_name == Some(StandardResidue::HOH))
+            .count()
+    }
+
     /// Counts all atoms across every chain.
     ///
     /// # Returns
@@ -327,6 +629,123 @@ impl Structure {
         self.chains.iter_mut().flat_map(|c| c.iter_atoms_mut())
     }
 
+    /// Reorders the atoms of every standard residue to match its internal template.
+    ///
+    /// Equivalent to [`Structure::canonicalize_atom_order_with`] without hetero templates.
+    pub fn canonicalize_atom_order(&mut self) {
+        self.canonicalize_atom_order_with(&[]);
+    }
+
+    /// Reorders atoms of standard residues and templated hetero residues to template order.
+    ///
+    /// Standard residues follow the internal template (heavy atoms, then hydrogens); hetero
+    /// residues whose name matches one of `hetero_templates` follow that template's atom
+    /// list. Atoms absent from the template are kept at the end in their original order.
+    ///
+    /// # Arguments
+    ///
+    /// * `hetero_templates` - User templates for hetero residues, matched by name.
+    pub fn canonicalize_atom_order_with(&mut self, hetero_templates: &[Template]) {
+        self.par_residues_mut().for_each(|residue| {
+            let template = hetero_templates.iter().find(|t| t.name == residue.name);
+            residue.canonicalize_atom_order(template);
+        });
+    }
+
+    /// Compares two structures while tolerating small coordinate differences.
+    ///
+    /// Chains and residues must appear in the same order with matching identifiers and
+    /// names. Atoms are matched by name, so their order within a residue does not matter,
+    /// and must share elements with positions at most `tol` ångströms apart. Box vectors must
+    /// both be absent or agree component-wise within `tol`.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - Structure to compare against.
+    /// * `tol` - Maximum allowed coordinate deviation in ångströms.
+    ///
+    /// # Returns
+    ///
+    /// `true` when the structures match within the tolerance.
+    pub fn approx_eq(&self, other: &Structure, tol: f64) -> bool {
+        let boxes_match = match (self.box_vectors, other.box_vectors) {
+            (None, None) => true,
+            (Some(a), Some(b)) => a
+                .iter()
+                .flatten()
+                .zip(b.iter().flatten())
+                .all(|(x, y)| (x - y).abs() <= tol),
+            _ => false,
+        };
+        if !boxes_match || self.chains.len() != other.chains.len() {
+            return false;
+        }
+
+        self.chains.iter().zip(&other.chains).all(|(a, b)| {
+            a.id == b.id
+                && a.residue_count() == b.residue_count()
+                && a.iter_residues().zip(b.iter_residues()).all(|(ra, rb)| {
+                    ra.id == rb.id
+                        && ra.insertion_code == rb.insertion_code
+                        && ra.name == rb.name
+                        && ra.atom_count() == rb.atom_count()
+                        && ra.iter_atoms().all(|atom| {
+                            rb.atom(&atom.name).is_some_and(|o| {
+                                o.element == atom.element && atom.distance(o) <= tol
+                            })
+                        })
+                })
+        })
+    }
+
+    /// Looks up an atom by its global index.
+    ///
+    /// Global indices follow the chain/residue order of [`Structure::iter_atoms`], matching
+    /// the indices used by [`Topology`](super::topology::Topology) bonds.
+    ///
+    /// # Arguments
+    ///
+    /// * `global_idx` - Zero-based index across all atoms of the structure.
+    ///
+    /// # Returns
+    ///
+    /// `Some(&Atom)` when the index is in range, otherwise `None`.
+    pub fn atom_at(&self, global_idx: usize) -> Option<&super::atom::Atom> {
+        let mut remaining = global_idx;
+        for residue in self.chains.iter().flat_map(|c| c.iter_residues()) {
+            let count = residue.atom_count();
+            if remaining < count {
+                return residue.atoms().get(remaining);
+            }
+            remaining -= count;
+        }
+        None
+    }
+
+    /// Looks up a mutable atom by its global index.
+    ///
+    /// Whole residues are skipped by their atom counts, so index-based algorithms can edit
+    /// coordinates or per-atom properties without re-walking every atom.
+    ///
+    /// # Arguments
+    ///
+    /// * `global_idx` - Zero-based index across all atoms of the structure.
+    ///
+    /// # Returns
+    ///
+    /// `Some(&mut Atom)` when the index is in range, otherwise `None`.
+    pub fn atom_mut_at(&mut self, global_idx: usize) -> Option<&mut super::atom::Atom> {
+        let mut remaining = global_idx;
+        for residue in self.chains.iter_mut().flat_map(|c| c.iter_residues_mut()) {
+            let count = residue.atom_count();
+            if remaining < count {
+                return residue.iter_atoms_mut().nth(remaining);
+            }
+            remaining -= count;
+        }
+        None
+    }
+
     /// Retains residues that satisfy a predicate, removing all others.
     ///
     /// The predicate receives the chain ID and a residue reference, enabling
@@ -415,6 +834,89 @@ impl Structure {
         self.retain_residues_mut(f);
     }
 
+    /// Re-derives every residue's `category` via [`Residue::infer_category`].
+    ///
+    /// Useful after manual edits or when residues were built from sources that do not carry
+    /// category information. Residues that become [`ResidueCategory::Standard`] without a
+    /// standard mapping receive one from [`StandardResidue::from_name`]; when no internal
+    /// template exists under their name (such as `WAT`), they are also renamed to the
+    /// canonical residue name, so templates resolve as they do for parsed files.
+    pub fn reclassify_categories(&mut self) {
+        for chain in &mut self.chains {
+            for residue in chain.iter_residues_mut() {
+                residue.category = residue.infer_category();
+                if residue.category != ResidueCategory::Standard || residue.is_standard() {
+                    continue;
+                }
+                let Some(standard) = StandardResidue::from_name(&residue.name) else {
+                    continue;
+                };
+                residue.set_standard_name(Some(standard));
+                if crate::db::get_template(&residue.name).is_none() {
+                    residue.name = standard.canonical_name().into();
+                }
+            }
+        }
+    }
+
+    /// Annotates every residue with its position within its chain's polymer.
+    ///
+    /// Polymer residues are standard residues other than water. Within each chain the first
+    /// polymer residue is marked [`ResiduePosition::NTerminal`] (or
+    /// [`ResiduePosition::FivePrime`] for nucleic acids), the last is marked
+    /// [`ResiduePosition::CTerminal`] (or [`ResiduePosition::ThreePrime`]), and the rest
+    /// become [`ResiduePosition::Internal`]. Non-polymer residues are reset to
+    /// [`ResiduePosition::None`].
+    pub fn assign_positions(&mut self) {
+        let is_polymer = |res: &Residue| {
+            res.category == ResidueCategory::Standard
+                && res.standard_name != Some(StandardResidue::HOH)
+        };
+
+        for chain in &mut self.chains {
+            let first_idx = chain.iter_residues().position(is_polymer);
+            let last_idx = chain.iter_residues().rposition(is_polymer);
+
+            for (i, residue) in chain.iter_residues_mut().enumerate() {
+                if !is_polymer(residue) {
+                    residue.position = ResiduePosition::None;
+                    continue;
+                }
+
+                let is_protein = residue.standard_name.is_some_and(|s| s.is_protein());
+                let is_nucleic = residue.standard_name.is_some_and(|s| s.is_nucleic());
+
+                residue.position = if Some(i) == first_idx && is_protein {
+                    ResiduePosition::NTerminal
+                } else if Some(i) == first_idx && is_nucleic {
+                    ResiduePosition::FivePrime
+                } else if Some(i) == last_idx && is_protein {
+                    ResiduePosition::CTerminal
+                } else if Some(i) == last_idx && is_nucleic {
+                    ResiduePosition::ThreePrime
+                } else {
+                    ResiduePosition::Internal
+                };
+            }
+        }
+    }
+
+    /// Retains residues whose mean atom B-factor is at least `min`.
+    ///
+    /// Useful for trimming low-confidence regions from predicted models whose B-factor column
+    /// stores pLDDT. Residues without atoms are removed; chains left empty are kept and can be
+    /// dropped with [`Structure::prune_empty_chains`].
+    ///
+    /// # Arguments
+    ///
+    /// * `min` - Inclusive lower bound on the per-residue mean B-factor.
+    pub fn retain_residues_by_bfactor(&mut self, min: f64) {
+        self.retain_residues(|_, residue| {
+            let count = residue.atom_count();
+            count > 0 && residue.iter_atoms().map(|a| a.b_factor).sum::<f64>() / count as f64 >= min
+        });
+    }
+
     /// Removes any chain that became empty after residue pruning.
     pub fn prune_empty_chains(&mut self) {
         self.chains.retain(|chain| !chain.is_empty());
@@ -435,6 +937,51 @@ impl Structure {
         })
     }
 
+    /// Iterates over residues together with the global index of their first atom.
+    ///
+    /// The offset is the position of the residue's first atom in [`Structure::iter_atoms`]
+    /// order, which is also the index space used by [`Topology`](super::topology::Topology)
+    /// bonds, so residue atom `i` has global index `offset + i`. Empty residues share the
+    /// offset of the residue that follows them.
+    ///
+    /// # Returns
+    ///
+    /// An iterator yielding `(&Chain, &Residue, usize)` triples in chain/residue order.
+    pub fn iter_residues_with_offset(&self) -> impl Iterator<Item = (&Chain, &Residue, usize)> {
+        self.chains
+            .iter()
+            .flat_map(|chain| chain.iter_residues().map(move |residue| (chain, residue)))
+            .scan(0, |offset, (chain, residue)| {
+                let start = *offset;
+                *offset += residue.atom_count();
+                Some((chain, residue, start))
+            })
+    }
+
+    /// Flattens every atom into an owned [`AtomRecord`].
+    ///
+    /// # Returns
+    ///
+    /// One record per atom in [`Structure::iter_atoms`] order, with `index` matching the
+    /// global atom index.
+    pub fn atom_table(&self) -> Vec<AtomRecord> {
+        self.iter_atoms_with_context()
+            .enumerate()
+            .map(|(index, (chain, residue, atom))| AtomRecord {
+                index,
+                chain_id: chain.id.to_string(),
+                residue_id: residue.id,
+                insertion_code: residue.insertion_code,
+                residue_name: residue.name.to_string(),
+                atom_name: atom.name.to_string(),
+                element: atom.element,
+                pos: atom.pos,
+                occupancy: atom.occupancy,
+                b_factor: atom.b_factor,
+            })
+            .collect()
+    }
+
     /// Computes the geometric center of all atom coordinates.
     ///
     /// Falls back to the origin when the structure contains no atoms.
@@ -467,109 +1014,857 @@ impl Structure {
     ///
     /// A `Point` representing the center of mass.
     pub fn center_of_mass(&self) -> Point {
-        let mut total_mass = 0.0;
-        let mut weighted_sum = nalgebra::Vector3::zeros();
-
-        for atom in self.iter_atoms() {
-            let mass = atom.element.atomic_mass();
-            weighted_sum += atom.pos.coords * mass;
-            total_mass += mass;
-        }
-
-        if total_mass > 1e-9 {
-            Point::from(weighted_sum / total_mass)
-        } else {
-            Point::origin()
-        }
+        self.center_of_mass_with(&ElementProperties::default())
     }
 
-    /// Constructs a spatial grid indexing all atoms in the structure.
-    ///
-    /// The grid stores `(chain_idx, residue_idx, atom_idx)` tuples, allowing efficient
-    /// retrieval of atoms within a spatial neighborhood.
+    /// Computes the mass-weighted center of all atoms using custom element masses.
     ///
     /// # Arguments
     ///
-    /// * `cell_size` - The side length of each spatial bin.
+    /// * `properties` - Element parameters supplying the atomic masses.
     ///
     /// # Returns
     ///
-    /// A [`Grid`] containing all atoms in the structure.
-    pub fn spatial_grid(&self, cell_size: f64) -> Grid<(usize, usize, usize)> {
-        let items: Vec<_> = self
-            .par_chains()
-            .enumerate()
-            .flat_map(|(c_idx, chain)| {
-                chain
-                    .par_residues()
-                    .enumerate()
-                    .flat_map_iter(move |(r_idx, residue)| {
-                        residue
-                            .iter_atoms()
-                            .enumerate()
-                            .map(move |(a_idx, atom)| (atom.pos, (c_idx, r_idx, a_idx)))
-                    })
-            })
-            .collect();
-        Grid::new(items, cell_size)
+    /// A `Point` representing the center of mass, or the origin when the total mass is
+    /// below numerical tolerance.
+    pub fn center_of_mass_with(&self, properties: &ElementProperties) -> Point {
+        properties
+            .center_of_mass(self.iter_atoms())
+            .unwrap_or_else(Point::origin)
     }
 
-    pub fn box_volume(&self) -> Option<f64> {
-        match self.box_vectors {
-            Some(box_vectors) => Some(box_volume(box_vectors)),
-            None => None,
-        }
+    /// Computes the mass-weighted inertia tensor about the center of mass.
+    ///
+    /// # Returns
+    ///
+    /// The symmetric tensor `Σ m (|r|² I − r rᵀ)` in amu·Å², with `r` measured from the
+    /// center of mass; the zero matrix for an empty structure.
+    pub fn inertia_tensor(&self) -> nalgebra::Matrix3<f64> {
+        self.inertia_tensor_with(&ElementProperties::default())
     }
-}
-
-fn box_volume(box_vectors: [[f64; 3]; 3]) -> f64 {
-    let [a, b, c] = box_vectors;
 
-    let cross = [
-        b[1] * c[2] - b[2] * c[1],
-        b[2] * c[0] - b[0] * c[2],
-        b[0] * c[1] - b[1] * c[0],
-    ];
+    /// Computes the inertia tensor using custom element masses.
+    ///
+    /// # Arguments
+    ///
+    /// * `properties` - Element parameters supplying the atomic masses.
+    ///
+    /// # Returns
+    ///
+    /// The inertia tensor about the center of mass computed with the same masses.
+    pub fn inertia_tensor_with(&self, properties: &ElementProperties) -> nalgebra::Matrix3<f64> {
+        let center = self.center_of_mass_with(properties);
+        let mut tensor = nalgebra::Matrix3::zeros();
 
-    let dot = a[0] * cross[0] + a[1] * cross[1] + a[2] * cross[2];
-    dot.abs()
-}
+        for atom in self.iter_atoms() {
+            let mass = properties.mass(atom.element);
+            let r = atom.pos - center;
+            tensor += (nalgebra::Matrix3::identity() * r.norm_squared() - r * r.transpose()) * mass;
+        }
 
-impl fmt::Display for Structure {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(
-            f,
-            "Structure {{ chains: {}, residues: {}, atoms: {} }}",
-            self.chain_count(),
-            self.residue_count(),
-            self.atom_count()
-        )
+        tensor
     }
-}
 
-impl FromIterator<Chain> for Structure {
-    fn from_iter<T: IntoIterator<Item = Chain>>(iter: T) -> Self {
-        Self {
-            chains: iter.into_iter().collect(),
-            box_vectors: None,
-        }
+    /// Diagonalizes the inertia tensor into principal moments and axes.
+    ///
+    /// # Returns
+    ///
+    /// The principal moments sorted ascending, and a proper rotation whose columns are the
+    /// matching principal axes. Applying the inverse rotation to coordinates centered on the
+    /// center of mass aligns the smallest moment with x and the largest with z.
+    pub fn principal_moments(&self) -> (nalgebra::Vector3<f64>, nalgebra::Rotation3<f64>) {
+        principal_frame(self.inertia_tensor())
     }
-}
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use crate::model::atom::Atom;
-    use crate::model::types::{Element, ResidueCategory, StandardResidue};
+    /// Computes the unweighted centroid of a selection of atoms.
+    ///
+    /// Indices follow [`Structure::iter_atoms`] order; indices beyond the atom count are
+    /// ignored and duplicates count once.
+    ///
+    /// # Arguments
+    ///
+    /// * `indices` - Global atom indices forming the selection.
+    ///
+    /// # Returns
+    ///
+    /// `Some(Point)` at the centroid, or `None` when no valid atom is selected.
+    pub fn selection_center(&self, indices: &[usize]) -> Option<Point> {
+        let mut sum = nalgebra::Vector3::zeros();
+        let mut count = 0;
 
-    fn make_residue(id: i32, name: &str) -> Residue {
-        Residue::new(
-            id,
-            None,
-            name,
-            Some(StandardResidue::ALA),
-            ResidueCategory::Standard,
-        )
+        for atom in self.selected_atoms(indices) {
+            sum += atom.pos.coords;
+            count += 1;
+        }
+
+        (count > 0).then(|| Point::from(sum / count as f64))
+    }
+
+    /// Computes the mass-weighted center of a selection of atoms.
+    ///
+    /// # Arguments
+    ///
+    /// * `indices` - Global atom indices forming the selection.
+    ///
+    /// # Returns
+    ///
+    /// `Some(Point)` at the center of mass, or `None` when no valid atom is selected.
+    pub fn selection_center_of_mass(&self, indices: &[usize]) -> Option<Point> {
+        self.selection_center_of_mass_with(indices, &ElementProperties::default())
+    }
+
+    /// Computes the mass-weighted center of a selection using custom element masses.
+    ///
+    /// Selection semantics match [`Structure::selection_center`].
+    ///
+    /// # Arguments
+    ///
+    /// * `indices` - Global atom indices forming the selection.
+    /// * `properties` - Element parameters supplying the atomic masses.
+    ///
+    /// # Returns
+    ///
+    /// `Some(Point)` at the center of mass, or `None` when no valid atom is selected or the
+    /// selected mass is below numerical tolerance.
+    pub fn selection_center_of_mass_with(
+        &self,
+        indices: &[usize],
+        properties: &ElementProperties,
+    ) -> Option<Point> {
+        properties.center_of_mass(self.selected_atoms(indices))
+    }
+
+    /// Iterates the atoms whose global indices appear in `indices`, each at most once.
+    fn selected_atoms<'a>(
+        &'a self,
+        indices: &[usize],
+    ) -> impl Iterator<Item = &'a super::atom::Atom> + 'a {
+        self.iter_atoms()
+            .zip(self.selection_mask(indices))
+            .filter_map(|(atom, selected)| selected.then_some(atom))
+    }
+
+    /// Builds a per-atom flag marking the atoms selected by global index.
+    ///
+    /// # Arguments
+    ///
+    /// * `indices` - Global atom indices in [`Structure::iter_atoms`] order; indices beyond
+    ///   the atom count are ignored and duplicates collapse.
+    ///
+    /// # Returns
+    ///
+    /// A vector with one entry per atom, `true` where the atom is selected.
+    pub(crate) fn selection_mask(&self, indices: &[usize]) -> Vec<bool> {
+        let mut mask = vec![false; self.atom_count()];
+        for &idx in indices {
+            if let Some(flag) = mask.get_mut(idx) {
+                *flag = true;
+            }
+        }
+        mask
+    }
+
+    /// Computes the electric dipole moment about the structure's center of mass.
+    ///
+    /// Atoms without a partial charge contribute nothing, so check
+    /// [`Structure::has_partial_charges`] to tell a genuinely zero dipole from missing
+    /// charges. Per-residue dipoles are available through [`Residue::dipole_moment`].
+    ///
+    /// # Returns
+    ///
+    /// The dipole vector in e·Å, or the zero vector when no partial charges are assigned.
+    pub fn dipole_moment(&self) -> nalgebra::Vector3<f64> {
+        let origin = self.center_of_mass();
+        self.par_residues()
+            .map(|residue| residue.dipole_moment_about(&origin))
+            .sum()
+    }
+
+    /// Reports whether every atom of the structure carries a partial charge.
+    ///
+    /// # Returns
+    ///
+    /// `true` when the structure has atoms and none lack a partial charge.
+    pub fn has_partial_charges(&self) -> bool {
+        self.atom_count() > 0 && self.iter_atoms().all(|a| a.partial_charge.is_some())
+    }
+
+    /// Counts atoms per element across the whole structure.
+    ///
+    /// # Returns
+    ///
+    /// A map from [`Element`] to the number of atoms of that element, ordered by element.
+    pub fn molecular_formula(&self) -> BTreeMap<Element, usize> {
+        let mut formula = BTreeMap::new();
+        for atom in self.iter_atoms() {
+            *formula.entry(atom.element).or_insert(0) += 1;
+        }
+        formula
+    }
+
+    /// Sums the atomic masses of every atom in the structure.
+    ///
+    /// # Returns
+    ///
+    /// The total mass in daltons; atoms of unknown element contribute nothing.
+    pub fn total_mass(&self) -> f64 {
+        self.total_mass_with(&ElementProperties::default())
+    }
+
+    /// Sums the atomic masses of every atom using custom element masses.
+    ///
+    /// # Arguments
+    ///
+    /// * `properties` - Element parameters supplying the atomic masses.
+    ///
+    /// # Returns
+    ///
+    /// The total mass in daltons.
+    pub fn total_mass_with(&self, properties: &ElementProperties) -> f64 {
+        self.iter_atoms()
+            .map(|atom| properties.mass(atom.element))
+            .sum()
+    }
+
+    /// Computes the solvent-accessible surface area of every atom.
+    ///
+    /// Uses the Shrake-Rupley dot method with 100 test points per atom and the built-in van der
+    /// Waals radii inflated by `probe_radius`. Hydrogens are ignored and report zero, so the
+    /// areas do not depend on protonation and match heavy-atom reference tables.
+    ///
+    /// # Arguments
+    ///
+    /// * `probe_radius` - Solvent probe radius in ångströms; 1.4 Å models water.
+    ///
+    /// # Returns
+    ///
+    /// Accessible area in Å² for each atom, in [`Structure::iter_atoms`] order.
+    pub fn atom_sasa(&self, probe_radius: f64) -> Vec<f64> {
+        self.atom_sasa_with(probe_radius, &ElementProperties::default())
+    }
+
+    /// Computes the solvent-accessible surface area of every atom using custom radii.
+    ///
+    /// # Arguments
+    ///
+    /// * `probe_radius` - Solvent probe radius in ångströms; 1.4 Å models water.
+    /// * `properties` - Element parameters supplying the van der Waals radii.
+    ///
+    /// # Returns
+    ///
+    /// Accessible area in Å² for each atom, as described for [`Structure::atom_sasa`].
+    pub fn atom_sasa_with(&self, probe_radius: f64, properties: &ElementProperties) -> Vec<f64> {
+        let radii: Vec<f64> = self
+            .iter_atoms()
+            .map(|atom| {
+                if atom.element.is_heavy_atom() {
+                    properties.vdw_radius(atom.element) + probe_radius
+                } else {
+                    0.0
+                }
+            })
+            .collect();
+        sasa::shrake_rupley(self, &radii)
+    }
+
+    /// Sums the per-atom accessible surface area of each residue.
+    ///
+    /// # Arguments
+    ///
+    /// * `probe_radius` - Solvent probe radius in ångströms; 1.4 Å models water.
+    ///
+    /// # Returns
+    ///
+    /// `(chain_id, residue_id, insertion_code, area)` tuples in chain and residue order, with
+    /// areas in Å² as computed by [`Structure::atom_sasa`].
+    pub fn residue_sasa(&self, probe_radius: f64) -> Vec<(String, i32, Option<char>, f64)> {
+        self.residue_sasa_with(probe_radius, &ElementProperties::default())
+    }
+
+    /// Sums the per-atom accessible surface area of each residue using custom radii.
+    ///
+    /// # Arguments
+    ///
+    /// * `probe_radius` - Solvent probe radius in ångströms; 1.4 Å models water.
+    /// * `properties` - Element parameters supplying the van der Waals radii.
+    ///
+    /// # Returns
+    ///
+    /// `(chain_id, residue_id, insertion_code, area)` tuples as described for
+    /// [`Structure::residue_sasa`], with areas computed by [`Structure::atom_sasa_with`].
+    pub fn residue_sasa_with(
+        &self,
+        probe_radius: f64,
+        properties: &ElementProperties,
+    ) -> Vec<(String, i32, Option<char>, f64)> {
+        let areas = self.atom_sasa_with(probe_radius, properties);
+        self.iter_residues_with_offset()
+            .map(|(chain, residue, offset)| {
+                let area = areas[offset..offset + residue.atom_count()].iter().sum();
+                (
+                    chain.id.to_string(),
+                    residue.id,
+                    residue.insertion_code,
+                    area,
+                )
+            })
+            .collect()
+    }
+
+    /// Computes the relative accessibility of each residue.
+    ///
+    /// Residue areas from [`Structure::residue_sasa`] are divided by the maximal area of
+    /// the same amino acid in an extended Gly-X-Gly tripeptide (Tien et al., 2013). Values
+    /// near 0 mark buried core residues and values near 1 fully exposed ones; the burial
+    /// fraction is `1 - relative`. Strained conformations can slightly exceed 1.
+    ///
+    /// # Arguments
+    ///
+    /// * `probe_radius` - Solvent probe radius in ångströms; 1.4 Å models water.
+    ///
+    /// # Returns
+    ///
+    /// `(chain_id, residue_id, insertion_code, relative)` tuples in chain and residue order,
+    /// where `relative` is `None` for residues other than the 20 amino acids and their
+    /// protonation variants.
+    pub fn relative_sasa(
+        &self,
+        probe_radius: f64,
+    ) -> Vec<(String, i32, Option<char>, Option<f64>)> {
+        let references: Vec<Option<f64>> = self
+            .chains
+            .iter()
+            .flat_map(Chain::iter_residues)
+            .map(|residue| residue.standard_name.and_then(sasa::max_residue_area))
+            .collect();
+
+        self.residue_sasa(probe_radius)
+            .into_iter()
+            .zip(references)
+            .map(
+                |((chain_id, residue_id, insertion_code, area), reference)| {
+                    let relative = reference.map(|max| area / max);
+                    (chain_id, residue_id, insertion_code, relative)
+                },
+            )
+            .collect()
+    }
+
+    /// Formats the molecular formula using Hill notation.
+    ///
+    /// Carbon is listed first and hydrogen second when carbon is present; all remaining
+    /// elements follow alphabetically by symbol. Counts of one are omitted, entries are
+    /// separated by spaces, and atoms of unknown element are left out.
+    ///
+    /// # Returns
+    ///
+    /// A string such as `"C3 H7 N O2"`, empty for a structure without known atoms.
+    pub fn formula_string(&self) -> String {
+        let formula = self.molecular_formula();
+        let has_carbon = formula.contains_key(&Element::C);
+
+        let mut entries: Vec<(Element, usize)> = formula
+            .into_iter()
+            .filter(|(element, _)| *element != Element::Unknown)
+            .collect();
+        entries.sort_by_key(|(element, _)| match element {
+            Element::C if has_carbon => (0, ""),
+            Element::H if has_carbon => (1, ""),
+            other => (2, other.symbol()),
+        });
+
+        entries
+            .into_iter()
+            .map(|(element, count)| {
+                if count == 1 {
+                    element.symbol().to_string()
+                } else {
+                    format!("{}{}", element.symbol(), count)
+                }
+            })
+            .collect::<Vec<_>>()
+            .join(" ")
+    }
+
+    /// Constructs a spatial grid indexing all atoms in the structure.
+    ///
+    /// The grid stores `(chain_idx, residue_idx, atom_idx)` tuples, allowing efficient
+    /// retrieval of atoms within a spatial neighborhood.
+    ///
+    /// # Arguments
+    ///
+    /// * `cell_size` - The side length of each spatial bin.
+    ///
+    /// # Returns
+    ///
+    /// A [`Grid`] containing all atoms in the structure.
+    pub fn spatial_grid(&self, cell_size: f64) -> Grid<(usize, usize, usize)> {
+        let items: Vec<_> = self
+            .par_chains()
+            .enumerate()
+            .flat_map(|(c_idx, chain)| {
+                chain
+                    .par_residues()
+                    .enumerate()
+                    .flat_map_iter(move |(r_idx, residue)| {
+                        residue
+                            .iter_atoms()
+                            .enumerate()
+                            .map(move |(a_idx, atom)| (atom.pos, (c_idx, r_idx, a_idx)))
+                    })
+            })
+            .collect();
+        Grid::new(items, cell_size)
+    }
+
+    /// Builds a spatial grid whose items are global atom indices.
+    ///
+    /// This is the canonical atom index for neighbor searches: each stored `usize` is the
+    /// atom's position in [`Structure::iter_atoms`] order, the same indexing used by
+    /// [`Topology`](super::topology::Topology) bonds and selections, and never a position
+    /// inside the grid's own storage. Use [`Grid::from_structure`] to store a different
+    /// payload or to leave atoms out.
+    ///
+    /// # Arguments
+    ///
+    /// * `cell_size` - Edge length of the grid cells in ångströms, ideally the query radius.
+    ///
+    /// # Returns
+    ///
+    /// A [`Grid`] holding every atom's global index at the atom's position.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `cell_size` is non-positive.
+    pub fn build_atom_grid(&self, cell_size: f64) -> Grid<usize> {
+        Grid::from_structure(self, cell_size, |idx, _| Some(idx))
+    }
+
+    /// Finds the atom closest to a point.
+    ///
+    /// A single query scans every atom, which is cheaper than building a spatial index that
+    /// is used only once. For many queries against the same coordinates, build a grid with
+    /// [`Structure::build_atom_grid`] and call [`Grid::nearest`] instead.
+    ///
+    /// # Arguments
+    ///
+    /// * `point` - The query position in ångströms.
+    ///
+    /// # Returns
+    ///
+    /// The global atom index and distance of the nearest atom, preferring the lowest index on
+    /// ties, or `None` for a structure without atoms.
+    pub fn nearest_atom(&self, point: &Point) -> Option<(usize, f64)> {
+        self.iter_atoms()
+            .map(|atom| nalgebra::distance(&atom.pos, point))
+            .enumerate()
+            .min_by(|a, b| a.1.total_cmp(&b.1))
+    }
+
+    pub fn box_volume(&self) -> Option<f64> {
+        self.box_vectors.map(box_volume)
+    }
+
+    /// Reports residues that are empty or lack expected backbone atoms.
+    ///
+    /// Standard polymer residues are checked with [`Residue::missing_backbone_atoms`];
+    /// residues of any kind without atoms are reported as well. Such residues otherwise only
+    /// surface later as missing-atom errors from topology building.
+    ///
+    /// # Returns
+    ///
+    /// `(chain_id, residue_id, missing_atom_names)` tuples in chain and residue order. The
+    /// name list is empty for atom-less residues that have no backbone expectations.
+    pub fn find_incomplete_residues(&self) -> Vec<(String, i32, Vec<&'static str>)> {
+        self.chains
+            .iter()
+            .flat_map(|chain| {
+                chain.iter_residues().filter_map(move |residue| {
+                    let missing = residue.missing_backbone_atoms();
+                    (!missing.is_empty() || residue.is_empty())
+                        .then(|| (chain.id.to_string(), residue.id, missing))
+                })
+            })
+            .collect()
+    }
+
+    /// Compares every standard residue against its internal template.
+    ///
+    /// This is the atom-level counterpart of [`Structure::find_incomplete_residues`]: it lists
+    /// the template atoms that are absent and the residue atoms the template does not know,
+    /// i.e. what [`repair_structure`](crate::ops::repair_structure) would add or strip.
+    /// Terminal atoms (`OXT`, `OP3`, and the matching terminal hydrogens) are accepted at
+    /// the corresponding chain ends, and a 5' nucleotide without `P` is not expected to carry
+    /// a phosphate. Hetero residues, ions, and residues without a template are skipped.
+    ///
+    /// # Arguments
+    ///
+    /// * `include_hydrogens` - Whether hydrogens take part in the comparison; when `false`,
+    ///   template hydrogens are never missing and residue hydrogens are never extra.
+    ///
+    /// # Returns
+    ///
+    /// One [`ResidueDiff`] per residue that differs from its template, in chain and residue
+    /// order.
+    pub fn template_diff(&self, include_hydrogens: bool) -> Vec<ResidueDiff> {
+        self.chains
+            .iter()
+            .flat_map(|chain| {
+                chain.iter_residues().filter_map(move |residue| {
+                    let (missing, extra) = diff_against_template(residue, include_hydrogens)?;
+                    (!missing.is_empty() || !extra.is_empty()).then(|| ResidueDiff {
+                        chain_id: chain.id.to_string(),
+                        residue_id: residue.id,
+                        insertion_code: residue.insertion_code,
+                        residue_name: residue.name.to_string(),
+                        missing,
+                        extra,
+                    })
+                })
+            })
+            .collect()
+    }
+
+    /// Counts the hydrogens [`add_hydrogens`](crate::ops::add_hydrogens) would build, without
+    /// building them.
+    ///
+    /// A template hydrogen counts when the residue lacks it but has all of its anchor atoms.
+    /// Chain ends follow the default terminal protonation at pH 7: an N-terminal amino acid
+    /// expects `H1`–`H3` instead of `H`, C-termini and 5' phosphates stay deprotonated, and
+    /// terminal nucleotides expect `HO3'` or, without a phosphate, `HO5'`. Residues keep their
+    /// current names, so protonation-state renaming is not anticipated. Hetero residues, ions,
+    /// and residues without a template contribute nothing.
+    ///
+    /// # Returns
+    ///
+    /// `(chain_id, residue_id, insertion_code, count)` tuples for residues missing at least
+    /// one hydrogen, in chain and residue order.
+    pub fn missing_hydrogens(&self) -> Vec<(String, i32, Option<char>, usize)> {
+        self.chains
+            .iter()
+            .flat_map(|chain| {
+                chain.iter_residues().filter_map(move |residue| {
+                    let count = count_missing_hydrogens(residue);
+                    (count > 0).then(|| {
+                        (
+                            chain.id.to_string(),
+                            residue.id,
+                            residue.insertion_code,
+                            count,
+                        )
+                    })
+                })
+            })
+            .collect()
+    }
+
+    /// Counts the hydrogens missing from the whole structure.
+    ///
+    /// Useful for estimating the final atom count before protonating, e.g. to size a solvent
+    /// box; see [`Structure::missing_hydrogens`] for the counting rules.
+    ///
+    /// # Returns
+    ///
+    /// The total number of hydrogens [`add_hydrogens`](crate::ops::add_hydrogens) would add.
+    pub fn missing_hydrogen_count(&self) -> usize {
+        self.par_residues().map(count_missing_hydrogens).sum()
+    }
+
+    /// Locates backbone discontinuities between consecutive polymer residues.
+    ///
+    /// Adjacent standard residues of the same polymer type are compared using the
+    /// C···N (protein) or O3'···P (nucleic) distance and their residue numbers. Hetero
+    /// residues, ions, and solvent never participate in break detection.
+    ///
+    /// # Returns
+    ///
+    /// A vector of [`ChainBreak`] entries in chain and residue order.
+    pub fn chain_breaks(&self) -> Vec<ChainBreak> {
+        let mut breaks = Vec::new();
+
+        for chain in &self.chains {
+            let residues = chain.residues();
+            for pair in residues.windows(2) {
+                let (prev, next) = (&pair[0], &pair[1]);
+                if prev.category != ResidueCategory::Standard
+                    || next.category != ResidueCategory::Standard
+                {
+                    continue;
+                }
+
+                let (Some(prev_std), Some(next_std)) = (prev.standard_name, next.standard_name)
+                else {
+                    continue;
+                };
+
+                let (link_atoms, cutoff) = if prev_std.is_protein() && next_std.is_protein() {
+                    (("C", "N"), PEPTIDE_LINK_CUTOFF)
+                } else if prev_std.is_nucleic() && next_std.is_nucleic() {
+                    (("O3'", "P"), NUCLEIC_LINK_CUTOFF)
+                } else {
+                    continue;
+                };
+
+                let distance = prev
+                    .atom(link_atoms.0)
+                    .zip(next.atom(link_atoms.1))
+                    .map(|(a, b)| a.distance(b));
+                let id_step = next.id - prev.id;
+                let numbering_gap =
+                    !(id_step == 1 || (id_step == 0 && prev.insertion_code != next.insertion_code));
+                let too_far = distance.is_none_or(|d| d > cutoff);
+
+                if numbering_gap || too_far {
+                    breaks.push(ChainBreak {
+                        chain_id: chain.id.to_string(),
+                        prev_residue_id: prev.id,
+                        prev_insertion_code: prev.insertion_code,
+                        next_residue_id: next.id,
+                        next_insertion_code: next.insertion_code,
+                        distance,
+                    });
+                }
+            }
+        }
+
+        breaks
+    }
+
+    /// Groups chains into entities, i.e. distinct molecules.
+    ///
+    /// Polymer chains sharing the same polymer type and the same sequence of standard
+    /// three-letter residue names map to a single entity, matching the mmCIF convention that
+    /// identical molecules share a `label_entity_id`. Comparing residue names rather than
+    /// one-letter codes keeps protein `ACG`, RNA `A C G`, and DNA `DA DC DG` apart. Chains
+    /// without polymer residues keep one entity per chain identifier. IDs are numbered from 1
+    /// in order of first appearance, so output is deterministic for a given chain order.
+    ///
+    /// # Returns
+    ///
+    /// Entity IDs aligned with [`Structure::iter_chains`] order.
+    pub fn entity_ids(&self) -> Vec<usize> {
+        #[derive(PartialEq, Eq, Hash)]
+        enum EntityKey {
+            Polymer(Vec<StandardResidue>),
+            NonPolymer(String),
+        }
+
+        let mut entities: HashMap<EntityKey, usize> = HashMap::new();
+
+        self.chains
+            .iter()
+            .map(|chain| {
+                let residues: Vec<StandardResidue> = chain
+                    .iter_residues()
+                    .filter_map(|r| r.standard_name)
+                    .filter(|s| s.is_protein() || s.is_nucleic())
+                    .collect();
+                let key = if residues.is_empty() {
+                    EntityKey::NonPolymer(chain.id.to_string())
+                } else {
+                    EntityKey::Polymer(residues)
+                };
+                let next_id = entities.len() + 1;
+                *entities.entry(key).or_insert(next_id)
+            })
+            .collect()
+    }
+
+    /// Splits the structure into one single-chain structure per chain.
+    ///
+    /// Every part receives a copy of the box vectors, so periodic analyses behave as they
+    /// would on the full structure.
+    ///
+    /// # Returns
+    ///
+    /// Structures in [`Structure::iter_chains`] order.
+    pub fn split_by_chain(&self) -> Vec<Structure> {
+        self.chains
+            .iter()
+            .map(|chain| Structure {
+                chains: vec![chain.clone()],
+                box_vectors: self.box_vectors,
+            })
+            .collect()
+    }
+
+    /// Splits the structure into one structure per entity as assigned by
+    /// [`Structure::entity_ids`].
+    ///
+    /// Copies of the same molecule, such as the chains of a homodimer, end up together.
+    /// Every part receives a copy of the box vectors.
+    ///
+    /// # Returns
+    ///
+    /// Structures ordered by entity ID, each keeping its chains in their original order.
+    pub fn split_by_entity(&self) -> Vec<Structure> {
+        let mut parts: Vec<Structure> = Vec::new();
+        for (chain, entity_id) in self.chains.iter().zip(self.entity_ids()) {
+            if entity_id > parts.len() {
+                parts.push(Structure {
+                    chains: Vec::new(),
+                    box_vectors: self.box_vectors,
+                });
+            }
+            parts[entity_id - 1].chains.push(chain.clone());
+        }
+        parts
+    }
+}
+
+/// Eigen-decomposes a symmetric tensor into ascending eigenvalues and a right-handed frame.
+fn principal_frame(
+    tensor: nalgebra::Matrix3<f64>,
+) -> (nalgebra::Vector3<f64>, nalgebra::Rotation3<f64>) {
+    let eigen = tensor.symmetric_eigen();
+    let mut order = [0, 1, 2];
+    order.sort_by(|&i, &j| eigen.eigenvalues[i].total_cmp(&eigen.eigenvalues[j]));
+
+    let moments = nalgebra::Vector3::from_fn(|i, _| eigen.eigenvalues[order[i]]);
+    let mut axes = nalgebra::Matrix3::from_fn(|row, col| eigen.eigenvectors[(row, order[col])]);
+    if axes.determinant() < 0.0 {
+        axes.set_column(2, &-axes.column(2));
+    }
+
+    (moments, nalgebra::Rotation3::from_matrix_unchecked(axes))
+}
+
+/// Splits a standard residue's atoms into template atoms it lacks and atoms the template lacks.
+///
+/// Returns `None` for non-standard residues and residues without a template.
+fn diff_against_template(
+    residue: &Residue,
+    include_hydrogens: bool,
+) -> Option<(Vec<String>, Vec<String>)> {
+    if residue.category != ResidueCategory::Standard {
+        return None;
+    }
+    let template = crate::db::get_template(&residue.name)?;
+
+    let has_phosphate = residue.has_atom("P");
+    let (optional, terminal): (&[&str], &[&str]) = match residue.position {
+        ResiduePosition::NTerminal => (&["H"], &["H1", "H2", "H3"]),
+        ResiduePosition::CTerminal => (&[], &["OXT", "HXT"]),
+        ResiduePosition::FivePrime if has_phosphate => (&[], &["OP3", "HOP3", "HOP2"]),
+        ResiduePosition::FivePrime => (&["P", "OP1", "OP2"], &["HO5'"]),
+        ResiduePosition::ThreePrime => (&[], &["HO3'"]),
+        ResiduePosition::None | ResiduePosition::Internal => (&[], &[]),
+    };
+
+    let expected: Vec<&str> = template
+        .heavy_atoms()
+        .map(|(name, _, _)| name)
+        .chain(
+            template
+                .hydrogens()
+                .map(|(name, _, _)| name)
+                .filter(|_| include_hydrogens),
+        )
+        .collect();
+
+    let missing = expected
+        .iter()
+        .filter(|name| !optional.contains(name) && !residue.has_atom(name))
+        .map(|name| name.to_string())
+        .collect();
+    let extra = residue
+        .iter_atoms()
+        .filter(|atom| include_hydrogens || atom.element != Element::H)
+        .map(|atom| &*atom.name)
+        .filter(|name| !expected.contains(name) && !terminal.contains(name))
+        .map(str::to_string)
+        .collect();
+
+    Some((missing, extra))
+}
+
+/// Counts template hydrogens a standard residue lacks but could be given from its anchors.
+fn count_missing_hydrogens(residue: &Residue) -> usize {
+    if residue.category != ResidueCategory::Standard {
+        return 0;
+    }
+    let Some(template) = crate::db::get_template(&residue.name) else {
+        return 0;
+    };
+    let has_all = |names: &[&str]| names.iter().all(|name| residue.has_atom(name));
+    let missing_of = |names: &[&str]| names.iter().filter(|n| !residue.has_atom(n)).count();
+
+    let protein = residue.standard_name.is_some_and(|s| s.is_protein());
+    let nucleic = residue.standard_name.is_some_and(|s| s.is_nucleic());
+    let n_terminal = protein && residue.position == ResiduePosition::NTerminal;
+
+    let from_template = template
+        .hydrogens()
+        .filter(|(name, _, _)| !residue.has_atom(name))
+        .filter(|(name, _, _)| !(n_terminal && *name == "H"))
+        .map(|(_, _, mut anchors)| anchors.all(|anchor| residue.has_atom(anchor)))
+        .filter(|&anchored| anchored)
+        .count();
+
+    let terminal = match residue.position {
+        ResiduePosition::NTerminal if protein && has_all(&["N", "CA"]) => {
+            missing_of(&["H1", "H2", "H3"])
+        }
+        ResiduePosition::ThreePrime if nucleic && has_all(&["O3'", "C3'"]) => missing_of(&["HO3'"]),
+        ResiduePosition::FivePrime
+            if nucleic && !residue.has_atom("P") && has_all(&["O5'", "C5'"]) =>
+        {
+            missing_of(&["HO5'"])
+        }
+        _ => 0,
+    };
+
+    from_template + terminal
+}
+
+fn box_volume(box_vectors: [[f64; 3]; 3]) -> f64 {
+    let [a, b, c] = box_vectors;
+
+    let cross = [
+        b[1] * c[2] - b[2] * c[1],
+        b[2] * c[0] - b[0] * c[2],
+        b[0] * c[1] - b[1] * c[0],
+    ];
+
+    let dot = a[0] * cross[0] + a[1] * cross[1] + a[2] * cross[2];
+    dot.abs()
+}
+
+impl fmt::Display for Structure {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Structure {{ chains: {}, residues: {}, atoms: {} }}",
+            self.chain_count(),
+            self.residue_count(),
+            self.atom_count()
+        )
+    }
+}
+
+impl FromIterator<Chain> for Structure {
+    fn from_iter<T: IntoIterator<Item = Chain>>(iter: T) -> Self {
+        Self {
+            chains: iter.into_iter().collect(),
+            box_vectors: None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::atom::Atom;
+    use crate::model::types::{Element, ResidueCategory, StandardResidue};
+
+    fn make_residue(id: i32, name: &str) -> Residue {
+        Residue::new(
+            id,
+            None,
+            name,
+            Some(StandardResidue::ALA),
+            ResidueCategory::Standard,
+        )
     }
 
     #[test]
@@ -790,6 +2085,85 @@ mod tests {
         assert_eq!(structure.residue_count(), 2);
     }
 
+    #[test]
+    fn structure_hetero_and_water_counts_classify_residues() {
+        let mut structure = Structure::new();
+        let mut chain = Chain::new("A");
+        chain.add_residue(make_residue(1, "ALA"));
+        chain.add_residue(Residue::new(2, None, "LIG", None, ResidueCategory::Hetero));
+        chain.add_residue(Residue::new(3, None, "NA", None, ResidueCategory::Ion));
+        structure.add_chain(chain);
+        let mut waters = Chain::new("W");
+        for (id, name) in [(1, "HOH"), (2, "WAT")] {
+            waters.add_residue(Residue::new(
+                id,
+                None,
+                name,
+                Some(StandardResidue::HOH),
+                ResidueCategory::Standard,
+            ));
+        }
+        waters.add_residue(Residue::new(
+            3,
+            None,
+            "HOH",
+            Some(StandardResidue::HOH),
+            ResidueCategory::Hetero,
+        ));
+        structure.add_chain(waters);
+
+        assert_eq!(structure.chain_count(), 2);
+        assert_eq!(structure.residue_count(), 6);
+        assert_eq!(structure.hetero_residue_count(), 1);
+        assert_eq!(structure.water_count(), 3);
+    }
+
+    #[test]
+    fn structure_residue_order_issues_flag_and_sort_repairs_misordered_pairs() {
+        let mut structure = Structure::new();
+        let mut chain = Chain::new("A");
+        for (id, code) in [(10, None), (10, Some('B')), (10, Some('A')), (9, None)] {
+            let mut residue = make_residue(id, "ALA");
+            residue.insertion_code = code;
+            chain.add_residue(residue);
+        }
+        structure.add_chain(chain);
+        let mut sorted_chain = Chain::new("B");
+        sorted_chain.add_residue(make_residue(1, "ALA"));
+        sorted_chain.add_residue(make_residue(2, "ALA"));
+        structure.add_chain(sorted_chain);
+
+        assert_eq!(
+            structure.residue_order_issues(),
+            vec![
+                ResidueOrderIssue {
+                    chain_id: "A".into(),
+                    prev: (10, Some('B')),
+                    next: (10, Some('A')),
+                },
+                ResidueOrderIssue {
+                    chain_id: "A".into(),
+                    prev: (10, Some('A')),
+                    next: (9, None),
+                },
+            ]
+        );
+
+        structure.sort_residues();
+
+        assert!(structure.residue_order_issues().is_empty());
+        let ids: Vec<_> = structure
+            .chain("A")
+            .unwrap()
+            .iter_residues()
+            .map(|r| (r.id, r.insertion_code))
+            .collect();
+        assert_eq!(
+            ids,
+            vec![(9, None), (10, None), (10, Some('A')), (10, Some('B'))]
+        );
+    }
+
     #[test]
     fn structure_atom_count_returns_correct_count() {
         let mut structure = Structure::new();
@@ -1012,6 +2386,116 @@ mod tests {
         assert!((translated_atom.pos.x - 1.0).abs() < 1e-10);
     }
 
+    #[test]
+    fn structure_canonicalize_atom_order_follows_templates() {
+        let mut ala = make_residue(1, "ALA");
+        for name in ["HA", "XX", "CB", "N", "H", "C", "CA", "YY"] {
+            ala.add_atom(Atom::new(name, Element::C, Point::origin()));
+        }
+        let mut lig = Residue::new(2, None, "LIG", None, ResidueCategory::Hetero);
+        for name in ["C2", "O1", "C1"] {
+            lig.add_atom(Atom::new(name, Element::C, Point::origin()));
+        }
+        let mut chain = Chain::new("A");
+        chain.add_residue(ala);
+        chain.add_residue(lig);
+        let mut structure: Structure = std::iter::once(chain).collect();
+        let names = |s: &Structure, id: i32| -> Vec<String> {
+            s.find_residue("A", id, None)
+                .unwrap()
+                .iter_atoms()
+                .map(|a| a.name.to_string())
+                .collect()
+        };
+
+        structure.canonicalize_atom_order();
+        assert_eq!(
+            names(&structure, 1),
+            ["N", "CA", "C", "CB", "H", "HA", "XX", "YY"]
+        );
+        assert_eq!(names(&structure, 2), ["C2", "O1", "C1"]);
+
+        let template = Template::new(
+            "LIG",
+            vec!["C1".into(), "C2".into(), "O1".into()],
+            Vec::new(),
+        );
+        structure.canonicalize_atom_order_with(&[template]);
+        assert_eq!(names(&structure, 2), ["C1", "C2", "O1"]);
+    }
+
+    #[test]
+    fn structure_approx_eq_tolerates_reordering_and_small_shifts() {
+        let build = |shift: f64, reversed: bool| {
+            let mut residue = make_residue(1, "ALA");
+            let mut atoms = vec![
+                Atom::new("N", Element::N, Point::new(shift, 0.0, 0.0)),
+                Atom::new("CA", Element::C, Point::new(1.5, shift, 0.0)),
+            ];
+            if reversed {
+                atoms.reverse();
+            }
+            for atom in atoms {
+                residue.add_atom(atom);
+            }
+            let mut chain = Chain::new("A");
+            chain.add_residue(residue);
+            let mut structure: Structure = std::iter::once(chain).collect();
+            structure.box_vectors =
+                Some([[10.0 + shift, 0.0, 0.0], [0.0, 10.0, 0.0], [0.0, 0.0, 10.0]]);
+            structure
+        };
+
+        let reference = build(0.0, false);
+        assert!(reference.approx_eq(&build(0.0, true), 0.0));
+        assert!(reference.approx_eq(&build(0.001, true), 0.01));
+        assert!(!reference.approx_eq(&build(0.1, false), 0.01));
+
+        let mut renamed = build(0.0, false);
+        renamed.chain_mut("A").unwrap().id = "B".into();
+        assert!(!reference.approx_eq(&renamed, 1.0));
+
+        let mut unboxed = build(0.0, false);
+        unboxed.box_vectors = None;
+        assert!(!reference.approx_eq(&unboxed, 1.0));
+    }
+
+    #[test]
+    fn structure_atom_at_resolves_global_indices_across_chains() {
+        let mut structure = Structure::new();
+        for (chain_id, names) in [("A", ["N", "CA"]), ("B", ["C", "O"])] {
+            let mut chain = Chain::new(chain_id);
+            for (offset, name) in names.into_iter().enumerate() {
+                let mut residue = make_residue(offset as i32 + 1, "ALA");
+                residue.add_atom(Atom::new(name, Element::C, Point::origin()));
+                chain.add_residue(residue);
+            }
+            structure.add_chain(chain);
+        }
+        structure
+            .chain_mut("A")
+            .unwrap()
+            .add_residue(make_residue(3, "ALA"));
+
+        let names: Vec<_> = (0..4)
+            .map(|i| structure.atom_at(i).unwrap().name.to_string())
+            .collect();
+        assert_eq!(names, ["N", "CA", "C", "O"]);
+        assert!(structure.atom_at(4).is_none());
+
+        structure.atom_mut_at(2).unwrap().b_factor = 42.0;
+        assert_eq!(
+            structure
+                .find_residue("B", 1, None)
+                .unwrap()
+                .atom("C")
+                .unwrap()
+                .b_factor,
+            42.0
+        );
+        assert!(structure.atom_mut_at(4).is_none());
+    }
+
     #[test]
     fn structure_retain_residues_filters_using_chain_context() {
         let mut structure = Structure::new();
@@ -1096,10 +2580,194 @@ mod tests {
             }
         });
 
-        let chain_a = structure.chain("A").unwrap();
-        assert_eq!(chain_a.residue_count(), 1);
-        assert_eq!(chain_a.residue(1, None).unwrap().name, "ALA_MOD");
-        assert!(structure.chain("B").unwrap().is_empty());
+        let chain_a = structure.chain("A").unwrap();
+        assert_eq!(chain_a.residue_count(), 1);
+        assert_eq!(chain_a.residue(1, None).unwrap().name, "ALA_MOD");
+        assert!(structure.chain("B").unwrap().is_empty());
+    }
+
+    #[test]
+    fn structure_assign_positions_marks_polymer_termini() {
+        let mut protein = Chain::new("A");
+        let mut water = Residue::new(
+            0,
+            None,
+            "HOH",
+            Some(StandardResidue::HOH),
+            ResidueCategory::Standard,
+        );
+        water.position = ResiduePosition::Internal;
+        protein.add_residue(water);
+        for (id, name, std) in [
+            (1, "MET", StandardResidue::MET),
+            (2, "GLY", StandardResidue::GLY),
+            (3, "LYS", StandardResidue::LYS),
+        ] {
+            protein.add_residue(Residue::new(
+                id,
+                None,
+                name,
+                Some(std),
+                ResidueCategory::Standard,
+            ));
+        }
+        protein.add_residue(Residue::new(4, None, "LIG", None, ResidueCategory::Hetero));
+
+        let mut nucleic = Chain::new("N");
+        for (id, name, std) in [
+            (1, "DA", StandardResidue::DA),
+            (2, "DT", StandardResidue::DT),
+        ] {
+            nucleic.add_residue(Residue::new(
+                id,
+                None,
+                name,
+                Some(std),
+                ResidueCategory::Standard,
+            ));
+        }
+
+        let mut structure: Structure = [protein, nucleic].into_iter().collect();
+        structure.assign_positions();
+
+        let positions = |id: &str| -> Vec<ResiduePosition> {
+            structure
+                .chain(id)
+                .unwrap()
+                .iter_residues()
+                .map(|r| r.position)
+                .collect()
+        };
+        assert_eq!(
+            positions("A"),
+            vec![
+                ResiduePosition::None,
+                ResiduePosition::NTerminal,
+                ResiduePosition::Internal,
+                ResiduePosition::CTerminal,
+                ResiduePosition::None,
+            ]
+        );
+        assert_eq!(
+            positions("N"),
+            vec![ResiduePosition::FivePrime, ResiduePosition::ThreePrime]
+        );
+    }
+
+    #[test]
+    fn structure_reclassify_categories_handles_mixed_residues() {
+        let mut chain = Chain::new("A");
+        let mut ala = make_residue(1, "ALA");
+        ala.category = ResidueCategory::Hetero;
+        ala.add_atom(Atom::new("CA", Element::C, Point::origin()));
+        chain.add_residue(ala);
+
+        let mut water = Residue::new(
+            2,
+            None,
+            "HOH",
+            Some(StandardResidue::HOH),
+            ResidueCategory::Hetero,
+        );
+        water.add_atom(Atom::new("O", Element::O, Point::origin()));
+        chain.add_residue(water);
+
+        let mut sodium = Residue::new(3, None, "NA", None, ResidueCategory::Hetero);
+        sodium.add_atom(Atom::new("NA", Element::Na, Point::origin()));
+        chain.add_residue(sodium);
+
+        let mut zinc = Residue::new(4, None, "ZN2", None, ResidueCategory::Hetero);
+        zinc.add_atom(Atom::new("ZN", Element::Zn, Point::origin()));
+        chain.add_residue(zinc);
+
+        let mut ligand = Residue::new(5, None, "LIG", None, ResidueCategory::Ion);
+        ligand.add_atom(Atom::new("C1", Element::C, Point::origin()));
+        ligand.add_atom(Atom::new("O1", Element::O, Point::origin()));
+        chain.add_residue(ligand);
+
+        let mut carbon = Residue::new(6, None, "MTH", None, ResidueCategory::Ion);
+        carbon.add_atom(Atom::new("C", Element::C, Point::origin()));
+        chain.add_residue(carbon);
+
+        let mut structure: Structure = std::iter::once(chain).collect();
+        structure.reclassify_categories();
+
+        let categories: Vec<ResidueCategory> = structure
+            .chain("A")
+            .unwrap()
+            .iter_residues()
+            .map(|r| r.category)
+            .collect();
+        assert_eq!(
+            categories,
+            vec![
+                ResidueCategory::Standard,
+                ResidueCategory::Standard,
+                ResidueCategory::Ion,
+                ResidueCategory::Ion,
+                ResidueCategory::Hetero,
+                ResidueCategory::Hetero,
+            ]
+        );
+    }
+
+    #[test]
+    fn reclassified_standard_residues_gain_mapping_and_build_a_topology() {
+        let mut chain = Chain::new("A");
+        let mut his = heavy_atom_residue("HIE", 1, ResiduePosition::None);
+        his.set_standard_name(None);
+        his.category = ResidueCategory::Hetero;
+        chain.add_residue(his);
+        let mut water = Residue::new(2, None, "WAT", None, ResidueCategory::Hetero);
+        water.add_atom(Atom::new("O", Element::O, Point::new(9.0, 0.0, 0.0)));
+        chain.add_residue(water);
+        let mut structure: Structure = std::iter::once(chain).collect();
+
+        structure.reclassify_categories();
+
+        let residues: Vec<(&str, Option<StandardResidue>, ResidueCategory)> = structure
+            .iter_chains()
+            .flat_map(Chain::iter_residues)
+            .map(|r| (r.name.as_str(), r.standard_name, r.category))
+            .collect();
+        assert_eq!(
+            residues,
+            vec![
+                ("HIE", Some(StandardResidue::HIS), ResidueCategory::Standard),
+                ("HOH", Some(StandardResidue::HOH), ResidueCategory::Standard),
+            ]
+        );
+        let topology = crate::ops::TopologyBuilder::new()
+            .lenient(true)
+            .build(structure)
+            .expect("reclassified residues resolve their templates");
+        assert!(!topology.bonds().is_empty());
+    }
+
+    #[test]
+    fn structure_retain_residues_by_bfactor_drops_low_confidence_residues() {
+        let mut chain = Chain::new("A");
+        for (id, plddt) in [(1, [40.0, 50.0]), (2, [70.0, 70.0]), (3, [90.0, 60.0])] {
+            let mut residue = make_residue(id, "ALA");
+            for (name, b) in ["N", "CA"].into_iter().zip(plddt) {
+                let mut atom = Atom::new(name, Element::C, Point::origin());
+                atom.b_factor = b;
+                residue.add_atom(atom);
+            }
+            chain.add_residue(residue);
+        }
+        chain.add_residue(make_residue(4, "GLY"));
+        let mut structure: Structure = std::iter::once(chain).collect();
+
+        structure.retain_residues_by_bfactor(70.0);
+
+        let ids: Vec<i32> = structure
+            .chain("A")
+            .unwrap()
+            .iter_residues()
+            .map(|r| r.id)
+            .collect();
+        assert_eq!(ids, vec![2, 3]);
     }
 
     #[test]
@@ -1141,6 +2809,137 @@ mod tests {
         assert_eq!(contexts, vec![("A".into(), 1, "CA".into())]);
     }
 
+    #[test]
+    fn structure_iter_residues_with_offset_tracks_global_atom_indices() {
+        let mut chain_a = Chain::new("A");
+        let mut gly = Residue::new(1, None, "GLY", None, ResidueCategory::Standard);
+        gly.add_atom(Atom::new("N", Element::N, Point::origin()));
+        gly.add_atom(Atom::new("CA", Element::C, Point::origin()));
+        chain_a.add_residue(gly);
+        chain_a.add_residue(Residue::new(2, None, "UNK", None, ResidueCategory::Hetero));
+        let mut chain_b = Chain::new("B");
+        let mut ser = Residue::new(1, None, "SER", None, ResidueCategory::Standard);
+        ser.add_atom(Atom::new("N", Element::N, Point::origin()));
+        ser.add_atom(Atom::new("OG", Element::O, Point::origin()));
+        chain_b.add_residue(ser);
+        let structure: Structure = [chain_a, chain_b].into_iter().collect();
+
+        let offsets: Vec<(&str, i32, usize)> = structure
+            .iter_residues_with_offset()
+            .map(|(chain, residue, offset)| (chain.id.as_str(), residue.id, offset))
+            .collect();
+
+        assert_eq!(offsets, vec![("A", 1, 0), ("A", 2, 2), ("B", 1, 2)]);
+        let atoms: Vec<_> = structure.iter_atoms().collect();
+        for (_, residue, offset) in structure.iter_residues_with_offset() {
+            for (i, atom) in residue.iter_atoms().enumerate() {
+                assert!(std::ptr::eq(atoms[offset + i], atom));
+            }
+        }
+    }
+
+    #[test]
+    fn structure_try_finalize_accepts_valid_structure() {
+        let mut chain = Chain::new("A");
+        let mut residue = Residue::new(1, None, "GLY", None, ResidueCategory::Standard);
+        residue.add_atom(Atom::new("CA", Element::C, Point::origin()));
+        chain.add_residue(residue);
+        let structure: Structure = std::iter::once(chain).collect();
+
+        let finalized = structure.try_finalize().expect("valid structure");
+
+        assert_eq!(finalized.atom_count(), 1);
+    }
+
+    #[test]
+    fn structure_try_finalize_reports_every_invariant_violation() {
+        let mut chain = Chain::new("A");
+        for id in [1, 2] {
+            let mut residue = Residue::new(id, Some('A'), "SER", None, ResidueCategory::Standard);
+            residue.add_atom(Atom::new("CA", Element::C, Point::origin()));
+            residue.add_atom(Atom::new("OG", Element::O, Point::origin()));
+            chain.add_residue(residue);
+        }
+        let mut residues = chain.iter_residues_mut();
+        let first = residues.next().unwrap();
+        first.iter_atoms_mut().last().unwrap().pos.x = f64::NAN;
+        let second = residues.next().unwrap();
+        second.id = 1;
+        second.iter_atoms_mut().last().unwrap().name = "CA".into();
+        let mut structure: Structure = std::iter::once(chain).collect();
+        structure.chains.push(Chain::new("A"));
+        structure.chains.push(Chain::new(""));
+
+        let issues = structure.try_finalize().expect_err("invalid structure");
+
+        assert_eq!(
+            issues,
+            vec![
+                ValidationIssue::NonFiniteCoordinate {
+                    chain_id: "A".into(),
+                    residue_id: 1,
+                    insertion_code: Some('A'),
+                    atom_name: "OG".into(),
+                },
+                ValidationIssue::DuplicateResidue {
+                    chain_id: "A".into(),
+                    residue_id: 1,
+                    insertion_code: Some('A'),
+                },
+                ValidationIssue::DuplicateAtom {
+                    chain_id: "A".into(),
+                    residue_id: 1,
+                    insertion_code: Some('A'),
+                    atom_name: "CA".into(),
+                },
+                ValidationIssue::DuplicateChain {
+                    chain_id: "A".into(),
+                },
+                ValidationIssue::EmptyChainId { chain_index: 2 },
+            ]
+        );
+        assert_eq!(issues[1].to_string(), "duplicate residue 1A in chain 'A'");
+    }
+
+    #[test]
+    fn structure_atom_table_flattens_atoms_into_owned_records() {
+        let mut structure = Structure::new();
+        let mut chain_a = Chain::new("A");
+        let mut residue = Residue::new(5, Some('B'), "SER", None, ResidueCategory::Standard);
+        residue.add_atom(Atom::new("N", Element::N, Point::new(1.0, 2.0, 3.0)));
+        residue.add_atom(Atom::new("OG", Element::O, Point::new(4.0, 5.0, 6.0)));
+        chain_a.add_residue(residue);
+        let mut chain_b = Chain::new("B");
+        let mut ion = Residue::new(1, None, "NA", None, ResidueCategory::Ion);
+        ion.add_atom(Atom::new("NA", Element::Na, Point::origin()));
+        chain_b.add_residue(ion);
+        structure.add_chain(chain_a);
+        structure.add_chain(chain_b);
+
+        let table = structure.atom_table();
+        drop(structure);
+
+        assert_eq!(table.len(), 3);
+        assert_eq!(
+            table[1],
+            AtomRecord {
+                index: 1,
+                chain_id: "A".into(),
+                residue_id: 5,
+                insertion_code: Some('B'),
+                residue_name: "SER".into(),
+                atom_name: "OG".into(),
+                element: Element::O,
+                pos: Point::new(4.0, 5.0, 6.0),
+                occupancy: 1.0,
+                b_factor: 0.0,
+            }
+        );
+        assert_eq!(table[2].index, 2);
+        assert_eq!(table[2].chain_id, "B");
+        assert_eq!(table[2].element, Element::Na);
+    }
+
     #[test]
     fn structure_geometric_center_calculates_correctly() {
         let mut structure = Structure::new();
@@ -1206,6 +3005,177 @@ mod tests {
         assert_eq!(com, Point::origin());
     }
 
+    fn carbon_structure(positions: &[[f64; 3]]) -> Structure {
+        let mut residue = make_residue(1, "LIG");
+        for (i, p) in positions.iter().enumerate() {
+            residue.add_atom(Atom::new(&format!("C{i}"), Element::C, Point::from(*p)));
+        }
+        let mut chain = Chain::new("A");
+        chain.add_residue(residue);
+        std::iter::once(chain).collect()
+    }
+
+    #[test]
+    fn structure_inertia_tensor_of_linear_molecule() {
+        let structure = carbon_structure(&[[4.0, 2.0, 0.0], [6.0, 2.0, 0.0]]);
+        let m = Element::C.atomic_mass();
+
+        let tensor = structure.inertia_tensor();
+
+        let expected = nalgebra::Matrix3::from_diagonal(&nalgebra::Vector3::new(0.0, 2.0, 2.0)) * m;
+        assert!((tensor - expected).norm() < 1e-9);
+
+        let (moments, axes) = structure.principal_moments();
+        assert!((moments - nalgebra::Vector3::new(0.0, 2.0 * m, 2.0 * m)).norm() < 1e-9);
+        assert!((axes * nalgebra::Vector3::x()).x.abs() > 1.0 - 1e-9);
+    }
+
+    #[test]
+    fn structure_principal_moments_diagonalize_tensor_with_proper_rotation() {
+        let structure = carbon_structure(&[
+            [0.0, 0.0, 0.0],
+            [1.5, 0.4, -0.2],
+            [0.3, 2.2, 0.9],
+            [-1.1, 0.8, 1.7],
+            [0.6, -0.9, 2.5],
+        ]);
+
+        let tensor = structure.inertia_tensor();
+        let (moments, axes) = structure.principal_moments();
+
+        assert!(moments.x <= moments.y && moments.y <= moments.z);
+        assert!((axes.matrix().determinant() - 1.0).abs() < 1e-9);
+        let diagonal = axes.matrix().transpose() * tensor * axes.matrix();
+        assert!((diagonal - nalgebra::Matrix3::from_diagonal(&moments)).norm() < 1e-6);
+    }
+
+    #[test]
+    fn structure_inertia_tensor_is_zero_for_empty_structure() {
+        let structure = Structure::new();
+
+        assert_eq!(structure.inertia_tensor(), nalgebra::Matrix3::zeros());
+        let (moments, _) = structure.principal_moments();
+        assert_eq!(moments, nalgebra::Vector3::zeros());
+    }
+
+    #[test]
+    fn structure_selection_centers_cover_selected_atoms_only() {
+        let mut residue = make_residue(1, "ALA");
+        residue.add_atom(Atom::new("H", Element::H, Point::new(0.0, 0.0, 0.0)));
+        residue.add_atom(Atom::new("C", Element::C, Point::new(2.0, 0.0, 0.0)));
+        residue.add_atom(Atom::new("N", Element::N, Point::new(0.0, 0.0, 50.0)));
+        let mut chain = Chain::new("A");
+        chain.add_residue(residue);
+        let mut structure = Structure::new();
+        structure.add_chain(chain);
+
+        let center = structure.selection_center(&[0, 1, 1, 42]).unwrap();
+        assert_eq!(center, Point::new(1.0, 0.0, 0.0));
+
+        let com = structure.selection_center_of_mass(&[1, 0]).unwrap();
+        let expected_x = (2.0 * 12.0107) / (1.00794 + 12.0107);
+        assert!((com.x - expected_x).abs() < 1e-3);
+        assert_eq!(com.z, 0.0);
+
+        assert_eq!(structure.selection_center(&[]), None);
+        assert_eq!(structure.selection_center_of_mass(&[7]), None);
+    }
+
+    #[test]
+    fn structure_dipole_moment_sums_charges_about_center_of_mass() {
+        let mut chain = Chain::new("A");
+        for (id, x, charge) in [(1, -3.0, -0.5), (2, 1.0, 0.25), (3, 5.0, 0.25)] {
+            let mut residue = Residue::new(id, None, "ION", None, ResidueCategory::Hetero);
+            let mut atom = Atom::new("O", Element::O, Point::new(x, 0.0, 0.0));
+            atom.partial_charge = Some(charge);
+            residue.add_atom(atom);
+            chain.add_residue(residue);
+        }
+        let mut structure = Structure::new();
+        structure.add_chain(chain);
+
+        assert!(structure.has_partial_charges());
+        let dipole = structure.dipole_moment();
+        assert!((dipole.x - 3.0).abs() < 1e-10);
+        assert!(dipole.y.abs() < 1e-10 && dipole.z.abs() < 1e-10);
+    }
+
+    #[test]
+    fn structure_dipole_moment_is_zero_without_charges() {
+        let mut chain = Chain::new("A");
+        let mut residue = make_residue(1, "ALA");
+        residue.add_atom(Atom::new("CA", Element::C, Point::new(1.0, 2.0, 3.0)));
+        chain.add_residue(residue);
+        let mut structure = Structure::new();
+        structure.add_chain(chain);
+
+        assert!(!structure.has_partial_charges());
+        assert_eq!(structure.dipole_moment(), nalgebra::Vector3::zeros());
+        assert!(!Structure::new().has_partial_charges());
+    }
+
+    #[test]
+    fn structure_molecular_formula_and_mass_aggregate_atoms() {
+        let mut residue = Residue::new(
+            1,
+            None,
+            "ALA",
+            Some(StandardResidue::ALA),
+            ResidueCategory::Standard,
+        );
+        for (name, element) in [
+            ("N", Element::N),
+            ("CA", Element::C),
+            ("C", Element::C),
+            ("O", Element::O),
+            ("CB", Element::C),
+            ("H", Element::H),
+            ("HA", Element::H),
+        ] {
+            residue.add_atom(Atom::new(name, element, Point::origin()));
+        }
+        let mut chain = Chain::new("A");
+        chain.add_residue(residue);
+        let structure: Structure = std::iter::once(chain).collect();
+
+        let formula = structure.molecular_formula();
+        assert_eq!(formula.get(&Element::C), Some(&3));
+        assert_eq!(formula.get(&Element::H), Some(&2));
+        assert_eq!(formula.get(&Element::N), Some(&1));
+        assert_eq!(formula.get(&Element::S), None);
+
+        let expected = 3.0 * Element::C.atomic_mass()
+            + 2.0 * Element::H.atomic_mass()
+            + Element::N.atomic_mass()
+            + Element::O.atomic_mass();
+        assert!((structure.total_mass() - expected).abs() < 1e-9);
+        assert_eq!(structure.formula_string(), "C3 H2 N O");
+    }
+
+    #[test]
+    fn structure_formula_string_sorts_alphabetically_without_carbon() {
+        let mut chain = Chain::new("A");
+        let mut water = Residue::new(
+            1,
+            None,
+            "HOH",
+            Some(StandardResidue::HOH),
+            ResidueCategory::Standard,
+        );
+        water.add_atom(Atom::new("O", Element::O, Point::origin()));
+        water.add_atom(Atom::new("H1", Element::H, Point::origin()));
+        water.add_atom(Atom::new("H2", Element::H, Point::origin()));
+        chain.add_residue(water);
+        let mut ion = Residue::new(2, None, "NA", None, ResidueCategory::Ion);
+        ion.add_atom(Atom::new("NA", Element::Na, Point::origin()));
+        chain.add_residue(ion);
+        let structure: Structure = std::iter::once(chain).collect();
+
+        assert_eq!(structure.formula_string(), "H2 Na O");
+        assert_eq!(Structure::new().formula_string(), "");
+        assert_eq!(Structure::new().total_mass(), 0.0);
+    }
+
     #[test]
     fn structure_display_formats_correctly() {
         let mut structure = Structure::new();
@@ -1346,4 +3316,491 @@ mod tests {
             assert!(atom_ref.name.starts_with('X'));
         }
     }
+
+    fn backbone_residue(id: i32, insertion_code: Option<char>, c_x: f64) -> Residue {
+        let mut residue = Residue::new(
+            id,
+            insertion_code,
+            "ALA",
+            Some(StandardResidue::ALA),
+            ResidueCategory::Standard,
+        );
+        residue.add_atom(Atom::new("N", Element::N, Point::new(c_x - 2.4, 0.0, 0.0)));
+        residue.add_atom(Atom::new("CA", Element::C, Point::new(c_x - 1.2, 0.0, 0.0)));
+        residue.add_atom(Atom::new("C", Element::C, Point::new(c_x, 0.0, 0.0)));
+        residue
+    }
+
+    #[test]
+    fn build_atom_grid_stores_global_atom_indices() {
+        let mut first = make_residue(1, "GLY");
+        first.add_atom(Atom::new("N", Element::N, Point::new(0.0, 0.0, 0.0)));
+        first.add_atom(Atom::new("CA", Element::C, Point::new(10.0, 0.0, 0.0)));
+        let mut second = make_residue(2, "GLY");
+        second.add_atom(Atom::new("N", Element::N, Point::new(10.5, 0.0, 0.0)));
+        let mut chain = Chain::new("B");
+        chain.add_residue(first);
+        chain.add_residue(second);
+        let structure: Structure = std::iter::once(chain).collect();
+
+        let grid = structure.build_atom_grid(2.0);
+
+        assert_eq!(grid.len(), structure.atom_count());
+        let mut found: Vec<usize> = grid
+            .neighbors(&Point::new(10.2, 0.0, 0.0), 1.0)
+            .exact()
+            .map(|(_, idx)| *idx)
+            .collect();
+        found.sort_unstable();
+        assert_eq!(found, vec![1, 2]);
+        let atoms: Vec<_> = structure.iter_atoms().collect();
+        assert!(found.iter().all(|&idx| atoms[idx].pos.x > 9.0));
+    }
+
+    #[test]
+    fn nearest_atom_returns_index_and_distance_matching_grid_lookup() {
+        let mut residue = make_residue(1, "GLY");
+        residue.add_atom(Atom::new("N", Element::N, Point::new(0.0, 0.0, 0.0)));
+        residue.add_atom(Atom::new("CA", Element::C, Point::new(1.5, 0.0, 0.0)));
+        residue.add_atom(Atom::new("C", Element::C, Point::new(2.0, 1.4, 0.0)));
+        let mut chain = Chain::new("A");
+        chain.add_residue(residue);
+        let structure: Structure = std::iter::once(chain).collect();
+        let query = Point::new(2.2, 2.0, 0.0);
+
+        let (index, distance) = structure.nearest_atom(&query).expect("structure has atoms");
+
+        assert_eq!(index, 2);
+        assert!((distance - 0.4f64.sqrt()).abs() < 1e-12);
+        let grid = structure.build_atom_grid(2.0);
+        let (grid_index, grid_distance) = grid.nearest(&query).unwrap();
+        assert_eq!((*grid_index, grid_distance), (index, distance));
+        assert!(Structure::new().nearest_atom(&query).is_none());
+    }
+
+    #[test]
+    fn residue_sasa_sums_heavy_atom_areas_per_residue() {
+        let mut ligand = Residue::new(1, Some('A'), "LIG", None, ResidueCategory::Hetero);
+        ligand.add_atom(Atom::new("C1", Element::C, Point::origin()));
+        ligand.add_atom(Atom::new("H1", Element::H, Point::new(1.0, 0.0, 0.0)));
+        let mut ion = Residue::new(2, None, "NA", None, ResidueCategory::Ion);
+        ion.add_atom(Atom::new("NA", Element::Na, Point::new(40.0, 0.0, 0.0)));
+        let mut chain = Chain::new("A");
+        chain.add_residue(ligand);
+        chain.add_residue(ion);
+        let structure: Structure = std::iter::once(chain).collect();
+
+        let atom_areas = structure.atom_sasa(1.4);
+        let residue_areas = structure.residue_sasa(1.4);
+
+        let carbon_radius = ElementProperties::default().vdw_radius(Element::C) + 1.4;
+        let sphere = 4.0 * std::f64::consts::PI * carbon_radius * carbon_radius;
+        assert!((atom_areas[0] - sphere).abs() < 1e-9);
+        assert_eq!(atom_areas[1], 0.0);
+        assert_eq!(residue_areas.len(), 2);
+        let (chain_id, residue_id, insertion_code, area) = &residue_areas[0];
+        assert_eq!(
+            (chain_id.as_str(), *residue_id, *insertion_code),
+            ("A", 1, Some('A'))
+        );
+        assert!((area - sphere).abs() < 1e-9);
+        assert_eq!(residue_areas[1].2, None);
+        assert!((residue_areas[1].3 - atom_areas[2]).abs() < 1e-12);
+    }
+
+    #[test]
+    fn sasa_with_custom_radii_matches_overridden_sphere() {
+        let mut ion = Residue::new(1, None, "NA", None, ResidueCategory::Ion);
+        ion.add_atom(Atom::new("NA", Element::Na, Point::origin()));
+        let mut chain = Chain::new("A");
+        chain.add_residue(ion);
+        let structure: Structure = std::iter::once(chain).collect();
+        let properties = ElementProperties::default().with_vdw_radius(Element::Na, 1.0);
+
+        let atom_areas = structure.atom_sasa_with(1.4, &properties);
+        let residue_areas = structure.residue_sasa_with(1.4, &properties);
+
+        let sphere = 4.0 * std::f64::consts::PI * 2.4 * 2.4;
+        assert!((atom_areas[0] - sphere).abs() < 1e-9);
+        assert!((residue_areas[0].3 - sphere).abs() < 1e-9);
+        assert!(structure.atom_sasa(1.4)[0] > sphere);
+    }
+
+    #[test]
+    fn relative_sasa_normalizes_amino_acids_by_reference_area() {
+        let template = crate::db::get_template("GLY").expect("GLY template");
+        let mut gly = Residue::new(
+            1,
+            None,
+            "GLY",
+            Some(StandardResidue::GLY),
+            ResidueCategory::Standard,
+        );
+        for (name, element, pos) in template.heavy_atoms() {
+            gly.add_atom(Atom::new(name, element, pos));
+        }
+        let mut ligand = Residue::new(2, None, "LIG", None, ResidueCategory::Hetero);
+        ligand.add_atom(Atom::new("C1", Element::C, Point::new(30.0, 0.0, 0.0)));
+        let mut chain = Chain::new("A");
+        chain.add_residue(gly);
+        chain.add_residue(ligand);
+        let structure: Structure = std::iter::once(chain).collect();
+
+        let relative = structure.relative_sasa(1.4);
+
+        let absolute = structure.residue_sasa(1.4);
+        let gly_relative = relative[0].3.expect("amino acid reference");
+        assert!((gly_relative - absolute[0].3 / 104.0).abs() < 1e-12);
+        assert!(
+            gly_relative > 1.0,
+            "free glycine is more exposed than in Gly-Gly-Gly"
+        );
+        assert_eq!(relative[1], ("A".to_string(), 2, None, None));
+    }
+
+    #[test]
+    fn find_incomplete_residues_reports_missing_backbone_and_empty_residues() {
+        let mut chain = Chain::new("A");
+        chain.add_residue(backbone_residue(1, None, 0.0));
+        let mut truncated = make_residue(2, "ALA");
+        truncated.add_atom(Atom::new("CA", Element::C, Point::new(5.0, 0.0, 0.0)));
+        chain.add_residue(truncated);
+        chain.add_residue(Residue::new(3, None, "LIG", None, ResidueCategory::Hetero));
+        let structure: Structure = std::iter::once(chain).collect();
+
+        let incomplete = structure.find_incomplete_residues();
+
+        assert_eq!(
+            incomplete,
+            vec![
+                ("A".to_string(), 2, vec!["N", "C"]),
+                ("A".to_string(), 3, Vec::new()),
+            ]
+        );
+    }
+
+    #[test]
+    fn template_diff_lists_missing_and_extra_heavy_atoms() {
+        let mut ala = backbone_residue(1, None, 0.0);
+        ala.add_atom(Atom::new("CB", Element::C, Point::new(-1.2, 1.5, 0.0)));
+        ala.add_atom(Atom::new("CL1", Element::Cl, Point::new(3.0, 3.0, 3.0)));
+        ala.add_atom(Atom::new("HA", Element::H, Point::new(-1.2, -1.0, 0.0)));
+        let mut last = backbone_residue(2, None, 3.73);
+        last.add_atom(Atom::new("O", Element::O, Point::new(4.5, 1.0, 0.0)));
+        last.add_atom(Atom::new("CB", Element::C, Point::new(2.5, 1.5, 0.0)));
+        last.add_atom(Atom::new("OXT", Element::O, Point::new(4.5, -1.0, 0.0)));
+        last.position = ResiduePosition::CTerminal;
+        let mut chain = Chain::new("A");
+        chain.add_residue(ala);
+        chain.add_residue(last);
+        chain.add_residue(Residue::new(3, None, "LIG", None, ResidueCategory::Hetero));
+        let structure: Structure = std::iter::once(chain).collect();
+
+        let diffs = structure.template_diff(false);
+
+        assert_eq!(
+            diffs,
+            vec![ResidueDiff {
+                chain_id: "A".to_string(),
+                residue_id: 1,
+                insertion_code: None,
+                residue_name: "ALA".to_string(),
+                missing: vec!["O".to_string()],
+                extra: vec!["CL1".to_string()],
+            }]
+        );
+    }
+
+    fn heavy_atom_residue(name: &str, id: i32, position: ResiduePosition) -> Residue {
+        let template = crate::db::get_template(name).expect("template exists");
+        let mut residue = Residue::new(
+            id,
+            None,
+            name,
+            Some(template.standard_name()),
+            ResidueCategory::Standard,
+        );
+        residue.position = position;
+        for (atom_name, element, pos) in template.heavy_atoms() {
+            residue.add_atom(Atom::new(atom_name, element, pos));
+        }
+        residue
+    }
+
+    #[test]
+    fn missing_hydrogen_count_matches_hydrogens_added() {
+        let mut protein = Chain::new("A");
+        protein.add_residue(heavy_atom_residue("SER", 1, ResiduePosition::NTerminal));
+        protein.add_residue(heavy_atom_residue("ALA", 2, ResiduePosition::Internal));
+        protein.add_residue(heavy_atom_residue("GLY", 3, ResiduePosition::CTerminal));
+        let mut dna = Chain::new("B");
+        let mut first = heavy_atom_residue("DA", 1, ResiduePosition::FivePrime);
+        for name in ["P", "OP1", "OP2"] {
+            first.remove_atom(name);
+        }
+        dna.add_residue(first);
+        dna.add_residue(heavy_atom_residue("DT", 2, ResiduePosition::ThreePrime));
+        let mut structure: Structure = [protein, dna].into_iter().collect();
+
+        let predicted = structure.missing_hydrogen_count();
+        let per_residue = structure.missing_hydrogens();
+        crate::ops::add_hydrogens(&mut structure, &crate::ops::HydroConfig::default())
+            .expect("hydrogens added");
+
+        let added = structure
+            .iter_atoms()
+            .filter(|a| a.element == Element::H)
+            .count();
+        assert_eq!(predicted, added);
+        assert_eq!(per_residue.len(), 5);
+        assert_eq!(
+            per_residue.iter().map(|(_, _, _, n)| n).sum::<usize>(),
+            added
+        );
+        assert_eq!(structure.missing_hydrogen_count(), 0);
+    }
+
+    #[test]
+    fn missing_hydrogens_distinguish_insertion_coded_residues() {
+        let mut chain = Chain::new("A");
+        chain.add_residue(heavy_atom_residue("ALA", 52, ResiduePosition::Internal));
+        let mut inserted = heavy_atom_residue("ALA", 52, ResiduePosition::Internal);
+        inserted.insertion_code = Some('A');
+        chain.add_residue(inserted);
+        let structure: Structure = std::iter::once(chain).collect();
+
+        let keys: Vec<_> = structure
+            .missing_hydrogens()
+            .into_iter()
+            .map(|(chain, id, insertion_code, _)| (chain, id, insertion_code))
+            .collect();
+
+        assert_eq!(
+            keys,
+            vec![
+                ("A".to_string(), 52, None),
+                ("A".to_string(), 52, Some('A'))
+            ]
+        );
+    }
+
+    #[test]
+    fn missing_hydrogens_skip_hydrogens_without_anchors() {
+        let mut ala = heavy_atom_residue("ALA", 1, ResiduePosition::Internal);
+        let complete = count_missing_hydrogens(&ala);
+        ala.remove_atom("CB");
+
+        assert_eq!(count_missing_hydrogens(&ala), complete - 3);
+        let ligand = Residue::new(2, None, "LIG", None, ResidueCategory::Hetero);
+        assert_eq!(count_missing_hydrogens(&ligand), 0);
+    }
+
+    #[test]
+    fn template_diff_optionally_compares_hydrogens() {
+        let mut ala = backbone_residue(1, None, 0.0);
+        ala.add_atom(Atom::new("O", Element::O, Point::new(1.0, 1.0, 0.0)));
+        ala.add_atom(Atom::new("CB", Element::C, Point::new(-1.2, 1.5, 0.0)));
+        ala.add_atom(Atom::new("HQ", Element::H, Point::new(-1.2, -1.0, 0.0)));
+        let mut chain = Chain::new("A");
+        chain.add_residue(ala);
+        let structure: Structure = std::iter::once(chain).collect();
+
+        assert!(structure.template_diff(false).is_empty());
+
+        let diffs = structure.template_diff(true);
+        assert_eq!(diffs.len(), 1);
+        assert_eq!(diffs[0].extra, vec!["HQ".to_string()]);
+        assert!(diffs[0].missing.contains(&"HA".to_string()));
+        assert!(diffs[0].missing.contains(&"HB3".to_string()));
+    }
+
+    #[test]
+    fn chain_breaks_is_empty_for_connected_chain() {
+        let mut chain = Chain::new("A");
+        chain.add_residue(backbone_residue(1, None, 0.0));
+        chain.add_residue(backbone_residue(2, None, 3.73));
+        chain.add_residue(backbone_residue(2, Some('A'), 7.46));
+        chain.add_residue(backbone_residue(3, None, 11.19));
+        let structure: Structure = std::iter::once(chain).collect();
+
+        assert!(structure.chain_breaks().is_empty());
+    }
+
+    #[test]
+    fn chain_breaks_reports_distance_gap() {
+        let mut chain = Chain::new("A");
+        chain.add_residue(backbone_residue(1, None, 0.0));
+        chain.add_residue(backbone_residue(2, None, 10.0));
+        let structure: Structure = std::iter::once(chain).collect();
+
+        let breaks = structure.chain_breaks();
+
+        assert_eq!(breaks.len(), 1);
+        assert_eq!(breaks[0].chain_id, "A");
+        assert_eq!(breaks[0].prev_residue_id, 1);
+        assert_eq!(breaks[0].next_residue_id, 2);
+        assert!((breaks[0].distance.unwrap() - 7.6).abs() < 1e-9);
+        assert_eq!(breaks[0].missing_residue_count(), 0);
+    }
+
+    #[test]
+    fn chain_breaks_reports_numbering_gap_even_when_close() {
+        let mut chain = Chain::new("B");
+        chain.add_residue(backbone_residue(5, None, 0.0));
+        chain.add_residue(backbone_residue(9, None, 3.73));
+        let structure: Structure = std::iter::once(chain).collect();
+
+        let breaks = structure.chain_breaks();
+
+        assert_eq!(breaks.len(), 1);
+        assert_eq!(breaks[0].chain_id, "B");
+        assert_eq!(breaks[0].missing_residue_count(), 3);
+    }
+
+    #[test]
+    fn chain_breaks_ignores_hetero_and_water_neighbors() {
+        let mut chain = Chain::new("A");
+        chain.add_residue(backbone_residue(1, None, 0.0));
+        chain.add_residue(Residue::new(50, None, "LIG", None, ResidueCategory::Hetero));
+        chain.add_residue(Residue::new(
+            60,
+            None,
+            "HOH",
+            Some(StandardResidue::HOH),
+            ResidueCategory::Standard,
+        ));
+        let structure: Structure = std::iter::once(chain).collect();
+
+        assert!(structure.chain_breaks().is_empty());
+    }
+
+    fn dimer_with_ligand() -> Structure {
+        let mut structure = Structure::new();
+        for id in ["A", "B"] {
+            let mut chain = Chain::new(id);
+            chain.add_residue(make_residue(1, "ALA"));
+            chain.add_residue(make_residue(2, "ALA"));
+            structure.add_chain(chain);
+        }
+        let mut ligand = Chain::new("C");
+        ligand.add_residue(Residue::new(1, None, "LIG", None, ResidueCategory::Hetero));
+        structure.add_chain(ligand);
+        structure.box_vectors = Some([[20.0, 0.0, 0.0], [0.0, 20.0, 0.0], [0.0, 0.0, 20.0]]);
+        structure
+    }
+
+    #[test]
+    fn split_by_chain_yields_one_structure_per_chain_with_box() {
+        let structure = dimer_with_ligand();
+
+        let parts = structure.split_by_chain();
+
+        assert_eq!(parts.len(), 3);
+        for (part, chain) in parts.iter().zip(structure.iter_chains()) {
+            assert_eq!(part.chain_count(), 1);
+            assert_eq!(part.iter_chains().next(), Some(chain));
+            assert_eq!(part.box_vectors, structure.box_vectors);
+        }
+    }
+
+    #[test]
+    fn entity_ids_separate_polymer_types_with_identical_one_letter_sequences() {
+        let mut structure = Structure::new();
+        let chains = [
+            (
+                "P",
+                [
+                    StandardResidue::ALA,
+                    StandardResidue::CYS,
+                    StandardResidue::GLY,
+                ],
+            ),
+            (
+                "R",
+                [StandardResidue::A, StandardResidue::C, StandardResidue::G],
+            ),
+            (
+                "D",
+                [
+                    StandardResidue::DA,
+                    StandardResidue::DC,
+                    StandardResidue::DG,
+                ],
+            ),
+            (
+                "Q",
+                [
+                    StandardResidue::ALA,
+                    StandardResidue::CYS,
+                    StandardResidue::GLY,
+                ],
+            ),
+        ];
+        for (id, residues) in chains {
+            let mut chain = Chain::new(id);
+            for (i, standard) in residues.into_iter().enumerate() {
+                chain.add_residue(Residue::new(
+                    i as i32 + 1,
+                    None,
+                    standard.canonical_name(),
+                    Some(standard),
+                    ResidueCategory::Standard,
+                ));
+            }
+            structure.add_chain(chain);
+        }
+        assert!(
+            structure
+                .iter_chains()
+                .all(|c| c.sequence_one_letter() == "ACG")
+        );
+
+        assert_eq!(structure.entity_ids(), vec![1, 2, 3, 1]);
+    }
+
+    #[test]
+    fn split_by_entity_groups_identical_polymer_chains() {
+        let structure = dimer_with_ligand();
+        assert_eq!(structure.entity_ids(), vec![1, 1, 2]);
+
+        let parts = structure.split_by_entity();
+
+        assert_eq!(parts.len(), 2);
+        let ids = |part: &Structure| -> Vec<String> {
+            part.iter_chains().map(|c| c.id.to_string()).collect()
+        };
+        assert_eq!(ids(&parts[0]), ["A", "B"]);
+        assert_eq!(ids(&parts[1]), ["C"]);
+        assert!(parts.iter().all(|p| p.box_vectors == structure.box_vectors));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn structure_round_trips_through_json() {
+        let mut residue = Residue::new(
+            7,
+            Some('B'),
+            "SER",
+            Some(StandardResidue::SER),
+            ResidueCategory::Standard,
+        );
+        residue.position = ResiduePosition::NTerminal;
+        let mut atom = Atom::new("OG", Element::O, Point::new(0.1, -2.345678901234, 1e-7));
+        atom.occupancy = 0.5;
+        atom.b_factor = 12.25;
+        atom.partial_charge = Some(-0.6546);
+        residue.add_atom(atom);
+        let mut chain = Chain::new("AB");
+        chain.add_residue(residue);
+        chain.add_residue(Residue::new(8, None, "LIG", None, ResidueCategory::Hetero));
+        let mut structure: Structure = std::iter::once(chain).collect();
+        structure.box_vectors = Some([[30.1, 0.0, 0.0], [0.0, 30.2, 0.0], [1.5, 0.0, 30.3]]);
+
+        let json = serde_json::to_string(&structure).unwrap();
+        let restored: Structure = serde_json::from_str(&json).unwrap();
+
+        assert!(json.contains("[0.1,-2.345678901234,1e-7]"));
+        assert_eq!(restored.box_vectors, structure.box_vectors);
+        assert!(restored.iter_chains().eq(structure.iter_chains()));
+    }
 }
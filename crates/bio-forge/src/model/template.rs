@@ -13,6 +13,7 @@ use std::fmt;
 /// information needed to validate coordinate files, seed missing atoms, or emit force-field
 /// compatible topologies.
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Template {
     /// External identifier such as a three-letter residue code.
     pub name: String,
@@ -4,15 +4,21 @@
 //! residue-level analysis, and provides helper utilities used by operations such as repair,
 //! hydrogen completion, and solvation to reason about neighboring atoms.
 
+use super::geometry::improper_dihedral_deg;
 use super::structure::Structure;
-use super::types::BondOrder;
+use super::types::{BondOrder, Point, StandardResidue};
+use std::collections::HashMap;
 use std::fmt;
 
+/// Largest improper dihedral (degrees) for which a three-coordinate atom counts as planar.
+const PLANAR_IMPROPER_TOLERANCE: f64 = 15.0;
+
 /// Undirected bond connecting two atoms within a structure.
 ///
 /// Bonds store canonical atom indices (ascending order) so equality, hashing, and sorting
 /// remain stable regardless of the order in which the connection was created.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Bond {
     /// Index of the first atom (always the lesser index after canonicalization).
     pub a1_idx: usize,
@@ -54,11 +60,67 @@ impl Bond {
     }
 }
 
+/// Category of a non-covalent interaction between two atoms.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum InteractionKind {
+    /// Hydrogen bond between a donor and an acceptor.
+    HydrogenBond,
+    /// Coordination of a metal ion by a ligand atom.
+    MetalCoordination,
+}
+
+/// Non-covalent interaction connecting two atoms within a structure.
+///
+/// Unlike [`Bond`], endpoints keep the order they were given in so producers can list the
+/// donor or the metal first.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Interaction {
+    /// Index of the first atom (donor or metal by convention).
+    pub a1_idx: usize,
+    /// Index of the second atom.
+    pub a2_idx: usize,
+    /// Category of the interaction.
+    pub kind: InteractionKind,
+}
+
+impl Interaction {
+    /// Creates an interaction between two atoms.
+    ///
+    /// # Arguments
+    ///
+    /// * `idx1` - Index of the first atom within the owning `Structure`.
+    /// * `idx2` - Index of the partner atom.
+    /// * `kind` - Category of the interaction.
+    ///
+    /// # Returns
+    ///
+    /// An `Interaction` preserving the endpoint order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bio_forge::{Interaction, InteractionKind};
+    ///
+    /// let hbond = Interaction::new(4, 1, InteractionKind::HydrogenBond);
+    /// assert_eq!((hbond.a1_idx, hbond.a2_idx), (4, 1));
+    /// ```
+    pub fn new(idx1: usize, idx2: usize, kind: InteractionKind) -> Self {
+        Self {
+            a1_idx: idx1,
+            a2_idx: idx2,
+            kind,
+        }
+    }
+}
+
 /// Bond graph overlay for a [`Structure`].
 ///
 /// A `Topology` pairs structural coordinates with explicit bonds, enabling neighbor queries,
 /// validation routines, and format writers that require connectivity information.
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Topology {
     structure: Structure,
     bonds: Vec<Bond>,
@@ -104,6 +166,16 @@ impl Topology {
         &self.bonds
     }
 
+    /// Replaces the order of the bond stored at `index`.
+    ///
+    /// # Arguments
+    ///
+    /// * `index` - Position of the bond within [`Topology::bonds`].
+    /// * `order` - New bond order.
+    pub(crate) fn set_bond_order(&mut self, index: usize, order: BondOrder) {
+        self.bonds[index].order = order;
+    }
+
     /// Counts the number of stored bonds.
     ///
     /// # Returns
@@ -155,6 +227,290 @@ impl Topology {
             }
         })
     }
+
+    /// Iterates over bonds touching a chain.
+    ///
+    /// Inter-chain bonds (such as disulfides bridging two chains) are yielded for both of the
+    /// chains they connect.
+    ///
+    /// # Arguments
+    ///
+    /// * `chain_id` - Identifier of the chain whose bonds should be returned.
+    ///
+    /// # Returns
+    ///
+    /// Iterator yielding bonds with at least one endpoint inside the chain.
+    pub fn bonds_in_chain<'a>(&'a self, chain_id: &'a str) -> impl Iterator<Item = &'a Bond> {
+        let owners = self.atom_owners();
+        self.bonds
+            .iter()
+            .filter(move |b| owners[b.a1_idx].0 == chain_id || owners[b.a2_idx].0 == chain_id)
+    }
+
+    /// Iterates over bonds touching a residue.
+    ///
+    /// Bonds linking the residue to its neighbors (peptide bonds, disulfides) are included
+    /// alongside its internal bonds.
+    ///
+    /// # Arguments
+    ///
+    /// * `chain_id` - Identifier of the chain that owns the residue.
+    /// * `res_id` - Sequence number of the residue.
+    /// * `insertion_code` - Insertion code distinguishing residues that share `res_id`.
+    ///
+    /// # Returns
+    ///
+    /// Iterator yielding bonds with at least one endpoint inside the residue.
+    pub fn bonds_in_residue<'a>(
+        &'a self,
+        chain_id: &'a str,
+        res_id: i32,
+        insertion_code: Option<char>,
+    ) -> impl Iterator<Item = &'a Bond> {
+        let owners = self.atom_owners();
+        let key = (chain_id, res_id, insertion_code);
+        self.bonds
+            .iter()
+            .filter(move |b| owners[b.a1_idx] == key || owners[b.a2_idx] == key)
+    }
+
+    /// Lists the disulfide bridges present in the topology.
+    ///
+    /// A bond counts as a disulfide when both endpoints are `SG` atoms of cysteine residues
+    /// (`CYS`, `CYX`, or `CYM`).
+    ///
+    /// # Returns
+    ///
+    /// `(sg1_idx, sg2_idx)` global atom index pairs with `sg1_idx < sg2_idx`, in bond order.
+    pub fn disulfide_bonds(&self) -> Vec<(usize, usize)> {
+        let is_cys_sg: Vec<bool> = self
+            .structure
+            .iter_atoms_with_context()
+            .map(|(_, residue, atom)| {
+                atom.name == "SG"
+                    && (residue.standard_name == Some(StandardResidue::CYS)
+                        || matches!(residue.name.as_str(), "CYS" | "CYX" | "CYM"))
+            })
+            .collect();
+
+        self.bonds
+            .iter()
+            .filter(|b| b.a1_idx != b.a2_idx && is_cys_sg[b.a1_idx] && is_cys_sg[b.a2_idx])
+            .map(|b| (b.a1_idx, b.a2_idx))
+            .collect()
+    }
+
+    /// Counts the disulfide bridges present in the topology.
+    ///
+    /// # Returns
+    ///
+    /// Number of entries returned by [`Topology::disulfide_bonds`].
+    pub fn disulfide_count(&self) -> usize {
+        self.disulfide_bonds().len()
+    }
+
+    /// Groups bonds by their bond order.
+    ///
+    /// # Returns
+    ///
+    /// A map from each [`BondOrder`] present in the topology to its bonds, in bond order.
+    pub fn bonds_by_order(&self) -> HashMap<BondOrder, Vec<&Bond>> {
+        let mut groups: HashMap<BondOrder, Vec<&Bond>> = HashMap::new();
+        for bond in &self.bonds {
+            groups.entry(bond.order).or_default().push(bond);
+        }
+        groups
+    }
+
+    /// Iterates over bonds of a single bond order.
+    ///
+    /// # Arguments
+    ///
+    /// * `order` - Bond order to keep, e.g. [`BondOrder::Aromatic`].
+    ///
+    /// # Returns
+    ///
+    /// Iterator yielding matching bonds in bond order.
+    pub fn bonds_with_order(&self, order: BondOrder) -> impl Iterator<Item = &Bond> {
+        self.bonds.iter().filter(move |b| b.order == order)
+    }
+
+    /// Lists the rotatable bonds of the topology.
+    ///
+    /// A bond is rotatable when it is a single bond between two heavy atoms, is not part of
+    /// a ring, and neither endpoint is terminal (each has at least one other heavy-atom
+    /// neighbor). Ring membership is exact: a bond lies in a ring whenever removing it
+    /// leaves its endpoints connected. Coordination bonds count neither as neighbors nor
+    /// toward rings, so a metal bound to a carboxylate leaves its C–O bond non-rotatable.
+    ///
+    /// # Returns
+    ///
+    /// References to the rotatable bonds in bond order.
+    pub fn rotatable_bonds(&self) -> Vec<&Bond> {
+        let heavy: Vec<bool> = self
+            .structure
+            .iter_atoms()
+            .map(|a| a.element.is_heavy_atom())
+            .collect();
+
+        let mut heavy_degree = vec![0usize; heavy.len()];
+        for bond in &self.bonds {
+            if bond.a1_idx != bond.a2_idx
+                && bond.order != BondOrder::Coordination
+                && heavy[bond.a1_idx]
+                && heavy[bond.a2_idx]
+            {
+                heavy_degree[bond.a1_idx] += 1;
+                heavy_degree[bond.a2_idx] += 1;
+            }
+        }
+
+        let in_ring = self.ring_bond_flags();
+        self.bonds
+            .iter()
+            .zip(in_ring)
+            .filter(|(bond, in_ring)| {
+                bond.order == BondOrder::Single
+                    && !in_ring
+                    && heavy[bond.a1_idx]
+                    && heavy[bond.a2_idx]
+                    && heavy_degree[bond.a1_idx] > 1
+                    && heavy_degree[bond.a2_idx] > 1
+            })
+            .map(|(bond, _)| bond)
+            .collect()
+    }
+
+    /// Lists improper dihedrals that keep sp² centers planar.
+    ///
+    /// An atom qualifies when it has exactly three bonded neighbors and is sp²: either one
+    /// of its bonds is [`BondOrder::Double`] or [`BondOrder::Aromatic`], or its neighbors
+    /// surround it in a plane, i.e. the improper dihedral stays within 15°. The geometric
+    /// test catches centers whose bonds are all single, such as peptide nitrogens in
+    /// template-built topologies. Coordination bonds and self-bonds are ignored.
+    ///
+    /// Each entry is `[center, n1, n2, n3]` with the neighbors in ascending atom index, so
+    /// the output is stable for a given topology; it matches the argument order of
+    /// [`improper_dihedral`](super::geometry::improper_dihedral), i.e. the CHARMM
+    /// convention with the central atom first.
+    ///
+    /// # Returns
+    ///
+    /// One improper per sp² center, ordered by central atom index.
+    pub fn improper_dihedrals(&self) -> Vec<[usize; 4]> {
+        let atom_count = self.atom_count();
+        let mut neighbors: Vec<Vec<usize>> = vec![Vec::new(); atom_count];
+        let mut unsaturated = vec![false; atom_count];
+        for bond in &self.bonds {
+            if bond.a1_idx == bond.a2_idx || bond.order == BondOrder::Coordination {
+                continue;
+            }
+            neighbors[bond.a1_idx].push(bond.a2_idx);
+            neighbors[bond.a2_idx].push(bond.a1_idx);
+            if matches!(bond.order, BondOrder::Double | BondOrder::Aromatic) {
+                unsaturated[bond.a1_idx] = true;
+                unsaturated[bond.a2_idx] = true;
+            }
+        }
+
+        let positions: Vec<Point> = self.structure.iter_atoms().map(|a| a.pos).collect();
+        neighbors
+            .iter_mut()
+            .enumerate()
+            .filter_map(|(center, bonded)| {
+                bonded.sort_unstable();
+                bonded.dedup();
+                let &mut [a, b, c] = bonded.as_mut_slice() else {
+                    return None;
+                };
+                let planar = || {
+                    let [p, pa, pb, pc] = [center, a, b, c].map(|i| positions[i]);
+                    let normal = (pb - pa).cross(&(pc - pa));
+                    normal.norm() > 1e-6
+                        && improper_dihedral_deg(&p, &pa, &pb, &pc).abs()
+                            <= PLANAR_IMPROPER_TOLERANCE
+                };
+                (unsaturated[center] || planar()).then_some([center, a, b, c])
+            })
+            .collect()
+    }
+
+    /// Flags, for every bond, whether it belongs to at least one ring.
+    ///
+    /// Uses an iterative bridge search: a bond lies on a cycle exactly when it is not a
+    /// bridge of the bond graph. Self-bonds and coordination bonds are never ring bonds and
+    /// do not close rings, so chelated metals leave their ligands' bonds acyclic.
+    ///
+    /// # Returns
+    ///
+    /// One flag per entry of [`Topology::bonds`].
+    pub(crate) fn ring_bond_flags(&self) -> Vec<bool> {
+        let atom_count = self.atom_count();
+        let is_ring_candidate =
+            |bond: &Bond| bond.a1_idx != bond.a2_idx && bond.order != BondOrder::Coordination;
+        let mut adjacency: Vec<Vec<(usize, usize)>> = vec![Vec::new(); atom_count];
+        for (bond_idx, bond) in self.bonds.iter().enumerate() {
+            if is_ring_candidate(bond) {
+                adjacency[bond.a1_idx].push((bond.a2_idx, bond_idx));
+                adjacency[bond.a2_idx].push((bond.a1_idx, bond_idx));
+            }
+        }
+
+        let mut in_ring: Vec<bool> = self.bonds.iter().map(is_ring_candidate).collect();
+        let mut discovery = vec![usize::MAX; atom_count];
+        let mut low = vec![0; atom_count];
+        let mut timer = 0;
+        let mut stack: Vec<(usize, Option<usize>, usize)> = Vec::new();
+
+        for root in 0..atom_count {
+            if discovery[root] != usize::MAX {
+                continue;
+            }
+            discovery[root] = timer;
+            low[root] = timer;
+            timer += 1;
+            stack.push((root, None, 0));
+
+            while let Some((atom, parent_bond, next)) = stack.last_mut() {
+                let atom = *atom;
+                if let Some(&(neighbor, bond_idx)) = adjacency[atom].get(*next) {
+                    *next += 1;
+                    if Some(bond_idx) == *parent_bond {
+                        continue;
+                    }
+                    if discovery[neighbor] == usize::MAX {
+                        discovery[neighbor] = timer;
+                        low[neighbor] = timer;
+                        timer += 1;
+                        stack.push((neighbor, Some(bond_idx), 0));
+                    } else {
+                        low[atom] = low[atom].min(discovery[neighbor]);
+                    }
+                    continue;
+                }
+
+                let parent_bond = *parent_bond;
+                stack.pop();
+                if let (Some(bond_idx), Some(&(parent, _, _))) = (parent_bond, stack.last()) {
+                    low[parent] = low[parent].min(low[atom]);
+                    if low[atom] > discovery[parent] {
+                        in_ring[bond_idx] = false;
+                    }
+                }
+            }
+        }
+
+        in_ring
+    }
+
+    /// Maps every global atom index to the chain and residue (number and insertion code) that
+    /// own it.
+    fn atom_owners(&self) -> Vec<(&str, i32, Option<char>)> {
+        self.structure
+            .iter_atoms_with_context()
+            .map(|(chain, residue, _)| (chain.id.as_str(), residue.id, residue.insertion_code))
+            .collect()
+    }
 }
 
 impl fmt::Display for Topology {
@@ -177,7 +533,7 @@ mod tests {
     use crate::model::atom::Atom;
     use crate::model::chain::Chain;
     use crate::model::residue::Residue;
-    use crate::model::types::{Element, Point, ResidueCategory, StandardResidue};
+    use crate::model::types::{Element, Point, ResidueCategory};
 
     #[test]
     fn bond_new_creates_bond_with_canonical_ordering() {
@@ -589,4 +945,313 @@ mod tests {
         assert_eq!(bonds_of_0.len(), 1);
         assert_eq!(bonds_of_0[0].order, BondOrder::Aromatic);
     }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn topology_round_trips_through_json() {
+        let mut chain = Chain::new("A");
+        let mut residue = Residue::new(
+            1,
+            None,
+            "ALA",
+            Some(StandardResidue::ALA),
+            ResidueCategory::Standard,
+        );
+        residue.add_atom(Atom::new("C", Element::C, Point::new(0.0, 0.0, 0.0)));
+        residue.add_atom(Atom::new("O", Element::O, Point::new(1.23, 0.0, 0.0)));
+        chain.add_residue(residue);
+        let structure: Structure = std::iter::once(chain).collect();
+        let topology = Topology::new(structure, vec![Bond::new(0, 1, BondOrder::Double)]);
+
+        let json = serde_json::to_string(&topology).unwrap();
+        let restored: Topology = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(restored.bonds(), topology.bonds());
+        assert!(
+            restored
+                .structure()
+                .iter_atoms()
+                .eq(topology.structure().iter_atoms())
+        );
+    }
+
+    fn two_chain_topology() -> Topology {
+        let mut structure = Structure::new();
+        for chain_id in ["A", "B"] {
+            let mut chain = Chain::new(chain_id);
+            for res_id in 1..=2 {
+                let mut residue = Residue::new(
+                    res_id,
+                    None,
+                    "CYS",
+                    Some(StandardResidue::CYS),
+                    ResidueCategory::Standard,
+                );
+                residue.add_atom(Atom::new("N", Element::N, Point::origin()));
+                residue.add_atom(Atom::new("C", Element::C, Point::origin()));
+                chain.add_residue(residue);
+            }
+            structure.add_chain(chain);
+        }
+
+        let bonds = vec![
+            Bond::new(0, 1, BondOrder::Single),
+            Bond::new(1, 2, BondOrder::Single),
+            Bond::new(2, 3, BondOrder::Single),
+            Bond::new(4, 5, BondOrder::Single),
+            Bond::new(5, 6, BondOrder::Single),
+            Bond::new(6, 7, BondOrder::Single),
+            Bond::new(3, 7, BondOrder::Single),
+        ];
+        Topology::new(structure, bonds)
+    }
+
+    #[test]
+    fn topology_disulfide_bonds_lists_only_cysteine_sg_pairs() {
+        let mut chain = Chain::new("A");
+        for (res_id, name) in [(1, "CYX"), (2, "CYX"), (3, "MET")] {
+            let standard = if name == "MET" {
+                StandardResidue::MET
+            } else {
+                StandardResidue::CYS
+            };
+            let mut residue = Residue::new(
+                res_id,
+                None,
+                name,
+                Some(standard),
+                ResidueCategory::Standard,
+            );
+            residue.add_atom(Atom::new("CB", Element::C, Point::origin()));
+            let sulfur = if name == "MET" { "SD" } else { "SG" };
+            residue.add_atom(Atom::new(sulfur, Element::S, Point::origin()));
+            chain.add_residue(residue);
+        }
+        let mut structure = Structure::new();
+        structure.add_chain(chain);
+
+        let bonds = vec![
+            Bond::new(0, 1, BondOrder::Single),
+            Bond::new(2, 3, BondOrder::Single),
+            Bond::new(3, 1, BondOrder::Single),
+            Bond::new(3, 5, BondOrder::Single),
+        ];
+        let topology = Topology::new(structure, bonds);
+
+        assert_eq!(topology.disulfide_bonds(), vec![(1, 3)]);
+        assert_eq!(topology.disulfide_count(), 1);
+    }
+
+    fn ring_with_side_chain() -> Topology {
+        let mut residue = Residue::new(1, None, "LIG", None, ResidueCategory::Hetero);
+        for (name, element) in [
+            ("C1", Element::C),
+            ("C2", Element::C),
+            ("C3", Element::C),
+            ("C4", Element::C),
+            ("O5", Element::O),
+            ("H5", Element::H),
+            ("O6", Element::O),
+        ] {
+            residue.add_atom(Atom::new(name, element, Point::origin()));
+        }
+        let mut chain = Chain::new("A");
+        chain.add_residue(residue);
+        let mut structure = Structure::new();
+        structure.add_chain(chain);
+
+        Topology::new(
+            structure,
+            vec![
+                Bond::new(0, 1, BondOrder::Single),
+                Bond::new(1, 2, BondOrder::Single),
+                Bond::new(2, 0, BondOrder::Single),
+                Bond::new(2, 3, BondOrder::Single),
+                Bond::new(3, 4, BondOrder::Single),
+                Bond::new(4, 5, BondOrder::Single),
+                Bond::new(3, 6, BondOrder::Double),
+            ],
+        )
+    }
+
+    #[test]
+    fn topology_bonds_by_order_partitions_every_bond() {
+        let topology = ring_with_side_chain();
+
+        let groups = topology.bonds_by_order();
+
+        assert_eq!(groups[&BondOrder::Single].len(), 6);
+        assert_eq!(
+            groups[&BondOrder::Double],
+            vec![&Bond::new(3, 6, BondOrder::Double)]
+        );
+        assert!(!groups.contains_key(&BondOrder::Aromatic));
+        assert_eq!(topology.bonds_with_order(BondOrder::Double).count(), 1);
+        assert_eq!(topology.bonds_with_order(BondOrder::Triple).count(), 0);
+    }
+
+    #[test]
+    fn topology_ring_bond_flags_mark_cycle_members_only() {
+        let topology = ring_with_side_chain();
+
+        assert_eq!(
+            topology.ring_bond_flags(),
+            vec![true, true, true, false, false, false, false]
+        );
+    }
+
+    #[test]
+    fn topology_rotatable_bonds_skip_rings_terminals_and_multiple_bonds() {
+        let topology = ring_with_side_chain();
+
+        let rotatable = topology.rotatable_bonds();
+
+        assert_eq!(rotatable, vec![&Bond::new(2, 3, BondOrder::Single)]);
+    }
+
+    /// Acetate chelating a zinc ion through both carboxylate oxygens.
+    fn zinc_acetate() -> Topology {
+        let mut acetate = Residue::new(1, None, "ACT", None, ResidueCategory::Hetero);
+        for (name, element) in [
+            ("C1", Element::C),
+            ("C2", Element::C),
+            ("O1", Element::O),
+            ("O2", Element::O),
+        ] {
+            acetate.add_atom(Atom::new(name, element, Point::origin()));
+        }
+        let mut zinc = Residue::new(2, None, "ZN", None, ResidueCategory::Ion);
+        zinc.add_atom(Atom::new("ZN", Element::Zn, Point::origin()));
+        let mut chain = Chain::new("A");
+        chain.add_residue(acetate);
+        chain.add_residue(zinc);
+        let structure: Structure = std::iter::once(chain).collect();
+
+        Topology::new(
+            structure,
+            vec![
+                Bond::new(0, 1, BondOrder::Single),
+                Bond::new(1, 2, BondOrder::Single),
+                Bond::new(1, 3, BondOrder::Double),
+                Bond::new(2, 4, BondOrder::Coordination),
+                Bond::new(3, 4, BondOrder::Coordination),
+            ],
+        )
+    }
+
+    #[test]
+    fn topology_ring_and_rotatable_bonds_ignore_metal_coordination() {
+        let topology = zinc_acetate();
+
+        assert_eq!(topology.ring_bond_flags(), vec![false; 5]);
+        assert!(topology.rotatable_bonds().is_empty());
+    }
+
+    #[test]
+    fn topology_improper_dihedrals_use_bond_orders_for_unsaturated_centers() {
+        let topology = ring_with_side_chain();
+
+        assert_eq!(topology.improper_dihedrals(), vec![[3, 2, 4, 6]]);
+    }
+
+    #[test]
+    fn topology_improper_dihedrals_detect_planar_centers_from_geometry() {
+        let mut residue = Residue::new(1, None, "LIG", None, ResidueCategory::Hetero);
+        for (name, element, pos) in [
+            ("N1", Element::N, Point::new(0.0, 0.0, 0.0)),
+            ("C2", Element::C, Point::new(1.33, 0.0, 0.0)),
+            ("C3", Element::C, Point::new(-0.73, 1.25, 0.05)),
+            ("H4", Element::H, Point::new(-0.5, -0.87, 0.0)),
+            ("N5", Element::N, Point::new(10.0, 0.0, 0.0)),
+            ("H6", Element::H, Point::new(10.94, 0.0, -0.33)),
+            ("H7", Element::H, Point::new(9.53, 0.82, -0.33)),
+            ("H8", Element::H, Point::new(9.53, -0.82, -0.33)),
+            ("ZN", Element::Zn, Point::new(0.0, 0.0, 2.1)),
+        ] {
+            residue.add_atom(Atom::new(name, element, pos));
+        }
+        let structure: Structure = std::iter::once({
+            let mut chain = Chain::new("A");
+            chain.add_residue(residue);
+            chain
+        })
+        .collect();
+        let bonds = vec![
+            Bond::new(0, 1, BondOrder::Single),
+            Bond::new(0, 2, BondOrder::Single),
+            Bond::new(0, 3, BondOrder::Single),
+            Bond::new(4, 5, BondOrder::Single),
+            Bond::new(4, 6, BondOrder::Single),
+            Bond::new(4, 7, BondOrder::Single),
+            Bond::new(0, 8, BondOrder::Coordination),
+        ];
+        let topology = Topology::new(structure, bonds);
+
+        let impropers = topology.improper_dihedrals();
+
+        assert_eq!(impropers, vec![[0, 1, 2, 3]]);
+    }
+
+    #[test]
+    fn topology_bonds_in_chain_includes_inter_chain_bonds() {
+        let topology = two_chain_topology();
+
+        let chain_a: Vec<_> = topology.bonds_in_chain("A").collect();
+        let chain_b: Vec<_> = topology.bonds_in_chain("B").collect();
+
+        assert_eq!(chain_a.len(), 4);
+        assert_eq!(chain_b.len(), 4);
+        assert!(chain_a.contains(&&Bond::new(3, 7, BondOrder::Single)));
+        assert!(chain_b.contains(&&Bond::new(3, 7, BondOrder::Single)));
+        assert_eq!(topology.bonds_in_chain("C").count(), 0);
+    }
+
+    #[test]
+    fn topology_bonds_in_residue_includes_bonds_to_neighbors() {
+        let topology = two_chain_topology();
+
+        let residue: Vec<_> = topology.bonds_in_residue("A", 2, None).collect();
+
+        assert_eq!(
+            residue,
+            vec![
+                &Bond::new(1, 2, BondOrder::Single),
+                &Bond::new(2, 3, BondOrder::Single),
+                &Bond::new(3, 7, BondOrder::Single),
+            ]
+        );
+        assert_eq!(topology.bonds_in_residue("B", 3, None).count(), 0);
+    }
+
+    #[test]
+    fn topology_bonds_in_residue_distinguishes_insertion_codes() {
+        let mut chain = Chain::new("A");
+        for icode in [None, Some('A')] {
+            let mut residue = Residue::new(
+                52,
+                icode,
+                "GLY",
+                Some(StandardResidue::GLY),
+                ResidueCategory::Standard,
+            );
+            residue.add_atom(Atom::new("N", Element::N, Point::origin()));
+            residue.add_atom(Atom::new("CA", Element::C, Point::origin()));
+            chain.add_residue(residue);
+        }
+        let structure: Structure = std::iter::once(chain).collect();
+        let topology = Topology::new(
+            structure,
+            vec![
+                Bond::new(0, 1, BondOrder::Single),
+                Bond::new(2, 3, BondOrder::Single),
+            ],
+        );
+
+        let plain: Vec<_> = topology.bonds_in_residue("A", 52, None).collect();
+        let inserted: Vec<_> = topology.bonds_in_residue("A", 52, Some('A')).collect();
+
+        assert_eq!(plain, vec![&Bond::new(0, 1, BondOrder::Single)]);
+        assert_eq!(inserted, vec![&Bond::new(2, 3, BondOrder::Single)]);
+        assert_eq!(topology.bonds_in_residue("A", 52, Some('B')).count(), 0);
+    }
 }
@@ -14,12 +14,31 @@ use std::str::FromStr;
 /// IO readers, topology builders, and structure editing utilities.
 pub type Point = Point3<f64>;
 
+/// Serde adapter encoding a [`Point`] as a plain `[x, y, z]` array.
+///
+/// Used through `#[serde(with = "...")]` so serialized models do not depend on the
+/// nalgebra representation of points.
+#[cfg(feature = "serde")]
+pub(crate) mod point_serde {
+    use super::Point;
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    pub fn serialize<S: Serializer>(point: &Point, serializer: S) -> Result<S::Ok, S::Error> {
+        [point.x, point.y, point.z].serialize(serializer)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Point, D::Error> {
+        <[f64; 3]>::deserialize(deserializer).map(Point::from)
+    }
+}
+
 /// Periodic table entries supported by `bio-forge`.
 ///
 /// Each variant encodes its atomic number in the `repr(u8)` discriminant and provides
 /// conveniences for retrieving standard symbols, heavy-atom classification, and atomic
 /// masses used when exporting coordinates or computing physical properties.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, serde::Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, serde::Deserialize)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 #[repr(u8)]
 pub enum Element {
     /// Hydrogen (Z = 1).
@@ -269,6 +288,7 @@ pub enum Element {
 /// a residue belongs to protein or nucleic acid polymers so that operations can branch on
 /// biopolymer type.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, serde::Deserialize)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub enum StandardResidue {
     /// Alanine residue (ALA).
     ALA,
@@ -339,6 +359,7 @@ pub enum StandardResidue {
 /// Distinguishes classical single, double, triple, and aromatic delocalized interactions so
 /// that exported formats and force-field derivations can preserve chemical intent.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, serde::Deserialize)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub enum BondOrder {
     /// Classical single bond.
     Single,
@@ -348,10 +369,16 @@ pub enum BondOrder {
     Triple,
     /// Aromatic bond representing delocalized pi systems.
     Aromatic,
+    /// Dative metal–ligand contact, e.g. a zinc bound by a histidine nitrogen.
+    ///
+    /// Coordination bonds have order zero: they keep the metal attached in the bond graph
+    /// but contribute nothing to valence.
+    Coordination,
 }
 
 /// High-level classification for residues appearing in a structure.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum ResidueCategory {
     /// Standard polymer residue (protein or nucleic acid).
     Standard,
@@ -363,6 +390,7 @@ pub enum ResidueCategory {
 
 /// Topological context of a residue within a polymer chain.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum ResiduePosition {
     /// No specific positional annotation is available.
     None,
@@ -436,6 +464,190 @@ impl StandardResidue {
                 | StandardResidue::DI
         )
     }
+
+    /// Returns the canonical residue code written to structure files.
+    ///
+    /// # Returns
+    ///
+    /// The three-letter amino acid code, the one- or two-letter nucleotide code, or `HOH`.
+    pub fn canonical_name(self) -> &'static str {
+        match self {
+            StandardResidue::ALA => "ALA",
+            StandardResidue::ARG => "ARG",
+            StandardResidue::ASN => "ASN",
+            StandardResidue::ASP => "ASP",
+            StandardResidue::CYS => "CYS",
+            StandardResidue::GLN => "GLN",
+            StandardResidue::GLU => "GLU",
+            StandardResidue::GLY => "GLY",
+            StandardResidue::HIS => "HIS",
+            StandardResidue::ILE => "ILE",
+            StandardResidue::LEU => "LEU",
+            StandardResidue::LYS => "LYS",
+            StandardResidue::MET => "MET",
+            StandardResidue::PHE => "PHE",
+            StandardResidue::PRO => "PRO",
+            StandardResidue::SER => "SER",
+            StandardResidue::THR => "THR",
+            StandardResidue::TRP => "TRP",
+            StandardResidue::TYR => "TYR",
+            StandardResidue::VAL => "VAL",
+            StandardResidue::A => "A",
+            StandardResidue::C => "C",
+            StandardResidue::G => "G",
+            StandardResidue::U => "U",
+            StandardResidue::I => "I",
+            StandardResidue::DA => "DA",
+            StandardResidue::DC => "DC",
+            StandardResidue::DG => "DG",
+            StandardResidue::DT => "DT",
+            StandardResidue::DI => "DI",
+            StandardResidue::HOH => "HOH",
+        }
+    }
+
+    /// Iterates over every standard residue variant.
+    ///
+    /// Amino acids come first, followed by RNA and DNA nucleotides and water.
+    ///
+    /// # Returns
+    ///
+    /// An iterator yielding each variant exactly once.
+    pub fn all() -> impl Iterator<Item = StandardResidue> {
+        Self::ALL.into_iter()
+    }
+
+    /// Resolves a residue name, including common force-field aliases, to its standard residue.
+    ///
+    /// Matching ignores surrounding whitespace and letter case. Besides canonical codes the
+    /// lookup accepts protonation variants (`HID`/`HIE`/`HIP`, `HSD`/`HSE`/`HSP`, `CYX`/`CYM`,
+    /// `ASH`, `GLH`, `LYN`, `ARN`, `TYM`), AMBER terminal forms (`NALA`, `CGLY`, `DA5`,
+    /// `RU3`, ...), `R`-prefixed ribonucleotides, and common water names (`WAT`, `TIP3`,
+    /// `SOL`, `H2O`).
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - Residue label as found in a structure file or user input.
+    ///
+    /// # Returns
+    ///
+    /// `Some(StandardResidue)` when the name maps to a standard residue, otherwise `None`.
+    pub fn from_name(name: &str) -> Option<StandardResidue> {
+        let upper = name.trim().to_ascii_uppercase();
+        Self::from_core_name(&upper).or_else(|| {
+            let protein_core = upper
+                .strip_prefix('N')
+                .or_else(|| upper.strip_prefix('C'))
+                .filter(|core| core.len() == 3)
+                .and_then(Self::from_core_name)
+                .filter(|std| std.is_protein());
+
+            let nucleic_core = upper
+                .strip_suffix('5')
+                .or_else(|| upper.strip_suffix('3'))
+                .and_then(Self::from_core_name)
+                .filter(|std| std.is_nucleic());
+
+            protein_core.or(nucleic_core)
+        })
+    }
+
+    /// Resolves an upper-case name without terminal decorations.
+    fn from_core_name(name: &str) -> Option<StandardResidue> {
+        let std = match name {
+            "HID" | "HIE" | "HIP" | "HSD" | "HSE" | "HSP" | "HIS" => StandardResidue::HIS,
+            "CYX" | "CYM" => StandardResidue::CYS,
+            "ASH" => StandardResidue::ASP,
+            "GLH" => StandardResidue::GLU,
+            "LYN" => StandardResidue::LYS,
+            "ARN" => StandardResidue::ARG,
+            "TYM" => StandardResidue::TYR,
+            "RA" => StandardResidue::A,
+            "RC" => StandardResidue::C,
+            "RG" => StandardResidue::G,
+            "RU" => StandardResidue::U,
+            "RI" => StandardResidue::I,
+            "WAT" | "TIP3" | "TIP" | "SOL" | "H2O" => StandardResidue::HOH,
+            other => return other.parse().ok(),
+        };
+        Some(std)
+    }
+
+    /// Every variant in declaration order.
+    const ALL: [StandardResidue; 31] = [
+        StandardResidue::ALA,
+        StandardResidue::ARG,
+        StandardResidue::ASN,
+        StandardResidue::ASP,
+        StandardResidue::CYS,
+        StandardResidue::GLN,
+        StandardResidue::GLU,
+        StandardResidue::GLY,
+        StandardResidue::HIS,
+        StandardResidue::ILE,
+        StandardResidue::LEU,
+        StandardResidue::LYS,
+        StandardResidue::MET,
+        StandardResidue::PHE,
+        StandardResidue::PRO,
+        StandardResidue::SER,
+        StandardResidue::THR,
+        StandardResidue::TRP,
+        StandardResidue::TYR,
+        StandardResidue::VAL,
+        StandardResidue::A,
+        StandardResidue::C,
+        StandardResidue::G,
+        StandardResidue::U,
+        StandardResidue::I,
+        StandardResidue::DA,
+        StandardResidue::DC,
+        StandardResidue::DG,
+        StandardResidue::DT,
+        StandardResidue::DI,
+        StandardResidue::HOH,
+    ];
+
+    /// Returns the IUPAC one-letter code for polymer residues.
+    ///
+    /// Deoxy nucleotides share the letter of their ribose counterpart (`DA` → `A`), except
+    /// thymidine which maps to `T`.
+    ///
+    /// # Returns
+    ///
+    /// `Some(code)` for amino acids and nucleotides, or `None` for water.
+    pub fn one_letter_code(self) -> Option<char> {
+        let code = match self {
+            StandardResidue::ALA => 'A',
+            StandardResidue::ARG => 'R',
+            StandardResidue::ASN => 'N',
+            StandardResidue::ASP => 'D',
+            StandardResidue::CYS => 'C',
+            StandardResidue::GLN => 'Q',
+            StandardResidue::GLU => 'E',
+            StandardResidue::GLY => 'G',
+            StandardResidue::HIS => 'H',
+            StandardResidue::ILE => 'I',
+            StandardResidue::LEU => 'L',
+            StandardResidue::LYS => 'K',
+            StandardResidue::MET => 'M',
+            StandardResidue::PHE => 'F',
+            StandardResidue::PRO => 'P',
+            StandardResidue::SER => 'S',
+            StandardResidue::THR => 'T',
+            StandardResidue::TRP => 'W',
+            StandardResidue::TYR => 'Y',
+            StandardResidue::VAL => 'V',
+            StandardResidue::A | StandardResidue::DA => 'A',
+            StandardResidue::C | StandardResidue::DC => 'C',
+            StandardResidue::G | StandardResidue::DG => 'G',
+            StandardResidue::U => 'U',
+            StandardResidue::DT => 'T',
+            StandardResidue::I | StandardResidue::DI => 'I',
+            StandardResidue::HOH => return None,
+        };
+        Some(code)
+    }
 }
 
 impl BondOrder {
@@ -446,13 +658,15 @@ impl BondOrder {
     ///
     /// # Returns
     ///
-    /// The multiplicity expressed as an `f64` (1.0, 2.0, 3.0, or 1.5 for aromatic).
+    /// The multiplicity expressed as an `f64` (1.0, 2.0, 3.0, 1.5 for aromatic, or 0.0 for
+    /// coordination).
     pub fn value(&self) -> f64 {
         match self {
             BondOrder::Single => 1.0,
             BondOrder::Double => 2.0,
             BondOrder::Triple => 3.0,
             BondOrder::Aromatic => 1.5,
+            BondOrder::Coordination => 0.0,
         }
     }
 }
@@ -472,6 +686,7 @@ impl FromStr for BondOrder {
             "2" | "2.0" | "Double" => Ok(BondOrder::Double),
             "3" | "3.0" | "Triple" => Ok(BondOrder::Triple),
             "1.5" | "Aromatic" => Ok(BondOrder::Aromatic),
+            "0" | "0.0" | "Coordination" => Ok(BondOrder::Coordination),
             _ => Err(format!("Invalid bond order: {}", s)),
         }
     }
@@ -492,6 +707,29 @@ impl ResidueCategory {
             ResidueCategory::Ion => "Ion",
         }
     }
+
+    /// Reports whether a residue or element label denotes a monatomic ion.
+    ///
+    /// Covers common alkali, alkaline-earth, transition-metal, and halide ions as written in
+    /// PDB chemical component names (`NA`, `CL`, `ZN`, `IOD`, ...). Matching ignores case
+    /// and surrounding whitespace.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - Residue name or element symbol to test.
+    ///
+    /// # Returns
+    ///
+    /// `true` if the label is a recognized ion.
+    pub fn is_ion_name(name: &str) -> bool {
+        const ION_NAMES: &[&str] = &[
+            "LI", "NA", "K", "RB", "CS", "MG", "CA", "SR", "BA", "ZN", "FE", "FE2", "CU", "CU1",
+            "MN", "CO", "NI", "CD", "HG", "AG", "AU", "PT", "PB", "AL", "F", "CL", "BR", "I",
+            "IOD",
+        ];
+        let name = name.trim();
+        ION_NAMES.iter().any(|ion| ion.eq_ignore_ascii_case(name))
+    }
 }
 
 impl fmt::Display for ResidueCategory {
@@ -832,6 +1070,80 @@ impl Element {
             Element::Unknown => 0.0,
         }
     }
+
+    /// Infers an element from a PDB-style atom name.
+    ///
+    /// Leading digits and whitespace are ignored. Names inside standard polymer residues
+    /// resolve ambiguous prefixes to the organic elements (`CA` → carbon, `HG2` → hydrogen),
+    /// whereas hetero names honor column alignment and may map to two-letter symbols such as
+    /// `FE`, `ZN`, or `CA` (calcium) when the name starts in the first column.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - Atom name, either trimmed or as the raw four-character PDB field.
+    /// * `hint_hetero` - Whether the atom belongs to a hetero residue (ligand, ion, etc.).
+    ///
+    /// # Returns
+    ///
+    /// The inferred [`Element`], or [`Element::Unknown`] when no symbol can be recognized.
+    pub fn from_atom_name(name: &str, hint_hetero: bool) -> Element {
+        fn parse_symbol(symbol: &str) -> Option<Element> {
+            Element::from_str(symbol)
+                .ok()
+                .filter(|el| *el != Element::Unknown)
+        }
+
+        fn parse_pair(first: char, second: char) -> Option<Element> {
+            parse_symbol(&format!("{first}{second}"))
+        }
+
+        let letters: Vec<(usize, char)> = name
+            .char_indices()
+            .filter(|(_, ch)| ch.is_ascii_alphabetic())
+            .collect();
+
+        let Some(&(first_idx, first_char)) = letters.first() else {
+            return Element::Unknown;
+        };
+
+        if !hint_hetero
+            && let Some(el) = parse_symbol(&first_char.to_string()).filter(|el| {
+                matches!(
+                    el,
+                    Element::C | Element::N | Element::O | Element::S | Element::H | Element::P
+                )
+            })
+        {
+            return el;
+        }
+
+        if let Some(&(second_idx, second_char)) = letters.get(1)
+            && first_idx == 0
+            && second_idx == first_idx + 1
+            && let Some(el) = parse_pair(first_char, second_char)
+        {
+            return el;
+        }
+
+        if let Some(el) = parse_symbol(&first_char.to_string()) {
+            return el;
+        }
+
+        for window in letters.windows(2) {
+            let (first_idx, first_char) = window[0];
+            let (second_idx, second_char) = window[1];
+            if second_idx == first_idx + 1
+                && let Some(el) = parse_pair(first_char, second_char)
+            {
+                return el;
+            }
+            if let Some(el) = parse_symbol(&second_char.to_string()) {
+                return el;
+            }
+        }
+
+        Element::Unknown
+    }
 }
 
 impl fmt::Display for Element {
@@ -1097,40 +1409,7 @@ impl FromStr for Element {
 
 impl fmt::Display for StandardResidue {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        let code = match self {
-            StandardResidue::ALA => "ALA",
-            StandardResidue::ARG => "ARG",
-            StandardResidue::ASN => "ASN",
-            StandardResidue::ASP => "ASP",
-            StandardResidue::CYS => "CYS",
-            StandardResidue::GLN => "GLN",
-            StandardResidue::GLU => "GLU",
-            StandardResidue::GLY => "GLY",
-            StandardResidue::HIS => "HIS",
-            StandardResidue::ILE => "ILE",
-            StandardResidue::LEU => "LEU",
-            StandardResidue::LYS => "LYS",
-            StandardResidue::MET => "MET",
-            StandardResidue::PHE => "PHE",
-            StandardResidue::PRO => "PRO",
-            StandardResidue::SER => "SER",
-            StandardResidue::THR => "THR",
-            StandardResidue::TRP => "TRP",
-            StandardResidue::TYR => "TYR",
-            StandardResidue::VAL => "VAL",
-            StandardResidue::A => "A",
-            StandardResidue::C => "C",
-            StandardResidue::G => "G",
-            StandardResidue::U => "U",
-            StandardResidue::I => "I",
-            StandardResidue::DA => "DA",
-            StandardResidue::DC => "DC",
-            StandardResidue::DG => "DG",
-            StandardResidue::DT => "DT",
-            StandardResidue::DI => "DI",
-            StandardResidue::HOH => "HOH",
-        };
-        write!(f, "{}", code)
+        write!(f, "{}", self.canonical_name())
     }
 }
 
@@ -1179,6 +1458,67 @@ impl FromStr for StandardResidue {
 mod tests {
     use super::*;
 
+    #[test]
+    fn standard_residue_from_name_resolves_aliases_and_terminal_forms() {
+        assert_eq!(
+            StandardResidue::from_name("HIS"),
+            Some(StandardResidue::HIS)
+        );
+        assert_eq!(
+            StandardResidue::from_name("hie"),
+            Some(StandardResidue::HIS)
+        );
+        assert_eq!(
+            StandardResidue::from_name(" HSP "),
+            Some(StandardResidue::HIS)
+        );
+        assert_eq!(
+            StandardResidue::from_name("CYX"),
+            Some(StandardResidue::CYS)
+        );
+        assert_eq!(
+            StandardResidue::from_name("CYM"),
+            Some(StandardResidue::CYS)
+        );
+        assert_eq!(
+            StandardResidue::from_name("NALA"),
+            Some(StandardResidue::ALA)
+        );
+        assert_eq!(
+            StandardResidue::from_name("CHID"),
+            Some(StandardResidue::HIS)
+        );
+        assert_eq!(StandardResidue::from_name("DA5"), Some(StandardResidue::DA));
+        assert_eq!(StandardResidue::from_name("RU3"), Some(StandardResidue::U));
+        assert_eq!(
+            StandardResidue::from_name("WAT"),
+            Some(StandardResidue::HOH)
+        );
+    }
+
+    #[test]
+    fn standard_residue_from_name_rejects_ions_and_ligands() {
+        for name in ["NA", "CL", "CA", "ZN", "LIG", "NME", "ACE", ""] {
+            assert_eq!(StandardResidue::from_name(name), None, "{name}");
+        }
+    }
+
+    #[test]
+    fn standard_residue_all_round_trips_through_canonical_name() {
+        let all: Vec<StandardResidue> = StandardResidue::all().collect();
+
+        assert_eq!(all.len(), 31);
+        assert_eq!(all.iter().filter(|r| r.is_protein()).count(), 20);
+        assert_eq!(all.iter().filter(|r| r.is_nucleic()).count(), 10);
+        for residue in all {
+            assert_eq!(residue.canonical_name(), residue.to_string());
+            assert_eq!(
+                StandardResidue::from_name(residue.canonical_name()),
+                Some(residue)
+            );
+        }
+    }
+
     #[test]
     fn element_symbol_returns_correct_value() {
         assert_eq!(Element::H.symbol(), "H");
@@ -1240,12 +1580,44 @@ mod tests {
         assert_eq!(Element::from_str("-1").unwrap(), Element::Unknown);
     }
 
+    #[test]
+    fn element_from_atom_name_prefers_organic_elements_in_standard_residues() {
+        assert_eq!(Element::from_atom_name("CA", false), Element::C);
+        assert_eq!(Element::from_atom_name(" CA ", false), Element::C);
+        assert_eq!(Element::from_atom_name("HB2", false), Element::H);
+        assert_eq!(Element::from_atom_name("HG  ", false), Element::H);
+        assert_eq!(Element::from_atom_name("1HG1", false), Element::H);
+        assert_eq!(Element::from_atom_name("NE2", false), Element::N);
+        assert_eq!(Element::from_atom_name("OXT", false), Element::O);
+        assert_eq!(Element::from_atom_name("SG", false), Element::S);
+        assert_eq!(Element::from_atom_name("P", false), Element::P);
+    }
+
+    #[test]
+    fn element_from_atom_name_allows_two_letter_symbols_in_hetero_residues() {
+        assert_eq!(Element::from_atom_name("CA", true), Element::Ca);
+        assert_eq!(Element::from_atom_name("FE", true), Element::Fe);
+        assert_eq!(Element::from_atom_name("ZN  ", true), Element::Zn);
+        assert_eq!(Element::from_atom_name("CL1", true), Element::Cl);
+        assert_eq!(Element::from_atom_name(" CA ", true), Element::C);
+        assert_eq!(Element::from_atom_name("C12", true), Element::C);
+        assert_eq!(Element::from_atom_name("2H5", true), Element::H);
+    }
+
+    #[test]
+    fn element_from_atom_name_handles_unrecognized_names() {
+        assert_eq!(Element::from_atom_name("", false), Element::Unknown);
+        assert_eq!(Element::from_atom_name("  12", true), Element::Unknown);
+        assert_eq!(Element::from_atom_name("FE", false), Element::Fe);
+    }
+
     #[test]
     fn bond_order_value_returns_correct_f64() {
         assert_eq!(BondOrder::Single.value(), 1.0);
         assert_eq!(BondOrder::Double.value(), 2.0);
         assert_eq!(BondOrder::Triple.value(), 3.0);
         assert_eq!(BondOrder::Aromatic.value(), 1.5);
+        assert_eq!(BondOrder::Coordination.value(), 0.0);
     }
 
     #[test]
@@ -1272,6 +1644,11 @@ mod tests {
             BondOrder::from_str("Aromatic").unwrap(),
             BondOrder::Aromatic
         );
+        assert_eq!(BondOrder::from_str("0").unwrap(), BondOrder::Coordination);
+        assert_eq!(
+            BondOrder::from_str("Coordination").unwrap(),
+            BondOrder::Coordination
+        );
     }
 
     #[test]
@@ -1546,4 +1923,22 @@ mod tests {
         assert!(!StandardResidue::HOH.is_nucleic());
         assert!(!StandardResidue::GLY.is_nucleic());
     }
+
+    #[test]
+    fn residue_category_is_ion_name_recognizes_common_ions() {
+        assert!(ResidueCategory::is_ion_name("NA"));
+        assert!(ResidueCategory::is_ion_name("Zn"));
+        assert!(ResidueCategory::is_ion_name(" IOD"));
+        assert!(!ResidueCategory::is_ion_name("HOH"));
+        assert!(!ResidueCategory::is_ion_name("LIG"));
+    }
+
+    #[test]
+    fn standard_residue_one_letter_code_maps_polymer_residues() {
+        assert_eq!(StandardResidue::TRP.one_letter_code(), Some('W'));
+        assert_eq!(StandardResidue::LYS.one_letter_code(), Some('K'));
+        assert_eq!(StandardResidue::DT.one_letter_code(), Some('T'));
+        assert_eq!(StandardResidue::U.one_letter_code(), Some('U'));
+        assert_eq!(StandardResidue::HOH.one_letter_code(), None);
+    }
 }
@@ -0,0 +1,332 @@
+//! Translation of legacy atom names to current PDB nomenclature.
+//!
+//! Files written against PDB format v2 (and many older tools) use atom names such as `1HB`,
+//! `O1P`, or `C1*` where the bundled templates expect `HB2`, `OP1`, or `C1'`. Template-driven
+//! operations look atoms up by name, so these residues would silently lose hydrogens and
+//! phosphate bonds. This module rewrites legacy names to the template spelling using a curated
+//! alias table plus the leading-digit hydrogen convention.
+
+use crate::db::{self, TemplateView};
+use crate::model::{residue::Residue, structure::Structure};
+use std::collections::{HashMap, HashSet};
+
+/// Direct renames between legacy and current atom names.
+const ATOM_ALIASES: [(&str, &str); 7] = [
+    ("O1P", "OP1"),
+    ("O2P", "OP2"),
+    ("O3P", "OP3"),
+    ("C5M", "C7"),
+    ("OT1", "O"),
+    ("OT2", "OXT"),
+    ("HN", "H"),
+];
+
+/// Terminal atoms that are valid for a residue even though its template omits them.
+const TERMINAL_ATOMS: [&str; 11] = [
+    "OXT", "HXT", "HOXT", "H1", "H2", "H3", "OP3", "HOP2", "HOP3", "HO5'", "HO3'",
+];
+
+/// An atom renamed by [`normalize_atom_names`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AtomRename {
+    /// Identifier of the chain that owns the residue.
+    pub chain_id: String,
+    /// Residue sequence number.
+    pub residue_id: i32,
+    /// Optional insertion code of the residue.
+    pub insertion_code: Option<char>,
+    /// Name of the residue containing the atom.
+    pub residue_name: String,
+    /// Atom name before normalization.
+    pub old_name: String,
+    /// Atom name after normalization.
+    pub new_name: String,
+}
+
+/// Renames legacy atom names in-place so they match the template database.
+///
+/// Only residues with an internal template are touched, and an atom is renamed only when its
+/// current name is unknown to the template while the translated name is known. Translations
+/// cover the phosphate and methyl aliases (`O1P` → `OP1`, `C5M` → `C7`), `*` sugar primes
+/// (`C1*` → `C1'`), and leading-digit hydrogens (`1HB` → `HB1`). Leading-digit hydrogens that
+/// share a parent are renumbered together, so methylene pairs such as `1HB`/`2HB` become
+/// `HB2`/`HB3` when the template starts counting at 2, and sugar pairs such as `1H5*`/`2H5*`
+/// become `H5'`/`H5''`. A rename that would duplicate an existing atom name is skipped.
+///
+/// # Arguments
+///
+/// * `structure` - Mutable structure whose atom names are normalized.
+///
+/// # Returns
+///
+/// One [`AtomRename`] per renamed atom, in chain, residue, and atom order.
+pub fn normalize_atom_names(structure: &mut Structure) -> Vec<AtomRename> {
+    let mut renamed = Vec::new();
+
+    for chain in structure.iter_chains_mut() {
+        let chain_id = chain.id.to_string();
+        for residue in chain.iter_residues_mut() {
+            let res_name = residue.name.clone();
+            let Some(template) = db::get_template(&res_name) else {
+                continue;
+            };
+
+            for (old_name, new_name) in normalize_residue(residue, template) {
+                renamed.push(AtomRename {
+                    chain_id: chain_id.clone(),
+                    residue_id: residue.id,
+                    insertion_code: residue.insertion_code,
+                    residue_name: res_name.to_string(),
+                    old_name,
+                    new_name,
+                });
+            }
+        }
+    }
+
+    renamed
+}
+
+/// Renames the legacy atoms of a single residue against its template.
+///
+/// # Returns
+///
+/// The `(old, new)` name pairs that were applied, in atom order.
+fn normalize_residue(residue: &mut Residue, template: TemplateView<'_>) -> Vec<(String, String)> {
+    let valid: HashSet<&str> = template
+        .all_atoms()
+        .map(|(name, _, _)| name)
+        .chain(TERMINAL_ATOMS)
+        .collect();
+
+    let legacy: Vec<String> = residue
+        .iter_atoms()
+        .map(|atom| atom.name.to_string())
+        .filter(|name| !valid.contains(name.as_str()))
+        .collect();
+    if legacy.is_empty() {
+        return Vec::new();
+    }
+
+    let numbered: Vec<(&str, String, u32)> = legacy
+        .iter()
+        .filter_map(|name| split_leading_digit(name).map(|(d, parent)| (name.as_str(), parent, d)))
+        .collect();
+    let mut offsets: HashMap<&str, u32> = HashMap::new();
+    for (_, parent, _) in &numbered {
+        if offsets.contains_key(parent.as_str()) {
+            continue;
+        }
+        let group = numbered.iter().filter(|(_, p, _)| p == parent);
+        let offset = [0, 1].into_iter().find(|&offset| {
+            group
+                .clone()
+                .all(|(_, p, d)| valid.contains(numbered_name(p, d + offset).as_str()))
+        });
+        if let Some(offset) = offset {
+            offsets.insert(parent.as_str(), offset);
+        }
+    }
+
+    let mut applied = Vec::new();
+    for old_name in &legacy {
+        let new_name = match numbered.iter().find(|(name, _, _)| name == old_name) {
+            Some((_, parent, digit)) => match offsets.get(parent.as_str()) {
+                Some(offset) => numbered_name(parent, digit + offset),
+                None => continue,
+            },
+            None => alias_for(old_name),
+        };
+
+        if new_name == *old_name
+            || !valid.contains(new_name.as_str())
+            || residue.has_atom(&new_name)
+        {
+            continue;
+        }
+        if let Some(atom) = residue.iter_atoms_mut().find(|atom| atom.name == *old_name) {
+            atom.name = new_name.as_str().into();
+            applied.push((old_name.clone(), new_name));
+        }
+    }
+
+    applied
+}
+
+/// Translates a legacy name that does not start with a digit.
+fn alias_for(name: &str) -> String {
+    ATOM_ALIASES
+        .iter()
+        .find(|(legacy, _)| *legacy == name)
+        .map(|(_, current)| current.to_string())
+        .unwrap_or_else(|| name.replace('*', "'"))
+}
+
+/// Splits a leading-digit hydrogen name into its digit and the translated parent name.
+///
+/// The parent has `*` primes replaced and the thymine methyl `H5M` mapped to `H7`.
+fn split_leading_digit(name: &str) -> Option<(u32, String)> {
+    let mut chars = name.chars();
+    let digit = chars.next()?.to_digit(10)?;
+    let rest = chars.as_str();
+    if !rest.starts_with('H') {
+        return None;
+    }
+    let parent = match rest {
+        "H5M" => "H7".to_string(),
+        _ => rest.replace('*', "'"),
+    };
+    Some((digit, parent))
+}
+
+/// Builds the current name of the `index`-th hydrogen attached under `parent`.
+///
+/// Sugar hydrogens add primes instead of digits (`H5'`, `H5''`).
+fn numbered_name(parent: &str, index: u32) -> String {
+    if parent.ends_with('\'') {
+        match index {
+            1 => parent.to_string(),
+            2 => format!("{parent}'"),
+            _ => format!("{parent}{index}"),
+        }
+    } else {
+        format!("{parent}{index}")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::{
+        atom::Atom,
+        chain::Chain,
+        types::{Element, Point, ResidueCategory, ResiduePosition, StandardResidue},
+    };
+    use crate::ops::TopologyBuilder;
+
+    fn residue_from_template(
+        name: &str,
+        standard: StandardResidue,
+        rename: impl Fn(&str) -> String,
+    ) -> Residue {
+        let template = db::get_template(name).expect("template");
+        let mut residue = Residue::new(1, None, name, Some(standard), ResidueCategory::Standard);
+        for (atom_name, element, pos) in template.all_atoms() {
+            residue.add_atom(Atom::new(&rename(atom_name), element, pos));
+        }
+        residue
+    }
+
+    fn v2_nucleotide_name(name: &str) -> String {
+        match name {
+            "OP1" => "O1P".into(),
+            "OP2" => "O2P".into(),
+            "H5'" => "1H5*".into(),
+            "H5''" => "2H5*".into(),
+            "H2'" => "1H2*".into(),
+            "H2''" => "2H2*".into(),
+            "H61" => "1H6".into(),
+            "H62" => "2H6".into(),
+            _ => name.replace('\'', "*"),
+        }
+    }
+
+    fn structure_with(residue: Residue) -> Structure {
+        let mut chain = Chain::new("A");
+        chain.add_residue(residue);
+        std::iter::once(chain).collect()
+    }
+
+    #[test]
+    fn normalize_atom_names_lets_v2_nucleotide_bonds_resolve() {
+        let mut residue = residue_from_template("DA", StandardResidue::DA, v2_nucleotide_name);
+        residue.position = ResiduePosition::Internal;
+        let mut structure = structure_with(residue);
+
+        let (_, warnings) = TopologyBuilder::new()
+            .lenient(true)
+            .build_reported(structure.clone())
+            .unwrap();
+        assert_eq!(warnings.len(), 1);
+
+        let renamed = normalize_atom_names(&mut structure);
+
+        assert!(
+            renamed
+                .iter()
+                .any(|r| r.old_name == "O1P" && r.new_name == "OP1")
+        );
+        assert!(
+            renamed
+                .iter()
+                .any(|r| r.old_name == "2H5*" && r.new_name == "H5''")
+        );
+        assert!(
+            renamed
+                .iter()
+                .any(|r| r.old_name == "1H6" && r.new_name == "H61")
+        );
+        assert!(
+            renamed
+                .iter()
+                .all(|r| r.chain_id == "A" && r.residue_name == "DA")
+        );
+
+        let (topology, warnings) = TopologyBuilder::new()
+            .lenient(true)
+            .build_reported(structure)
+            .unwrap();
+        assert!(warnings.is_empty());
+        let template_bonds = db::get_template("DA").unwrap().bonds().count();
+        assert_eq!(
+            topology.bonds_in_residue("A", 1, None).count(),
+            template_bonds
+        );
+    }
+
+    #[test]
+    fn normalize_atom_names_renumbers_methylene_and_methyl_hydrogens() {
+        let mut residue = residue_from_template("LEU", StandardResidue::LEU, |name| match name {
+            "HB2" => "1HB".into(),
+            "HB3" => "2HB".into(),
+            "HD11" => "1HD1".into(),
+            "HD12" => "2HD1".into(),
+            "HD13" => "3HD1".into(),
+            _ => name.into(),
+        });
+        residue.position = ResiduePosition::Internal;
+        let mut structure = structure_with(residue);
+
+        let renamed = normalize_atom_names(&mut structure);
+
+        let pairs: Vec<(&str, &str)> = renamed
+            .iter()
+            .map(|r| (r.old_name.as_str(), r.new_name.as_str()))
+            .collect();
+        assert_eq!(
+            pairs,
+            vec![
+                ("1HB", "HB2"),
+                ("2HB", "HB3"),
+                ("1HD1", "HD11"),
+                ("2HD1", "HD12"),
+                ("3HD1", "HD13"),
+            ]
+        );
+    }
+
+    #[test]
+    fn normalize_atom_names_ignores_current_names_and_unknown_residues() {
+        let mut residue = residue_from_template("ALA", StandardResidue::ALA, str::to_string);
+        residue.add_atom(Atom::new("OXT", Element::O, Point::origin()));
+        let mut structure = structure_with(residue);
+        let mut ligand = Residue::new(2, None, "LIG", None, ResidueCategory::Hetero);
+        ligand.add_atom(Atom::new("1HB", Element::H, Point::origin()));
+        ligand.add_atom(Atom::new("O1P", Element::O, Point::origin()));
+        structure.chain_mut("A").unwrap().add_residue(ligand);
+
+        assert!(normalize_atom_names(&mut structure).is_empty());
+        let ligand = structure.find_residue("A", 2, None).unwrap();
+        assert!(ligand.has_atom("1HB") && ligand.has_atom("O1P"));
+    }
+}
@@ -0,0 +1,169 @@
+//! Reduction of polymers to their backbone atoms.
+//!
+//! Coarse analyses such as backbone RMSD or reduced representations only need the main-chain
+//! atoms. This module strips side chains from protein residues and bases from nucleic acid
+//! residues while leaving the polymer ordering intact.
+
+use crate::model::{residue::Residue, structure::Structure};
+
+/// Main-chain atoms kept for amino acid residues, including the terminal carboxylate oxygen.
+const PROTEIN_BACKBONE: [&str; 5] = ["N", "CA", "C", "O", "OXT"];
+/// Phosphate and sugar atoms kept for nucleotide residues.
+const NUCLEIC_BACKBONE: [&str; 13] = [
+    "P", "OP1", "OP2", "OP3", "O5'", "C5'", "C4'", "O4'", "C3'", "O3'", "C2'", "O2'", "C1'",
+];
+
+/// Keeps only the backbone atoms of every polymer residue in-place.
+///
+/// Protein residues retain `N`, `CA`, `C`, `O`, and `OXT` (plus `CB` when requested) and
+/// nucleic acid residues retain their phosphate and sugar atoms. Hydrogens are removed from
+/// polymer residues because they are not part of the reduced representation. Water, ions,
+/// and hetero groups are either kept unchanged or dropped entirely.
+///
+/// # Arguments
+///
+/// * `structure` - Mutable structure to reduce.
+/// * `include_cb` - Whether to keep the `CB` atom of amino acids.
+/// * `keep_non_polymer` - Whether non-polymer residues survive the reduction.
+pub fn retain_backbone(structure: &mut Structure, include_cb: bool, keep_non_polymer: bool) {
+    structure.par_retain_residues_mut(|_, residue| {
+        let Some((names, has_cb)) = backbone_atom_names(residue) else {
+            return keep_non_polymer;
+        };
+        let keep_cb = include_cb && has_cb;
+        residue.retain_atoms(|atom| names.contains(&&*atom.name) || (keep_cb && atom.name == "CB"));
+        true
+    });
+}
+
+/// Resolves the backbone atom list of a polymer residue and whether it can carry a `CB`.
+///
+/// # Returns
+///
+/// `None` for residues that are not amino acids or nucleotides.
+fn backbone_atom_names(residue: &Residue) -> Option<(&'static [&'static str], bool)> {
+    let standard = residue.standard_name?;
+    if standard.is_protein() {
+        Some((&PROTEIN_BACKBONE, true))
+    } else if standard.is_nucleic() {
+        Some((&NUCLEIC_BACKBONE, false))
+    } else {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::{
+        atom::Atom,
+        chain::Chain,
+        types::{Element, Point, ResidueCategory, StandardResidue},
+    };
+
+    fn residue_with_atoms(
+        id: i32,
+        name: &str,
+        standard: Option<StandardResidue>,
+        category: ResidueCategory,
+        atoms: &[(&str, Element)],
+    ) -> Residue {
+        let mut residue = Residue::new(id, None, name, standard, category);
+        for (atom_name, element) in atoms {
+            residue.add_atom(Atom::new(atom_name, *element, Point::origin()));
+        }
+        residue
+    }
+
+    fn mixed_structure() -> Structure {
+        let mut chain = Chain::new("A");
+        chain.add_residue(residue_with_atoms(
+            1,
+            "SER",
+            Some(StandardResidue::SER),
+            ResidueCategory::Standard,
+            &[
+                ("N", Element::N),
+                ("CA", Element::C),
+                ("C", Element::C),
+                ("O", Element::O),
+                ("CB", Element::C),
+                ("OG", Element::O),
+                ("HA", Element::H),
+            ],
+        ));
+        chain.add_residue(residue_with_atoms(
+            2,
+            "DA",
+            Some(StandardResidue::DA),
+            ResidueCategory::Standard,
+            &[
+                ("P", Element::P),
+                ("O5'", Element::O),
+                ("C1'", Element::C),
+                ("N9", Element::N),
+                ("C8", Element::C),
+            ],
+        ));
+        chain.add_residue(residue_with_atoms(
+            3,
+            "HOH",
+            Some(StandardResidue::HOH),
+            ResidueCategory::Standard,
+            &[("O", Element::O)],
+        ));
+        chain.add_residue(residue_with_atoms(
+            4,
+            "LIG",
+            None,
+            ResidueCategory::Hetero,
+            &[("C1", Element::C), ("CB", Element::C)],
+        ));
+        std::iter::once(chain).collect()
+    }
+
+    fn atom_names(structure: &Structure, id: i32) -> Vec<String> {
+        structure
+            .find_residue("A", id, None)
+            .unwrap()
+            .iter_atoms()
+            .map(|a| a.name.to_string())
+            .collect()
+    }
+
+    #[test]
+    fn retain_backbone_strips_side_chains_and_bases() {
+        let mut structure = mixed_structure();
+
+        retain_backbone(&mut structure, false, true);
+
+        assert_eq!(atom_names(&structure, 1), vec!["N", "CA", "C", "O"]);
+        assert_eq!(atom_names(&structure, 2), vec!["P", "O5'", "C1'"]);
+        assert_eq!(atom_names(&structure, 3), vec!["O"]);
+        assert_eq!(atom_names(&structure, 4), vec!["C1", "CB"]);
+    }
+
+    #[test]
+    fn retain_backbone_optionally_keeps_cb() {
+        let mut structure = mixed_structure();
+
+        retain_backbone(&mut structure, true, true);
+
+        assert_eq!(atom_names(&structure, 1), vec!["N", "CA", "C", "O", "CB"]);
+    }
+
+    #[test]
+    fn retain_backbone_can_drop_non_polymer_residues() {
+        let mut structure = mixed_structure();
+
+        retain_backbone(&mut structure, false, false);
+
+        let ids: Vec<i32> = structure
+            .chain("A")
+            .unwrap()
+            .iter_residues()
+            .map(|r| r.id)
+            .collect();
+        assert_eq!(ids, vec![1, 2]);
+    }
+}
@@ -0,0 +1,482 @@
+//! Geometry-based perception of bond orders for distance-derived topologies.
+//!
+//! Topologies guessed from interatomic distances carry single bonds only. This module infers
+//! hybridization from bond angles and neighbor counts, detects planar aromatic rings, and
+//! upgrades short bonds between unsaturated atoms to double or triple bonds so that ligand
+//! topologies become usable for formats that encode bond multiplicity.
+
+use crate::model::{
+    topology::Topology,
+    types::{BondOrder, Element, Point},
+};
+use nalgebra::{Matrix3, SymmetricEigen};
+use std::collections::{HashSet, VecDeque};
+
+/// Average bond angle (degrees) above which a three-coordinate atom is considered trigonal.
+const TRIGONAL_MIN_ANGLE: f64 = 115.0;
+/// Bond angle (degrees) above which a two-coordinate atom is considered linear.
+const LINEAR_MIN_ANGLE: f64 = 155.0;
+/// Maximum root-mean-square deviation (Å) from the best-fit plane for aromatic rings.
+const RING_PLANARITY_TOLERANCE: f64 = 0.1;
+/// Largest ring size explored during ring perception.
+const MAX_RING_SIZE: usize = 6;
+
+/// Hybridization states distinguished by the perception heuristics.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Hybridization {
+    /// Linear geometry able to form a triple bond.
+    Sp,
+    /// Trigonal planar geometry able to form one double bond.
+    Sp2,
+    /// Tetrahedral or undetermined geometry; never upgraded.
+    Sp3,
+}
+
+/// Assigns double, triple, and aromatic bond orders from geometry and valence.
+///
+/// The perception runs in three stages:
+///
+/// 1. **Hybridization** — Each C, N, O, and S atom is classified as sp, sp², or sp³ from
+///    its neighbor count and average bond angle. Terminal atoms, which have no angles, are
+///    classified by the length of their single bond.
+/// 2. **Aromaticity** — Five- and six-membered rings found by shortest-cycle search are
+///    marked aromatic when every ring atom is sp² (or a ring O/S in a five-membered ring)
+///    and the ring is planar within 0.1 Å.
+/// 3. **Localized multiple bonds** — Remaining bonds between unsaturated atoms are upgraded
+///    greedily: terminal oxygen and sulfur first (C=O, C=S), then the shortest bonds, so
+///    each atom receives at most one extra bond within its standard valence.
+///
+/// Only bonds that are currently [`BondOrder::Single`] are modified. Coordination bonds are
+/// ignored entirely, so a bound metal neither changes a ligand atom's hybridization nor
+/// consumes its valence. The heuristics assume
+/// hydrogens are present or that heavy-atom geometry is accurate; they do not apply Hückel
+/// electron counting, formal charges, or resonance, so charged groups such as carboxylates
+/// receive one localized double bond and strained or poorly resolved geometries may be
+/// misclassified.
+///
+/// # Arguments
+///
+/// * `topology` - Topology whose bond orders are refined in-place.
+pub fn perceive_bond_orders(topology: &mut Topology) {
+    let atoms: Vec<(Element, Point)> = topology
+        .structure()
+        .iter_atoms()
+        .map(|a| (a.element, a.pos))
+        .collect();
+    let mut neighbors: Vec<Vec<usize>> = vec![Vec::new(); atoms.len()];
+    for bond in topology.bonds() {
+        if bond.order == BondOrder::Coordination {
+            continue;
+        }
+        neighbors[bond.a1_idx].push(bond.a2_idx);
+        neighbors[bond.a2_idx].push(bond.a1_idx);
+    }
+
+    let hybridization: Vec<Hybridization> = (0..atoms.len())
+        .map(|i| classify_hybridization(i, &atoms, &neighbors))
+        .collect();
+    let mut free_valence: Vec<usize> = (0..atoms.len())
+        .map(|i| standard_valence(atoms[i].0).saturating_sub(neighbors[i].len()))
+        .collect();
+
+    let mut aromatic_bonds: HashSet<(usize, usize)> = HashSet::new();
+    for ring in find_rings(&neighbors) {
+        if is_aromatic_ring(&ring, &atoms, &hybridization) {
+            for k in 0..ring.len() {
+                let (a, b) = (ring[k], ring[(k + 1) % ring.len()]);
+                aromatic_bonds.insert((a.min(b), a.max(b)));
+            }
+            for &atom in &ring {
+                free_valence[atom] = free_valence[atom].saturating_sub(1);
+            }
+        }
+    }
+
+    let mut candidates = Vec::new();
+    for (index, bond) in topology.bonds().iter().enumerate() {
+        if bond.order != BondOrder::Single {
+            continue;
+        }
+        let (a, b) = (bond.a1_idx, bond.a2_idx);
+        if aromatic_bonds.contains(&(a, b)) {
+            candidates.push((index, BondOrder::Aromatic, 0, 0.0));
+            continue;
+        }
+
+        let length = (atoms[a].1 - atoms[b].1).norm();
+        let order = match (hybridization[a], hybridization[b]) {
+            (Hybridization::Sp, Hybridization::Sp)
+                if triple_bond_cutoff(atoms[a].0, atoms[b].0).is_some_and(|c| length < c) =>
+            {
+                BondOrder::Triple
+            }
+            (Hybridization::Sp3, _) | (_, Hybridization::Sp3) => continue,
+            _ if double_bond_cutoff(atoms[a].0, atoms[b].0).is_some_and(|c| length < c) => {
+                BondOrder::Double
+            }
+            _ => continue,
+        };
+        let is_terminal_chalcogen =
+            |i: usize| matches!(atoms[i].0, Element::O | Element::S) && neighbors[i].len() == 1;
+        let priority = if is_terminal_chalcogen(a) || is_terminal_chalcogen(b) {
+            1
+        } else {
+            2
+        };
+        candidates.push((index, order, priority, length));
+    }
+
+    candidates.sort_by(|x, y| x.2.cmp(&y.2).then(x.3.total_cmp(&y.3)));
+
+    for (index, order, _, _) in candidates {
+        let bond = topology.bonds()[index];
+        let (a, b) = (bond.a1_idx, bond.a2_idx);
+        let needed = match order {
+            BondOrder::Aromatic => 0,
+            BondOrder::Double => 1,
+            BondOrder::Triple => 2,
+            BondOrder::Single | BondOrder::Coordination => continue,
+        };
+        if free_valence[a] < needed || free_valence[b] < needed {
+            continue;
+        }
+        free_valence[a] -= needed;
+        free_valence[b] -= needed;
+        topology.set_bond_order(index, order);
+    }
+}
+
+/// Returns the neutral valence used to bound multiple-bond assignment.
+fn standard_valence(element: Element) -> usize {
+    match element {
+        Element::C => 4,
+        Element::N => 3,
+        Element::O | Element::S => 2,
+        _ => 0,
+    }
+}
+
+/// Upper distance (Å) below which a bond between two elements is treated as double.
+fn double_bond_cutoff(e1: Element, e2: Element) -> Option<f64> {
+    use Element::{C, N, O, S};
+    match (e1.min(e2), e1.max(e2)) {
+        (C, C) => Some(1.42),
+        (C, N) => Some(1.38),
+        (C, O) => Some(1.30),
+        (N, N) => Some(1.32),
+        (N, O) => Some(1.30),
+        (C, S) => Some(1.72),
+        _ => None,
+    }
+}
+
+/// Upper distance (Å) below which a bond between two elements is treated as triple.
+fn triple_bond_cutoff(e1: Element, e2: Element) -> Option<f64> {
+    use Element::{C, N};
+    match (e1.min(e2), e1.max(e2)) {
+        (C, C) => Some(1.25),
+        (C, N) => Some(1.20),
+        (N, N) => Some(1.15),
+        _ => None,
+    }
+}
+
+/// Infers the hybridization of one atom from its bonded neighbors.
+fn classify_hybridization(
+    atom: usize,
+    atoms: &[(Element, Point)],
+    neighbors: &[Vec<usize>],
+) -> Hybridization {
+    let (element, center) = atoms[atom];
+    if standard_valence(element) == 0 {
+        return Hybridization::Sp3;
+    }
+
+    let bonded = &neighbors[atom];
+    match bonded.len() {
+        1 => {
+            let partner = atoms[bonded[0]].0;
+            let length = (atoms[bonded[0]].1 - center).norm();
+            if triple_bond_cutoff(element, partner).is_some_and(|c| length < c) {
+                Hybridization::Sp
+            } else if double_bond_cutoff(element, partner).is_some_and(|c| length < c) {
+                Hybridization::Sp2
+            } else {
+                Hybridization::Sp3
+            }
+        }
+        2 | 3 => {
+            let mut total = 0.0;
+            let mut count = 0;
+            for (k, &i) in bonded.iter().enumerate() {
+                for &j in &bonded[k + 1..] {
+                    let u = atoms[i].1 - center;
+                    let v = atoms[j].1 - center;
+                    total += u.angle(&v).to_degrees();
+                    count += 1;
+                }
+            }
+            let mean = total / count as f64;
+            let has_short_bond = bonded.iter().any(|&i| {
+                double_bond_cutoff(element, atoms[i].0)
+                    .is_some_and(|c| (atoms[i].1 - center).norm() < c)
+            });
+            if bonded.len() == 2 && mean > LINEAR_MIN_ANGLE {
+                Hybridization::Sp
+            } else if bonded.len() == 2 && matches!(element, Element::O | Element::S) {
+                Hybridization::Sp3
+            } else if mean > TRIGONAL_MIN_ANGLE || (bonded.len() == 2 && has_short_bond) {
+                Hybridization::Sp2
+            } else {
+                Hybridization::Sp3
+            }
+        }
+        _ => Hybridization::Sp3,
+    }
+}
+
+/// Finds the smallest ring through every bond, up to [`MAX_RING_SIZE`] atoms.
+///
+/// Rings are returned as atom sequences in traversal order, deduplicated by atom set.
+fn find_rings(neighbors: &[Vec<usize>]) -> Vec<Vec<usize>> {
+    let mut seen: HashSet<Vec<usize>> = HashSet::new();
+    let mut rings = Vec::new();
+
+    for (start, bonded) in neighbors.iter().enumerate() {
+        for &end in bonded.iter().filter(|&&end| end > start) {
+            let Some(path) = shortest_path_avoiding_edge(neighbors, start, end) else {
+                continue;
+            };
+            let mut key = path.clone();
+            key.sort_unstable();
+            if seen.insert(key) {
+                rings.push(path);
+            }
+        }
+    }
+
+    rings
+}
+
+/// Breadth-first search from `start` to `end` that ignores their direct bond.
+fn shortest_path_avoiding_edge(
+    neighbors: &[Vec<usize>],
+    start: usize,
+    end: usize,
+) -> Option<Vec<usize>> {
+    let mut parent = vec![usize::MAX; neighbors.len()];
+    let mut depth = vec![0usize; neighbors.len()];
+    let mut queue = VecDeque::from([start]);
+    parent[start] = start;
+
+    while let Some(current) = queue.pop_front() {
+        if depth[current] + 1 >= MAX_RING_SIZE {
+            continue;
+        }
+        for &next in &neighbors[current] {
+            if parent[next] != usize::MAX || (current == start && next == end) {
+                continue;
+            }
+            parent[next] = current;
+            depth[next] = depth[current] + 1;
+            if next == end {
+                let mut path = vec![end];
+                let mut node = end;
+                while node != start {
+                    node = parent[node];
+                    path.push(node);
+                }
+                return Some(path);
+            }
+            queue.push_back(next);
+        }
+    }
+
+    None
+}
+
+/// Decides whether a ring is a planar conjugated system.
+fn is_aromatic_ring(
+    ring: &[usize],
+    atoms: &[(Element, Point)],
+    hybridization: &[Hybridization],
+) -> bool {
+    if !(5..=6).contains(&ring.len()) {
+        return false;
+    }
+
+    let conjugated = ring.iter().all(|&i| match atoms[i].0 {
+        Element::C | Element::N => hybridization[i] == Hybridization::Sp2,
+        Element::O | Element::S => ring.len() == 5,
+        _ => false,
+    });
+
+    conjugated && ring_plane_deviation(ring, atoms) < RING_PLANARITY_TOLERANCE
+}
+
+/// Computes the root-mean-square distance of ring atoms from their best-fit plane.
+fn ring_plane_deviation(ring: &[usize], atoms: &[(Element, Point)]) -> f64 {
+    let n = ring.len() as f64;
+    let centroid = ring.iter().fold(nalgebra::Vector3::zeros(), |acc, &i| {
+        acc + atoms[i].1.coords
+    }) / n;
+    let covariance = ring.iter().fold(Matrix3::zeros(), |acc, &i| {
+        let d = atoms[i].1.coords - centroid;
+        acc + d * d.transpose()
+    });
+    let smallest = SymmetricEigen::new(covariance).eigenvalues.min().max(0.0);
+    (smallest / n).sqrt()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::{
+        atom::Atom,
+        chain::Chain,
+        residue::Residue,
+        structure::Structure,
+        topology::Bond,
+        types::{Point, ResidueCategory},
+    };
+
+    fn topology(atoms: &[(Element, Point)], bonds: &[(usize, usize)]) -> Topology {
+        let mut residue = Residue::new(1, None, "LIG", None, ResidueCategory::Hetero);
+        for (i, (element, pos)) in atoms.iter().enumerate() {
+            residue.add_atom(Atom::new(&format!("X{i}"), *element, *pos));
+        }
+        let mut chain = Chain::new("A");
+        chain.add_residue(residue);
+        let structure: Structure = std::iter::once(chain).collect();
+        let bonds = bonds
+            .iter()
+            .map(|&(a, b)| Bond::new(a, b, BondOrder::Single))
+            .collect();
+        Topology::new(structure, bonds)
+    }
+
+    fn order_of(topology: &Topology, a: usize, b: usize) -> BondOrder {
+        topology
+            .bonds()
+            .iter()
+            .find(|bond| bond.a1_idx == a.min(b) && bond.a2_idx == a.max(b))
+            .map(|bond| bond.order)
+            .expect("bond exists")
+    }
+
+    fn ring(n: usize, radius: f64, z: impl Fn(usize) -> f64) -> Vec<Point> {
+        (0..n)
+            .map(|k| {
+                let t = std::f64::consts::TAU * k as f64 / n as f64;
+                Point::new(radius * t.cos(), radius * t.sin(), z(k))
+            })
+            .collect()
+    }
+
+    #[test]
+    fn perceive_bond_orders_marks_planar_benzene_aromatic() {
+        let atoms: Vec<(Element, Point)> = ring(6, 1.39, |_| 0.0)
+            .into_iter()
+            .map(|p| (Element::C, p))
+            .collect();
+        let bonds: Vec<(usize, usize)> = (0..6).map(|k| (k, (k + 1) % 6)).collect();
+        let mut topo = topology(&atoms, &bonds);
+
+        perceive_bond_orders(&mut topo);
+
+        assert!(topo.bonds().iter().all(|b| b.order == BondOrder::Aromatic));
+    }
+
+    #[test]
+    fn perceive_bond_orders_leaves_puckered_rings_single() {
+        let atoms: Vec<(Element, Point)> = ring(6, 1.46, |k| if k % 2 == 0 { 0.25 } else { -0.25 })
+            .into_iter()
+            .map(|p| (Element::C, p))
+            .collect();
+        let bonds: Vec<(usize, usize)> = (0..6).map(|k| (k, (k + 1) % 6)).collect();
+        let mut topo = topology(&atoms, &bonds);
+
+        perceive_bond_orders(&mut topo);
+
+        assert!(topo.bonds().iter().all(|b| b.order == BondOrder::Single));
+    }
+
+    #[test]
+    fn perceive_bond_orders_assigns_one_double_bond_per_carboxylate() {
+        let atoms = [
+            (Element::C, Point::new(0.0, 0.0, 0.0)),
+            (Element::C, Point::new(-1.52, 0.0, 0.0)),
+            (Element::O, Point::new(0.63, 1.08, 0.0)),
+            (Element::O, Point::new(0.62, -1.10, 0.0)),
+        ];
+        let mut topo = topology(&atoms, &[(0, 1), (0, 2), (0, 3)]);
+
+        perceive_bond_orders(&mut topo);
+
+        assert_eq!(order_of(&topo, 0, 1), BondOrder::Single);
+        assert_eq!(order_of(&topo, 0, 2), BondOrder::Double);
+        assert_eq!(order_of(&topo, 0, 3), BondOrder::Single);
+    }
+
+    #[test]
+    fn perceive_bond_orders_prefers_carbonyl_over_amide_nitrogen() {
+        let atoms = [
+            (Element::C, Point::new(0.0, 0.0, 0.0)),
+            (Element::O, Point::new(0.62, 1.05, 0.0)),
+            (Element::N, Point::new(0.67, -1.15, 0.0)),
+            (Element::C, Point::new(-1.51, 0.0, 0.0)),
+        ];
+        let mut topo = topology(&atoms, &[(0, 1), (0, 2), (0, 3)]);
+
+        perceive_bond_orders(&mut topo);
+
+        assert_eq!(order_of(&topo, 0, 1), BondOrder::Double);
+        assert_eq!(order_of(&topo, 0, 2), BondOrder::Single);
+    }
+
+    #[test]
+    fn perceive_bond_orders_detects_nitrile_triple_bond() {
+        let atoms = [
+            (Element::C, Point::new(-1.46, 0.0, 0.0)),
+            (Element::C, Point::new(0.0, 0.0, 0.0)),
+            (Element::N, Point::new(1.16, 0.0, 0.0)),
+        ];
+        let mut topo = topology(&atoms, &[(0, 1), (1, 2)]);
+
+        perceive_bond_orders(&mut topo);
+
+        assert_eq!(order_of(&topo, 0, 1), BondOrder::Single);
+        assert_eq!(order_of(&topo, 1, 2), BondOrder::Triple);
+    }
+
+    #[test]
+    fn perceive_bond_orders_keeps_existing_multiple_bonds() {
+        let atoms = [
+            (Element::C, Point::new(0.0, 0.0, 0.0)),
+            (Element::O, Point::new(1.21, 0.0, 0.0)),
+        ];
+        let mut topo = topology(&atoms, &[(0, 1)]);
+        topo.set_bond_order(0, BondOrder::Triple);
+
+        perceive_bond_orders(&mut topo);
+
+        assert_eq!(order_of(&topo, 0, 1), BondOrder::Triple);
+    }
+
+    #[test]
+    fn perceive_bond_orders_ignores_coordination_bonds() {
+        let atoms = [
+            (Element::C, Point::new(-1.51, 0.0, 0.0)),
+            (Element::C, Point::new(0.0, 0.0, 0.0)),
+            (Element::O, Point::new(0.61, 1.04, 0.0)),
+            (Element::Zn, Point::new(-0.4, 2.9, 0.0)),
+        ];
+        let mut topo = topology(&atoms, &[(0, 1), (1, 2), (2, 3)]);
+        topo.set_bond_order(2, BondOrder::Coordination);
+
+        perceive_bond_orders(&mut topo);
+
+        assert_eq!(order_of(&topo, 1, 2), BondOrder::Double);
+        assert_eq!(order_of(&topo, 2, 3), BondOrder::Coordination);
+    }
+}
@@ -0,0 +1,263 @@
+//! Copies force-field partial charges from residue templates onto structure atoms.
+//!
+//! Template-backed (standard) residues are matched atom-by-atom against their template so
+//! that downstream electrostatics, PQR export, or Coulomb analyses can consume per-atom
+//! charges. Hetero residues and ions have no internal template and are left untouched.
+//!
+//! Coverage is deliberately narrow for now: only the internal `ALA` and `GLY` templates
+//! (AMBER ff14SB) and `HOH` (TIP3P) carry charges. Every other standard residue, and every
+//! polymer terminus (whose force-field charge set differs from the internal residue), is
+//! left uncharged and listed in the returned [`ChargeReport`], so a typical protein comes
+//! back incomplete. Check [`ChargeReport::is_complete`] before relying on the charges.
+
+use crate::db;
+use crate::model::{
+    residue::Residue,
+    structure::Structure,
+    types::{ResidueCategory, ResiduePosition},
+};
+use crate::ops::error::Error;
+
+/// Summary of a partial-charge assignment pass.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ChargeReport {
+    /// Number of atoms that received a template charge.
+    pub charged_atoms: usize,
+    /// Standard residues left uncharged, labeled `chain/residue_name residue_id`, because
+    /// their template carries no partial charges or they sit at a polymer terminus.
+    pub uncharged_residues: Vec<String>,
+}
+
+impl ChargeReport {
+    /// Reports whether every standard residue received template charges.
+    pub fn is_complete(&self) -> bool {
+        self.uncharged_residues.is_empty()
+    }
+}
+
+/// Assigns template partial charges to every atom of every chargeable standard residue.
+///
+/// Only internal `ALA` and `GLY` residues and `HOH` waters are chargeable at present; every
+/// other standard residue is reported as uncharged, so check
+/// [`ChargeReport::is_complete`] before relying on the charges.
+///
+/// Each atom's [`partial_charge`](crate::Atom::partial_charge) is overwritten with the value
+/// stored in the residue's template. Residues whose template defines no charges at all, and
+/// residues marked as polymer termini (see [`Structure::assign_positions`]), have their
+/// charges reset to `None` and are listed in [`ChargeReport::uncharged_residues`] instead of
+/// receiving internal-residue charges that would give the wrong net charge. Hetero residues
+/// and ions are skipped.
+///
+/// # Arguments
+///
+/// * `structure` - Mutable structure whose atoms receive partial charges.
+///
+/// # Returns
+///
+/// A [`ChargeReport`] counting charged atoms and naming the residues left uncharged.
+///
+/// # Errors
+///
+/// Returns [`Error::MissingPartialCharges`] when a residue with a charged template contains
+/// atoms the template does not parameterize, listing each as
+/// `chain/residue_name residue_id/atom_name`. All resolvable charges are still assigned
+/// before the error is returned.
+pub fn assign_partial_charges(structure: &mut Structure) -> Result<ChargeReport, Error> {
+    let mut report = ChargeReport::default();
+    let mut missing = Vec::new();
+
+    for chain in structure.iter_chains_mut() {
+        let chain_id = chain.id.clone();
+        for residue in chain.iter_residues_mut() {
+            if residue.category != ResidueCategory::Standard {
+                continue;
+            }
+
+            let res_name = residue.name.clone();
+            let res_label = format!(
+                "{}/{} {}{}",
+                chain_id,
+                res_name,
+                residue.id,
+                residue.insertion_code.map(String::from).unwrap_or_default()
+            );
+            let template = db::get_template(&res_name)
+                .filter(|t| t.partial_charges().next().is_some())
+                .filter(|_| !is_terminal(residue));
+            let Some(template) = template else {
+                for atom in residue.iter_atoms_mut() {
+                    atom.partial_charge = None;
+                }
+                report.uncharged_residues.push(res_label);
+                continue;
+            };
+
+            for atom in residue.iter_atoms_mut() {
+                atom.partial_charge = template.partial_charge(&atom.name);
+                if atom.partial_charge.is_some() {
+                    report.charged_atoms += 1;
+                } else {
+                    missing.push(format!("{}/{}", res_label, atom.name));
+                }
+            }
+        }
+    }
+
+    if missing.is_empty() {
+        Ok(report)
+    } else {
+        Err(Error::MissingPartialCharges { atoms: missing })
+    }
+}
+
+/// Reports whether the residue is annotated as a polymer terminus.
+fn is_terminal(residue: &Residue) -> bool {
+    matches!(
+        residue.position,
+        ResiduePosition::NTerminal
+            | ResiduePosition::CTerminal
+            | ResiduePosition::FivePrime
+            | ResiduePosition::ThreePrime
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::{
+        atom::Atom,
+        chain::Chain,
+        residue::Residue,
+        types::{Element, Point},
+    };
+
+    fn residue_from_template(name: &str, id: i32) -> Residue {
+        let template = db::get_template(name).expect("template");
+        let mut residue = Residue::new(
+            id,
+            None,
+            name,
+            Some(template.standard_name()),
+            ResidueCategory::Standard,
+        );
+        for (atom_name, element, pos) in template.heavy_atoms() {
+            residue.add_atom(Atom::new(atom_name, element, pos));
+        }
+        for (atom_name, pos, _) in template.hydrogens() {
+            residue.add_atom(Atom::new(atom_name, Element::H, pos));
+        }
+        residue
+    }
+
+    fn structure_with(residues: Vec<Residue>) -> Structure {
+        let mut chain = Chain::new("A");
+        for residue in residues {
+            chain.add_residue(residue);
+        }
+        std::iter::once(chain).collect()
+    }
+
+    #[test]
+    fn assign_partial_charges_copies_template_values() {
+        let mut structure = structure_with(vec![residue_from_template("ALA", 1)]);
+
+        let report = assign_partial_charges(&mut structure).expect("charges available");
+
+        let residue = structure.find_residue("A", 1, None).unwrap();
+        assert_eq!(report.charged_atoms, residue.atom_count());
+        assert!(report.is_complete());
+        assert_eq!(residue.atom("N").unwrap().partial_charge, Some(-0.4157));
+        assert_eq!(residue.atom("HB2").unwrap().partial_charge, Some(0.0603));
+        let total: f64 = residue.iter_atoms().filter_map(|a| a.partial_charge).sum();
+        assert!(total.abs() < 1e-6);
+    }
+
+    #[test]
+    fn assign_partial_charges_sums_to_zero_for_water() {
+        let mut structure = structure_with(vec![residue_from_template("HOH", 1)]);
+
+        assign_partial_charges(&mut structure).expect("charges available");
+
+        let total: f64 = structure
+            .iter_atoms()
+            .filter_map(|a| a.partial_charge)
+            .sum();
+        assert!(total.abs() < 1e-9);
+    }
+
+    #[test]
+    fn assign_partial_charges_reports_atoms_without_charges() {
+        let mut ala = residue_from_template("ALA", 1);
+        ala.add_atom(Atom::new("OXT", Element::O, Point::origin()));
+        let mut structure = structure_with(vec![ala]);
+
+        let err = assign_partial_charges(&mut structure).unwrap_err();
+
+        match err {
+            Error::MissingPartialCharges { atoms } => {
+                assert_eq!(atoms, vec!["A/ALA 1/OXT".to_string()]);
+            }
+            other => panic!("unexpected error: {other:?}"),
+        }
+        let residue = structure.find_residue("A", 1, None).unwrap();
+        assert_eq!(residue.atom("CA").unwrap().partial_charge, Some(0.0337));
+        assert_eq!(residue.atom("OXT").unwrap().partial_charge, None);
+    }
+
+    #[test]
+    fn assign_partial_charges_reports_termini_and_uncharged_templates() {
+        let mut n_term = residue_from_template("ALA", 1);
+        n_term.remove_atom("H");
+        for name in ["H1", "H2", "H3"] {
+            n_term.add_atom(Atom::new(name, Element::H, Point::origin()));
+        }
+        let mut c_term = residue_from_template("ALA", 4);
+        c_term.add_atom(Atom::new("OXT", Element::O, Point::origin()));
+        let mut structure = structure_with(vec![
+            n_term,
+            residue_from_template("GLY", 2),
+            residue_from_template("SER", 3),
+            c_term,
+        ]);
+        structure.assign_positions();
+        for atom in structure.iter_atoms_mut() {
+            atom.partial_charge = Some(9.9);
+        }
+
+        let report = assign_partial_charges(&mut structure).expect("no unparameterized atoms");
+
+        assert_eq!(
+            report.uncharged_residues,
+            vec!["A/ALA 1", "A/SER 3", "A/ALA 4"]
+        );
+        assert!(!report.is_complete());
+        let gly = structure.find_residue("A", 2, None).unwrap();
+        assert_eq!(report.charged_atoms, gly.atom_count());
+        assert!(gly.iter_atoms().all(|a| a.partial_charge.is_some()));
+        let gly_total: f64 = gly.iter_atoms().filter_map(|a| a.partial_charge).sum();
+        assert!(gly_total.abs() < 1e-6);
+        for id in [1, 3, 4] {
+            let residue = structure.find_residue("A", id, None).unwrap();
+            assert!(residue.iter_atoms().all(|a| a.partial_charge.is_none()));
+        }
+    }
+
+    #[test]
+    fn assign_partial_charges_skips_hetero_residues() {
+        let mut lig = Residue::new(2, None, "LIG", None, ResidueCategory::Hetero);
+        lig.add_atom(Atom::new("C1", Element::C, Point::origin()));
+        let mut structure = structure_with(vec![residue_from_template("GLY", 1), lig]);
+
+        assign_partial_charges(&mut structure).expect("hetero residues are ignored");
+
+        let lig = structure.find_residue("A", 2, None).unwrap();
+        assert_eq!(lig.atom("C1").unwrap().partial_charge, None);
+        assert!(
+            structure
+                .find_residue("A", 1, None)
+                .unwrap()
+                .iter_atoms()
+                .all(|a| a.partial_charge.is_some())
+        );
+    }
+}
@@ -5,8 +5,11 @@
 //! instances and honor fine-grained controls such as keep/remove lists so workflows can
 //! standardize inputs before repair, hydrogenation, or topology building.
 
+use crate::model::grid::Grid;
 use crate::model::structure::Structure;
-use crate::model::types::{ResidueCategory, StandardResidue};
+use crate::model::types::{Element, ResidueCategory, StandardResidue};
+use crate::ops::contact::ResidueKey;
+use crate::ops::diagnostics::{Diagnostic, Diagnostics, Severity};
 use crate::ops::error::Error;
 use std::collections::HashSet;
 
@@ -18,12 +21,18 @@ use std::collections::HashSet;
 pub struct CleanConfig {
     /// Strip crystallographic waters (HOH) when `true`.
     pub remove_water: bool,
+    /// When removing water, keep waters whose oxygen lies within this distance (Å) of a
+    /// non-water heavy atom. Has no effect unless `remove_water` is enabled.
+    pub keep_water_within: Option<f64>,
     /// Strip ionic residues (category `Ion`) when `true`.
     pub remove_ions: bool,
     /// Remove hydrogen atoms before structural refinement.
     pub remove_hydrogens: bool,
     /// Remove heterogen residues (category `Hetero`).
     pub remove_hetero: bool,
+    /// Remove empty residues and standard residues missing backbone atoms, as reported by
+    /// [`Structure::find_incomplete_residues`].
+    pub remove_incomplete_residues: bool,
     /// Case-sensitive residue names to always remove, regardless of category.
     pub remove_residue_names: HashSet<String>,
     /// Case-sensitive residue names to always keep, overriding other rules.
@@ -64,7 +73,9 @@ impl CleanConfig {
 /// Applies the cleaning rules to a mutable structure in-place.
 ///
 /// Hydrogens can be stripped prior to solvation or protonation, and residues matching the
-/// configured filters are removed with chain bookkeeping handled automatically.
+/// configured filters are removed with chain bookkeeping handled automatically. When
+/// `keep_water_within` is set, the distance check runs against the non-water heavy atoms
+/// present before any residue is removed.
 ///
 /// # Arguments
 ///
@@ -80,6 +91,20 @@ impl CleanConfig {
 /// Currently never returns [`Error`] variants but reserves the signature for future
 /// validation failures to stay compatible with other ops APIs.
 pub fn clean_structure(structure: &mut Structure, config: &CleanConfig) -> Result<(), Error> {
+    let shell = config
+        .keep_water_within
+        .filter(|cutoff| config.remove_water && *cutoff > 0.0)
+        .map(|cutoff| {
+            let anchors = structure
+                .iter_atoms_with_context()
+                .filter(|(_, residue, atom)| {
+                    residue.standard_name != Some(StandardResidue::HOH)
+                        && atom.element.is_heavy_atom()
+                })
+                .map(|(_, _, atom)| (atom.pos, ()));
+            (Grid::new(anchors, cutoff), cutoff)
+        });
+
     structure.par_retain_residues_mut(|_chain_id, residue| {
         if config.keep_residue_names.contains(residue.name.as_str()) {
             if config.remove_hydrogens {
@@ -92,10 +117,24 @@ pub fn clean_structure(structure: &mut Structure, config: &CleanConfig) -> Resul
             return false;
         }
 
-        if config.remove_water && residue.standard_name == Some(StandardResidue::HOH) {
+        if config.remove_incomplete_residues
+            && (residue.is_empty() || !residue.missing_backbone_atoms().is_empty())
+        {
             return false;
         }
 
+        if config.remove_water && residue.standard_name == Some(StandardResidue::HOH) {
+            let near_solute = shell.as_ref().is_some_and(|(grid, cutoff)| {
+                residue
+                    .iter_atoms()
+                    .filter(|atom| atom.element == Element::O)
+                    .any(|atom| grid.neighbors(&atom.pos, *cutoff).exact().next().is_some())
+            });
+            if !near_solute {
+                return false;
+            }
+        }
+
         if config.remove_ions && residue.category == ResidueCategory::Ion {
             return false;
         }
@@ -116,6 +155,64 @@ pub fn clean_structure(structure: &mut Structure, config: &CleanConfig) -> Resul
     Ok(())
 }
 
+/// Cleans a structure like [`clean_structure`] and lists every removed residue.
+///
+/// Each residue dropped by the configured filters is reported as a [`Severity::Info`]
+/// diagnostic, so callers can audit which waters, ions, or ligands disappeared.
+///
+/// # Arguments
+///
+/// * `structure` - Mutable structure that will be filtered.
+/// * `config` - Cleaning switches describing which components to remove or keep.
+///
+/// # Returns
+///
+/// One diagnostic per removed residue, in their original chain and residue order.
+///
+/// # Errors
+///
+/// Returns [`Error`] under the same conditions as [`clean_structure`].
+pub fn clean_structure_with_diagnostics(
+    structure: &mut Structure,
+    config: &CleanConfig,
+) -> Result<Diagnostics, Error> {
+    let residue_keys = |structure: &Structure| {
+        structure
+            .iter_chains()
+            .flat_map(|chain| {
+                chain.iter_residues().map(|residue| {
+                    let key = ResidueKey {
+                        chain_id: chain.id.to_string(),
+                        residue_id: residue.id,
+                        insertion_code: residue.insertion_code,
+                    };
+                    (key, residue.name.to_string())
+                })
+            })
+            .collect::<Vec<_>>()
+    };
+
+    let before = residue_keys(structure);
+    clean_structure(structure, config)?;
+    let kept: HashSet<ResidueKey> = residue_keys(structure)
+        .into_iter()
+        .map(|(key, _)| key)
+        .collect();
+
+    Ok(before
+        .into_iter()
+        .filter(|(key, _)| !kept.contains(key))
+        .map(|(key, name)| {
+            Diagnostic::for_key(
+                Severity::Info,
+                "clean",
+                key,
+                format!("removed residue {name}"),
+            )
+        })
+        .collect())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -143,6 +240,88 @@ mod tests {
         structure
     }
 
+    fn hydrated_structure() -> Structure {
+        let mut chain = Chain::new("A");
+        let mut ala = Residue::new(
+            1,
+            None,
+            "ALA",
+            Some(StandardResidue::ALA),
+            ResidueCategory::Standard,
+        );
+        ala.add_atom(Atom::new("CA", Element::C, Point::origin()));
+        ala.add_atom(Atom::new("HA", Element::H, Point::new(-1.0, 0.0, 0.0)));
+        chain.add_residue(ala);
+
+        for (id, x) in [(2, 2.8), (3, 8.0)] {
+            let mut water = Residue::new(
+                id,
+                None,
+                "HOH",
+                Some(StandardResidue::HOH),
+                ResidueCategory::Standard,
+            );
+            water.add_atom(Atom::new("O", Element::O, Point::new(x, 0.0, 0.0)));
+            chain.add_residue(water);
+        }
+
+        let mut structure = Structure::new();
+        structure.add_chain(chain);
+        structure
+    }
+
+    #[test]
+    fn keep_water_within_retains_waters_near_solute() {
+        let mut structure = hydrated_structure();
+        let config = CleanConfig {
+            remove_water: true,
+            keep_water_within: Some(3.5),
+            ..Default::default()
+        };
+
+        clean_structure(&mut structure, &config).unwrap();
+
+        let ids: Vec<i32> = structure
+            .chain("A")
+            .unwrap()
+            .iter_residues()
+            .map(|r| r.id)
+            .collect();
+        assert_eq!(ids, vec![1, 2]);
+    }
+
+    #[test]
+    fn clean_structure_with_diagnostics_lists_removed_residues() {
+        let mut structure = hydrated_structure();
+        let config = CleanConfig {
+            remove_water: true,
+            keep_water_within: Some(3.5),
+            ..Default::default()
+        };
+
+        let diagnostics = clean_structure_with_diagnostics(&mut structure, &config).unwrap();
+
+        assert_eq!(structure.residue_count(), 2);
+        let findings: Vec<_> = diagnostics.iter().collect();
+        assert_eq!(findings.len(), 1);
+        assert_eq!(findings[0].severity, Severity::Info);
+        assert_eq!(findings[0].residue.as_ref().unwrap().residue_id, 3);
+        assert_eq!(findings[0].message, "removed residue HOH");
+    }
+
+    #[test]
+    fn keep_water_within_is_noop_without_remove_water() {
+        let mut structure = hydrated_structure();
+        let config = CleanConfig {
+            keep_water_within: Some(3.5),
+            ..Default::default()
+        };
+
+        clean_structure(&mut structure, &config).unwrap();
+
+        assert_eq!(structure.residue_count(), 3);
+    }
+
     #[test]
     fn removes_hydrogens_when_flag_enabled() {
         let mut structure = Structure::new();
@@ -273,4 +452,47 @@ mod tests {
         assert!(structure.chain("A").is_none());
         assert!(structure.chain("B").is_some());
     }
+
+    #[test]
+    fn removes_incomplete_residues_when_flag_enabled() {
+        let mut chain = Chain::new("A");
+        let mut complete = Residue::new(
+            1,
+            None,
+            "GLY",
+            Some(StandardResidue::GLY),
+            ResidueCategory::Standard,
+        );
+        for (name, x) in [("N", 0.0), ("CA", 1.5), ("C", 3.0)] {
+            complete.add_atom(Atom::new(name, Element::C, Point::new(x, 0.0, 0.0)));
+        }
+        chain.add_residue(complete);
+        let mut truncated = Residue::new(
+            2,
+            None,
+            "GLY",
+            Some(StandardResidue::GLY),
+            ResidueCategory::Standard,
+        );
+        truncated.add_atom(Atom::new("CA", Element::C, Point::new(5.0, 0.0, 0.0)));
+        chain.add_residue(truncated);
+        chain.add_residue(Residue::new(3, None, "LIG", None, ResidueCategory::Hetero));
+        let mut structure = Structure::new();
+        structure.add_chain(chain);
+
+        let config = CleanConfig {
+            remove_incomplete_residues: true,
+            ..Default::default()
+        };
+
+        clean_structure(&mut structure, &config).unwrap();
+
+        let ids: Vec<i32> = structure
+            .chain("A")
+            .unwrap()
+            .iter_residues()
+            .map(|r| r.id)
+            .collect();
+        assert_eq!(ids, vec![1]);
+    }
 }
@@ -0,0 +1,294 @@
+//! One-call comparison of two versions of the same structure.
+//!
+//! Atoms are matched by chain, residue number, insertion code, and atom name. The report
+//! combines overall and per-residue RMSD, optionally after a Kabsch superposition of the
+//! model onto the reference, with the atoms that only one of the structures contains.
+
+use crate::model::{geometry::superposition, structure::Structure, types::Point};
+use std::collections::{HashMap, HashSet};
+
+/// Identifies an atom by chain, residue, and atom name.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct AtomKey {
+    /// Identifier of the chain that owns the atom.
+    pub chain_id: String,
+    /// Residue sequence number.
+    pub residue_id: i32,
+    /// Optional insertion code of the residue.
+    pub insertion_code: Option<char>,
+    /// Atom name within the residue.
+    pub atom_name: String,
+}
+
+/// Deviation of the matched atoms of a single residue.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ResidueDeviation {
+    /// Identifier of the chain that owns the residue.
+    pub chain_id: String,
+    /// Residue sequence number.
+    pub residue_id: i32,
+    /// Optional insertion code of the residue.
+    pub insertion_code: Option<char>,
+    /// Residue name taken from the reference structure.
+    pub residue_name: String,
+    /// Number of atoms matched between the reference and the model.
+    pub matched_atoms: usize,
+    /// Root-mean-square deviation of the matched atoms in ångströms.
+    pub rmsd: f64,
+}
+
+/// Summary produced by [`compare`].
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct CompareReport {
+    /// Number of atoms matched between the reference and the model.
+    pub matched_atoms: usize,
+    /// Overall RMSD over all matched atoms, or `None` when nothing matched.
+    pub rmsd: Option<f64>,
+    /// Per-residue deviations in reference order, for residues with matched atoms.
+    pub residues: Vec<ResidueDeviation>,
+    /// Atoms present in the reference but absent from the model.
+    pub only_in_reference: Vec<AtomKey>,
+    /// Atoms present in the model but absent from the reference.
+    pub only_in_model: Vec<AtomKey>,
+}
+
+/// Compares a model against a reference structure.
+///
+/// Atoms are paired by [`AtomKey`]; when `superpose` is set the model coordinates are first
+/// aligned onto the reference with a least-squares Kabsch fit over all matched atoms. Neither
+/// input is modified.
+///
+/// # Arguments
+///
+/// * `reference` - Structure treated as ground truth.
+/// * `model` - Structure evaluated against the reference.
+/// * `superpose` - Whether to superpose the model before measuring deviations.
+///
+/// # Returns
+///
+/// A [`CompareReport`] with overall and per-residue RMSD plus unmatched atoms.
+pub fn compare(reference: &Structure, model: &Structure, superpose: bool) -> CompareReport {
+    let model_atoms: HashMap<AtomKey, Point> = keyed_atoms(model).collect();
+
+    let mut report = CompareReport::default();
+    let mut pairs = Vec::new();
+    let mut residue_spans = Vec::new();
+    let mut matched_keys = HashSet::new();
+
+    for chain in reference.iter_chains() {
+        for residue in chain.iter_residues() {
+            let start = pairs.len();
+            for atom in residue.iter_atoms() {
+                let key = AtomKey {
+                    chain_id: chain.id.to_string(),
+                    residue_id: residue.id,
+                    insertion_code: residue.insertion_code,
+                    atom_name: atom.name.to_string(),
+                };
+                match model_atoms.get(&key) {
+                    Some(model_pos) => {
+                        pairs.push((atom.pos, *model_pos));
+                        matched_keys.insert(key);
+                    }
+                    None => report.only_in_reference.push(key),
+                }
+            }
+            if pairs.len() > start {
+                residue_spans.push((
+                    ResidueDeviation {
+                        chain_id: chain.id.to_string(),
+                        residue_id: residue.id,
+                        insertion_code: residue.insertion_code,
+                        residue_name: residue.name.to_string(),
+                        matched_atoms: pairs.len() - start,
+                        rmsd: 0.0,
+                    },
+                    start..pairs.len(),
+                ));
+            }
+        }
+    }
+
+    report.only_in_model = keyed_atoms(model)
+        .map(|(key, _)| key)
+        .filter(|key| !matched_keys.contains(key))
+        .collect();
+
+    if pairs.is_empty() {
+        return report;
+    }
+
+    if superpose && let Some((rotation, translation)) = superposition(&pairs) {
+        for (_, model_pos) in &mut pairs {
+            *model_pos = Point::from(rotation * model_pos.coords + translation);
+        }
+    }
+
+    report.matched_atoms = pairs.len();
+    report.rmsd = Some(rmsd_of(&pairs));
+    report.residues = residue_spans
+        .into_iter()
+        .map(|(mut deviation, span)| {
+            deviation.rmsd = rmsd_of(&pairs[span]);
+            deviation
+        })
+        .collect();
+
+    report
+}
+
+/// Iterates over the atoms of a structure together with their matching keys.
+fn keyed_atoms(structure: &Structure) -> impl Iterator<Item = (AtomKey, Point)> + '_ {
+    structure.iter_chains().flat_map(|chain| {
+        chain.iter_residues().flat_map(move |residue| {
+            residue.iter_atoms().map(move |atom| {
+                (
+                    AtomKey {
+                        chain_id: chain.id.to_string(),
+                        residue_id: residue.id,
+                        insertion_code: residue.insertion_code,
+                        atom_name: atom.name.to_string(),
+                    },
+                    atom.pos,
+                )
+            })
+        })
+    })
+}
+
+/// Computes the RMSD of `(reference, model)` coordinate pairs.
+fn rmsd_of(pairs: &[(Point, Point)]) -> f64 {
+    let sum_sq: f64 = pairs.iter().map(|(r, m)| (r - m).norm_squared()).sum();
+    (sum_sq / pairs.len() as f64).sqrt()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::{
+        atom::Atom,
+        chain::Chain,
+        residue::Residue,
+        types::{Element, ResidueCategory, StandardResidue},
+    };
+    use nalgebra::{Rotation3, Vector3};
+
+    fn structure_from(residues: &[(i32, &[(&str, Point)])]) -> Structure {
+        let mut chain = Chain::new("A");
+        for (id, atoms) in residues {
+            let mut residue = Residue::new(
+                *id,
+                None,
+                "ALA",
+                Some(StandardResidue::ALA),
+                ResidueCategory::Standard,
+            );
+            for (name, pos) in atoms.iter() {
+                residue.add_atom(Atom::new(name, Element::C, *pos));
+            }
+            chain.add_residue(residue);
+        }
+        std::iter::once(chain).collect()
+    }
+
+    fn reference() -> Structure {
+        structure_from(&[
+            (
+                1,
+                &[
+                    ("N", Point::new(0.0, 0.0, 0.0)),
+                    ("CA", Point::new(1.5, 0.0, 0.0)),
+                ],
+            ),
+            (
+                2,
+                &[
+                    ("N", Point::new(2.0, 1.5, 0.0)),
+                    ("CA", Point::new(3.0, 2.0, 1.0)),
+                ],
+            ),
+        ])
+    }
+
+    #[test]
+    fn compare_without_superposition_measures_raw_deviation() {
+        let reference = reference();
+        let mut model = reference.clone();
+        for atom in model.iter_atoms_mut() {
+            atom.pos.x += 2.0;
+        }
+
+        let report = compare(&reference, &model, false);
+
+        assert_eq!(report.matched_atoms, 4);
+        assert!((report.rmsd.unwrap() - 2.0).abs() < 1e-12);
+        assert_eq!(report.residues.len(), 2);
+        assert!(report.residues.iter().all(|r| (r.rmsd - 2.0).abs() < 1e-12));
+        assert!(report.only_in_reference.is_empty());
+        assert!(report.only_in_model.is_empty());
+    }
+
+    #[test]
+    fn compare_with_superposition_removes_rigid_motion() {
+        let reference = reference();
+        let mut model = reference.clone();
+        let rotation = Rotation3::from_euler_angles(0.3, -0.7, 1.1);
+        for atom in model.iter_atoms_mut() {
+            atom.pos = rotation * atom.pos + Vector3::new(4.0, -2.0, 7.5);
+        }
+
+        let report = compare(&reference, &model, true);
+
+        assert_eq!(report.matched_atoms, 4);
+        assert!(report.rmsd.unwrap() < 1e-9);
+        assert!(report.residues.iter().all(|r| r.rmsd < 1e-9));
+    }
+
+    #[test]
+    fn compare_lists_atoms_missing_from_either_side() {
+        let reference = reference();
+        let model = structure_from(&[
+            (
+                1,
+                &[("N", Point::new(0.0, 0.0, 0.0)), ("CB", Point::origin())],
+            ),
+            (3, &[("CA", Point::origin())]),
+        ]);
+
+        let report = compare(&reference, &model, false);
+
+        assert_eq!(report.matched_atoms, 1);
+        assert_eq!(report.rmsd, Some(0.0));
+        assert_eq!(report.residues.len(), 1);
+        assert_eq!(report.residues[0].residue_id, 1);
+        assert_eq!(report.residues[0].matched_atoms, 1);
+
+        let names = |keys: &[AtomKey]| -> Vec<(i32, String)> {
+            keys.iter()
+                .map(|k| (k.residue_id, k.atom_name.clone()))
+                .collect()
+        };
+        assert_eq!(
+            names(&report.only_in_reference),
+            vec![
+                (1, "CA".to_string()),
+                (2, "N".to_string()),
+                (2, "CA".to_string())
+            ]
+        );
+        assert_eq!(
+            names(&report.only_in_model),
+            vec![(1, "CB".to_string()), (3, "CA".to_string())]
+        );
+    }
+
+    #[test]
+    fn compare_reports_no_rmsd_without_matches() {
+        let report = compare(&reference(), &Structure::new(), true);
+
+        assert_eq!(report.matched_atoms, 0);
+        assert!(report.rmsd.is_none());
+        assert!(report.residues.is_empty());
+        assert_eq!(report.only_in_reference.len(), 4);
+    }
+}
@@ -0,0 +1,229 @@
+//! Residue-residue contact maps.
+//!
+//! Contacts are reported as sparse pairs of residue indices together with a lookup table
+//! that maps each index back to its chain, residue number, and insertion code. The any-atom
+//! mode bins heavy atoms in the spatial [`Grid`] so large structures avoid an all-pairs scan.
+
+use crate::model::{grid::Grid, structure::Structure, types::Point};
+use crate::utils::parallel::*;
+
+/// Criteria used to decide whether two residues are in contact.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ContactMode {
+    /// Two residues touch when their CA atoms lie within the cutoff; residues without a CA
+    /// never form contacts.
+    #[default]
+    CaDistance,
+    /// Two residues touch when any pair of their heavy atoms lies within the cutoff.
+    AnyHeavyAtom,
+}
+
+/// Identifies a residue listed in a [`ContactMap`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ResidueKey {
+    /// Identifier of the chain that owns the residue.
+    pub chain_id: String,
+    /// Residue sequence number.
+    pub residue_id: i32,
+    /// Optional insertion code of the residue.
+    pub insertion_code: Option<char>,
+}
+
+/// Sparse residue-residue contact map produced by [`contact_map`].
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct ContactMap {
+    /// Every residue of the structure in chain and residue order; contact pairs index here.
+    pub residues: Vec<ResidueKey>,
+    /// Contacting residue index pairs `(i, j)` with `i < j`, sorted ascending.
+    pub contacts: Vec<(usize, usize)>,
+}
+
+impl ContactMap {
+    /// Expands the sparse pair list into a dense symmetric boolean matrix.
+    ///
+    /// The diagonal is always `false`.
+    ///
+    /// # Returns
+    ///
+    /// A square matrix with one row and column per entry of [`ContactMap::residues`].
+    pub fn to_matrix(&self) -> Vec<Vec<bool>> {
+        let n = self.residues.len();
+        let mut matrix = vec![vec![false; n]; n];
+        for &(i, j) in &self.contacts {
+            matrix[i][j] = true;
+            matrix[j][i] = true;
+        }
+        matrix
+    }
+}
+
+/// Computes the residue-residue contact map of a structure.
+///
+/// A residue is never reported in contact with itself. Contacts between residues of
+/// different chains are included.
+///
+/// # Arguments
+///
+/// * `structure` - Structure whose residues are analyzed.
+/// * `cutoff` - Maximum distance in ångströms for two residues to be in contact.
+/// * `mode` - Whether to compare CA atoms or all heavy atoms.
+///
+/// # Returns
+///
+/// A [`ContactMap`] listing every residue and the contacting pairs; no pairs are reported
+/// when `cutoff` is not positive.
+pub fn contact_map(structure: &Structure, cutoff: f64, mode: ContactMode) -> ContactMap {
+    let mut residues = Vec::new();
+    let mut points: Vec<(Point, usize)> = Vec::new();
+
+    for chain in structure.iter_chains() {
+        for residue in chain.iter_residues() {
+            let res_idx = residues.len();
+            residues.push(ResidueKey {
+                chain_id: chain.id.to_string(),
+                residue_id: residue.id,
+                insertion_code: residue.insertion_code,
+            });
+
+            match mode {
+                ContactMode::CaDistance => {
+                    if let Some(ca) = residue.atom("CA") {
+                        points.push((ca.pos, res_idx));
+                    }
+                }
+                ContactMode::AnyHeavyAtom => points.extend(
+                    residue
+                        .iter_atoms()
+                        .filter(|a| a.element.is_heavy_atom())
+                        .map(|a| (a.pos, res_idx)),
+                ),
+            }
+        }
+    }
+
+    if points.is_empty() || cutoff <= 0.0 {
+        return ContactMap {
+            residues,
+            contacts: Vec::new(),
+        };
+    }
+
+    let grid = Grid::new(points.iter().copied(), cutoff);
+
+    let mut contacts: Vec<(usize, usize)> = points
+        .par_iter()
+        .flat_map_iter(|&(pos, i)| {
+            grid.neighbors(&pos, cutoff)
+                .exact()
+                .filter_map(move |(_, &j)| (i < j).then_some((i, j)))
+                .collect::<Vec<_>>()
+        })
+        .collect();
+
+    contacts.par_sort_unstable();
+    contacts.dedup();
+
+    ContactMap { residues, contacts }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::{
+        atom::Atom,
+        chain::Chain,
+        residue::Residue,
+        types::{Element, ResidueCategory, StandardResidue},
+    };
+
+    fn residue_with(id: i32, atoms: &[(&str, Element, Point)]) -> Residue {
+        let mut residue = Residue::new(
+            id,
+            None,
+            "ALA",
+            Some(StandardResidue::ALA),
+            ResidueCategory::Standard,
+        );
+        for (name, element, pos) in atoms {
+            residue.add_atom(Atom::new(name, *element, *pos));
+        }
+        residue
+    }
+
+    fn sample_structure() -> Structure {
+        let mut chain_a = Chain::new("A");
+        chain_a.add_residue(residue_with(
+            1,
+            &[
+                ("CA", Element::C, Point::new(0.0, 0.0, 0.0)),
+                ("CB", Element::C, Point::new(0.0, 5.0, 0.0)),
+            ],
+        ));
+        chain_a.add_residue(residue_with(
+            2,
+            &[("CA", Element::C, Point::new(3.8, 0.0, 0.0))],
+        ));
+        chain_a.add_residue(residue_with(
+            3,
+            &[
+                ("CA", Element::C, Point::new(0.0, 12.0, 0.0)),
+                ("CB", Element::C, Point::new(0.0, 8.0, 0.0)),
+                ("HB1", Element::H, Point::new(0.0, 5.5, 0.0)),
+            ],
+        ));
+
+        let mut chain_b = Chain::new("B");
+        chain_b.add_residue(residue_with(
+            1,
+            &[("CA", Element::C, Point::new(7.0, 0.0, 0.0))],
+        ));
+
+        let mut structure = Structure::new();
+        structure.add_chain(chain_a);
+        structure.add_chain(chain_b);
+        structure
+    }
+
+    #[test]
+    fn contact_map_by_ca_distance_pairs_nearby_residues() {
+        let map = contact_map(&sample_structure(), 4.0, ContactMode::CaDistance);
+
+        assert_eq!(map.residues.len(), 4);
+        assert_eq!(map.contacts, vec![(0, 1), (1, 3)]);
+        assert_eq!(map.residues[3].chain_id, "B");
+        assert_eq!(map.residues[3].residue_id, 1);
+    }
+
+    #[test]
+    fn contact_map_any_heavy_atom_ignores_hydrogens() {
+        let map = contact_map(&sample_structure(), 4.0, ContactMode::AnyHeavyAtom);
+
+        assert_eq!(map.contacts, vec![(0, 1), (0, 2), (1, 3)]);
+
+        let tight = contact_map(&sample_structure(), 3.5, ContactMode::AnyHeavyAtom);
+        assert_eq!(tight.contacts, vec![(0, 2), (1, 3)]);
+    }
+
+    #[test]
+    fn contact_map_to_matrix_is_symmetric() {
+        let map = contact_map(&sample_structure(), 4.0, ContactMode::CaDistance);
+        let matrix = map.to_matrix();
+
+        assert_eq!(matrix.len(), 4);
+        assert!(matrix[0][1] && matrix[1][0]);
+        assert!(matrix[1][3] && matrix[3][1]);
+        assert!(!matrix[0][0]);
+        assert!(!matrix[0][3]);
+    }
+
+    #[test]
+    fn contact_map_handles_empty_structure_and_zero_cutoff() {
+        let empty = contact_map(&Structure::new(), 4.0, ContactMode::AnyHeavyAtom);
+        assert!(empty.residues.is_empty());
+        assert!(empty.contacts.is_empty());
+
+        let zero = contact_map(&sample_structure(), 0.0, ContactMode::CaDistance);
+        assert_eq!(zero.residues.len(), 4);
+        assert!(zero.contacts.is_empty());
+    }
+}
@@ -0,0 +1,250 @@
+//! Severity-tagged findings shared by operations that recover from problems.
+//!
+//! Operations such as repair, cleaning, hydrogen addition, and topology building offer
+//! `*_with_diagnostics` variants that keep going past recoverable problems and return a
+//! [`Diagnostics`] collection next to their result. Every entry names the operation that
+//! raised it and, when applicable, the residue it concerns, so a whole pipeline can be
+//! summarized through one type instead of per-operation report structs.
+
+use super::contact::ResidueKey;
+use crate::model::residue::Residue;
+use std::fmt;
+
+/// Importance of a [`Diagnostic`], ordered from least to most severe.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Severity {
+    /// Noteworthy change made on purpose, such as a removed or rebuilt atom.
+    Info,
+    /// Recoverable problem that left part of the input unprocessed or uncertain.
+    Warning,
+    /// Problem that invalidates the affected residue for downstream steps.
+    Error,
+}
+
+impl fmt::Display for Severity {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let label = match self {
+            Self::Info => "info",
+            Self::Warning => "warning",
+            Self::Error => "error",
+        };
+        f.write_str(label)
+    }
+}
+
+/// Single finding reported by an operation.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Diagnostic {
+    /// How serious the finding is.
+    pub severity: Severity,
+    /// Short name of the operation that raised it, e.g. `"repair"` or `"topology"`.
+    pub operation: &'static str,
+    /// Residue the finding concerns, or `None` for structure-wide findings.
+    pub residue: Option<ResidueKey>,
+    /// Human-readable description.
+    pub message: String,
+}
+
+impl Diagnostic {
+    /// Creates a finding attached to one residue.
+    ///
+    /// # Arguments
+    ///
+    /// * `severity` - How serious the finding is.
+    /// * `operation` - Short name of the reporting operation.
+    /// * `chain_id` - Identifier of the chain that owns the residue.
+    /// * `residue` - Residue the finding concerns.
+    /// * `message` - Human-readable description.
+    pub(crate) fn for_residue(
+        severity: Severity,
+        operation: &'static str,
+        chain_id: &str,
+        residue: &Residue,
+        message: impl Into<String>,
+    ) -> Self {
+        let key = ResidueKey {
+            chain_id: chain_id.to_string(),
+            residue_id: residue.id,
+            insertion_code: residue.insertion_code,
+        };
+        Self::for_key(severity, operation, key, message)
+    }
+
+    /// Creates a finding attached to a residue identified only by its key.
+    ///
+    /// Used when the reporting operation no longer holds the [`Residue`] itself, e.g. when
+    /// converting a per-residue report.
+    ///
+    /// # Arguments
+    ///
+    /// * `severity` - How serious the finding is.
+    /// * `operation` - Short name of the reporting operation.
+    /// * `key` - Chain, sequence number, and insertion code of the residue.
+    /// * `message` - Human-readable description.
+    pub(crate) fn for_key(
+        severity: Severity,
+        operation: &'static str,
+        key: ResidueKey,
+        message: impl Into<String>,
+    ) -> Self {
+        Self {
+            severity,
+            operation,
+            residue: Some(key),
+            message: message.into(),
+        }
+    }
+}
+
+impl fmt::Display for Diagnostic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} [{}]", self.severity, self.operation)?;
+        if let Some(key) = &self.residue {
+            write!(f, " {}:{}", key.chain_id, key.residue_id)?;
+            if let Some(code) = key.insertion_code {
+                write!(f, "{code}")?;
+            }
+        }
+        write!(f, ": {}", self.message)
+    }
+}
+
+/// Ordered collection of [`Diagnostic`] entries.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Diagnostics {
+    entries: Vec<Diagnostic>,
+}
+
+impl Diagnostics {
+    /// Creates an empty collection.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Appends a finding.
+    ///
+    /// # Arguments
+    ///
+    /// * `diagnostic` - Finding to record.
+    pub fn push(&mut self, diagnostic: Diagnostic) {
+        self.entries.push(diagnostic);
+    }
+
+    /// Returns the number of recorded findings.
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Reports whether nothing was recorded.
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Iterates over the findings in the order they were recorded.
+    pub fn iter(&self) -> std::slice::Iter<'_, Diagnostic> {
+        self.entries.iter()
+    }
+
+    /// Iterates over findings at or above a severity.
+    ///
+    /// # Arguments
+    ///
+    /// * `min` - Lowest severity to include.
+    ///
+    /// # Returns
+    ///
+    /// An iterator over matching findings in recording order.
+    pub fn at_least(&self, min: Severity) -> impl Iterator<Item = &Diagnostic> {
+        self.entries.iter().filter(move |d| d.severity >= min)
+    }
+
+    /// Returns the highest severity recorded.
+    ///
+    /// # Returns
+    ///
+    /// `Some(severity)` for a non-empty collection, otherwise `None`.
+    pub fn max_severity(&self) -> Option<Severity> {
+        self.entries.iter().map(|d| d.severity).max()
+    }
+
+    /// Reports whether any finding has [`Severity::Warning`] or higher.
+    pub fn has_warnings(&self) -> bool {
+        self.max_severity() >= Some(Severity::Warning)
+    }
+}
+
+impl Extend<Diagnostic> for Diagnostics {
+    fn extend<I: IntoIterator<Item = Diagnostic>>(&mut self, iter: I) {
+        self.entries.extend(iter);
+    }
+}
+
+impl FromIterator<Diagnostic> for Diagnostics {
+    fn from_iter<I: IntoIterator<Item = Diagnostic>>(iter: I) -> Self {
+        Self {
+            entries: iter.into_iter().collect(),
+        }
+    }
+}
+
+impl IntoIterator for Diagnostics {
+    type Item = Diagnostic;
+    type IntoIter = std::vec::IntoIter<Diagnostic>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.entries.into_iter()
+    }
+}
+
+impl<'a> IntoIterator for &'a Diagnostics {
+    type Item = &'a Diagnostic;
+    type IntoIter = std::slice::Iter<'a, Diagnostic>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.entries.iter()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::types::ResidueCategory;
+
+    fn finding(severity: Severity) -> Diagnostic {
+        let residue = Residue::new(12, Some('B'), "LIG", None, ResidueCategory::Hetero);
+        Diagnostic::for_residue(severity, "repair", "A", &residue, "skipped")
+    }
+
+    #[test]
+    fn diagnostics_filter_by_severity_and_report_maximum() {
+        let mut diagnostics = Diagnostics::new();
+        assert_eq!(diagnostics.max_severity(), None);
+        assert!(!diagnostics.has_warnings());
+
+        diagnostics.push(finding(Severity::Info));
+        assert!(!diagnostics.has_warnings());
+        diagnostics.extend([finding(Severity::Warning), finding(Severity::Info)]);
+
+        assert_eq!(diagnostics.len(), 3);
+        assert_eq!(diagnostics.max_severity(), Some(Severity::Warning));
+        assert!(diagnostics.has_warnings());
+        assert_eq!(diagnostics.at_least(Severity::Warning).count(), 1);
+        assert_eq!(diagnostics.at_least(Severity::Error).count(), 0);
+    }
+
+    #[test]
+    fn diagnostic_display_includes_operation_and_residue() {
+        assert_eq!(
+            finding(Severity::Warning).to_string(),
+            "warning [repair] A:12B: skipped"
+        );
+
+        let global = Diagnostic {
+            severity: Severity::Info,
+            operation: "clean",
+            residue: None,
+            message: "nothing to do".into(),
+        };
+        assert_eq!(global.to_string(), "info [clean]: nothing to do");
+    }
+}
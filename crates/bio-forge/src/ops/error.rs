@@ -8,6 +8,7 @@ use thiserror::Error;
 
 /// Error conditions surfaced by the operations layer.
 #[derive(Debug, Error)]
+#[non_exhaustive]
 pub enum Error {
     /// Internal template lookup failed for a standard residue.
     #[error("internal template not found for standard residue '{res_name}'")]
@@ -35,6 +36,10 @@ pub enum Error {
     #[error("simulation box is too small for the requested solvation parameters")]
     BoxTooSmall,
 
+    /// Custom solvent molecule cannot be packed because it has no heavy atoms.
+    #[error("solvent molecule '{res_name}' has no heavy atoms to place")]
+    InvalidSolvent { res_name: String },
+
     /// Replacement of waters with ions could not reach the requested charge balance.
     #[error("ionization failed: {details}")]
     IonizationFailed { details: String },
@@ -52,6 +57,20 @@ pub enum Error {
         res_id: i32,
         atom_name: String,
     },
+
+    /// Partial charges could not be resolved for some atoms of template-backed residues.
+    #[error("partial charges unavailable for {} atom(s): {}", atoms.len(), atoms.join(", "))]
+    MissingPartialCharges { atoms: Vec<String> },
+
+    /// Template chosen for loop placeholders is not an amino acid with a CA atom.
+    #[error(
+        "residue '{res_name}' cannot be used as a loop placeholder: not a protein residue with a CA atom"
+    )]
+    InvalidLoopTemplate { res_name: String },
+
+    /// Atom indices supplied by the caller are empty or out of range.
+    #[error("invalid atom selection: {details}")]
+    InvalidAtomSelection { details: String },
 }
 
 impl Error {
@@ -7,14 +7,17 @@
 use crate::db;
 use crate::model::{
     atom::Atom,
+    geometry::superposition,
     grid::Grid,
     residue::Residue,
     structure::Structure,
     types::{Element, Point, ResidueCategory, ResiduePosition, StandardResidue},
 };
+use crate::ops::contact::ResidueKey;
+use crate::ops::diagnostics::{Diagnostic, Diagnostics, Severity};
 use crate::ops::error::Error;
 use crate::utils::parallel::*;
-use nalgebra::{Matrix3, Rotation3, Vector3};
+use nalgebra::{Rotation3, Vector3};
 use rand::Rng;
 use std::collections::HashSet;
 
@@ -52,34 +55,52 @@ const NH_BOND_LENGTH: f64 = 1.01;
 const OH_BOND_LENGTH: f64 = 0.96;
 /// Carboxylic acid O-H bond length (Å).
 const COOH_BOND_LENGTH: f64 = 0.97;
+/// Distance (Å) below which an added hydrogen clashes with a non-bonded heavy atom.
+const HYDROGEN_CLASH_CUTOFF: f64 = 1.6;
+/// Hydrogens placed by the terminal builders rather than by residue templates.
+const TERMINAL_HYDROGENS: [&str; 9] = [
+    "H1", "H2", "H3", "HXT", "HOXT", "HO3'", "HO5'", "HOP2", "HOP3",
+];
+
+/// Maximum heavy-atom distance (Å) from a hydrogen's parent treated as a covalent neighbor.
+const PARENT_BOND_CUTOFF: f64 = 2.0;
 
 /// Parameters controlling hydrogen addition behavior.
 ///
-/// `HydroConfig` can target a specific solution pH, remove pre-existing hydrogens,
+/// `HydroConfig` can target a specific solution pH, replace pre-existing hydrogens,
 /// choose how neutral histidine tautomers are assigned, and enable/disable salt bridge detection.
 #[derive(Debug, Clone)]
 pub struct HydroConfig {
     /// Optional solvent pH value used for titration decisions.
     pub target_ph: Option<f64>,
-    /// Whether to strip all existing hydrogens before reconstruction.
-    pub remove_existing_h: bool,
+    /// Whether to strip all existing hydrogens and rebuild them from the templates.
+    ///
+    /// Disabled by default: hydrogens already present are kept and only the missing
+    /// template hydrogens are built, so repeated runs never duplicate atoms and
+    /// mixed-protonation inputs are completed rather than overwritten. Residues relabeled
+    /// to another protonation state lose the hydrogens their new template lacks either way.
+    pub replace_existing: bool,
     /// Strategy for selecting neutral histidine tautomers (HID/HIE).
     pub his_strategy: HisStrategy,
     /// Whether to protonate histidine to HIP when forming salt bridges with
     /// nearby carboxylate groups (ASP⁻/GLU⁻/C-terminal COO⁻).
     pub his_salt_bridge_protonation: bool,
+    /// Whether to record added hydrogens that clash with non-bonded heavy atoms in
+    /// [`ResidueHydroReport::clashes`].
+    pub check_clashes: bool,
 }
 
 impl Default for HydroConfig {
     /// Provides biologically reasonable defaults (no protonation state changes,
-    /// removal of old hydrogens, hydrogen-bond-aware histidine selection,
-    /// and HIS salt bridge detection).
+    /// existing hydrogens kept, hydrogen-bond-aware histidine selection,
+    /// HIS salt bridge detection, and no clash checking).
     fn default() -> Self {
         Self {
             target_ph: None,
-            remove_existing_h: true,
+            replace_existing: false,
             his_strategy: HisStrategy::HbNetwork,
             his_salt_bridge_protonation: true,
+            check_clashes: false,
         }
     }
 }
@@ -98,8 +119,208 @@ pub enum HisStrategy {
     HbNetwork,
 }
 
+/// Outcome of hydrogen construction for a single residue.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ResidueHydroReport {
+    /// Identifier of the chain that owns the residue.
+    pub chain_id: String,
+    /// Residue sequence number.
+    pub residue_id: i32,
+    /// Optional insertion code of the residue.
+    pub insertion_code: Option<char>,
+    /// Residue name after protonation-state relabeling.
+    pub residue_name: String,
+    /// Names of hydrogens that were added to the residue.
+    pub added: Vec<String>,
+    /// Names of input-authored hydrogens kept in place, which requires
+    /// [`HydroConfig::replace_existing`] to be disabled; empty when the template is missing.
+    pub retained: Vec<String>,
+    /// Expected hydrogens that could not be placed.
+    pub skipped: Vec<SkippedHydrogen>,
+    /// Whether no internal template exists for the residue name.
+    pub template_missing: bool,
+    /// Added hydrogens lying too close to a non-bonded heavy atom; only populated when
+    /// [`HydroConfig::check_clashes`] is enabled.
+    pub clashes: Vec<HydrogenClash>,
+}
+
+impl ResidueHydroReport {
+    /// Reports whether every expected hydrogen was placed.
+    pub fn is_complete(&self) -> bool {
+        !self.template_missing && self.skipped.is_empty()
+    }
+}
+
+/// Expected hydrogen that was not built because its anchors were incomplete.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SkippedHydrogen {
+    /// Name of the hydrogen that was not placed.
+    pub name: String,
+    /// Anchor atom missing from the residue.
+    pub missing_anchor: String,
+}
+
+/// Added hydrogen that lies within clash distance of a non-bonded heavy atom.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HydrogenClash {
+    /// Name of the clashing hydrogen in the reporting residue.
+    pub hydrogen: String,
+    /// Chain identifier of the heavy atom involved.
+    pub chain_id: String,
+    /// Residue sequence number of the heavy atom involved.
+    pub residue_id: i32,
+    /// Optional insertion code of the heavy atom's residue.
+    pub insertion_code: Option<char>,
+    /// Name of the heavy atom involved.
+    pub atom_name: String,
+}
+
+/// Per-residue summary produced by [`add_hydrogens_with_report`].
+///
+/// Only standard residues are listed, in chain and residue order.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct HydroReport {
+    /// Reports for each processed residue.
+    pub residues: Vec<ResidueHydroReport>,
+}
+
+impl HydroReport {
+    /// Counts the hydrogens added across all residues.
+    pub fn added_count(&self) -> usize {
+        self.residues.iter().map(|r| r.added.len()).sum()
+    }
+
+    /// Counts the expected hydrogens that were skipped across all residues.
+    pub fn skipped_count(&self) -> usize {
+        self.residues.iter().map(|r| r.skipped.len()).sum()
+    }
+
+    /// Iterates over residues whose template could not be found.
+    pub fn missing_templates(&self) -> impl Iterator<Item = &ResidueHydroReport> {
+        self.residues.iter().filter(|r| r.template_missing)
+    }
+
+    /// Reports whether every processed residue received all expected hydrogens.
+    pub fn is_complete(&self) -> bool {
+        self.residues.iter().all(ResidueHydroReport::is_complete)
+    }
+
+    /// Counts the hydrogen clashes recorded across all residues.
+    pub fn clash_count(&self) -> usize {
+        self.residues.iter().map(|r| r.clashes.len()).sum()
+    }
+
+    /// Converts the recorded problems into shared [`Diagnostics`].
+    ///
+    /// Missing templates and skipped hydrogens become [`Severity::Warning`] entries, as do
+    /// clashes, since a clashing hydrogen was placed with low confidence. Added hydrogens are
+    /// not listed.
+    ///
+    /// # Returns
+    ///
+    /// Findings in chain and residue order.
+    pub fn diagnostics(&self) -> Diagnostics {
+        let mut diagnostics = Diagnostics::new();
+        for residue in &self.residues {
+            let mut messages = Vec::new();
+            if residue.template_missing {
+                messages.push(format!(
+                    "no internal template for {}; hydrogens not added",
+                    residue.residue_name
+                ));
+            }
+            for skipped in &residue.skipped {
+                messages.push(format!(
+                    "hydrogen {} not placed: anchor {} is missing",
+                    skipped.name, skipped.missing_anchor
+                ));
+            }
+            for clash in &residue.clashes {
+                messages.push(format!(
+                    "hydrogen {} clashes with {} of residue {}:{}{}",
+                    clash.hydrogen,
+                    clash.atom_name,
+                    clash.chain_id,
+                    clash.residue_id,
+                    clash.insertion_code.map(String::from).unwrap_or_default()
+                ));
+            }
+
+            let key = ResidueKey {
+                chain_id: residue.chain_id.clone(),
+                residue_id: residue.residue_id,
+                insertion_code: residue.insertion_code,
+            };
+            diagnostics.extend(messages.into_iter().map(|message| {
+                Diagnostic::for_key(Severity::Warning, "hydrogens", key.clone(), message)
+            }));
+        }
+        diagnostics
+    }
+
+    /// Converts the first recorded problem into the corresponding error.
+    fn into_result(self) -> Result<(), Error> {
+        for residue in self.residues {
+            if residue.template_missing {
+                return Err(Error::MissingInternalTemplate {
+                    res_name: residue.residue_name,
+                });
+            }
+            if let Some(skipped) = residue.skipped.into_iter().next() {
+                return Err(Error::incomplete_for_hydro(
+                    residue.residue_name,
+                    residue.residue_id,
+                    skipped.missing_anchor,
+                ));
+            }
+        }
+        Ok(())
+    }
+}
+
 /// Adds hydrogens to all standard residues in-place, updating protonation states when needed.
 ///
+/// This is a strict wrapper around [`add_hydrogens_with_report`] that fails on the first
+/// residue whose template is missing or whose hydrogens could not all be placed.
+///
+/// # Arguments
+///
+/// * `structure` - Mutable structure whose residues will be protonated and hydrated.
+/// * `config` - Hydrogenation configuration controlling pH, strategy, and options.
+///
+/// # Returns
+///
+/// `Ok(())` when hydrogenation succeeds.
+///
+/// # Errors
+///
+/// Returns [`Error::MissingInternalTemplate`] when no template is found or
+/// [`Error::IncompleteResidueForHydro`] when required anchor atoms are missing.
+pub fn add_hydrogens(structure: &mut Structure, config: &HydroConfig) -> Result<(), Error> {
+    add_hydrogens_with_report(structure, config).into_result()
+}
+
+/// Adds hydrogens like [`add_hydrogens_with_report`] and summarizes problems as [`Diagnostics`].
+///
+/// # Arguments
+///
+/// * `structure` - Mutable structure whose residues will be protonated and hydrated.
+/// * `config` - Hydrogenation configuration controlling pH, strategy, and options.
+///
+/// # Returns
+///
+/// The full [`HydroReport`] together with its [`HydroReport::diagnostics`].
+pub fn add_hydrogens_with_diagnostics(
+    structure: &mut Structure,
+    config: &HydroConfig,
+) -> (HydroReport, Diagnostics) {
+    let report = add_hydrogens_with_report(structure, config);
+    let diagnostics = report.diagnostics();
+    (report, diagnostics)
+}
+
+/// Adds hydrogens to all standard residues and reports what was built for each residue.
+///
 /// This function implements a multi-phase pipeline:
 ///
 /// 1. **Disulfide detection** — Identifies CYS pairs forming S-S bonds and relabels to CYX.
@@ -109,6 +330,12 @@ pub enum HisStrategy {
 ///    and tautomer strategy.
 /// 4. **Hydrogen construction** — Builds hydrogens according to template geometry and
 ///    terminal-specific rules.
+/// 5. **Clash detection** — When [`HydroConfig::check_clashes`] is set, records each added
+///    hydrogen closer than 1.6 Å to a heavy atom other than its parent or the parent's
+///    covalent neighbors. Clashes are reported only; hydrogen positions are left unchanged.
+///
+/// Residues without a template or with missing anchor atoms do not abort the pipeline; they
+/// are recorded in the report and the remaining residues are still processed.
 ///
 /// # Arguments
 ///
@@ -117,13 +344,13 @@ pub enum HisStrategy {
 ///
 /// # Returns
 ///
-/// `Ok(())` when hydrogenation succeeds.
-///
-/// # Errors
-///
-/// Returns [`Error::MissingInternalTemplate`] when no template is found or
-/// [`Error::IncompleteResidueForHydro`] when required anchor atoms are missing.
-pub fn add_hydrogens(structure: &mut Structure, config: &HydroConfig) -> Result<(), Error> {
+/// A [`HydroReport`] listing the hydrogens added and skipped for every standard residue.
+pub fn add_hydrogens_with_report(structure: &mut Structure, config: &HydroConfig) -> HydroReport {
+    let input_names: Vec<Vec<String>> = structure
+        .iter_chains()
+        .map(|chain| chain.iter_residues().map(|r| r.name.to_string()).collect())
+        .collect();
+
     mark_disulfide_bridges(structure);
 
     let acceptor_grid = if config.his_strategy == HisStrategy::HbNetwork
@@ -134,8 +361,8 @@ pub fn add_hydrogens(structure: &mut Structure, config: &HydroConfig) -> Result<
         None
     };
 
-    if config.target_ph.is_some() {
-        apply_non_his_protonation(structure, config.target_ph.unwrap());
+    if let Some(ph) = config.target_ph {
+        apply_non_his_protonation(structure, ph);
     }
 
     let carboxylate_grid = if config.his_salt_bridge_protonation {
@@ -144,18 +371,16 @@ pub fn add_hydrogens(structure: &mut Structure, config: &HydroConfig) -> Result<
         None
     };
 
-    structure
+    let mut residues: Vec<_> = structure
         .par_chains_mut()
         .enumerate()
-        .try_for_each(|(c_idx, chain)| {
+        .flat_map_iter(|(c_idx, chain)| {
+            let chain_id = chain.id.to_string();
             chain
                 .par_residues_mut()
                 .enumerate()
-                .try_for_each(|(r_idx, residue)| {
-                    if residue.category != ResidueCategory::Standard {
-                        return Ok(());
-                    }
-
+                .filter(|(_, residue)| residue.category == ResidueCategory::Standard)
+                .map(|(r_idx, residue)| {
                     if let Some(StandardResidue::HIS) = residue.standard_name
                         && let Some(new_name) = determine_his_protonation(
                             residue,
@@ -168,13 +393,109 @@ pub fn add_hydrogens(structure: &mut Structure, config: &HydroConfig) -> Result<
                         residue.name = new_name.into();
                     }
 
-                    if config.remove_existing_h {
+                    if config.replace_existing {
                         residue.strip_hydrogens();
+                    } else if residue.name != input_names[c_idx][r_idx] {
+                        remove_foreign_hydrogens(residue);
                     }
 
-                    construct_hydrogens_for_residue(residue, config)
+                    let mut report = construct_hydrogens_for_residue(residue, config);
+                    report.chain_id = chain_id.clone();
+                    report
                 })
+                .collect::<Vec<_>>()
         })
+        .collect();
+
+    if config.check_clashes {
+        detect_hydrogen_clashes(structure, &mut residues);
+    }
+
+    HydroReport { residues }
+}
+
+/// Records added hydrogens that clash with non-bonded heavy atoms.
+///
+/// The parent of each hydrogen is the nearest heavy atom of its residue; the parent itself
+/// and heavy atoms within [`PARENT_BOND_CUTOFF`] of it are treated as bonded and ignored.
+///
+/// # Arguments
+///
+/// * `structure` - Structure after hydrogen construction.
+/// * `reports` - Reports for the standard residues, in chain and residue order.
+fn detect_hydrogen_clashes(structure: &Structure, reports: &mut [ResidueHydroReport]) {
+    let atoms: Vec<_> = structure.iter_atoms_with_context().collect();
+    let heavy_grid = Grid::new(
+        atoms
+            .iter()
+            .enumerate()
+            .filter(|(_, (_, _, a))| a.element.is_heavy_atom())
+            .map(|(idx, (_, _, a))| (a.pos, idx)),
+        HYDROGEN_CLASH_CUTOFF,
+    );
+
+    let standard = structure
+        .iter_chains()
+        .flat_map(|c| c.iter_residues())
+        .filter(|r| r.category == ResidueCategory::Standard);
+    for (residue, report) in standard.zip(reports.iter_mut()) {
+        let added = residue
+            .iter_atoms()
+            .filter(|a| a.element == Element::H && report.added.iter().any(|n| a.name == *n));
+        for hydrogen in added {
+            let Some(parent) = residue
+                .iter_atoms()
+                .filter(|a| a.element.is_heavy_atom())
+                .min_by(|a, b| {
+                    a.distance_squared(hydrogen)
+                        .total_cmp(&b.distance_squared(hydrogen))
+                })
+            else {
+                continue;
+            };
+
+            for &idx in heavy_grid
+                .neighbors(&hydrogen.pos, HYDROGEN_CLASH_CUTOFF)
+                .exact()
+                .map(|(_, idx)| idx)
+            {
+                let (chain, owner, atom) = atoms[idx];
+                if parent.distance_squared(atom) <= PARENT_BOND_CUTOFF.powi(2) {
+                    continue;
+                }
+                report.clashes.push(HydrogenClash {
+                    hydrogen: hydrogen.name.to_string(),
+                    chain_id: chain.id.to_string(),
+                    residue_id: owner.id,
+                    insertion_code: owner.insertion_code,
+                    atom_name: atom.name.to_string(),
+                });
+            }
+        }
+    }
+}
+
+/// Removes hydrogens that the residue's current template does not define.
+///
+/// Called after a residue is relabeled to another protonation state, so that hydrogens of
+/// the previous state (such as `HZ3` of LYS kept on LYN) do not change its charge. Terminal
+/// hydrogens are kept, as the terminal builders manage them. Residues without a template
+/// are left untouched.
+///
+/// # Arguments
+///
+/// * `residue` - Relabeled residue whose stale hydrogens are removed.
+fn remove_foreign_hydrogens(residue: &mut Residue) {
+    let template_name = residue.name.clone();
+    let Some(template) = db::get_template(&template_name) else {
+        return;
+    };
+    let expected: HashSet<&str> = template.hydrogens().map(|(name, _, _)| name).collect();
+    residue.retain_atoms(|atom| {
+        atom.element != Element::H
+            || expected.contains(atom.name.as_str())
+            || TERMINAL_HYDROGENS.contains(&atom.name.as_str())
+    });
 }
 
 /// Applies pH-based protonation to all non-HIS titratable residues.
@@ -617,22 +938,24 @@ fn build_acceptor_grid(structure: &Structure) -> Grid<(usize, usize)> {
 ///
 /// # Returns
 ///
-/// `Ok(())` when hydrogen construction succeeds.
-///
-/// # Errors
-///
-/// Returns [`Error::MissingInternalTemplate`] when no template is found or
-/// [`Error::IncompleteResidueForHydro`] when required anchor atoms are missing.
+/// A [`ResidueHydroReport`] describing the hydrogens added and skipped; the chain ID is left
+/// for the caller to fill in.
 fn construct_hydrogens_for_residue(
     residue: &mut Residue,
     config: &HydroConfig,
-) -> Result<(), Error> {
-    let template_name = residue.name.clone();
+) -> ResidueHydroReport {
+    let mut report = ResidueHydroReport {
+        residue_id: residue.id,
+        insertion_code: residue.insertion_code,
+        residue_name: residue.name.to_string(),
+        ..ResidueHydroReport::default()
+    };
 
-    let template_view =
-        db::get_template(&template_name).ok_or_else(|| Error::MissingInternalTemplate {
-            res_name: template_name.to_string(),
-        })?;
+    let template_name = residue.name.clone();
+    let Some(template_view) = db::get_template(&template_name) else {
+        report.template_missing = true;
+        return report;
+    };
 
     let existing_atoms: HashSet<String> =
         residue.atoms().iter().map(|a| a.name.to_string()).collect();
@@ -661,35 +984,70 @@ fn construct_hydrogens_for_residue(
         if let Ok(pos) = reconstruct_geometry(residue, h_tmpl_pos, &anchors, rotation_override) {
             residue.add_atom(Atom::new(h_name, Element::H, pos));
         } else {
-            return Err(Error::incomplete_for_hydro(
-                &*residue.name,
-                residue.id,
-                anchors.first().copied().unwrap_or("?"),
-            ));
+            let missing_anchor = anchors
+                .iter()
+                .find(|a| !residue.has_atom(a))
+                .or(anchors.first())
+                .copied()
+                .unwrap_or("?");
+            report.skipped.push(SkippedHydrogen {
+                name: h_name.to_string(),
+                missing_anchor: missing_anchor.to_string(),
+            });
         }
     }
 
-    match residue.position {
+    let terminal = match residue.position {
         ResiduePosition::NTerminal if residue.standard_name.is_some_and(|s| s.is_protein()) => {
-            construct_n_term_hydrogens(residue, n_term_is_protonated(config.target_ph))?;
+            Some((
+                "H1",
+                construct_n_term_hydrogens(residue, n_term_is_protonated(config.target_ph)),
+            ))
         }
         ResiduePosition::CTerminal if residue.standard_name.is_some_and(|s| s.is_protein()) => {
-            construct_c_term_hydrogen(residue, c_term_is_protonated(config.target_ph))?;
+            Some((
+                "HOXT",
+                construct_c_term_hydrogen(residue, c_term_is_protonated(config.target_ph)),
+            ))
         }
         ResiduePosition::ThreePrime if residue.standard_name.is_some_and(|s| s.is_nucleic()) => {
-            construct_3_prime_hydrogen(residue)?;
+            Some(("HO3'", construct_3_prime_hydrogen(residue)))
         }
         ResiduePosition::FivePrime if residue.standard_name.is_some_and(|s| s.is_nucleic()) => {
             if residue.has_atom("P") {
-                construct_5_prime_phosphate_hydrogens(residue, config.target_ph)?;
+                Some((
+                    "HOP3",
+                    construct_5_prime_phosphate_hydrogens(residue, config.target_ph),
+                ))
             } else if residue.has_atom("O5'") {
-                construct_5_prime_hydrogen(residue)?;
+                Some(("HO5'", construct_5_prime_hydrogen(residue)))
+            } else {
+                None
             }
         }
-        _ => {}
+        _ => None,
+    };
+
+    if let Some((h_name, Err(Error::IncompleteResidueForHydro { atom_name, .. }))) = terminal {
+        report.skipped.push(SkippedHydrogen {
+            name: h_name.to_string(),
+            missing_anchor: atom_name,
+        });
     }
 
-    Ok(())
+    for atom in residue.iter_atoms_mut() {
+        if atom.element != Element::H {
+            continue;
+        }
+        if !existing_atoms.contains(atom.name.as_str()) {
+            atom.generated = true;
+            report.added.push(atom.name.to_string());
+        } else if !atom.generated {
+            report.retained.push(atom.name.to_string());
+        }
+    }
+
+    report
 }
 
 /// Returns the effective pH used for terminal protonation state decisions.
@@ -936,7 +1294,12 @@ fn reconstruct_geometry(
         template_pts.push(t_pos);
     }
 
-    let (mut rot, mut trans) = calculate_transform(&residue_pts, &template_pts).ok_or(())?;
+    let pairs: Vec<(Point, Point)> = residue_pts
+        .iter()
+        .copied()
+        .zip(template_pts.iter().copied())
+        .collect();
+    let (mut rot, mut trans) = superposition(&pairs).ok_or(())?;
 
     if let (Some(override_rot), 1) = (rotation_override, anchor_names.len()) {
         rot = override_rot.into_inner();
@@ -996,52 +1359,6 @@ fn place_hydroxyl_hydrogen(
     o_pos + h_global * bond_length
 }
 
-/// Computes the optimal rigid transform mapping template anchor points to residue atoms.
-///
-/// Uses Kabsch alignment with safeguards for one- and two-point configurations.
-fn calculate_transform(r_pts: &[Point], t_pts: &[Point]) -> Option<(Matrix3<f64>, Vector3<f64>)> {
-    let n = r_pts.len();
-    if n != t_pts.len() || n == 0 {
-        return None;
-    }
-
-    let r_center = r_pts.iter().map(|p| p.coords).sum::<Vector3<f64>>() / n as f64;
-    let t_center = t_pts.iter().map(|p| p.coords).sum::<Vector3<f64>>() / n as f64;
-
-    if n == 1 {
-        return Some((Matrix3::identity(), r_center - t_center));
-    }
-
-    if n == 2 {
-        let v_r = r_pts[1] - r_pts[0];
-        let v_t = t_pts[1] - t_pts[0];
-        let rot = Rotation3::rotation_between(&v_t, &v_r).unwrap_or_else(Rotation3::identity);
-        let trans = r_center - rot * t_center;
-        return Some((rot.into_inner(), trans));
-    }
-
-    let mut cov = Matrix3::zeros();
-    for (p_r, p_t) in r_pts.iter().zip(t_pts.iter()) {
-        let v_r = p_r.coords - r_center;
-        let v_t = p_t.coords - t_center;
-        cov += v_r * v_t.transpose();
-    }
-
-    let svd = cov.svd(true, true);
-    let u = svd.u?;
-    let v_t = svd.v_t?;
-
-    let mut rot = u * v_t;
-    if rot.determinant() < 0.0 {
-        let mut corr = Matrix3::identity();
-        corr[(2, 2)] = -1.0;
-        rot = u * corr * v_t;
-    }
-
-    let trans = r_center - rot * t_center;
-    Some((rot, trans))
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1968,8 +2285,12 @@ mod tests {
         let mut residue = residue_from_template("LYS", StandardResidue::LYS, 10);
         residue.add_atom(Atom::new("FAKE", Element::H, Point::origin()));
         let mut structure = structure_with_residue(residue);
+        let config = HydroConfig {
+            replace_existing: true,
+            ..HydroConfig::default()
+        };
 
-        add_hydrogens(&mut structure, &HydroConfig::default()).unwrap();
+        add_hydrogens(&mut structure, &config).unwrap();
 
         let residue = structure.find_residue("A", 10, None).unwrap();
         assert!(residue.has_atom("HZ1"));
@@ -1977,7 +2298,7 @@ mod tests {
         assert!(residue.has_atom("HZ3"));
         assert!(
             !residue.has_atom("FAKE"),
-            "existing H should be removed by default"
+            "existing H should be removed when replacing"
         );
     }
 
@@ -1987,7 +2308,7 @@ mod tests {
         residue.add_atom(Atom::new("HX", Element::H, Point::origin()));
         let mut structure = structure_with_residue(residue);
         let config = HydroConfig {
-            remove_existing_h: false,
+            replace_existing: false,
             ..HydroConfig::default()
         };
 
@@ -2000,6 +2321,94 @@ mod tests {
         );
     }
 
+    #[test]
+    fn relabeled_lysine_drops_hydrogens_missing_from_lyn() {
+        let mut structure =
+            structure_with_residue(residue_from_template("LYS", StandardResidue::LYS, 1));
+        add_hydrogens(&mut structure, &HydroConfig::default()).unwrap();
+        assert!(
+            structure
+                .find_residue("A", 1, None)
+                .unwrap()
+                .has_atom("HZ3")
+        );
+
+        let config = HydroConfig {
+            target_ph: Some(12.0),
+            ..HydroConfig::default()
+        };
+        add_hydrogens(&mut structure, &config).unwrap();
+
+        let residue = structure.find_residue("A", 1, None).unwrap();
+        assert_eq!(residue.name, "LYN");
+        let template = db::get_template("LYN").unwrap();
+        let mut expected: Vec<&str> = template.hydrogens().map(|(name, _, _)| name).collect();
+        let mut hydrogens: Vec<&str> = residue
+            .iter_atoms()
+            .filter(|a| a.element == Element::H)
+            .map(|a| a.name.as_str())
+            .collect();
+        expected.sort_unstable();
+        hydrogens.sort_unstable();
+        assert_eq!(hydrogens, expected);
+    }
+
+    #[test]
+    fn relabeled_histidine_swaps_epsilon_for_delta_hydrogen() {
+        let mut structure =
+            structure_with_residue(residue_from_template("HIE", StandardResidue::HIS, 1));
+        add_hydrogens(&mut structure, &HydroConfig::default()).unwrap();
+        assert!(
+            structure
+                .find_residue("A", 1, None)
+                .unwrap()
+                .has_atom("HE2")
+        );
+
+        let config = HydroConfig {
+            target_ph: Some(7.0),
+            his_strategy: HisStrategy::DirectHID,
+            his_salt_bridge_protonation: false,
+            ..HydroConfig::default()
+        };
+        add_hydrogens(&mut structure, &config).unwrap();
+
+        let residue = structure.find_residue("A", 1, None).unwrap();
+        assert_eq!(residue.name, "HID");
+        assert!(residue.has_atom("HD1"));
+        assert!(!residue.has_atom("HE2"), "HE2 belongs to HIE only");
+    }
+
+    #[test]
+    fn add_hydrogens_flags_generated_hydrogens_and_reports_authored_ones() {
+        let mut residue = residue_from_template("ALA", StandardResidue::ALA, 1);
+        residue.add_atom(Atom::new("HA", Element::H, Point::origin()));
+        let mut structure = structure_with_residue(residue);
+        let config = HydroConfig {
+            replace_existing: false,
+            ..HydroConfig::default()
+        };
+
+        let first = add_hydrogens_with_report(&mut structure, &config);
+
+        let residue = structure.find_residue("A", 1, None).unwrap();
+        assert!(!residue.atom("HA").unwrap().generated);
+        assert!(residue.atom("HB1").unwrap().generated);
+        assert!(
+            residue
+                .atoms()
+                .iter()
+                .all(|a| !a.generated || a.element == Element::H)
+        );
+        assert_eq!(first.residues[0].retained, vec!["HA".to_string()]);
+        assert!(!first.residues[0].added.contains(&"HA".to_string()));
+
+        let second = add_hydrogens_with_report(&mut structure, &config);
+
+        assert!(second.residues[0].added.is_empty());
+        assert_eq!(second.residues[0].retained, vec!["HA".to_string()]);
+    }
+
     #[test]
     fn construct_hydrogens_errors_when_anchor_missing() {
         let template = db::get_template("ALA").expect("template ALA");
@@ -2015,9 +2424,123 @@ mod tests {
         let (name, element, pos) = template.heavy_atoms().next().unwrap();
         residue.add_atom(Atom::new(name, element, pos));
 
-        let err = construct_hydrogens_for_residue(&mut residue, &HydroConfig::default())
-            .expect_err("should fail");
-        assert!(matches!(err, Error::IncompleteResidueForHydro { .. }));
+        let report = construct_hydrogens_for_residue(&mut residue, &HydroConfig::default());
+        assert!(!report.is_complete());
+        assert!(!report.skipped.is_empty());
+        assert!(report.added.is_empty());
+    }
+
+    #[test]
+    fn add_hydrogens_errors_when_anchor_missing() {
+        let mut residue = Residue::new(
+            20,
+            None,
+            "ALA",
+            Some(StandardResidue::ALA),
+            ResidueCategory::Standard,
+        );
+        residue.add_atom(Atom::new("CB", Element::C, Point::origin()));
+        let mut structure = structure_with_residue(residue);
+
+        let err = add_hydrogens(&mut structure, &HydroConfig::default()).unwrap_err();
+        assert!(matches!(
+            err,
+            Error::IncompleteResidueForHydro { res_id: 20, .. }
+        ));
+    }
+
+    #[test]
+    fn report_lists_added_and_skipped_hydrogens_per_residue() {
+        let ala = residue_from_template("ALA", StandardResidue::ALA, 1);
+        let mut ser = residue_from_template("SER", StandardResidue::SER, 2);
+        ser.remove_atom("OG");
+        let mut structure = structure_with_residues(vec![ala, ser]);
+
+        let report = add_hydrogens_with_report(&mut structure, &HydroConfig::default());
+
+        assert_eq!(report.residues.len(), 2);
+        let ala = &report.residues[0];
+        assert_eq!(ala.chain_id, "A");
+        assert_eq!(ala.residue_id, 1);
+        assert!(ala.is_complete());
+        assert!(ala.added.iter().any(|h| h == "HA"));
+        assert!(ala.added.iter().any(|h| h == "HB1"));
+
+        let ser = &report.residues[1];
+        assert!(!ser.is_complete());
+        let skipped: Vec<&str> = ser.skipped.iter().map(|s| s.name.as_str()).collect();
+        assert_eq!(skipped, vec!["HB2", "HB3", "HG"]);
+        assert!(ser.skipped.iter().all(|s| s.missing_anchor == "OG"));
+        assert!(ser.added.iter().any(|h| h == "HA"));
+        assert_eq!(report.added_count(), ala.added.len() + ser.added.len());
+        assert_eq!(report.skipped_count(), 3);
+        assert!(!report.is_complete());
+    }
+
+    #[test]
+    fn report_flags_residues_without_template() {
+        let mut residue = Residue::new(
+            7,
+            None,
+            "XYZ",
+            Some(StandardResidue::ALA),
+            ResidueCategory::Standard,
+        );
+        residue.add_atom(Atom::new("CA", Element::C, Point::origin()));
+        let mut structure = structure_with_residue(residue);
+
+        let report = add_hydrogens_with_report(&mut structure, &HydroConfig::default());
+
+        let missing: Vec<_> = report.missing_templates().collect();
+        assert_eq!(missing.len(), 1);
+        assert_eq!(missing[0].residue_name, "XYZ");
+        assert!(missing[0].added.is_empty());
+
+        let err = add_hydrogens(&mut structure, &HydroConfig::default()).unwrap_err();
+        assert!(matches!(err, Error::MissingInternalTemplate { res_name } if res_name == "XYZ"));
+    }
+
+    #[test]
+    fn report_diagnostics_warn_about_skipped_hydrogens_and_missing_templates() {
+        let mut ser = residue_from_template("SER", StandardResidue::SER, 2);
+        ser.remove_atom("OG");
+        let mut unknown = Residue::new(
+            3,
+            None,
+            "XYZ",
+            Some(StandardResidue::ALA),
+            ResidueCategory::Standard,
+        );
+        unknown.add_atom(Atom::new("CA", Element::C, Point::new(9.0, 9.0, 9.0)));
+        let mut structure = structure_with_residues(vec![ser, unknown]);
+
+        let diagnostics =
+            add_hydrogens_with_report(&mut structure, &HydroConfig::default()).diagnostics();
+
+        let messages: Vec<String> = diagnostics.iter().map(|d| d.to_string()).collect();
+        assert_eq!(
+            messages,
+            vec![
+                "warning [hydrogens] A:2: hydrogen HB2 not placed: anchor OG is missing",
+                "warning [hydrogens] A:2: hydrogen HB3 not placed: anchor OG is missing",
+                "warning [hydrogens] A:2: hydrogen HG not placed: anchor OG is missing",
+                "warning [hydrogens] A:3: no internal template for XYZ; hydrogens not added",
+            ]
+        );
+    }
+
+    #[test]
+    fn add_hydrogens_with_diagnostics_matches_report_diagnostics() {
+        let mut ser = residue_from_template("SER", StandardResidue::SER, 2);
+        ser.remove_atom("OG");
+        let mut structure = structure_with_residues(vec![ser]);
+
+        let (report, diagnostics) =
+            add_hydrogens_with_diagnostics(&mut structure, &HydroConfig::default());
+
+        assert_eq!(report.skipped_count(), 3);
+        assert_eq!(diagnostics, report.diagnostics());
+        assert_eq!(diagnostics.max_severity(), Some(Severity::Warning));
     }
 
     #[test]
@@ -2468,9 +2991,49 @@ mod tests {
     }
 
     #[test]
-    fn hydro_config_defaults_to_remove_existing_h() {
+    fn add_hydrogens_is_idempotent() {
+        for replace_existing in [true, false] {
+            let residues = vec![
+                n_terminal_residue(1),
+                residue_from_template("SER", StandardResidue::SER, 2),
+                c_terminal_residue(3),
+            ];
+            let mut structure = structure_with_residues(residues);
+            let config = HydroConfig {
+                replace_existing,
+                ..HydroConfig::default()
+            };
+
+            add_hydrogens(&mut structure, &config).unwrap();
+            let first: Vec<usize> = structure
+                .iter_chains()
+                .flat_map(|c| c.iter_residues())
+                .map(|r| r.atom_count())
+                .collect();
+
+            let report = add_hydrogens_with_report(&mut structure, &config);
+            let second: Vec<usize> = structure
+                .iter_chains()
+                .flat_map(|c| c.iter_residues())
+                .map(|r| r.atom_count())
+                .collect();
+
+            assert_eq!(first, second, "replace_existing = {replace_existing}");
+            assert!(report.is_complete());
+            for residue in structure.iter_chains().flat_map(|c| c.iter_residues()) {
+                let names: HashSet<&str> = residue.atoms().iter().map(|a| &*a.name).collect();
+                assert_eq!(names.len(), residue.atom_count(), "duplicate atom names");
+            }
+            if !replace_existing {
+                assert_eq!(report.added_count(), 0);
+            }
+        }
+    }
+
+    #[test]
+    fn hydro_config_defaults_to_keeping_existing_h() {
         let config = HydroConfig::default();
-        assert!(config.remove_existing_h, "default should remove existing H");
+        assert!(!config.replace_existing, "default should keep existing H");
     }
 
     #[test]
@@ -2637,6 +3200,69 @@ mod tests {
         assert_eq!(asp.name, "ASP", "ASP should remain deprotonated");
     }
 
+    fn alanine_with_water_near_ha() -> Structure {
+        let mut probe =
+            structure_with_residue(residue_from_template("ALA", StandardResidue::ALA, 1));
+        add_hydrogens(&mut probe, &HydroConfig::default()).unwrap();
+        let ala = probe.find_residue("A", 1, None).unwrap();
+        let (ca, ha) = (ala.atom("CA").unwrap().pos, ala.atom("HA").unwrap().pos);
+        let water_pos = ha + (ha - ca).normalize() * 1.0;
+
+        let mut water = Residue::new(
+            2,
+            None,
+            "HOH",
+            Some(StandardResidue::HOH),
+            ResidueCategory::Hetero,
+        );
+        water.add_atom(Atom::new("O", Element::O, water_pos));
+        let mut structure =
+            structure_with_residue(residue_from_template("ALA", StandardResidue::ALA, 1));
+        structure.chain_mut("A").unwrap().add_residue(water);
+        structure
+    }
+
+    #[test]
+    fn check_clashes_reports_hydrogens_near_non_bonded_heavy_atoms() {
+        let mut structure = alanine_with_water_near_ha();
+        let config = HydroConfig {
+            check_clashes: true,
+            ..HydroConfig::default()
+        };
+
+        let report = add_hydrogens_with_report(&mut structure, &config);
+
+        assert_eq!(report.clash_count(), 1);
+        assert_eq!(
+            report.residues[0].clashes,
+            vec![HydrogenClash {
+                hydrogen: "HA".into(),
+                chain_id: "A".into(),
+                residue_id: 2,
+                insertion_code: None,
+                atom_name: "O".into(),
+            }]
+        );
+        assert!(report.is_complete());
+    }
+
+    #[test]
+    fn check_clashes_is_off_by_default_and_ignores_bonded_neighbors() {
+        let mut crowded = alanine_with_water_near_ha();
+        let report = add_hydrogens_with_report(&mut crowded, &HydroConfig::default());
+        assert_eq!(report.clash_count(), 0);
+
+        let mut isolated =
+            structure_with_residue(residue_from_template("ALA", StandardResidue::ALA, 1));
+        let config = HydroConfig {
+            check_clashes: true,
+            ..HydroConfig::default()
+        };
+        let report = add_hydrogens_with_report(&mut isolated, &config);
+        assert!(report.added_count() > 0);
+        assert_eq!(report.clash_count(), 0);
+    }
+
     #[test]
     fn full_pipeline_with_all_options_disabled() {
         let mut his = residue_from_template("HID", StandardResidue::HIS, 1);
@@ -2645,9 +3271,10 @@ mod tests {
 
         let config = HydroConfig {
             target_ph: None,
-            remove_existing_h: false,
+            replace_existing: false,
             his_salt_bridge_protonation: false,
             his_strategy: HisStrategy::DirectHIE,
+            check_clashes: false,
         };
 
         add_hydrogens(&mut structure, &config).unwrap();
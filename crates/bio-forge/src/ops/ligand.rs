@@ -0,0 +1,267 @@
+//! Classic drug-likeness descriptors for individual ligand residues.
+//!
+//! The descriptors are derived purely from a [`Topology`]: atom elements, bond orders, and
+//! bond graph connectivity. No partial charges or 3D geometry are consulted, so the counts
+//! are stable across conformers and suitable for quick triage of hetero residues.
+
+use crate::model::{
+    topology::{Bond, Topology},
+    types::{BondOrder, Element},
+};
+
+/// Descriptor summary produced by [`ligand_descriptors`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct LigandDescriptors {
+    /// Number of non-hydrogen atoms in the residue.
+    pub heavy_atoms: usize,
+    /// Single, non-ring bonds between two non-terminal heavy atoms of the residue.
+    pub rotatable_bonds: usize,
+    /// Size of the smallest set of smallest rings of the residue's bond graph.
+    pub rings: usize,
+    /// Nitrogen and oxygen atoms carrying at least one bonded hydrogen.
+    pub hbond_donors: usize,
+    /// Nitrogen and oxygen atoms.
+    pub hbond_acceptors: usize,
+}
+
+/// Computes heavy-atom, rotatable-bond, ring, and hydrogen-bond descriptors for one residue.
+///
+/// Only bonds with both endpoints inside the residue are considered, so links to
+/// neighboring residues neither contribute rotatable bonds or rings nor make a terminal
+/// atom non-terminal. Coordination bonds are ignored, so chelated metals add no rings and
+/// do not make coordinating atoms non-terminal. Ring membership still reflects the whole
+/// topology. The ring count equals the number of rings in the smallest set of smallest
+/// rings, obtained as the cyclomatic number `bonds - atoms + components` of the residue's
+/// bond graph. Donor perception relies on explicit hydrogens; add them first for
+/// heavy-atom-only inputs.
+///
+/// # Arguments
+///
+/// * `topology` - Topology containing the residue and its bonds.
+/// * `residue` - `(chain_id, residue_id, insertion_code)` of the residue to describe.
+///
+/// # Returns
+///
+/// `Some(LigandDescriptors)` for the matching residue, or `None` when no residue in the
+/// topology has that chain, residue identifier, and insertion code.
+pub fn ligand_descriptors(
+    topology: &Topology,
+    residue: (&str, i32, Option<char>),
+) -> Option<LigandDescriptors> {
+    let (chain_id, residue_id, insertion_code) = residue;
+    let elements: Vec<Option<Element>> = topology
+        .structure()
+        .iter_atoms_with_context()
+        .map(|(chain, res, atom)| {
+            (chain.id == chain_id && res.id == residue_id && res.insertion_code == insertion_code)
+                .then_some(atom.element)
+        })
+        .collect();
+
+    let atom_count = elements.iter().flatten().count();
+    if atom_count == 0 {
+        return None;
+    }
+
+    let in_ring = topology.ring_bond_flags();
+    let (internal, internal_in_ring): (Vec<&Bond>, Vec<bool>) = topology
+        .bonds()
+        .iter()
+        .zip(in_ring)
+        .filter(|(b, _)| b.a1_idx != b.a2_idx && b.order != BondOrder::Coordination)
+        .filter(|(b, _)| elements[b.a1_idx].is_some() && elements[b.a2_idx].is_some())
+        .unzip();
+
+    let mut has_hydrogen = vec![false; elements.len()];
+    for bond in &internal {
+        if elements[bond.a2_idx] == Some(Element::H) {
+            has_hydrogen[bond.a1_idx] = true;
+        }
+        if elements[bond.a1_idx] == Some(Element::H) {
+            has_hydrogen[bond.a2_idx] = true;
+        }
+    }
+
+    let polar = |element: &Option<Element>| matches!(element, Some(Element::N | Element::O));
+    let hbond_donors = elements
+        .iter()
+        .zip(&has_hydrogen)
+        .filter(|(element, h)| polar(element) && **h)
+        .count();
+    let hbond_acceptors = elements.iter().filter(|e| polar(e)).count();
+    let heavy_atoms = elements
+        .iter()
+        .flatten()
+        .filter(|e| e.is_heavy_atom())
+        .count();
+
+    let is_heavy = |idx: usize| elements[idx].is_some_and(|e| e.is_heavy_atom());
+    let mut heavy_degree = vec![0usize; elements.len()];
+    for bond in internal
+        .iter()
+        .filter(|b| is_heavy(b.a1_idx) && is_heavy(b.a2_idx))
+    {
+        heavy_degree[bond.a1_idx] += 1;
+        heavy_degree[bond.a2_idx] += 1;
+    }
+    let rotatable_bonds = internal
+        .iter()
+        .zip(&internal_in_ring)
+        .filter(|(b, in_ring)| {
+            b.order == BondOrder::Single
+                && !**in_ring
+                && heavy_degree[b.a1_idx] > 1
+                && heavy_degree[b.a2_idx] > 1
+        })
+        .count();
+
+    Some(LigandDescriptors {
+        heavy_atoms,
+        rotatable_bonds,
+        rings: internal.len() + component_count(&elements, &internal) - atom_count,
+        hbond_donors,
+        hbond_acceptors,
+    })
+}
+
+/// Counts the connected components formed by the residue atoms and their internal bonds.
+fn component_count(elements: &[Option<Element>], bonds: &[&Bond]) -> usize {
+    let mut parent: Vec<usize> = (0..elements.len()).collect();
+    let mut components = elements.iter().flatten().count();
+    for bond in bonds {
+        let (a, b) = (
+            find(&mut parent, bond.a1_idx),
+            find(&mut parent, bond.a2_idx),
+        );
+        if a != b {
+            parent[a] = b;
+            components -= 1;
+        }
+    }
+    components
+}
+
+/// Returns the union-find root of `x`, halving the path along the way.
+fn find(parent: &mut [usize], mut x: usize) -> usize {
+    while parent[x] != x {
+        parent[x] = parent[parent[x]];
+        x = parent[x];
+    }
+    x
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::{
+        atom::Atom,
+        chain::Chain,
+        residue::Residue,
+        structure::Structure,
+        types::{Point, ResidueCategory},
+    };
+
+    /// Phenol (ring + OH) followed by an ethyl-amine side residue in the same chain.
+    fn phenol_topology() -> Topology {
+        let mut phenol = Residue::new(1, None, "IPH", None, ResidueCategory::Hetero);
+        for i in 1..=6 {
+            phenol.add_atom(Atom::new(&format!("C{i}"), Element::C, Point::origin()));
+        }
+        phenol.add_atom(Atom::new("O7", Element::O, Point::origin()));
+        phenol.add_atom(Atom::new("HO7", Element::H, Point::origin()));
+
+        let mut amine = Residue::new(2, None, "EAM", None, ResidueCategory::Hetero);
+        amine.add_atom(Atom::new("C1", Element::C, Point::origin()));
+        amine.add_atom(Atom::new("C2", Element::C, Point::origin()));
+        amine.add_atom(Atom::new("C3", Element::C, Point::origin()));
+        amine.add_atom(Atom::new("N4", Element::N, Point::origin()));
+
+        let mut chain = Chain::new("L");
+        chain.add_residue(phenol);
+        chain.add_residue(amine);
+        let structure: Structure = std::iter::once(chain).collect();
+
+        let mut bonds: Vec<Bond> = (0..6)
+            .map(|i| Bond::new(i, (i + 1) % 6, BondOrder::Aromatic))
+            .collect();
+        bonds.push(Bond::new(0, 6, BondOrder::Single));
+        bonds.push(Bond::new(6, 7, BondOrder::Single));
+        bonds.push(Bond::new(8, 9, BondOrder::Single));
+        bonds.push(Bond::new(9, 10, BondOrder::Single));
+        bonds.push(Bond::new(10, 11, BondOrder::Single));
+        Topology::new(structure, bonds)
+    }
+
+    #[test]
+    fn ligand_descriptors_reports_ring_and_polar_counts() {
+        let topology = phenol_topology();
+
+        let descriptors = ligand_descriptors(&topology, ("L", 1, None)).expect("residue");
+
+        assert_eq!(
+            descriptors,
+            LigandDescriptors {
+                heavy_atoms: 7,
+                rotatable_bonds: 0,
+                rings: 1,
+                hbond_donors: 1,
+                hbond_acceptors: 1,
+            }
+        );
+    }
+
+    #[test]
+    fn ligand_descriptors_counts_rotatable_bonds_of_acyclic_chains() {
+        let topology = phenol_topology();
+
+        let descriptors = ligand_descriptors(&topology, ("L", 2, None)).expect("residue");
+
+        assert_eq!(descriptors.heavy_atoms, 4);
+        assert_eq!(descriptors.rotatable_bonds, 1);
+        assert_eq!(descriptors.rings, 0);
+        assert_eq!(descriptors.hbond_donors, 0);
+        assert_eq!(descriptors.hbond_acceptors, 1);
+    }
+
+    #[test]
+    fn ligand_descriptors_ignore_metal_coordination_within_the_residue() {
+        let mut complex = Residue::new(1, None, "ZAC", None, ResidueCategory::Hetero);
+        for (name, element) in [
+            ("C1", Element::C),
+            ("C2", Element::C),
+            ("O1", Element::O),
+            ("O2", Element::O),
+            ("ZN", Element::Zn),
+        ] {
+            complex.add_atom(Atom::new(name, element, Point::origin()));
+        }
+        let mut chain = Chain::new("L");
+        chain.add_residue(complex);
+        let structure: Structure = std::iter::once(chain).collect();
+        let topology = Topology::new(
+            structure,
+            vec![
+                Bond::new(0, 1, BondOrder::Single),
+                Bond::new(1, 2, BondOrder::Single),
+                Bond::new(1, 3, BondOrder::Double),
+                Bond::new(2, 4, BondOrder::Coordination),
+                Bond::new(3, 4, BondOrder::Coordination),
+            ],
+        );
+
+        let descriptors = ligand_descriptors(&topology, ("L", 1, None)).expect("residue");
+
+        assert_eq!(descriptors.rotatable_bonds, 0);
+        assert_eq!(descriptors.rings, 0);
+        assert_eq!(descriptors.heavy_atoms, 5);
+    }
+
+    #[test]
+    fn ligand_descriptors_returns_none_for_unknown_residue() {
+        let topology = phenol_topology();
+
+        assert!(ligand_descriptors(&topology, ("L", 3, None)).is_none());
+        assert!(ligand_descriptors(&topology, ("A", 1, None)).is_none());
+        assert!(ligand_descriptors(&topology, ("L", 1, Some('A'))).is_none());
+    }
+}
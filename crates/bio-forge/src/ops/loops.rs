@@ -0,0 +1,324 @@
+//! Fills numbering gaps in protein chains with crude placeholder residues.
+//!
+//! Missing loops are detected through [`Structure::chain_breaks`] and rebuilt by placing
+//! template residues whose CA atoms are linearly interpolated between the flanking CA
+//! atoms. The resulting geometry is only a starting point for refinement, so every
+//! synthesized atom carries a configurable (low) occupancy that marks it as modeled.
+
+use crate::db;
+use crate::model::{
+    atom::Atom,
+    residue::Residue,
+    structure::{ChainBreak, Structure},
+    types::{Point, ResidueCategory, ResiduePosition},
+};
+use crate::ops::error::Error;
+use nalgebra::{Rotation3, Vector3};
+
+/// Parameters controlling placeholder loop construction.
+#[derive(Debug, Clone)]
+pub struct LoopConfig {
+    /// Template residue name used for every inserted placeholder.
+    pub residue_name: String,
+    /// Longest gap (in missing residues) that will be filled; longer gaps are skipped.
+    pub max_gap: usize,
+    /// Occupancy assigned to synthesized atoms so they can be identified downstream.
+    pub occupancy: f64,
+}
+
+impl Default for LoopConfig {
+    /// Inserts glycine placeholders for gaps of up to 20 residues with zero occupancy.
+    fn default() -> Self {
+        Self {
+            residue_name: "GLY".to_string(),
+            max_gap: 20,
+            occupancy: 0.0,
+        }
+    }
+}
+
+/// Inserts placeholder residues for numbering gaps between present protein residues.
+///
+/// Only breaks between two protein residues whose numbering skips identifiers are filled.
+/// Nucleic acid chains, breaks that involve non-protein residues, and distance-only breaks
+/// (contiguous numbering, including insertion-code steps such as `52` → `52A`) are left
+/// untouched.
+/// Each missing residue is instantiated from the configured template's heavy atoms, oriented
+/// so that its N→C axis follows the gap direction, and translated so that its CA lies on the
+/// straight line connecting the flanking CA atoms.
+///
+/// # Arguments
+///
+/// * `structure` - Mutable structure whose chains will receive placeholder residues.
+/// * `config` - Loop-building parameters (template, gap limit, marker occupancy).
+///
+/// # Returns
+///
+/// `Ok(())` when all eligible gaps have been filled.
+///
+/// # Errors
+///
+/// Returns [`Error::MissingInternalTemplate`] when the configured residue has no template,
+/// or [`Error::InvalidLoopTemplate`] when that template is not a protein residue with a CA
+/// atom.
+pub fn build_missing_loops(structure: &mut Structure, config: &LoopConfig) -> Result<(), Error> {
+    let template =
+        db::get_template(&config.residue_name).ok_or_else(|| Error::MissingInternalTemplate {
+            res_name: config.residue_name.clone(),
+        })?;
+
+    let invalid_template = || Error::InvalidLoopTemplate {
+        res_name: config.residue_name.clone(),
+    };
+    if !template.standard_name().is_protein() {
+        return Err(invalid_template());
+    }
+
+    let tmpl_atoms: Vec<_> = template.heavy_atoms().collect();
+    let tmpl_pos = |name: &str| {
+        tmpl_atoms
+            .iter()
+            .find(|(n, _, _)| *n == name)
+            .map(|(_, _, p)| *p)
+    };
+    let tmpl_ca = tmpl_pos("CA").ok_or_else(invalid_template)?;
+    let tmpl_axis = match (tmpl_pos("N"), tmpl_pos("C")) {
+        (Some(n), Some(c)) => c - n,
+        _ => Vector3::x(),
+    };
+
+    let gaps: Vec<ChainBreak> = structure
+        .chain_breaks()
+        .into_iter()
+        .filter(|b| (1..=config.max_gap).contains(&b.missing_residue_count()))
+        .collect();
+
+    for gap in gaps {
+        let Some(chain) = structure.chain_mut(&gap.chain_id) else {
+            continue;
+        };
+
+        let Some(next_idx) = chain
+            .residues()
+            .windows(2)
+            .position(|w| {
+                (w[0].id, w[0].insertion_code) == (gap.prev_residue_id, gap.prev_insertion_code)
+                    && (w[1].id, w[1].insertion_code)
+                        == (gap.next_residue_id, gap.next_insertion_code)
+            })
+            .map(|i| i + 1)
+        else {
+            continue;
+        };
+
+        let prev = &chain.residues()[next_idx - 1];
+        let next = &chain.residues()[next_idx];
+        if !(prev.standard_name.is_some_and(|s| s.is_protein())
+            && next.standard_name.is_some_and(|s| s.is_protein()))
+        {
+            continue;
+        }
+        let (Some(start), Some(end)) = (prev.atom("CA"), next.atom("CA")) else {
+            continue;
+        };
+        let (start, end) = (start.pos, end.pos);
+
+        let rotation = Rotation3::rotation_between(&tmpl_axis, &(end - start))
+            .unwrap_or_else(Rotation3::identity);
+        let missing = gap.missing_residue_count();
+
+        for step in 1..=missing {
+            let fraction = step as f64 / (missing + 1) as f64;
+            let ca_pos = start + (end - start) * fraction;
+
+            let mut residue = Residue::new(
+                gap.prev_residue_id + step as i32,
+                None,
+                template.name(),
+                Some(template.standard_name()),
+                ResidueCategory::Standard,
+            );
+            residue.position = ResiduePosition::Internal;
+
+            for (name, element, pos) in &tmpl_atoms {
+                let placed: Point = ca_pos + rotation * (pos - tmpl_ca);
+                let mut atom = Atom::new(name, *element, placed);
+                atom.occupancy = config.occupancy;
+                residue.add_atom(atom);
+            }
+
+            chain.insert_residue(next_idx + step - 1, residue);
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::{
+        chain::Chain,
+        types::{Element, StandardResidue},
+    };
+
+    fn anchored_residue(id: i32, ca: Point) -> Residue {
+        let mut residue = Residue::new(
+            id,
+            None,
+            "ALA",
+            Some(StandardResidue::ALA),
+            ResidueCategory::Standard,
+        );
+        residue.position = ResiduePosition::Internal;
+        residue.add_atom(Atom::new(
+            "N",
+            Element::N,
+            ca + Vector3::new(-1.46, 0.0, 0.0),
+        ));
+        residue.add_atom(Atom::new("CA", Element::C, ca));
+        residue.add_atom(Atom::new(
+            "C",
+            Element::C,
+            ca + Vector3::new(1.52, 0.0, 0.0),
+        ));
+        residue
+    }
+
+    fn anchored_residue_with_icode(id: i32, icode: char, ca: Point) -> Residue {
+        let mut residue = anchored_residue(id, ca);
+        residue.insertion_code = Some(icode);
+        residue
+    }
+
+    fn gapped_structure(prev_id: i32, next_id: i32, distance: f64) -> Structure {
+        let mut chain = Chain::new("A");
+        chain.add_residue(anchored_residue(prev_id, Point::origin()));
+        chain.add_residue(anchored_residue(next_id, Point::new(distance, 0.0, 0.0)));
+        std::iter::once(chain).collect()
+    }
+
+    #[test]
+    fn build_missing_loops_fills_numbering_gap_with_interpolated_residues() {
+        let mut structure = gapped_structure(10, 14, 15.2);
+
+        build_missing_loops(&mut structure, &LoopConfig::default()).unwrap();
+
+        let chain = structure.chain("A").unwrap();
+        let ids: Vec<i32> = chain.iter_residues().map(|r| r.id).collect();
+        assert_eq!(ids, vec![10, 11, 12, 13, 14]);
+
+        for (step, residue) in chain.residues()[1..4].iter().enumerate() {
+            assert_eq!(residue.name, "GLY");
+            assert_eq!(residue.standard_name, Some(StandardResidue::GLY));
+            let ca = residue.atom("CA").expect("placeholder CA");
+            let expected_x = 15.2 * (step + 1) as f64 / 4.0;
+            assert!((ca.pos - Point::new(expected_x, 0.0, 0.0)).norm() < 1e-6);
+        }
+    }
+
+    #[test]
+    fn build_missing_loops_marks_created_atoms_with_configured_occupancy() {
+        let mut structure = gapped_structure(1, 3, 7.6);
+        let config = LoopConfig {
+            occupancy: 0.01,
+            ..LoopConfig::default()
+        };
+
+        build_missing_loops(&mut structure, &config).unwrap();
+
+        let chain = structure.chain("A").unwrap();
+        let inserted = chain.residue(2, None).expect("inserted residue");
+        assert!(!inserted.is_empty());
+        assert!(inserted.iter_atoms().all(|a| a.occupancy == 0.01));
+        assert!(
+            chain
+                .residue(1, None)
+                .unwrap()
+                .iter_atoms()
+                .all(|a| a.occupancy == 1.0)
+        );
+    }
+
+    #[test]
+    fn build_missing_loops_skips_gaps_longer_than_limit() {
+        let mut structure = gapped_structure(1, 30, 20.0);
+        let config = LoopConfig {
+            max_gap: 5,
+            ..LoopConfig::default()
+        };
+
+        build_missing_loops(&mut structure, &config).unwrap();
+
+        assert_eq!(structure.residue_count(), 2);
+    }
+
+    #[test]
+    fn build_missing_loops_ignores_distance_only_breaks() {
+        let mut structure = gapped_structure(1, 2, 12.0);
+
+        build_missing_loops(&mut structure, &LoopConfig::default()).unwrap();
+
+        assert_eq!(structure.residue_count(), 2);
+    }
+
+    #[test]
+    fn build_missing_loops_errors_for_unknown_template() {
+        let mut structure = gapped_structure(1, 3, 7.6);
+        let config = LoopConfig {
+            residue_name: "XYZ".to_string(),
+            ..LoopConfig::default()
+        };
+
+        let err = build_missing_loops(&mut structure, &config).unwrap_err();
+
+        assert!(matches!(err, Error::MissingInternalTemplate { res_name } if res_name == "XYZ"));
+    }
+
+    #[test]
+    fn build_missing_loops_errors_for_non_protein_template() {
+        let mut structure = gapped_structure(1, 3, 7.6);
+        let config = LoopConfig {
+            residue_name: "DA".to_string(),
+            ..LoopConfig::default()
+        };
+
+        let err = build_missing_loops(&mut structure, &config).unwrap_err();
+
+        assert!(matches!(err, Error::InvalidLoopTemplate { res_name } if res_name == "DA"));
+        assert_eq!(structure.residue_count(), 2);
+    }
+
+    #[test]
+    fn build_missing_loops_matches_gap_flanks_by_insertion_code() {
+        let mut chain = Chain::new("A");
+        chain.add_residue(anchored_residue(52, Point::origin()));
+        chain.add_residue(anchored_residue_with_icode(
+            52,
+            'A',
+            Point::new(3.8, 0.0, 0.0),
+        ));
+        chain.add_residue(anchored_residue(55, Point::new(15.2, 0.0, 0.0)));
+        let mut structure: Structure = std::iter::once(chain).collect();
+
+        build_missing_loops(&mut structure, &LoopConfig::default()).unwrap();
+
+        let chain = structure.chain("A").unwrap();
+        let keys: Vec<(i32, Option<char>)> = chain
+            .iter_residues()
+            .map(|r| (r.id, r.insertion_code))
+            .collect();
+        assert_eq!(
+            keys,
+            vec![
+                (52, None),
+                (52, Some('A')),
+                (53, None),
+                (54, None),
+                (55, None)
+            ]
+        );
+        let first = chain.residue(53, None).unwrap().atom("CA").unwrap();
+        assert!((first.pos - Point::new(3.8 + 11.4 / 3.0, 0.0, 0.0)).norm() < 1e-6);
+    }
+}
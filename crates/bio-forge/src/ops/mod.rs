@@ -1,28 +1,75 @@
 //! High-level operations that clean, repair, solvate, and otherwise prepare structures.
 //!
 //! This module groups together the public entry points for structure processing:
-//! cleaning, hydrogen addition, repairs, solvation, coordinate transforms, and
-//! topology reconstruction. Each submodule exposes a cohesive API and shares a
-//! common error type so downstream consumers can compose workflows easily.
+//! cleaning, hydrogen addition, repairs, solvation, coordinate transforms, structure
+//! comparison, contact analysis, ligand descriptors, atom and water renaming, and topology
+//! reconstruction. Each submodule exposes a cohesive API and shares a common error type so
+//! downstream consumers can compose workflows easily; operations that can recover from
+//! problems also report them through the shared [`Diagnostics`] collector.
 
+mod atom_names;
+mod backbone;
+mod bond_orders;
+mod charges;
 mod clean;
+mod compare;
+mod contact;
+mod diagnostics;
 mod error;
 mod hydro;
+mod ligand;
+mod loops;
+mod rdf;
 mod repair;
+mod rmsd;
 mod solvate;
+mod symmetry;
 mod topology;
 mod transform;
+mod water;
 
-pub use clean::{CleanConfig, clean_structure};
+pub use atom_names::{AtomRename, normalize_atom_names};
 
-pub use repair::repair_structure;
+pub use backbone::retain_backbone;
 
-pub use hydro::{HisStrategy, HydroConfig, add_hydrogens};
+pub use bond_orders::perceive_bond_orders;
 
-pub use solvate::{Anion, Cation, SolvateConfig, solvate_structure};
+pub use charges::{ChargeReport, assign_partial_charges};
 
-pub use transform::Transform;
+pub use clean::{CleanConfig, clean_structure, clean_structure_with_diagnostics};
 
-pub use topology::TopologyBuilder;
+pub use compare::{AtomKey, CompareReport, ResidueDeviation, compare};
+
+pub use contact::{ContactMap, ContactMode, ResidueKey, contact_map};
+
+pub use diagnostics::{Diagnostic, Diagnostics, Severity};
+
+pub use repair::{repair_structure, repair_structure_with_diagnostics};
+
+pub use hydro::{
+    HisStrategy, HydroConfig, HydroReport, HydrogenClash, ResidueHydroReport, SkippedHydrogen,
+    add_hydrogens, add_hydrogens_with_diagnostics, add_hydrogens_with_report,
+};
+
+pub use ligand::{LigandDescriptors, ligand_descriptors};
+
+pub use loops::{LoopConfig, build_missing_loops};
+
+pub use rdf::radial_distribution;
+
+pub use rmsd::{rmsd, rmsd_all};
+
+pub use solvate::{
+    Anion, Cation, SolvateConfig, SolvationReport, SolventSpec, WaterOrientation,
+    solvate_structure, solvate_structure_with_report,
+};
+
+pub use symmetry::{apply_symmetry, build_assembly};
+
+pub use transform::{PreparedRotation, Transform};
+
+pub use topology::{AltLocPolicy, StretchedBond, TopologyBuilder, TopologyWarning, UnknownPolicy};
+
+pub use water::{WaterNaming, standardize_water};
 
 pub use error::Error;
@@ -0,0 +1,279 @@
+//! Radial distribution functions between atom selections.
+//!
+//! The radial distribution function g(r) compares the observed pair density at distance `r`
+//! with the density expected for an ideal gas occupying the same volume. Pair finding uses
+//! the spatial [`Grid`] so large solvated systems remain tractable. When the structure
+//! defines periodic box vectors, pairs are measured with minimum-image distances.
+
+use crate::model::{grid::Grid, pbc, structure::Structure, types::Point};
+use crate::utils::parallel::*;
+use std::collections::HashSet;
+
+/// Computes the radial distribution function g(r) between two atom selections.
+///
+/// Selections are global atom indices following [`Structure::iter_atoms`] order; indices
+/// beyond the atom count are ignored. Pairs referring to the same atom are always excluded,
+/// which makes the self-RDF (`selection_a == selection_b`) well defined. The ideal-gas
+/// reference density is derived from `box_vectors`, falling back to the axis-aligned
+/// bounding box of the structure when no box is defined. With a box, every pair is counted
+/// once at its minimum-image distance, so `r_max` should not exceed half the shortest box
+/// width.
+///
+/// # Arguments
+///
+/// * `structure` - Structure providing atom coordinates and periodic box.
+/// * `selection_a` - Global indices of the reference atoms.
+/// * `selection_b` - Global indices of the partner atoms.
+/// * `r_max` - Maximum pair distance in ångströms.
+/// * `bins` - Number of equally spaced histogram bins covering `[0, r_max)`.
+///
+/// # Returns
+///
+/// A vector of length `bins` containing g(r) evaluated at each shell; all zeros when the
+/// selections, the volume, or `r_max` are degenerate.
+pub fn radial_distribution(
+    structure: &Structure,
+    selection_a: &[usize],
+    selection_b: &[usize],
+    r_max: f64,
+    bins: usize,
+) -> Vec<f64> {
+    let mut rdf = vec![0.0; bins];
+    if bins == 0 || r_max <= 0.0 {
+        return rdf;
+    }
+
+    let positions: Vec<Point> = structure.iter_atoms().map(|a| a.pos).collect();
+    let pick = |selection: &[usize]| -> Vec<usize> {
+        selection
+            .iter()
+            .copied()
+            .filter(|&i| i < positions.len())
+            .collect()
+    };
+    let sel_a = pick(selection_a);
+    let sel_b = pick(selection_b);
+
+    let set_b: HashSet<usize> = sel_b.iter().copied().collect();
+    let overlap = sel_a.iter().filter(|i| set_b.contains(i)).count();
+    let pair_count = sel_a.len() * sel_b.len() - overlap;
+
+    let volume = structure
+        .box_volume()
+        .filter(|v| *v > 0.0)
+        .unwrap_or_else(|| bounding_volume(&positions));
+    if pair_count == 0 || volume <= 0.0 {
+        return rdf;
+    }
+
+    let bin_width = r_max / bins as f64;
+    let bin_of = |distance: f64| {
+        let bin = (distance / bin_width) as usize;
+        (bin < bins).then_some(bin)
+    };
+
+    let hits: Vec<usize> = match structure.box_vectors {
+        Some(box_vectors) => {
+            let wrapped: Vec<Point> = positions
+                .iter()
+                .map(|p| pbc::wrap_into_cell(p, &box_vectors))
+                .collect();
+            let images = sel_b.iter().flat_map(|&j| {
+                let origin = wrapped[j];
+                pbc::neighbor_translations(&box_vectors).map(move |t| (origin + t, j))
+            });
+            let grid = Grid::new(images, r_max);
+
+            sel_a
+                .par_iter()
+                .flat_map_iter(|&i| {
+                    let center = wrapped[i];
+                    let mut partners: Vec<usize> = grid
+                        .neighbors(&center, r_max)
+                        .exact()
+                        .map(|(_, &j)| j)
+                        .filter(|&j| j != i)
+                        .collect();
+                    partners.sort_unstable();
+                    partners.dedup();
+                    partners
+                        .into_iter()
+                        .filter_map(|j| {
+                            bin_of(pbc::minimum_image_distance(
+                                &center,
+                                &wrapped[j],
+                                &box_vectors,
+                            ))
+                        })
+                        .collect::<Vec<_>>()
+                })
+                .collect()
+        }
+        None => {
+            let grid = Grid::new(sel_b.iter().map(|&j| (positions[j], j)), r_max);
+
+            sel_a
+                .par_iter()
+                .flat_map_iter(|&i| {
+                    let center = positions[i];
+                    grid.neighbors(&center, r_max)
+                        .exact()
+                        .filter(move |&(_, &j)| j != i)
+                        .filter_map(|(pos, _)| bin_of((pos - center).norm()))
+                        .collect::<Vec<_>>()
+                })
+                .collect()
+        }
+    };
+
+    let mut counts = vec![0usize; bins];
+    for bin in hits {
+        counts[bin] += 1;
+    }
+
+    let shell_factor = 4.0 / 3.0 * std::f64::consts::PI;
+    for (k, value) in rdf.iter_mut().enumerate() {
+        let r_lo = k as f64 * bin_width;
+        let r_hi = r_lo + bin_width;
+        let shell_volume = shell_factor * (r_hi.powi(3) - r_lo.powi(3));
+        *value = counts[k] as f64 * volume / (pair_count as f64 * shell_volume);
+    }
+
+    rdf
+}
+
+/// Computes the volume of the axis-aligned box enclosing the given points.
+fn bounding_volume(positions: &[Point]) -> f64 {
+    let Some(first) = positions.first() else {
+        return 0.0;
+    };
+    let (min, max) = positions
+        .iter()
+        .fold((first.coords, first.coords), |(min, max), p| {
+            (min.inf(&p.coords), max.sup(&p.coords))
+        });
+    let extent = max - min;
+    extent.x * extent.y * extent.z
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::{
+        atom::Atom,
+        chain::Chain,
+        residue::Residue,
+        types::{Element, ResidueCategory},
+    };
+
+    fn lattice_structure(n: usize, spacing: f64) -> Structure {
+        let mut chain = Chain::new("A");
+        let mut id = 1;
+        for x in 0..n {
+            for y in 0..n {
+                for z in 0..n {
+                    let mut residue = Residue::new(id, None, "AR", None, ResidueCategory::Ion);
+                    residue.add_atom(Atom::new(
+                        "AR",
+                        Element::Ar,
+                        Point::new(x as f64 * spacing, y as f64 * spacing, z as f64 * spacing),
+                    ));
+                    chain.add_residue(residue);
+                    id += 1;
+                }
+            }
+        }
+        let mut structure: Structure = std::iter::once(chain).collect();
+        let edge = n as f64 * spacing;
+        structure.box_vectors = Some([[edge, 0.0, 0.0], [0.0, edge, 0.0], [0.0, 0.0, edge]]);
+        structure
+    }
+
+    #[test]
+    fn radial_distribution_self_rdf_excludes_identical_pairs() {
+        let structure = lattice_structure(3, 3.0);
+        let all: Vec<usize> = (0..structure.atom_count()).collect();
+
+        let rdf = radial_distribution(&structure, &all, &all, 2.0, 4);
+
+        assert!(rdf.iter().all(|g| *g == 0.0));
+    }
+
+    #[test]
+    fn radial_distribution_peaks_at_lattice_spacing() {
+        let structure = lattice_structure(4, 3.0);
+        let all: Vec<usize> = (0..structure.atom_count()).collect();
+
+        let rdf = radial_distribution(&structure, &all, &all, 4.0, 8);
+
+        let peak_bin = rdf
+            .iter()
+            .enumerate()
+            .max_by(|a, b| a.1.partial_cmp(b.1).unwrap())
+            .map(|(k, _)| k)
+            .unwrap();
+        assert_eq!(peak_bin, 6);
+        assert!(rdf[..6].iter().all(|g| *g == 0.0));
+    }
+
+    #[test]
+    fn radial_distribution_normalizes_single_pair_by_box_density() {
+        let mut chain = Chain::new("A");
+        for (id, x) in [(1, 0.0), (2, 1.5)] {
+            let mut residue = Residue::new(id, None, "AR", None, ResidueCategory::Ion);
+            residue.add_atom(Atom::new("AR", Element::Ar, Point::new(x, 0.0, 0.0)));
+            chain.add_residue(residue);
+        }
+        let mut structure: Structure = std::iter::once(chain).collect();
+        structure.box_vectors = Some([[10.0, 0.0, 0.0], [0.0, 10.0, 0.0], [0.0, 0.0, 10.0]]);
+
+        let rdf = radial_distribution(&structure, &[0], &[1], 2.0, 2);
+
+        let shell = 4.0 / 3.0 * std::f64::consts::PI * (8.0 - 1.0);
+        assert_eq!(rdf[0], 0.0);
+        assert!((rdf[1] - 1000.0 / shell).abs() < 1e-9);
+    }
+
+    #[test]
+    fn radial_distribution_uses_minimum_image_distance_in_periodic_box() {
+        let mut chain = Chain::new("A");
+        for (id, x) in [(1, 0.5), (2, 9.5)] {
+            let mut residue = Residue::new(id, None, "AR", None, ResidueCategory::Ion);
+            residue.add_atom(Atom::new("AR", Element::Ar, Point::new(x, 0.0, 0.0)));
+            chain.add_residue(residue);
+        }
+        let mut structure: Structure = std::iter::once(chain).collect();
+        structure.box_vectors = Some([[10.0, 0.0, 0.0], [0.0, 10.0, 0.0], [0.0, 0.0, 10.0]]);
+
+        let rdf = radial_distribution(&structure, &[0], &[1], 2.0, 2);
+
+        let shell = 4.0 / 3.0 * std::f64::consts::PI;
+        assert!((rdf[0] - 1000.0 / shell).abs() < 1e-9);
+        assert_eq!(rdf[1], 0.0);
+
+        structure.box_vectors = None;
+        assert_eq!(
+            radial_distribution(&structure, &[0], &[1], 2.0, 2),
+            vec![0.0; 2]
+        );
+    }
+
+    #[test]
+    fn radial_distribution_handles_degenerate_input() {
+        let structure = lattice_structure(2, 3.0);
+
+        assert!(radial_distribution(&structure, &[0], &[1], 5.0, 0).is_empty());
+        assert_eq!(
+            radial_distribution(&structure, &[], &[1], 5.0, 3),
+            vec![0.0; 3]
+        );
+        assert_eq!(
+            radial_distribution(&structure, &[0], &[0], 5.0, 3),
+            vec![0.0; 3]
+        );
+        assert_eq!(
+            radial_distribution(&structure, &[0], &[99], 5.0, 3),
+            vec![0.0; 3]
+        );
+    }
+}
@@ -8,13 +8,15 @@
 use crate::db;
 use crate::model::{
     atom::Atom,
+    geometry::superposition,
     residue::Residue,
     structure::Structure,
     types::{Element, Point, ResidueCategory, ResiduePosition},
 };
+use crate::ops::diagnostics::{Diagnostic, Diagnostics, Severity};
 use crate::ops::error::Error;
 use crate::utils::parallel::*;
-use nalgebra::{Matrix3, Rotation3, Vector3};
+use nalgebra::{Matrix3, Vector3};
 use std::collections::HashSet;
 
 /// Standard C-O bond length in carboxylate groups (Å).
@@ -53,6 +55,97 @@ pub fn repair_structure(structure: &mut Structure) -> Result<(), Error> {
         .try_for_each(repair_residue)
 }
 
+/// Repairs every standard residue, skipping residues that cannot be repaired.
+///
+/// Unlike [`repair_structure`], a residue without a template or without alignment anchors
+/// is left exactly as it was and reported as a [`Severity::Warning`] instead of aborting the
+/// whole run. Atoms removed or rebuilt in successfully repaired residues are reported as
+/// [`Severity::Info`].
+///
+/// # Arguments
+///
+/// * `structure` - Mutable structure whose standard residues will be normalized.
+///
+/// # Returns
+///
+/// Findings for every residue that was skipped or changed, in chain and residue order.
+pub fn repair_structure_with_diagnostics(structure: &mut Structure) -> Diagnostics {
+    structure
+        .par_chains_mut()
+        .flat_map(|chain| {
+            let chain_id = chain.id.to_string();
+            chain
+                .par_residues_mut()
+                .filter(|r| r.category == ResidueCategory::Standard)
+                .flat_map_iter(|residue| repair_residue_reported(&chain_id, residue))
+                .collect::<Vec<_>>()
+        })
+        .collect::<Vec<_>>()
+        .into_iter()
+        .collect()
+}
+
+/// Repairs a copy of the residue and commits it only when the repair succeeds.
+///
+/// # Arguments
+///
+/// * `chain_id` - Identifier of the chain that owns the residue.
+/// * `residue` - Residue to be normalized.
+///
+/// # Returns
+///
+/// Findings describing the skipped repair or the atoms that were removed and rebuilt.
+fn repair_residue_reported(chain_id: &str, residue: &mut Residue) -> Vec<Diagnostic> {
+    let mut repaired = residue.clone();
+    if let Err(e) = repair_residue(&mut repaired) {
+        return vec![Diagnostic::for_residue(
+            Severity::Warning,
+            "repair",
+            chain_id,
+            residue,
+            format!("residue left unrepaired: {e}"),
+        )];
+    }
+
+    let names_absent_from = |from: &Residue, other: &Residue| -> Vec<String> {
+        from.iter_atoms()
+            .filter(|atom| !other.has_atom(&atom.name))
+            .map(|atom| atom.name.to_string())
+            .collect()
+    };
+    let removed = names_absent_from(residue, &repaired);
+    let rebuilt = names_absent_from(&repaired, residue);
+
+    let mut findings = Vec::new();
+    if !removed.is_empty() {
+        findings.push(Diagnostic::for_residue(
+            Severity::Info,
+            "repair",
+            chain_id,
+            residue,
+            format!(
+                "removed atoms absent from the template: {}",
+                removed.join(", ")
+            ),
+        ));
+    }
+    if !rebuilt.is_empty() {
+        findings.push(Diagnostic::for_residue(
+            Severity::Info,
+            "repair",
+            chain_id,
+            residue,
+            format!(
+                "rebuilt atoms from template geometry: {}",
+                rebuilt.join(", ")
+            ),
+        ));
+    }
+
+    *residue = repaired;
+    findings
+}
+
 /// Cleans and rebuilds an individual residue using its template definition.
 ///
 /// Removes atoms absent from the template, calculates rigid alignment using shared anchors,
@@ -94,7 +187,13 @@ fn repair_residue(residue: &mut Residue) -> Result<(), Error> {
         ));
     }
 
-    let transform = calculate_transform(&align_pairs)?;
+    let (rotation, translation) = superposition(&align_pairs).ok_or_else(|| {
+        Error::alignment_failed(&*residue.name, residue.id, "SVD decomposition failed")
+    })?;
+    let transform = Transform {
+        rotation,
+        translation,
+    };
     synthesize_missing_template_atoms(residue, missing_atoms, &transform);
 
     synthesize_terminal_atoms(residue, &status);
@@ -252,81 +351,6 @@ impl Transform {
     }
 }
 
-/// Computes the best-fit rigid transform mapping template positions to residue coordinates.
-///
-/// Handles special cases:
-/// - Single point: pure translation
-/// - Two points: single-axis rotation + translation
-/// - Three or more points: full Kabsch SVD alignment
-///
-/// # Arguments
-///
-/// * `pairs` - Slice of point pairs (residue position, template position).
-///
-/// # Returns
-///
-/// `Ok(Transform)` containing the computed rotation and translation.
-///
-/// # Errors
-///
-/// Returns [`Error::AlignmentFailed`] if SVD computation fails.
-fn calculate_transform(pairs: &[(Point, Point)]) -> Result<Transform, Error> {
-    let n = pairs.len();
-
-    let center_res = pairs.iter().map(|p| p.0.coords).sum::<Vector3<f64>>() / n as f64;
-    let center_tmpl = pairs.iter().map(|p| p.1.coords).sum::<Vector3<f64>>() / n as f64;
-
-    if n == 1 {
-        return Ok(Transform {
-            rotation: Matrix3::identity(),
-            translation: center_res - center_tmpl,
-        });
-    }
-
-    if n == 2 {
-        let v_res = pairs[1].0 - pairs[0].0;
-        let v_tmpl = pairs[1].1 - pairs[0].1;
-
-        let rotation =
-            Rotation3::rotation_between(&v_tmpl, &v_res).unwrap_or_else(Rotation3::identity);
-
-        return Ok(Transform {
-            rotation: rotation.into_inner(),
-            translation: center_res - rotation * center_tmpl,
-        });
-    }
-
-    let mut cov = Matrix3::zeros();
-
-    for (p_res, p_tmpl) in pairs {
-        let v_res = p_res.coords - center_res;
-        let v_tmpl = p_tmpl.coords - center_tmpl;
-
-        cov += v_res * v_tmpl.transpose();
-    }
-
-    let svd = cov.svd(true, true);
-
-    let u = svd.u.ok_or_else(|| {
-        Error::alignment_failed("", 0, "SVD decomposition failed: U matrix unavailable")
-    })?;
-    let v_t = svd.v_t.ok_or_else(|| {
-        Error::alignment_failed("", 0, "SVD decomposition failed: V^T matrix unavailable")
-    })?;
-
-    let mut rotation = u * v_t;
-    if rotation.determinant() < 0.0 {
-        let mut correction = Matrix3::identity();
-        correction[(2, 2)] = -1.0;
-        rotation = u * correction * v_t;
-    }
-
-    Ok(Transform {
-        rotation,
-        translation: center_res - rotation * center_tmpl,
-    })
-}
-
 /// Synthesizes missing template atoms by applying the rigid transform.
 ///
 /// # Arguments
@@ -341,7 +365,9 @@ fn synthesize_missing_template_atoms(
 ) {
     for (name, element, tmpl_pos) in missing_atoms {
         let new_pos = transform.apply(tmpl_pos);
-        residue.add_atom(Atom::new(&name, element, new_pos));
+        let mut atom = Atom::new(&name, element, new_pos);
+        atom.generated = true;
+        residue.add_atom(atom);
     }
 }
 
@@ -411,7 +437,9 @@ fn synthesize_oxt(residue: &mut Residue) {
 
     let oxt_pos = c + oxt_direction * CARBOXYL_CO_BOND_LENGTH;
 
-    residue.add_atom(Atom::new("OXT", Element::O, oxt_pos));
+    let mut oxt = Atom::new("OXT", Element::O, oxt_pos);
+    oxt.generated = true;
+    residue.add_atom(oxt);
 }
 
 /// Synthesizes OP3 atom for 5'-terminal phosphorylated nucleic acid residues.
@@ -443,7 +471,9 @@ fn synthesize_op3(residue: &mut Residue) {
 
     let op3_pos = p + direction * PHOSPHATE_PO_BOND_LENGTH;
 
-    residue.add_atom(Atom::new("OP3", Element::O, op3_pos));
+    let mut op3 = Atom::new("OP3", Element::O, op3_pos);
+    op3.generated = true;
+    residue.add_atom(op3);
 }
 
 #[cfg(test)]
@@ -508,8 +538,12 @@ mod tests {
         repair_residue(&mut residue).expect("repair succeeds");
 
         for (name, _, _) in template.heavy_atoms() {
-            assert!(residue.has_atom(name), "missing heavy atom {name}");
+            let atom = residue
+                .atom(name)
+                .unwrap_or_else(|| panic!("missing heavy atom {name}"));
+            assert_eq!(atom.generated, !matches!(name, "N" | "CA"), "{name}");
         }
+        assert!(!residue.atom("HA").unwrap().generated);
         assert!(
             residue.has_atom("HA"),
             "valid hydrogen removed unexpectedly"
@@ -520,6 +554,49 @@ mod tests {
         );
     }
 
+    #[test]
+    fn repair_structure_with_diagnostics_skips_unrepairable_residues() {
+        let template = db::get_template("ALA").expect("template ALA");
+        let mut ala = standard_residue("ALA", 1, StandardResidue::ALA);
+        ala.position = ResiduePosition::Internal;
+        add_atom_from_template(&mut ala, template, "N");
+        add_atom_from_template(&mut ala, template, "CA");
+        ala.add_atom(Atom::new("FAKE", Element::C, Point::new(5.0, 5.0, 5.0)));
+        let mut unknown = standard_residue("XYZ", 2, StandardResidue::ALA);
+        unknown.add_atom(Atom::new("FAKE", Element::C, Point::origin()));
+        let mut chain = Chain::new("A");
+        chain.add_residue(ala);
+        chain.add_residue(unknown);
+        let mut structure: Structure = std::iter::once(chain).collect();
+
+        let diagnostics = repair_structure_with_diagnostics(&mut structure);
+
+        let messages: Vec<(Severity, i32, &str)> = diagnostics
+            .iter()
+            .map(|d| {
+                let key = d.residue.as_ref().expect("residue finding");
+                (d.severity, key.residue_id, d.message.as_str())
+            })
+            .collect();
+        assert_eq!(messages.len(), 3);
+        assert_eq!(
+            messages[0],
+            (
+                Severity::Info,
+                1,
+                "removed atoms absent from the template: FAKE"
+            )
+        );
+        assert_eq!(messages[1].0, Severity::Info);
+        assert!(messages[1].2.contains("CB"));
+        assert_eq!(messages[2].0, Severity::Warning);
+        assert_eq!(messages[2].1, 2);
+
+        let chain = structure.chain("A").unwrap();
+        assert!(chain.residue(1, None).unwrap().has_atom("CB"));
+        assert!(chain.residue(2, None).unwrap().has_atom("FAKE"));
+    }
+
     #[test]
     fn repair_residue_adds_oxt_for_cterm_protein() {
         let template = db::get_template("ALA").expect("template ALA");
@@ -534,6 +611,8 @@ mod tests {
 
         let oxt = residue.atom("OXT").expect("OXT should be synthesized");
         assert_eq!(oxt.element, Element::O);
+        assert!(oxt.generated);
+        assert!(!residue.atom("C").unwrap().generated);
     }
 
     #[test]
@@ -559,6 +638,8 @@ mod tests {
             .atom("OP3")
             .expect("OP3 should be synthesized for 5'-phosphate");
         assert_eq!(op3.element, Element::O);
+        assert!(op3.generated);
+        assert!(!residue.atom("P").unwrap().generated);
     }
 
     #[test]
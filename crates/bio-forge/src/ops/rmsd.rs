@@ -0,0 +1,153 @@
+//! Root-mean-square deviation between matched atoms of two structures.
+//!
+//! These helpers measure coordinate differences as-is, without superposing the inputs, so
+//! callers can compare structures that already share a reference frame (for example frames
+//! of a trajectory or models refined against the same map).
+
+use crate::model::{structure::Structure, types::Point};
+use crate::ops::error::Error;
+
+/// Computes the RMSD over explicit pairs of global atom indices.
+///
+/// Indices follow [`Structure::iter_atoms`] order in each structure. Neither structure is
+/// modified or superposed before the deviation is measured.
+///
+/// # Arguments
+///
+/// * `a` - First structure.
+/// * `b` - Second structure.
+/// * `pairs` - Matched `(index_in_a, index_in_b)` atom pairs.
+///
+/// # Returns
+///
+/// The root-mean-square distance between the paired atoms in ångströms.
+///
+/// # Errors
+///
+/// Returns [`Error::InvalidAtomSelection`] when `pairs` is empty or references an atom
+/// index beyond either structure's atom count.
+pub fn rmsd(a: &Structure, b: &Structure, pairs: &[(usize, usize)]) -> Result<f64, Error> {
+    if pairs.is_empty() {
+        return Err(Error::InvalidAtomSelection {
+            details: "no atom pairs supplied for RMSD".to_string(),
+        });
+    }
+
+    let pos_a: Vec<Point> = a.iter_atoms().map(|atom| atom.pos).collect();
+    let pos_b: Vec<Point> = b.iter_atoms().map(|atom| atom.pos).collect();
+
+    let mut sum_sq = 0.0;
+    for &(i, j) in pairs {
+        let (Some(p), Some(q)) = (pos_a.get(i), pos_b.get(j)) else {
+            return Err(Error::InvalidAtomSelection {
+                details: format!(
+                    "pair ({i}, {j}) out of range for structures with {} and {} atoms",
+                    pos_a.len(),
+                    pos_b.len()
+                ),
+            });
+        };
+        sum_sq += (p - q).norm_squared();
+    }
+
+    Ok((sum_sq / pairs.len() as f64).sqrt())
+}
+
+/// Computes the RMSD between two structures with identical atom ordering.
+///
+/// # Arguments
+///
+/// * `a` - First structure.
+/// * `b` - Second structure sharing the atom order of `a`.
+///
+/// # Returns
+///
+/// The root-mean-square distance over all atoms in ångströms.
+///
+/// # Errors
+///
+/// Returns [`Error::InvalidAtomSelection`] when the atom counts differ or the structures
+/// contain no atoms.
+pub fn rmsd_all(a: &Structure, b: &Structure) -> Result<f64, Error> {
+    let count = a.atom_count();
+    if count != b.atom_count() {
+        return Err(Error::InvalidAtomSelection {
+            details: format!(
+                "atom count mismatch for RMSD: {} vs {}",
+                count,
+                b.atom_count()
+            ),
+        });
+    }
+
+    let pairs: Vec<(usize, usize)> = (0..count).map(|i| (i, i)).collect();
+    rmsd(a, b, &pairs)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::{
+        atom::Atom,
+        chain::Chain,
+        residue::Residue,
+        types::{Element, ResidueCategory},
+    };
+
+    fn structure_from(points: &[Point]) -> Structure {
+        let mut residue = Residue::new(1, None, "LIG", None, ResidueCategory::Hetero);
+        for (i, p) in points.iter().enumerate() {
+            residue.add_atom(Atom::new(&format!("C{}", i + 1), Element::C, *p));
+        }
+        let mut chain = Chain::new("A");
+        chain.add_residue(residue);
+        std::iter::once(chain).collect()
+    }
+
+    #[test]
+    fn rmsd_measures_paired_deviation_without_superposition() {
+        let a = structure_from(&[Point::origin(), Point::new(1.0, 0.0, 0.0)]);
+        let b = structure_from(&[Point::new(0.0, 3.0, 0.0), Point::new(1.0, 0.0, 4.0)]);
+
+        let value = rmsd(&a, &b, &[(0, 0), (1, 1)]).unwrap();
+
+        assert!((value - (12.5f64).sqrt()).abs() < 1e-12);
+    }
+
+    #[test]
+    fn rmsd_respects_explicit_pairing() {
+        let a = structure_from(&[Point::origin(), Point::new(2.0, 0.0, 0.0)]);
+        let b = structure_from(&[Point::new(2.0, 0.0, 0.0), Point::origin()]);
+
+        assert_eq!(rmsd(&a, &b, &[(0, 1), (1, 0)]).unwrap(), 0.0);
+        assert!((rmsd(&a, &b, &[(0, 0)]).unwrap() - 2.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn rmsd_rejects_empty_and_out_of_range_pairs() {
+        let a = structure_from(&[Point::origin()]);
+        let b = structure_from(&[Point::origin()]);
+
+        assert!(matches!(
+            rmsd(&a, &b, &[]),
+            Err(Error::InvalidAtomSelection { .. })
+        ));
+        assert!(matches!(
+            rmsd(&a, &b, &[(0, 1)]),
+            Err(Error::InvalidAtomSelection { .. })
+        ));
+    }
+
+    #[test]
+    fn rmsd_all_requires_matching_atom_counts() {
+        let a = structure_from(&[Point::origin(), Point::new(1.0, 1.0, 1.0)]);
+        let b = structure_from(&[Point::origin(), Point::new(1.0, 1.0, 2.0)]);
+        let c = structure_from(&[Point::origin()]);
+
+        assert!((rmsd_all(&a, &b).unwrap() - (0.5f64).sqrt()).abs() < 1e-12);
+        assert!(matches!(
+            rmsd_all(&a, &c),
+            Err(Error::InvalidAtomSelection { .. })
+        ));
+    }
+}
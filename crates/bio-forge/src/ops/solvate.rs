@@ -2,7 +2,8 @@
 //!
 //! The solvation pipeline packs waters on a configurable grid, recenters the solute, sets
 //! orthorhombic box vectors, and replaces selected waters with ions to reach a desired net
-//! charge. All randomization respects deterministic seeds for reproducibility.
+//! charge. Any other solvent molecule can be packed instead through [`SolventSpec`]. All
+//! randomization respects deterministic seeds for reproducibility.
 
 use crate::db;
 use crate::model::{
@@ -11,7 +12,7 @@ use crate::model::{
     grid::Grid,
     residue::Residue,
     structure::Structure,
-    types::{Element, Point, ResidueCategory, StandardResidue},
+    types::{Element, Point, ResidueCategory, ResiduePosition, StandardResidue},
 };
 use crate::ops::error::Error;
 use crate::utils::parallel::*;
@@ -22,6 +23,12 @@ use rand::{Rng, SeedableRng};
 
 const WATER_MOLARITY: f64 = 55.5;
 
+/// Maximum oxygen–heavy-atom distance (Å) at which a solute atom steers water orientation.
+const HBOND_CUTOFF: f64 = 3.5;
+
+/// Maximum heavy-atom–hydrogen distance (Å) for marking a polar solute atom as a donor.
+const DONOR_H_CUTOFF: f64 = 1.2;
+
 /// Supported cation species for ionic replacement.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum Cation {
@@ -52,6 +59,51 @@ pub enum Anion {
     F,
 }
 
+/// Strategies for orienting the hydrogens of newly placed waters.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum WaterOrientation {
+    /// Keep the template orientation for every water.
+    Fixed,
+    /// Apply a uniformly random rotation drawn from the seeded solvation RNG.
+    #[default]
+    Random,
+    /// Point an O–H bond at the nearest solute acceptor, or both hydrogens away from the
+    /// nearest solute donor, falling back to a random rotation when neither is in reach.
+    HBondAware,
+}
+
+/// Solvent molecule packed around the solute.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub enum SolventSpec {
+    /// Built-in water model from the internal `HOH` template.
+    #[default]
+    Water,
+    /// User-supplied molecule whose residue name, category, atom layout, and coordinates are
+    /// copied for every placement.
+    ///
+    /// Copies are centered on the heavy-atom centroid at each grid point and rotated
+    /// according to [`SolvateConfig::orient_waters`]; hydrogen-bond-aware orientation falls
+    /// back to random rotations. A grid point is rejected when any heavy atom of the copy
+    /// would lie within [`SolvateConfig::vdw_cutoff`] of the solute, and the grid spacing
+    /// should exceed the molecule's extent so neighboring copies do not overlap.
+    Custom(Residue),
+}
+
+/// Summary of the solvent and ions added by [`solvate_structure_with_report`].
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct SolvationReport {
+    /// Solvent molecules (waters by default) remaining after ion substitution.
+    pub waters_added: usize,
+    /// Cations that replaced waters.
+    pub cations_added: usize,
+    /// Anions that replaced waters.
+    pub anions_added: usize,
+    /// Net charge of solute plus placed ions, estimated from residue templates.
+    pub final_charge: i32,
+    /// Volume (Å³) of the orthorhombic solvent box.
+    pub box_volume: f64,
+}
+
 /// Supported ion species for solvent replacement.
 #[derive(Debug, Clone, Copy)]
 enum Ion {
@@ -66,7 +118,7 @@ enum Ion {
 pub struct SolvateConfig {
     /// Margin (Å) added in every direction around the solute before packing solvent.
     pub margin: f64,
-    /// Distance (Å) between candidate water grid points.
+    /// Distance (Å) between candidate solvent grid points.
     pub water_spacing: f64,
     /// Minimum separation (Å) between new waters and existing heavy atoms.
     pub vdw_cutoff: f64,
@@ -80,8 +132,16 @@ pub struct SolvateConfig {
     pub target_charge: i32,
     /// Desired salt concentration (M)
     pub salt_concentration: Option<f64>,
-    /// Optional RNG seed for deterministic solvent orientation.
+    /// Optional RNG seed; when set, water orientation, ion selection, and ion placement are
+    /// fully reproducible and repeated runs on the same input produce identical output.
     pub rng_seed: Option<u64>,
+    /// How hydrogens of newly placed waters are oriented.
+    pub orient_waters: WaterOrientation,
+    /// Solvent molecule to pack; water unless a custom molecule is supplied.
+    pub solvent: SolventSpec,
+    /// Global atom indices (in the input structure) treated as the solute during clash checks
+    /// and water orientation; other atoms may overlap new solvent. `None` uses every atom.
+    pub solute_selection: Option<Vec<usize>>,
 }
 
 impl Default for SolvateConfig {
@@ -97,6 +157,9 @@ impl Default for SolvateConfig {
             target_charge: 0,
             salt_concentration: None,
             rng_seed: None,
+            orient_waters: WaterOrientation::Random,
+            solvent: SolventSpec::Water,
+            solute_selection: None,
         }
     }
 }
@@ -230,9 +293,8 @@ impl Ion {
 
 /// Builds a solvent box, translates the solute to the padded origin, and inserts ions.
 ///
-/// The function removes existing solvent when requested, computes an orthorhombic box from
-/// the solute bounds plus margins, packs waters on a regular grid while randomizing orientation,
-/// and finally replaces selected waters with ions to reach the target charge.
+/// This is a thin wrapper around [`solvate_structure_with_report`] that discards the
+/// summary.
 ///
 /// # Arguments
 ///
@@ -245,16 +307,60 @@ impl Ion {
 ///
 /// # Errors
 ///
-/// Returns [`Error::MissingInternalTemplate`] if the water template is absent,
-/// [`Error::BoxTooSmall`] when insufficient waters remain for ion swapping, or
-/// [`Error::IonizationFailed`] when the requested charge cannot be achieved.
+/// Returns the same errors as [`solvate_structure_with_report`].
 pub fn solvate_structure(structure: &mut Structure, config: &SolvateConfig) -> Result<(), Error> {
+    solvate_structure_with_report(structure, config).map(|_| ())
+}
+
+/// Builds a solvent box and reports how many waters and ions were added.
+///
+/// The function removes existing solvent when requested, computes an orthorhombic box from
+/// the solute bounds plus margins, packs waters on a regular grid oriented according to
+/// [`SolvateConfig::orient_waters`], and finally replaces selected waters with ions to reach
+/// the target charge. When [`SolvateConfig::solute_selection`] is set, only the selected atoms
+/// reject overlapping solvent, while the box still encloses the whole structure. Selection
+/// indices refer to the input structure; out-of-range indices are ignored.
+///
+/// # Arguments
+///
+/// * `structure` - Mutable structure containing the solute atoms to surround with solvent.
+/// * `config` - Parameters controlling padding, spacing, ion species, and RNG seeding.
+///
+/// # Returns
+///
+/// A [`SolvationReport`] with the added water and ion counts, the resulting net charge,
+/// and the box volume.
+///
+/// # Errors
+///
+/// Returns [`Error::MissingInternalTemplate`] if the water template is absent,
+/// [`Error::BoxTooSmall`] when insufficient waters remain for ion swapping,
+/// [`Error::IonizationFailed`] when the requested charge cannot be achieved, or
+/// [`Error::InvalidSolvent`] when a custom solvent has no heavy atoms.
+pub fn solvate_structure_with_report(
+    structure: &mut Structure,
+    config: &SolvateConfig,
+) -> Result<SolvationReport, Error> {
+    let solvent = solvent_template(&config.solvent)?;
+    let is_kept = |res: &Residue| {
+        let is_water = res.standard_name == Some(StandardResidue::HOH);
+        let is_ion = res.category == ResidueCategory::Ion;
+        let is_solvent = res.name == solvent.name;
+        !config.remove_existing || (!is_water && !is_ion && !is_solvent)
+    };
+
+    let solute_mask = config.solute_selection.as_deref().map(|indices| {
+        structure
+            .iter_atoms_with_context()
+            .zip(structure.selection_mask(indices))
+            .filter(|((_, res, _), _)| is_kept(res))
+            .map(|(_, flag)| flag)
+            .collect::<Vec<_>>()
+    });
+    let in_solute = |idx: usize| solute_mask.as_ref().is_none_or(|mask| mask[idx]);
+
     if config.remove_existing {
-        structure.retain_residues(|_chain_id, res| {
-            let is_water = res.standard_name == Some(StandardResidue::HOH);
-            let is_ion = res.category == ResidueCategory::Ion;
-            !is_water && !is_ion
-        });
+        structure.retain_residues(|_chain_id, res| is_kept(res));
         structure.prune_empty_chains();
     }
 
@@ -282,29 +388,28 @@ pub fn solvate_structure(structure: &mut Structure, config: &SolvateConfig) -> R
 
     translate_structure(structure, &translation);
 
-    let heavy_atoms: Vec<_> = structure
-        .par_atoms()
-        .filter(|a| a.element != Element::H)
-        .map(|a| (a.pos, ()))
-        .collect();
-    let grid = Grid::new(heavy_atoms, 4.0);
+    let grid = Grid::from_structure(structure, 4.0, |idx, a| {
+        (a.element.is_heavy_atom() && in_solute(idx)).then_some(())
+    });
+    let polar_grid = match config.orient_waters {
+        WaterOrientation::HBondAware => Some(build_polar_grid(structure, in_solute)),
+        _ => None,
+    };
 
     let mut solvent_chain = Chain::new(&solvent_chain_id);
 
-    let water_tmpl = db::get_template("HOH").ok_or(Error::MissingInternalTemplate {
-        res_name: "HOH".to_string(),
-    })?;
-    let water_name = water_tmpl.name();
-    let water_standard = water_tmpl.standard_name();
-
-    let tmpl_o_pos = water_tmpl
-        .heavy_atoms()
-        .find(|(n, _, _)| *n == "O")
-        .map(|(_, _, p)| p)
-        .unwrap_or(Point::origin());
+    let anchor = heavy_atom_centroid(&solvent);
+    let offsets: Vec<Vector3<f64>> = solvent.iter_atoms().map(|a| a.pos - anchor).collect();
+    let tmpl_h_vecs: Vec<Vector3<f64>> = solvent
+        .iter_atoms()
+        .zip(&offsets)
+        .filter(|(a, _)| a.element == Element::H)
+        .map(|(_, offset)| *offset)
+        .collect();
+    let is_water = config.solvent == SolventSpec::Water;
 
     let z_steps = (0..((box_dim.z / config.water_spacing).ceil() as usize)).collect::<Vec<_>>();
-    let base_seed = config.rng_seed.unwrap_or_else(rand::random);
+    let base_seed: u64 = rng.random();
 
     let new_waters: Vec<Residue> = z_steps
         .into_par_iter()
@@ -324,42 +429,43 @@ pub fn solvate_structure(structure: &mut Structure, config: &SolvateConfig) -> R
                 while x < box_dim.x {
                     let candidate_pos = Point::new(x, y, z);
 
-                    if grid
-                        .neighbors(&candidate_pos, config.vdw_cutoff)
-                        .exact()
-                        .next()
-                        .is_none()
-                    {
-                        let rotation = Rotation3::from_axis_angle(
-                            &Vector3::y_axis(),
-                            local_rng.random_range(0.0..std::f64::consts::TAU),
-                        ) * Rotation3::from_axis_angle(
-                            &Vector3::x_axis(),
-                            local_rng.random_range(0.0..std::f64::consts::TAU),
-                        );
-
-                        let mut residue = Residue::new(
-                            0,
-                            None,
-                            water_name,
-                            Some(water_standard),
-                            ResidueCategory::Standard,
-                        );
-
-                        let final_o_pos = candidate_pos;
-                        residue.add_atom(Atom::new("O", Element::O, final_o_pos));
-
-                        for (h_name, h_pos, _) in water_tmpl.hydrogens() {
-                            let local_vec = h_pos - tmpl_o_pos;
-                            let rotated_vec = rotation * local_vec;
-                            residue.add_atom(Atom::new(
-                                h_name,
-                                Element::H,
-                                final_o_pos + rotated_vec,
-                            ));
+                    let clashes = |rotation: &Rotation3<f64>| {
+                        solvent.iter_atoms().zip(&offsets).any(|(atom, offset)| {
+                            atom.element.is_heavy_atom()
+                                && grid
+                                    .neighbors(
+                                        &(candidate_pos + rotation * offset),
+                                        config.vdw_cutoff,
+                                    )
+                                    .exact()
+                                    .next()
+                                    .is_some()
+                        })
+                    };
+
+                    // Water's only heavy atom sits on the grid point, so its clash test
+                    // does not depend on the rotation and runs before any RNG draw.
+                    if !(is_water && clashes(&Rotation3::identity())) {
+                        let rotation = match config.orient_waters {
+                            WaterOrientation::Fixed => Rotation3::identity(),
+                            WaterOrientation::HBondAware if is_water => hbond_rotation(
+                                &candidate_pos,
+                                &tmpl_h_vecs,
+                                polar_grid.as_ref(),
+                                &mut local_rng,
+                            ),
+                            WaterOrientation::Random | WaterOrientation::HBondAware => {
+                                random_rotation(&mut local_rng)
+                            }
+                        };
+
+                        if is_water || !clashes(&rotation) {
+                            let mut residue = solvent.clone();
+                            for (atom, offset) in residue.iter_atoms_mut().zip(&offsets) {
+                                atom.pos = candidate_pos + rotation * offset;
+                            }
+                            local_waters.push(residue);
                         }
-
-                        local_waters.push(residue);
                     }
                     x += config.water_spacing;
                 }
@@ -372,17 +478,15 @@ pub fn solvate_structure(structure: &mut Structure, config: &SolvateConfig) -> R
 
     let mut water_positions = Vec::with_capacity(new_waters.len());
     solvent_chain.reserve(new_waters.len());
-    let mut res_id_counter = 1;
-
-    for mut residue in new_waters {
-        residue.id = res_id_counter;
+    for (res_id, mut residue) in (1..).zip(new_waters) {
+        residue.id = res_id;
         solvent_chain.add_residue(residue);
-        water_positions.push(res_id_counter);
-        res_id_counter += 1;
+        water_positions.push(res_id);
     }
 
-    replace_with_ions(
-        structure,
+    let solute_charge = calculate_solute_charge(structure);
+    let placed_ions = replace_with_ions(
+        solute_charge,
         &mut solvent_chain,
         &mut water_positions,
         config,
@@ -393,7 +497,211 @@ pub fn solvate_structure(structure: &mut Structure, config: &SolvateConfig) -> R
         structure.add_chain(solvent_chain);
     }
 
-    Ok(())
+    let cations_added = placed_ions
+        .iter()
+        .filter(|ion| matches!(ion, Ion::Cation(_)))
+        .count();
+
+    Ok(SolvationReport {
+        waters_added: water_positions.len(),
+        cations_added,
+        anions_added: placed_ions.len() - cations_added,
+        final_charge: solute_charge + total_ion_charge(&placed_ions),
+        box_volume: box_dim.x * box_dim.y * box_dim.z,
+    })
+}
+
+/// Builds the residue copied for every solvent placement.
+///
+/// # Arguments
+///
+/// * `spec` - Requested solvent molecule.
+///
+/// # Returns
+///
+/// The water template (oxygen first, then hydrogens) or a clone of the custom molecule.
+///
+/// # Errors
+///
+/// Returns [`Error::MissingInternalTemplate`] if the water template is absent or
+/// [`Error::InvalidSolvent`] when a custom molecule has no heavy atoms.
+fn solvent_template(spec: &SolventSpec) -> Result<Residue, Error> {
+    match spec {
+        SolventSpec::Water => {
+            let tmpl = db::get_template("HOH").ok_or(Error::MissingInternalTemplate {
+                res_name: "HOH".to_string(),
+            })?;
+            let mut residue = Residue::new(
+                0,
+                None,
+                tmpl.name(),
+                Some(tmpl.standard_name()),
+                ResidueCategory::Standard,
+            );
+            for (name, element, pos) in tmpl.all_atoms() {
+                let mut atom = Atom::new(name, element, pos);
+                atom.generated = true;
+                residue.add_atom(atom);
+            }
+            Ok(residue)
+        }
+        SolventSpec::Custom(molecule) => {
+            if !molecule.iter_atoms().any(|a| a.element.is_heavy_atom()) {
+                return Err(Error::InvalidSolvent {
+                    res_name: molecule.name.to_string(),
+                });
+            }
+            let mut residue = molecule.clone();
+            residue.id = 0;
+            residue.insertion_code = None;
+            residue.position = ResiduePosition::None;
+            for atom in residue.iter_atoms_mut() {
+                atom.generated = true;
+            }
+            Ok(residue)
+        }
+    }
+}
+
+/// Computes the mean position of a residue's heavy atoms.
+///
+/// # Arguments
+///
+/// * `residue` - Residue whose heavy atoms are averaged.
+///
+/// # Returns
+///
+/// The heavy-atom centroid, or the origin when the residue has no heavy atoms.
+fn heavy_atom_centroid(residue: &Residue) -> Point {
+    let (sum, count) = residue
+        .iter_atoms()
+        .filter(|a| a.element.is_heavy_atom())
+        .fold((Vector3::zeros(), 0usize), |(sum, count), a| {
+            (sum + a.pos.coords, count + 1)
+        });
+    if count == 0 {
+        Point::origin()
+    } else {
+        Point::from(sum / count as f64)
+    }
+}
+
+/// Draws a random water rotation.
+///
+/// # Arguments
+///
+/// * `rng` - Random number generator used for sampling.
+///
+/// # Returns
+///
+/// Rotation composed of random turns about the y and x axes.
+fn random_rotation(rng: &mut impl Rng) -> Rotation3<f64> {
+    Rotation3::from_axis_angle(
+        &Vector3::y_axis(),
+        rng.random_range(0.0..std::f64::consts::TAU),
+    ) * Rotation3::from_axis_angle(
+        &Vector3::x_axis(),
+        rng.random_range(0.0..std::f64::consts::TAU),
+    )
+}
+
+/// Indexes polar solute heavy atoms (N and O), flagging those that carry a hydrogen.
+///
+/// # Arguments
+///
+/// * `structure` - Solute whose polar atoms may interact with new waters.
+/// * `in_solute` - Whether the atom at a global index belongs to the solute selection.
+///
+/// # Returns
+///
+/// Grid of polar atom positions with a `true` payload for hydrogen-bond donors.
+fn build_polar_grid(structure: &Structure, in_solute: impl Fn(usize) -> bool) -> Grid<bool> {
+    let polar_atoms: Vec<_> = structure
+        .iter_atoms_with_context()
+        .enumerate()
+        .filter(|(idx, (_, _, a))| matches!(a.element, Element::N | Element::O) && in_solute(*idx))
+        .map(|(_, (_, residue, a))| {
+            let is_donor = residue.iter_atoms().any(|h| {
+                h.element == Element::H && a.distance_squared(h) <= DONOR_H_CUTOFF.powi(2)
+            });
+            (a.pos, is_donor)
+        })
+        .collect();
+    Grid::new(polar_atoms, HBOND_CUTOFF)
+}
+
+/// Chooses a water rotation that forms a hydrogen bond with the nearest polar solute atom.
+///
+/// Acceptors take precedence: one O–H bond is aligned toward the nearest acceptor. Otherwise
+/// the H–O–H bisector is pointed away from the nearest donor so the water oxygen accepts.
+/// The remaining spin about the aligned axis is randomized.
+///
+/// # Arguments
+///
+/// * `o_pos` - Oxygen position of the new water.
+/// * `h_vecs` - Template O→H vectors.
+/// * `polar_grid` - Polar solute atoms built by [`build_polar_grid`].
+/// * `rng` - Random number generator for the free spin and the fallback rotation.
+///
+/// # Returns
+///
+/// Rotation to apply to the template hydrogens.
+fn hbond_rotation(
+    o_pos: &Point,
+    h_vecs: &[Vector3<f64>],
+    polar_grid: Option<&Grid<bool>>,
+    rng: &mut impl Rng,
+) -> Rotation3<f64> {
+    let nearest = |want_donor: bool| {
+        polar_grid?
+            .neighbors(o_pos, HBOND_CUTOFF)
+            .exact()
+            .filter(|(_, is_donor)| **is_donor == want_donor)
+            .map(|(pos, _)| pos)
+            .min_by(|a, b| {
+                nalgebra::distance_squared(a, o_pos)
+                    .total_cmp(&nalgebra::distance_squared(b, o_pos))
+            })
+    };
+
+    let (from, to) = if let (Some(acceptor), Some(h_vec)) = (nearest(false), h_vecs.first()) {
+        (*h_vec, acceptor - o_pos)
+    } else if let Some(donor) = nearest(true) {
+        (h_vecs.iter().sum::<Vector3<f64>>(), o_pos - donor)
+    } else {
+        return random_rotation(rng);
+    };
+
+    match align_vectors(&from, &to) {
+        Some(align) => {
+            let spin = rng.random_range(0.0..std::f64::consts::TAU);
+            Rotation3::new(to.normalize() * spin) * align
+        }
+        None => random_rotation(rng),
+    }
+}
+
+/// Builds the rotation mapping the direction of `from` onto the direction of `to`.
+///
+/// # Arguments
+///
+/// * `from` - Source direction.
+/// * `to` - Target direction.
+///
+/// # Returns
+///
+/// `Some(rotation)` for non-degenerate vectors, or `None` if either vector is zero.
+fn align_vectors(from: &Vector3<f64>, to: &Vector3<f64>) -> Option<Rotation3<f64>> {
+    if from.norm() < 1e-9 || to.norm() < 1e-9 {
+        return None;
+    }
+    Rotation3::rotation_between(from, to).or_else(|| {
+        let axis = from
+            .cross(&Vector3::x_axis())
+            .try_normalize(1e-9)
+            .unwrap_or_else(|| from.cross(&Vector3::y_axis()).normalize());
+        Some(Rotation3::new(axis * std::f64::consts::PI))
+    })
 }
 
 /// Computes axis-aligned bounding box for all atoms in the structure.
@@ -439,7 +747,7 @@ fn translate_structure(structure: &mut Structure, vec: &Vector3<f64>) {
     }
 }
 
-fn total_ion_charge(ions: &Vec<Ion>) -> i32 {
+fn total_ion_charge(ions: &[Ion]) -> i32 {
     ions.iter().map(|ion| ion.charge()).sum()
 }
 
@@ -475,7 +783,7 @@ fn calculate_solute_charge(structure: &Structure) -> i32 {
 ///
 /// # Arguments
 ///
-/// * `structure` - Current solute (used for charge estimation).
+/// * `current_charge` - Estimated net charge of the solute.
 /// * `solvent_chain` - Chain containing newly created solvent residues.
 /// * `water_indices` - Residue IDs that can be substituted with ions.
 /// * `config` - Solvation configuration specifying ion species and target charge.
@@ -483,24 +791,23 @@ fn calculate_solute_charge(structure: &Structure) -> i32 {
 ///
 /// # Returns
 ///
-/// `Ok(())` when the charge target is hit or ions are not requested.
+/// The ions that replaced waters; empty when ions are not requested.
 ///
 /// # Errors
 ///
 /// Returns [`Error::BoxTooSmall`] if no waters remain to swap or
 /// [`Error::IonizationFailed`] when charge neutrality cannot be achieved.
 fn replace_with_ions(
-    structure: &Structure,
+    current_charge: i32,
     solvent_chain: &mut Chain,
     water_indices: &mut Vec<i32>,
     config: &SolvateConfig,
     rng: &mut impl Rng,
-) -> Result<(), Error> {
+) -> Result<Vec<Ion>, Error> {
     if config.cations.is_empty() && config.anions.is_empty() {
-        return Ok(());
+        return Ok(Vec::new());
     }
 
-    let current_charge = calculate_solute_charge(structure);
     let charge_diff = config.target_charge - current_charge;
     dbg!(current_charge, config.target_charge);
     let total_waters = water_indices.len();
@@ -509,30 +816,27 @@ fn replace_with_ions(
     water_indices.shuffle(rng);
 
     // Add neutral ions to achieve desired salt concentration
-    match config.salt_concentration {
-        Some(salt_concentration) => {
-            // TODO: we are replacing waters with ions which changes total_waters for every ion added. We should apply a correction term
-            let total_ions =
-                ((total_waters as f64) * (salt_concentration / WATER_MOLARITY)).round() as usize;
-
-            for _ in 0..total_ions {
-                let cation = config
-                    .choose_random_cation(rng)
+    if let Some(salt_concentration) = config.salt_concentration {
+        // TODO: we are replacing waters with ions which changes total_waters for every ion added. We should apply a correction term
+        let total_ions =
+            ((total_waters as f64) * (salt_concentration / WATER_MOLARITY)).round() as usize;
+
+        for _ in 0..total_ions {
+            let cation = config
+                .choose_random_cation(rng)
+                .ok_or(Error::IonizationFailed {
+                    details: "No cations configured".to_string(),
+                })?;
+            ion_plan.push(Ion::Cation(*cation));
+            for _ in 0..cation.charge() {
+                let anion = config
+                    .choose_random_anion(rng)
                     .ok_or(Error::IonizationFailed {
-                        details: "No cations configured".to_string(),
+                        details: "No anions configured".to_string(),
                     })?;
-                ion_plan.push(Ion::Cation(*cation));
-                for _ in 0..cation.charge() {
-                    let anion = config
-                        .choose_random_anion(rng)
-                        .ok_or(Error::IonizationFailed {
-                            details: "No anions configured".to_string(),
-                        })?;
-                    ion_plan.push(Ion::Anion(*anion));
-                }
+                ion_plan.push(Ion::Anion(*anion));
             }
         }
-        None => {}
     }
 
     // Add ions to reach target charge
@@ -562,10 +866,10 @@ fn replace_with_ions(
     }
 
     ion_plan.shuffle(rng);
-    for ion in ion_plan {
+    for &ion in &ion_plan {
         let res_id = water_indices.pop().ok_or(Error::BoxTooSmall)?;
         let residue = solvent_chain.residue_mut(res_id, None).unwrap();
-        let pos = residue.atom("O").unwrap().pos;
+        let pos = heavy_atom_centroid(residue);
 
         *residue = match ion {
             Ion::Cation(cation) => create_cation_residue(res_id, cation, pos),
@@ -573,7 +877,7 @@ fn replace_with_ions(
         };
     }
 
-    Ok(())
+    Ok(ion_plan)
 }
 
 /// Creates a single-ion residue for the provided cation at a given position.
@@ -589,7 +893,9 @@ fn replace_with_ions(
 /// A residue labeled as [`ResidueCategory::Ion`].
 fn create_cation_residue(id: i32, cation: Cation, pos: Point) -> Residue {
     let mut res = Residue::new(id, None, cation.name(), None, ResidueCategory::Ion);
-    res.add_atom(Atom::new(cation.name(), cation.element(), pos));
+    let mut atom = Atom::new(cation.name(), cation.element(), pos);
+    atom.generated = true;
+    res.add_atom(atom);
     res
 }
 
@@ -606,12 +912,17 @@ fn create_cation_residue(id: i32, cation: Cation, pos: Point) -> Residue {
 /// A residue labeled as [`ResidueCategory::Ion`].
 fn create_anion_residue(id: i32, anion: Anion, pos: Point) -> Residue {
     let mut res = Residue::new(id, None, anion.name(), None, ResidueCategory::Ion);
-    res.add_atom(Atom::new(anion.name(), anion.element(), pos));
+    let mut atom = Atom::new(anion.name(), anion.element(), pos);
+    atom.generated = true;
+    res.add_atom(atom);
     res
 }
 
 /// Builds a seeded or OS-random generator for solvent placement.
 ///
+/// Every random decision in [`solvate_structure`] derives from this generator, including the
+/// per-slice seeds used while packing waters in parallel.
+///
 /// # Arguments
 ///
 /// * `config` - Solvation configuration containing an optional seed.
@@ -708,6 +1019,9 @@ mod tests {
             target_charge: 0,
             salt_concentration: None,
             rng_seed: Some(42),
+            orient_waters: WaterOrientation::Random,
+            solvent: SolventSpec::Water,
+            solute_selection: None,
         };
 
         solvate_structure(&mut structure, &config).expect("solvation should succeed");
@@ -742,6 +1056,13 @@ mod tests {
             .filter(|res| res.standard_name == Some(StandardResidue::HOH))
             .collect();
         assert!(!solvent_residues.is_empty());
+        assert!(
+            solvent_residues
+                .iter()
+                .flat_map(|res| res.iter_atoms())
+                .all(|atom| atom.generated)
+        );
+        assert!(solute_chain.iter_atoms().all(|atom| !atom.generated));
     }
 
     #[test]
@@ -769,6 +1090,9 @@ mod tests {
             target_charge: 0,
             salt_concentration: None,
             rng_seed: Some(7),
+            orient_waters: WaterOrientation::Random,
+            solvent: SolventSpec::Water,
+            solute_selection: None,
         };
 
         solvate_structure(&mut structure, &config).expect("solvation should succeed");
@@ -813,6 +1137,9 @@ mod tests {
             target_charge: 0,
             salt_concentration: None,
             rng_seed: Some(17),
+            orient_waters: WaterOrientation::Random,
+            solvent: SolventSpec::Water,
+            solute_selection: None,
         };
 
         solvate_structure(&mut structure, &config).expect("solvation should succeed");
@@ -825,6 +1152,49 @@ mod tests {
 
         assert_eq!(ion_residues.len() as i32, lys_charge);
         assert!(ion_residues.iter().all(|res| res.name == "CL"));
+        assert!(
+            ion_residues
+                .iter()
+                .flat_map(|res| res.iter_atoms())
+                .all(|atom| atom.generated)
+        );
+    }
+
+    #[test]
+    fn solvation_report_counts_waters_ions_and_final_charge() {
+        let mut structure = Structure::new();
+        let mut chain = Chain::new("A");
+        let mut residue = Residue::new(
+            1,
+            None,
+            "LYS",
+            Some(StandardResidue::LYS),
+            ResidueCategory::Standard,
+        );
+        residue.add_atom(Atom::new("NZ", Element::N, Point::origin()));
+        chain.add_residue(residue);
+        structure.add_chain(chain);
+
+        let config = SolvateConfig {
+            margin: 4.0,
+            water_spacing: 4.0,
+            vdw_cutoff: 1.0,
+            cations: vec![Cation::Na],
+            anions: vec![Anion::Cl],
+            target_charge: 0,
+            rng_seed: Some(17),
+            ..SolvateConfig::default()
+        };
+
+        let report =
+            solvate_structure_with_report(&mut structure, &config).expect("solvation succeeds");
+
+        assert_eq!(report.waters_added, 7);
+        assert_eq!(report.cations_added, 0);
+        assert_eq!(report.anions_added, 1);
+        assert_eq!(report.final_charge, 0);
+        assert!((report.box_volume - 512.0).abs() < 1e-9);
+        assert_eq!(report.box_volume, structure.box_volume().unwrap());
     }
 
     #[test]
@@ -855,9 +1225,301 @@ mod tests {
             target_charge: 2,
             salt_concentration: None,
             rng_seed: Some(5),
+            orient_waters: WaterOrientation::Random,
+            solvent: SolventSpec::Water,
+            solute_selection: None,
         };
 
         let result = solvate_structure(&mut structure, &config);
         assert!(matches!(result, Err(Error::BoxTooSmall)));
     }
+
+    fn single_atom_solute(name: &str, element: Element) -> Structure {
+        let mut structure = Structure::new();
+        let mut chain = Chain::new("A");
+        let mut residue = Residue::new(1, None, "LIG", None, ResidueCategory::Hetero);
+        residue.add_atom(Atom::new(name, element, Point::origin()));
+        chain.add_residue(residue);
+        structure.add_chain(chain);
+        structure
+    }
+
+    fn orientation_config(orient_waters: WaterOrientation) -> SolvateConfig {
+        SolvateConfig {
+            margin: 4.0,
+            water_spacing: 4.0,
+            vdw_cutoff: 1.0,
+            cations: vec![],
+            anions: vec![],
+            rng_seed: Some(11),
+            orient_waters,
+            ..SolvateConfig::default()
+        }
+    }
+
+    fn methanol() -> Residue {
+        let mut residue = Residue::new(1, None, "MOH", None, ResidueCategory::Hetero);
+        residue.add_atom(Atom::new("C", Element::C, Point::new(10.0, 0.0, 0.0)));
+        residue.add_atom(Atom::new("O", Element::O, Point::new(11.43, 0.0, 0.0)));
+        residue.add_atom(Atom::new("HO", Element::H, Point::new(11.75, 0.9, 0.0)));
+        residue
+    }
+
+    #[test]
+    fn custom_solvent_is_packed_with_template_layout() {
+        let mut structure = single_atom_solute("C1", Element::C);
+        let config = SolvateConfig {
+            solvent: SolventSpec::Custom(methanol()),
+            ..orientation_config(WaterOrientation::Fixed)
+        };
+
+        let report =
+            solvate_structure_with_report(&mut structure, &config).expect("solvation succeeds");
+
+        let solvent: Vec<&Residue> = structure
+            .iter_chains()
+            .flat_map(|chain| chain.iter_residues())
+            .filter(|res| res.name == "MOH")
+            .collect();
+        assert_eq!(solvent.len(), 8);
+        assert_eq!(report.waters_added, 8);
+        assert!(waters(&structure).is_empty());
+
+        for residue in solvent {
+            assert_eq!(residue.category, ResidueCategory::Hetero);
+            let names: Vec<&str> = residue.iter_atoms().map(|a| a.name.as_str()).collect();
+            assert_eq!(names, vec!["C", "O", "HO"]);
+            let co = residue.atom("O").unwrap().pos - residue.atom("C").unwrap().pos;
+            assert!((co - Vector3::new(1.43, 0.0, 0.0)).norm() < 1e-9);
+            let centroid = nalgebra::center(
+                &residue.atom("C").unwrap().pos,
+                &residue.atom("O").unwrap().pos,
+            );
+            assert!((centroid.x - 2.0).abs() < 1e-9 || (centroid.x - 6.0).abs() < 1e-9);
+        }
+    }
+
+    #[test]
+    fn custom_solvent_without_heavy_atoms_is_rejected() {
+        let mut structure = single_atom_solute("C1", Element::C);
+        let mut hydrogen = Residue::new(1, None, "H2", None, ResidueCategory::Hetero);
+        hydrogen.add_atom(Atom::new("H1", Element::H, Point::origin()));
+        let config = SolvateConfig {
+            solvent: SolventSpec::Custom(hydrogen),
+            ..SolvateConfig::default()
+        };
+
+        let err = solvate_structure(&mut structure, &config).unwrap_err();
+
+        assert!(matches!(err, Error::InvalidSolvent { res_name } if res_name == "H2"));
+    }
+
+    fn waters(structure: &Structure) -> Vec<&Residue> {
+        structure
+            .iter_chains()
+            .flat_map(|chain| chain.iter_residues())
+            .filter(|res| res.standard_name == Some(StandardResidue::HOH))
+            .collect()
+    }
+
+    #[test]
+    fn fixed_orientation_keeps_template_hydrogen_offsets() {
+        let mut structure = single_atom_solute("C1", Element::C);
+        solvate_structure(&mut structure, &orientation_config(WaterOrientation::Fixed))
+            .expect("solvation should succeed");
+
+        let tmpl = db::get_template("HOH").expect("HOH template");
+        let tmpl_o = tmpl.heavy_atoms().next().map(|(_, _, p)| p).unwrap();
+        let waters = waters(&structure);
+        assert_eq!(waters.len(), 8);
+        for water in waters {
+            let o = water.atom("O").unwrap().pos;
+            for (h_name, h_pos, _) in tmpl.hydrogens() {
+                let offset = water.atom(h_name).unwrap().pos - o;
+                assert!((offset - (h_pos - tmpl_o)).norm() < 1e-9);
+            }
+        }
+    }
+
+    #[test]
+    fn random_orientation_is_reproducible_with_seed() {
+        let run = || {
+            let mut structure = single_atom_solute("C1", Element::C);
+            solvate_structure(
+                &mut structure,
+                &orientation_config(WaterOrientation::Random),
+            )
+            .expect("solvation should succeed");
+            structure.iter_atoms().map(|a| a.pos).collect::<Vec<_>>()
+        };
+
+        assert_eq!(run(), run());
+    }
+
+    #[test]
+    fn hbond_aware_orientation_points_hydrogen_at_acceptor() {
+        let mut structure = single_atom_solute("O1", Element::O);
+        solvate_structure(
+            &mut structure,
+            &orientation_config(WaterOrientation::HBondAware),
+        )
+        .expect("solvation should succeed");
+
+        let acceptor = structure
+            .chain("A")
+            .unwrap()
+            .iter_atoms()
+            .next()
+            .unwrap()
+            .pos;
+        for water in waters(&structure) {
+            let o = water.atom("O").unwrap().pos;
+            let toward = (acceptor - o).normalize();
+            let best = water
+                .iter_atoms()
+                .filter(|a| a.element == Element::H)
+                .map(|h| (h.pos - o).normalize().dot(&toward))
+                .fold(f64::MIN, f64::max);
+            assert!(best > 1.0 - 1e-9, "water {} does not donate", water.id);
+        }
+    }
+
+    #[test]
+    fn hbond_aware_orientation_points_hydrogens_away_from_donor() {
+        let mut structure = single_atom_solute("N1", Element::N);
+        let residue = structure.find_residue_mut("A", 1, None).unwrap();
+        residue.add_atom(Atom::new("H1", Element::H, Point::new(0.0, 0.0, -1.0)));
+        solvate_structure(
+            &mut structure,
+            &orientation_config(WaterOrientation::HBondAware),
+        )
+        .expect("solvation should succeed");
+
+        let donor = structure
+            .find_residue("A", 1, None)
+            .unwrap()
+            .atom("N1")
+            .unwrap()
+            .pos;
+        let mut checked = 0;
+        for water in waters(&structure) {
+            let o = water.atom("O").unwrap().pos;
+            let bisector: Vector3<f64> = water
+                .iter_atoms()
+                .filter(|a| a.element == Element::H)
+                .map(|h| h.pos - o)
+                .sum();
+            if nalgebra::distance(&o, &donor) <= HBOND_CUTOFF {
+                assert!(bisector.normalize().dot(&(o - donor).normalize()) > 1.0 - 1e-9);
+                checked += 1;
+            }
+        }
+        assert!(checked > 0);
+    }
+
+    fn receptor_ligand_with_existing_water() -> Structure {
+        let mut structure = single_atom_solute("C1", Element::C);
+        let mut water_chain = Chain::new("W");
+        let mut water = Residue::new(
+            1,
+            None,
+            "HOH",
+            Some(StandardResidue::HOH),
+            ResidueCategory::Standard,
+        );
+        water.add_atom(Atom::new("O", Element::O, Point::new(6.0, 0.0, 0.0)));
+        water_chain.add_residue(water);
+        structure.add_chain(water_chain);
+        let mut ligand_chain = Chain::new("B");
+        let mut ligand = Residue::new(1, None, "LIG", None, ResidueCategory::Hetero);
+        ligand.add_atom(Atom::new("C1", Element::C, Point::new(12.0, 0.0, 0.0)));
+        ligand_chain.add_residue(ligand);
+        structure.add_chain(ligand_chain);
+        structure
+    }
+
+    fn waters_near(structure: &Structure, chain_id: &str, cutoff: f64) -> usize {
+        let solute = structure
+            .chain(chain_id)
+            .unwrap()
+            .iter_atoms()
+            .next()
+            .unwrap();
+        waters(structure)
+            .into_iter()
+            .filter(|w| {
+                w.iter_atoms()
+                    .any(|a| a.distance_squared(solute) < cutoff.powi(2))
+            })
+            .count()
+    }
+
+    #[test]
+    fn solute_selection_limits_clash_checks_to_selected_atoms() {
+        let config = SolvateConfig {
+            vdw_cutoff: 3.5,
+            ..orientation_config(WaterOrientation::Fixed)
+        };
+
+        let mut whole = receptor_ligand_with_existing_water();
+        solvate_structure(&mut whole, &config).expect("solvation should succeed");
+        assert_eq!(waters(&whole).len(), 4);
+        assert_eq!(waters_near(&whole, "A", 3.5), 0);
+        assert_eq!(waters_near(&whole, "B", 3.5), 0);
+
+        let mut selected = receptor_ligand_with_existing_water();
+        let config = SolvateConfig {
+            solute_selection: Some(vec![0, 99]),
+            ..config
+        };
+        solvate_structure(&mut selected, &config).expect("solvation should succeed");
+        assert_eq!(waters(&selected).len(), 12);
+        assert_eq!(waters_near(&selected, "A", 3.5), 0);
+        assert!(waters_near(&selected, "B", 3.5) > 0);
+        let box_vectors = selected.box_vectors.expect("box vectors");
+        assert!((box_vectors[0][0] - 20.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn solute_selection_indices_refer_to_atoms_before_solvent_removal() {
+        let mut structure = receptor_ligand_with_existing_water();
+        let config = SolvateConfig {
+            vdw_cutoff: 3.5,
+            solute_selection: Some(vec![2]),
+            ..orientation_config(WaterOrientation::Fixed)
+        };
+
+        solvate_structure(&mut structure, &config).expect("solvation should succeed");
+
+        assert_eq!(waters(&structure).len(), 12);
+        assert!(waters_near(&structure, "A", 3.5) > 0);
+        assert_eq!(waters_near(&structure, "B", 3.5), 0);
+    }
+
+    #[test]
+    fn seeded_solvation_produces_identical_output() {
+        let run = |seed: u64| {
+            let mut structure = single_atom_solute("C1", Element::C);
+            let config = SolvateConfig {
+                margin: 6.0,
+                water_spacing: 3.0,
+                vdw_cutoff: 1.0,
+                cations: vec![Cation::Na, Cation::K],
+                anions: vec![Anion::Cl, Anion::Br],
+                target_charge: 2,
+                salt_concentration: Some(5.0),
+                rng_seed: Some(seed),
+                ..SolvateConfig::default()
+            };
+            solvate_structure(&mut structure, &config).expect("solvation should succeed");
+
+            let mut buffer = Vec::new();
+            crate::io::write_pdb_structure(&mut buffer, &structure, &Default::default())
+                .expect("write should succeed");
+            buffer
+        };
+
+        assert_eq!(run(3), run(3));
+        assert_ne!(run(3), run(4));
+    }
 }
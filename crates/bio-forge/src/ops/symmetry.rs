@@ -0,0 +1,233 @@
+//! Expansion of asymmetric units into symmetry-generated assemblies.
+//!
+//! Crystallographic and non-crystallographic symmetry operators (for example the `BIOMT` or
+//! `MTRIX` records exposed by [`crate::io::read_pdb_biomt_operators`] and
+//! [`crate::io::read_pdb_mtrix_operators`]) are applied to copies of a structure, and the
+//! copies are merged into a single assembly with unique chain IDs.
+
+use crate::io::BiomtOperator;
+use crate::model::{chain::Chain, structure::Structure};
+use nalgebra::Isometry3;
+use std::collections::HashSet;
+
+/// Pool of single-character chain identifiers tried in order for symmetry copies.
+const CHAIN_ID_POOL: &str = "ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789";
+
+/// Builds an assembly by applying each operator to a copy of the structure.
+///
+/// The first copy keeps its original chain identifiers; every later copy receives unused
+/// single-character IDs drawn from `A-Z`, `a-z`, `0-9`, falling back to the original ID
+/// suffixed with the smallest number that does not collide with an existing or already
+/// assigned chain once the pool is exhausted. Box vectors are preserved.
+///
+/// # Arguments
+///
+/// * `structure` - Asymmetric unit to replicate.
+/// * `operators` - Rigid transforms applied to each copy, in order.
+///
+/// # Returns
+///
+/// A new `Structure` containing one transformed copy of every chain per operator; empty
+/// when `operators` is empty.
+pub fn apply_symmetry(structure: &Structure, operators: &[Isometry3<f64>]) -> Structure {
+    expand(
+        structure,
+        operators.iter().map(|op| (op, None::<&[String]>)),
+    )
+}
+
+/// Builds a biological assembly from `REMARK 350 BIOMT` operators.
+///
+/// Each operator is applied only to the chains it names, so assemblies whose operators
+/// target different chain groups are reproduced faithfully; operators without a chain list
+/// apply to every chain. Chain identifiers are assigned as in [`apply_symmetry`]: the first
+/// copy of a chain keeps its ID and later copies receive unused ones.
+///
+/// # Arguments
+///
+/// * `structure` - Asymmetric unit to replicate.
+/// * `operators` - Assembly operators as returned by [`crate::io::read_pdb_biomt_operators`].
+///
+/// # Returns
+///
+/// A new `Structure` holding the transformed chain copies in operator order.
+pub fn build_assembly(structure: &Structure, operators: &[BiomtOperator]) -> Structure {
+    expand(
+        structure,
+        operators
+            .iter()
+            .map(|op| (&op.transform, Some(op.chains.as_slice()))),
+    )
+}
+
+/// Applies each operator to the selected chains (all chains for `None`) and merges copies.
+fn expand<'a>(
+    structure: &Structure,
+    operators: impl Iterator<Item = (&'a Isometry3<f64>, Option<&'a [String]>)>,
+) -> Structure {
+    let mut assembly = Structure::new();
+    assembly.box_vectors = structure.box_vectors;
+
+    let mut used: HashSet<String> = HashSet::new();
+    let mut pool = CHAIN_ID_POOL.chars();
+
+    for (operator, chains) in operators {
+        let selected = structure.iter_chains().filter(|chain| {
+            chains.is_none_or(|ids| ids.is_empty() || ids.iter().any(|id| *id == chain.id))
+        });
+        for chain in selected {
+            let mut chain = chain.clone();
+
+            if used.contains(chain.id.as_str()) {
+                chain.id = fresh_chain_id(structure, &used, &mut pool, &chain).into();
+            }
+            used.insert(chain.id.to_string());
+
+            for atom in chain.iter_atoms_mut() {
+                atom.pos = operator * atom.pos;
+            }
+            assembly.add_chain(chain);
+        }
+    }
+
+    assembly
+}
+
+/// Picks a chain ID absent from both the source structure and the assembly built so far.
+fn fresh_chain_id(
+    structure: &Structure,
+    used: &HashSet<String>,
+    pool: &mut impl Iterator<Item = char>,
+    chain: &Chain,
+) -> String {
+    let is_free = |id: &String| !used.contains(id) && structure.chain(id).is_none();
+    pool.map(String::from).find(is_free).unwrap_or_else(|| {
+        (1..)
+            .map(|n| format!("{}{}", chain.id, n))
+            .find(is_free)
+            .expect("unbounded suffix search always finds a free id")
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::{
+        atom::Atom,
+        chain::Chain,
+        residue::Residue,
+        types::{Element, Point, ResidueCategory, StandardResidue},
+    };
+    use nalgebra::{Translation3, UnitQuaternion, Vector3};
+
+    fn single_atom_structure(chain_ids: &[&str]) -> Structure {
+        let mut structure = Structure::new();
+        for (i, id) in chain_ids.iter().enumerate() {
+            let mut chain = Chain::new(id);
+            let mut residue = Residue::new(
+                1,
+                None,
+                "GLY",
+                Some(StandardResidue::GLY),
+                ResidueCategory::Standard,
+            );
+            residue.add_atom(Atom::new("CA", Element::C, Point::new(1.0, i as f64, 0.0)));
+            chain.add_residue(residue);
+            structure.add_chain(chain);
+        }
+        structure
+    }
+
+    #[test]
+    fn apply_symmetry_transforms_each_copy() {
+        let structure = single_atom_structure(&["A"]);
+        let operators = [
+            Isometry3::identity(),
+            Isometry3::from_parts(
+                Translation3::new(0.0, 0.0, 5.0),
+                UnitQuaternion::from_axis_angle(&Vector3::z_axis(), std::f64::consts::PI),
+            ),
+        ];
+
+        let assembly = apply_symmetry(&structure, &operators);
+
+        assert_eq!(assembly.chain_count(), 2);
+        let original = assembly.chain("A").unwrap().iter_atoms().next().unwrap();
+        assert!((original.pos - Point::new(1.0, 0.0, 0.0)).norm() < 1e-9);
+        let mate = assembly.chain("B").unwrap().iter_atoms().next().unwrap();
+        assert!((mate.pos - Point::new(-1.0, 0.0, 5.0)).norm() < 1e-9);
+    }
+
+    #[test]
+    fn apply_symmetry_assigns_unused_chain_ids() {
+        let structure = single_atom_structure(&["A", "C"]);
+        let operators = [Isometry3::identity(); 3];
+
+        let assembly = apply_symmetry(&structure, &operators);
+
+        let ids: Vec<&str> = assembly.iter_chains().map(|c| c.id.as_str()).collect();
+        assert_eq!(ids, vec!["A", "C", "B", "D", "E", "F"]);
+        assert_eq!(assembly.atom_count(), 6);
+    }
+
+    #[test]
+    fn apply_symmetry_fallback_ids_avoid_existing_chains() {
+        let mut ids: Vec<String> = CHAIN_ID_POOL.chars().map(String::from).collect();
+        ids.push("A1".to_string());
+        let id_refs: Vec<&str> = ids.iter().map(String::as_str).collect();
+        let structure = single_atom_structure(&id_refs);
+
+        let assembly = apply_symmetry(&structure, &[Isometry3::identity(); 2]);
+
+        let assigned: HashSet<&str> = assembly.iter_chains().map(|c| c.id.as_str()).collect();
+        assert_eq!(assigned.len(), 2 * structure.chain_count());
+        assert!(assigned.contains("A2"));
+    }
+
+    #[test]
+    fn build_assembly_applies_operators_to_listed_chains_only() {
+        let structure = single_atom_structure(&["A", "B"]);
+        let shift = Isometry3::translation(0.0, 0.0, 10.0);
+        let operators = [
+            BiomtOperator {
+                chains: vec!["A".to_string(), "B".to_string()],
+                transform: Isometry3::identity(),
+            },
+            BiomtOperator {
+                chains: vec!["B".to_string()],
+                transform: shift,
+            },
+        ];
+
+        let assembly = build_assembly(&structure, &operators);
+
+        let ids: Vec<&str> = assembly.iter_chains().map(|c| c.id.as_str()).collect();
+        assert_eq!(ids, vec!["A", "B", "C"]);
+        let copy = assembly.chain("C").unwrap().iter_atoms().next().unwrap();
+        assert!((copy.pos - Point::new(1.0, 1.0, 10.0)).norm() < 1e-9);
+    }
+
+    #[test]
+    fn build_assembly_treats_empty_chain_list_as_all_chains() {
+        let structure = single_atom_structure(&["A", "B"]);
+        let operators = [BiomtOperator {
+            chains: Vec::new(),
+            transform: Isometry3::identity(),
+        }];
+
+        let assembly = build_assembly(&structure, &operators);
+
+        assert_eq!(assembly.chain_count(), 2);
+    }
+
+    #[test]
+    fn apply_symmetry_preserves_box_and_handles_empty_operators() {
+        let mut structure = single_atom_structure(&["A"]);
+        structure.box_vectors = Some([[10.0, 0.0, 0.0], [0.0, 10.0, 0.0], [0.0, 0.0, 10.0]]);
+
+        let assembly = apply_symmetry(&structure, &[]);
+
+        assert!(assembly.is_empty());
+        assert_eq!(assembly.box_vectors, structure.box_vectors);
+    }
+}
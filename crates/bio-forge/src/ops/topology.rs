@@ -5,29 +5,164 @@
 //! standard residues, while callers can provide additional hetero templates.
 //! Beyond template-driven intra-residue bonds, the builder also infers peptide,
 //! nucleic-backbone, terminal, and disulfide bonds using geometric thresholds.
+//! Residues without any template are handled according to an [`UnknownPolicy`], and
+//! residues holding several alternate conformers are bonded through the one picked by an
+//! [`AltLocPolicy`].
 
 use crate::db;
 use crate::model::{
+    atom::Atom,
     grid::Grid,
+    properties::ElementProperties,
+    residue::Residue,
     structure::Structure,
     template::Template,
     topology::{Bond, Topology},
-    types::{BondOrder, ResidueCategory, ResiduePosition},
+    types::{BondOrder, Element, ResidueCategory, ResiduePosition},
 };
+use crate::ops::contact::ResidueKey;
+use crate::ops::diagnostics::{Diagnostic, Diagnostics, Severity};
 use crate::ops::error::Error;
 use crate::utils::parallel::*;
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
+
+/// Slack (Å) added to the summed covalent radii when guessing bonds by distance.
+const BOND_GUESS_TOLERANCE: f64 = 0.45;
+
+/// Expected bonds per atom, in tenths, used to pre-size bond vectors.
+///
+/// Covalent graphs of biomolecules are close to trees, so typical systems carry about
+/// 1.1 bonds per atom once rings and backbone links are counted.
+const BONDS_PER_ATOM_TENTHS: usize = 11;
+
+/// How [`TopologyBuilder`] treats residues for which no template is available.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum UnknownPolicy {
+    /// Fail the build with a missing-template error.
+    #[default]
+    Error,
+    /// Leave the residue's atoms without intra-residue bonds.
+    Skip,
+    /// Bond atom pairs whose distance is within their summed covalent radii plus 0.45 Å;
+    /// hydrogen pairs are never bonded and every guessed bond is single.
+    GuessBonds,
+}
+
+/// Which alternate conformer of a residue [`TopologyBuilder`] bonds.
+///
+/// Atoms whose [`Atom::alt_loc`](crate::Atom::alt_loc) is `None` are shared by every conformer
+/// and always take part; labeled atoms take part only when they carry the chosen label.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum AltLocPolicy {
+    /// Bond the conformer whose label sorts first (usually `A`).
+    #[default]
+    First,
+    /// Bond the conformer with the highest mean occupancy; ties go to the first label.
+    Highest,
+    /// Bond the conformer with this label, falling back to [`AltLocPolicy::First`] in
+    /// residues that lack it.
+    Specific(char),
+}
+
+impl AltLocPolicy {
+    /// Picks the alternate-location label whose atoms are bonded in `residue`.
+    ///
+    /// Returns `None` when no atom of the residue carries a label.
+    fn select(self, residue: &Residue) -> Option<char> {
+        let mut labels: Vec<(char, f64, usize)> = Vec::new();
+        for atom in residue.iter_atoms() {
+            let Some(label) = atom.alt_loc else {
+                continue;
+            };
+            match labels.iter_mut().find(|(l, _, _)| *l == label) {
+                Some((_, occupancy, count)) => {
+                    *occupancy += atom.occupancy;
+                    *count += 1;
+                }
+                None => labels.push((label, atom.occupancy, 1)),
+            }
+        }
+        labels.sort_by_key(|(label, _, _)| *label);
+
+        match self {
+            Self::Specific(label) if labels.iter().any(|(l, _, _)| *l == label) => Some(label),
+            Self::Highest => labels
+                .iter()
+                .map(|(label, occupancy, count)| (*label, occupancy / *count as f64))
+                .max_by(|a, b| a.1.total_cmp(&b.1).then(b.0.cmp(&a.0)))
+                .map(|(label, _)| label),
+            _ => labels.first().map(|(label, _, _)| *label),
+        }
+    }
+}
+
+/// Reports whether `atom` takes part in bonding when conformer `label` is chosen.
+fn in_conformer(atom: &Atom, label: Option<char>) -> bool {
+    atom.alt_loc.is_none() || atom.alt_loc == label
+}
+
+/// Index of the first atom named `name` that belongs to conformer `label`.
+fn conformer_atom(residue: &Residue, label: Option<char>, name: &str) -> Option<usize> {
+    residue
+        .iter_atoms()
+        .position(|a| a.name == name && in_conformer(a, label))
+}
+
+/// Template bond skipped because its measured length exceeded the configured maximum.
+#[derive(Debug, Clone, PartialEq)]
+pub struct StretchedBond {
+    /// Name of the first bonded atom.
+    pub atom1: String,
+    /// Name of the second bonded atom.
+    pub atom2: String,
+    /// Measured interatomic distance in Ångström.
+    pub length: f64,
+}
+
+/// Template atoms or bonds that had to be skipped for a residue during a build.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TopologyWarning {
+    /// Identifier of the chain that owns the residue.
+    pub chain_id: String,
+    /// Residue sequence number.
+    pub residue_id: i32,
+    /// Optional insertion code of the residue.
+    pub insertion_code: Option<char>,
+    /// Residue name used for the template lookup.
+    pub residue_name: String,
+    /// Template atoms missing from the residue; bonds to them were skipped.
+    pub missing_atoms: Vec<String>,
+    /// Template bonds longer than [`TopologyBuilder::max_intra_bond_length`]; they were
+    /// left out of the topology.
+    pub stretched_bonds: Vec<StretchedBond>,
+}
 
 /// Builder responsible for creating [`Topology`] objects from a [`Structure`].
 ///
 /// The builder can augment the internal template database with additional
 /// hetero templates and tweak geometric cutoffs for disulfide, peptide, and
 /// nucleic bonds before running [`TopologyBuilder::build`].
+///
+/// # Alternate locations
+///
+/// Residues read with [`ReadOptions::keep_alt_locs`](crate::io::ReadOptions::keep_alt_locs)
+/// hold every conformer, each atom labeled through [`Atom::alt_loc`](crate::Atom::alt_loc).
+/// Only one conformer per residue is bonded, chosen by [`TopologyBuilder::altloc`]; atoms of
+/// the other conformers stay in the topology without bonds. Template bonds are resolved by
+/// atom name within that conformer and attach to the first matching atom, so a residue
+/// assembled by hand with duplicate unlabeled names still receives one bond per template
+/// entry; [`Structure::validate`] reports such duplicates.
 pub struct TopologyBuilder {
     hetero_templates: HashMap<String, Template>,
     disulfide_bond_cutoff: f64,
     peptide_bond_cutoff: f64,
     nucleic_bond_cutoff: f64,
+    disulfides_by_distance: bool,
+    rename_disulfides: bool,
+    lenient: bool,
+    unknown_policy: UnknownPolicy,
+    max_intra_bond_length: Option<f64>,
+    altloc: AltLocPolicy,
 }
 
 impl Default for TopologyBuilder {
@@ -37,6 +172,12 @@ impl Default for TopologyBuilder {
             disulfide_bond_cutoff: 2.2,
             peptide_bond_cutoff: 1.5,
             nucleic_bond_cutoff: 1.8,
+            disulfides_by_distance: false,
+            rename_disulfides: false,
+            lenient: false,
+            unknown_policy: UnknownPolicy::Error,
+            max_intra_bond_length: None,
+            altloc: AltLocPolicy::First,
         }
     }
 }
@@ -73,11 +214,144 @@ impl TopologyBuilder {
         self
     }
 
+    /// Configures the maximum C···N distance allowed for peptide bonds.
+    ///
+    /// # Arguments
+    ///
+    /// * `cutoff` - Maximum distance in Ångström between the carbonyl carbon
+    ///   of one residue and the amide nitrogen of the next.
+    pub fn peptide_cutoff(mut self, cutoff: f64) -> Self {
+        self.peptide_bond_cutoff = cutoff;
+        self
+    }
+
+    /// Configures the maximum O3'···P distance allowed for nucleic backbone bonds.
+    ///
+    /// # Arguments
+    ///
+    /// * `cutoff` - Maximum distance in Ångström between the O3' atom of one
+    ///   nucleotide and the phosphorus of the next.
+    pub fn nucleic_cutoff(mut self, cutoff: f64) -> Self {
+        self.nucleic_bond_cutoff = cutoff;
+        self
+    }
+
+    /// Controls whether disulfides are detected from SG···SG distances alone.
+    ///
+    /// By default only residues named `CYX` or `CYM` take part in disulfide
+    /// detection. When enabled, every `SG` sulfur atom is considered regardless
+    /// of its residue name, so oxidized cysteines still labeled `CYS` are
+    /// bonded whenever their SG atoms lie within the disulfide cutoff.
+    ///
+    /// # Arguments
+    ///
+    /// * `enable` - Whether to pair SG atoms by distance independent of names.
+    pub fn detect_disulfides_by_distance(mut self, enable: bool) -> Self {
+        self.disulfides_by_distance = enable;
+        self
+    }
+
+    /// Controls whether cysteines joined by a disulfide are renamed to `CYX`.
+    ///
+    /// Applies to every bridge the builder forms, whether it was found by residue name or
+    /// by [`TopologyBuilder::detect_disulfides_by_distance`]: bridged `CYS` and `CYM`
+    /// residues are relabeled so later template lookups and force fields treat them as
+    /// oxidized. The returned [`Topology`] carries the renamed residues.
+    ///
+    /// Only the residue name changes; atoms such as a leftover `HG` are kept,
+    /// so hydrogens should be added after the bridges have been resolved.
+    ///
+    /// # Arguments
+    ///
+    /// * `enable` - Whether to relabel bridged cysteines as `CYX`.
+    pub fn rename_disulfide_cys(mut self, enable: bool) -> Self {
+        self.rename_disulfides = enable;
+        self
+    }
+
+    /// Controls whether missing template atoms abort the build.
+    ///
+    /// In the default strict mode a template atom absent from its residue
+    /// fails the build with [`Error::TopologyAtomMissing`]. When lenient, the
+    /// bonds involving that atom are skipped and the residue is reported by
+    /// [`TopologyBuilder::build_reported`]. Missing templates remain errors.
+    ///
+    /// # Arguments
+    ///
+    /// * `enable` - Whether to skip bonds to missing atoms instead of failing.
+    pub fn lenient(mut self, enable: bool) -> Self {
+        self.lenient = enable;
+        self
+    }
+
+    /// Selects how residues without an internal or hetero template are handled.
+    ///
+    /// The default, [`UnknownPolicy::Error`], fails the build with
+    /// [`Error::MissingInternalTemplate`] or [`Error::MissingHeteroTemplate`].
+    /// Inter-residue bonds are still inferred for such residues either way.
+    ///
+    /// # Arguments
+    ///
+    /// * `policy` - Treatment applied to residues lacking a template.
+    pub fn on_unknown(mut self, policy: UnknownPolicy) -> Self {
+        self.unknown_policy = policy;
+        self
+    }
+
+    /// Rejects intra-residue template bonds whose measured length is too long.
+    ///
+    /// Template bonds are matched purely by atom name, so swapped atom labels or
+    /// corrupt coordinates otherwise yield bonds spanning several Ångström. When a
+    /// maximum is set, longer bonds are left out of the topology and listed in the
+    /// [`TopologyWarning::stretched_bonds`] of their residue. Inter-residue bonds keep
+    /// their own cutoffs.
+    ///
+    /// # Arguments
+    ///
+    /// * `max_length` - Maximum accepted bond length in Ångström, or `None` to
+    ///   accept every template bond (the default).
+    pub fn max_intra_bond_length(mut self, max_length: Option<f64>) -> Self {
+        self.max_intra_bond_length = max_length;
+        self
+    }
+
+    /// Selects which alternate conformer of each residue is bonded.
+    ///
+    /// Only matters for residues whose atoms carry an
+    /// [`Atom::alt_loc`](crate::Atom::alt_loc) label; atoms of the conformers not chosen are
+    /// kept in the topology but receive no bonds. Defaults to [`AltLocPolicy::First`].
+    ///
+    /// # Arguments
+    ///
+    /// * `policy` - Rule picking the bonded conformer per residue.
+    pub fn altloc(mut self, policy: AltLocPolicy) -> Self {
+        self.altloc = policy;
+        self
+    }
+
+    /// Returns the configured disulfide SG···SG cutoff in Ångström.
+    pub fn disulfide_bond_cutoff(&self) -> f64 {
+        self.disulfide_bond_cutoff
+    }
+
+    /// Returns the configured peptide C···N cutoff in Ångström.
+    pub fn peptide_bond_cutoff(&self) -> f64 {
+        self.peptide_bond_cutoff
+    }
+
+    /// Returns the configured nucleic O3'···P cutoff in Ångström.
+    pub fn nucleic_bond_cutoff(&self) -> f64 {
+        self.nucleic_bond_cutoff
+    }
+
     /// Builds a [`Topology`] for the provided structure.
     ///
     /// All intra-residue bonds are taken from templates and terminal rules,
     /// while inter-residue bonds rely on distance checks using the configured
-    /// cutoffs.
+    /// cutoffs. Polymer residues whose position is [`ResiduePosition::None`]
+    /// receive a terminal status derived from chain order and backbone
+    /// connectivity first, so terminal rules apply even when positions were
+    /// never assigned upstream.
     ///
     /// # Arguments
     ///
@@ -91,6 +365,40 @@ impl TopologyBuilder {
     ///
     /// Returns [`Error`] when a required template or atom is missing.
     pub fn build(self, structure: Structure) -> Result<Topology, Error> {
+        self.build_reported(structure).map(|(topology, _)| topology)
+    }
+
+    /// Builds a [`Topology`] and reports residues with skipped template atoms or bonds.
+    ///
+    /// Behaves like [`TopologyBuilder::build`]. Missing atoms are only reported
+    /// when [`TopologyBuilder::lenient`] is enabled, since strict builds fail
+    /// on the first missing atom instead; over-long bonds are reported whenever
+    /// [`TopologyBuilder::max_intra_bond_length`] is set.
+    ///
+    /// # Arguments
+    ///
+    /// * `structure` - Structure for which to build the bond topology.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing the built [`Topology`] together with one
+    /// [`TopologyWarning`] per incomplete residue, in chain and residue order.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error`] when a required template is missing, or when an atom
+    /// is missing and the builder is strict.
+    pub fn build_reported(
+        self,
+        mut structure: Structure,
+    ) -> Result<(Topology, Vec<TopologyWarning>), Error> {
+        Self::infer_missing_positions(
+            &mut structure,
+            self.peptide_bond_cutoff,
+            self.nucleic_bond_cutoff,
+            self.altloc,
+        );
+
         let mut chain_offsets = Vec::with_capacity(structure.chain_count());
         let mut current_offset = 0;
         for chain in structure.iter_chains() {
@@ -104,63 +412,95 @@ impl TopologyBuilder {
         let peptide_cutoff = self.peptide_bond_cutoff;
         let nucleic_cutoff = self.nucleic_bond_cutoff;
         let disulfide_cutoff = self.disulfide_bond_cutoff;
+        let disulfides_by_distance = self.disulfides_by_distance;
+        let lenient = self.lenient;
+        let unknown_policy = self.unknown_policy;
+        let max_intra_bond_length = self.max_intra_bond_length;
+        let altloc = self.altloc;
 
-        let (mut bonds, sulfurs) = structure
+        let (mut bonds, sulfurs, warnings) = structure
             .par_chains()
             .zip(chain_offsets)
             .map(|(chain, chain_start_offset)| {
-                let mut local_bonds = Vec::new();
+                let mut local_bonds =
+                    Vec::with_capacity(chain.atom_count() * BONDS_PER_ATOM_TENTHS / 10);
                 let mut local_sulfurs = Vec::new();
+                let mut local_warnings = Vec::new();
                 let mut residue_offset = chain_start_offset;
 
                 let residues: Vec<_> = chain.iter_residues().collect();
+                let labels: Vec<_> = residues.iter().map(|r| altloc.select(r)).collect();
 
                 for (i, residue) in residues.iter().enumerate() {
                     let atom_count = residue.atom_count();
+                    let mut missing_atoms = Vec::new();
+                    let first_bond = local_bonds.len();
 
                     Self::build_intra_residue_for_residue(
-                        residue,
-                        residue_offset,
+                        ConformerRef::new(residue, labels[i], residue_offset),
                         hetero_templates,
                         &mut local_bonds,
+                        lenient.then_some(&mut missing_atoms),
+                        unknown_policy,
                     )?;
 
+                    let stretched_bonds = match max_intra_bond_length {
+                        Some(max_length) => Self::remove_stretched_bonds(
+                            residue,
+                            residue_offset,
+                            &mut local_bonds,
+                            first_bond,
+                            max_length,
+                        ),
+                        None => Vec::new(),
+                    };
+
+                    if !missing_atoms.is_empty() || !stretched_bonds.is_empty() {
+                        local_warnings.push(TopologyWarning {
+                            chain_id: chain.id.to_string(),
+                            residue_id: residue.id,
+                            insertion_code: residue.insertion_code,
+                            residue_name: residue.name.to_string(),
+                            missing_atoms,
+                            stretched_bonds,
+                        });
+                    }
+
                     if i < residues.len() - 1 {
                         let next_residue = residues[i + 1];
                         let next_offset = residue_offset + atom_count;
 
                         Self::build_backbone_bond(
-                            residue,
-                            residue_offset,
-                            next_residue,
-                            next_offset,
+                            ConformerRef::new(residue, labels[i], residue_offset),
+                            ConformerRef::new(next_residue, labels[i + 1], next_offset),
                             peptide_cutoff,
                             nucleic_cutoff,
                             &mut local_bonds,
                         );
                     }
 
-                    match residue.name.as_str() {
-                        "CYX" | "CYM" => {
-                            if let Some(sg_idx) = residue.iter_atoms().position(|a| a.name == "SG")
-                            {
-                                let sg_pos = residue.atoms()[sg_idx].pos;
-                                local_sulfurs.push((sg_pos, residue_offset + sg_idx));
-                            }
-                        }
-                        _ => {}
+                    if (disulfides_by_distance || matches!(residue.name.as_str(), "CYX" | "CYM"))
+                        && let Some(sg_idx) = conformer_atom(residue, labels[i], "SG")
+                    {
+                        let sg_pos = residue.atoms()[sg_idx].pos;
+                        local_sulfurs.push((sg_pos, residue_offset + sg_idx));
                     }
 
                     residue_offset += atom_count;
                 }
 
-                Ok((local_bonds, local_sulfurs))
+                Ok((local_bonds, local_sulfurs, local_warnings))
             })
             .try_reduce(
-                || (Vec::new(), Vec::new()),
+                || (Vec::new(), Vec::new(), Vec::new()),
                 |mut a, b| {
-                    a.0.extend(b.0);
+                    if a.0.is_empty() {
+                        a.0 = b.0;
+                    } else {
+                        a.0.extend(b.0);
+                    }
                     a.1.extend(b.1);
+                    a.2.extend(b.2);
                     Ok(a)
                 },
             )?;
@@ -187,81 +527,358 @@ impl TopologyBuilder {
                 })
                 .collect();
 
+            if self.rename_disulfides {
+                Self::rename_bridged_cysteines(&mut structure, &disulfide_bonds);
+            }
+
             bonds.extend(disulfide_bonds);
         }
 
         bonds.par_sort_unstable();
         bonds.dedup();
 
-        Ok(Topology::new(structure, bonds))
+        Ok((Topology::new(structure, bonds), warnings))
+    }
+
+    /// Builds a [`Topology`] and reports skipped template atoms and bonds as [`Diagnostics`].
+    ///
+    /// Behaves like [`TopologyBuilder::build_reported`]; each [`TopologyWarning`] becomes one
+    /// [`Severity::Warning`] entry per missing atom list and per dropped bond. Template atoms
+    /// present under a differently cased or padded name (see [`Residue::atom_ci`]) are
+    /// flagged with the name actually found, since renaming them restores the bonds.
+    ///
+    /// # Arguments
+    ///
+    /// * `structure` - Structure for which to build the bond topology.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing the built [`Topology`] and findings in chain and residue order.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error`] under the same conditions as [`TopologyBuilder::build_reported`].
+    pub fn build_with_diagnostics(
+        self,
+        structure: Structure,
+    ) -> Result<(Topology, Diagnostics), Error> {
+        let (topology, warnings) = self.build_reported(structure)?;
+
+        let mut diagnostics = Diagnostics::new();
+        for warning in warnings {
+            let mut messages = Vec::new();
+            if !warning.missing_atoms.is_empty() {
+                let residue = topology.structure().find_residue(
+                    &warning.chain_id,
+                    warning.residue_id,
+                    warning.insertion_code,
+                );
+                let names: Vec<String> = warning
+                    .missing_atoms
+                    .iter()
+                    .map(|name| match residue.and_then(|r| r.atom_ci(name)) {
+                        Some(atom) => format!("{name} (found as {:?})", &*atom.name),
+                        None => name.clone(),
+                    })
+                    .collect();
+                messages.push(format!(
+                    "template atoms of {} missing; their bonds were skipped: {}",
+                    warning.residue_name,
+                    names.join(", ")
+                ));
+            }
+            for bond in &warning.stretched_bonds {
+                messages.push(format!(
+                    "dropped template bond {}-{} stretched to {:.2} Å",
+                    bond.atom1, bond.atom2, bond.length
+                ));
+            }
+
+            let key = ResidueKey {
+                chain_id: warning.chain_id,
+                residue_id: warning.residue_id,
+                insertion_code: warning.insertion_code,
+            };
+            diagnostics.extend(messages.into_iter().map(|message| {
+                Diagnostic::for_key(Severity::Warning, "topology", key.clone(), message)
+            }));
+        }
+
+        Ok((topology, diagnostics))
+    }
+
+    /// Derives positions for polymer residues whose [`ResiduePosition`] is unset.
+    ///
+    /// A residue starts a segment when the preceding residue of its chain is not
+    /// backbone-linked to it, and ends one when the following residue is not;
+    /// single-residue segments count as starts. Residues that already carry a
+    /// position are left untouched.
+    fn infer_missing_positions(
+        structure: &mut Structure,
+        peptide_cutoff: f64,
+        nucleic_cutoff: f64,
+        altloc: AltLocPolicy,
+    ) {
+        for chain in structure.iter_chains_mut() {
+            let links: Vec<bool> = chain
+                .residues()
+                .windows(2)
+                .map(|pair| {
+                    Self::is_backbone_linked(
+                        ConformerRef::new(&pair[0], altloc.select(&pair[0]), 0),
+                        ConformerRef::new(&pair[1], altloc.select(&pair[1]), 0),
+                        peptide_cutoff,
+                        nucleic_cutoff,
+                    )
+                })
+                .collect();
+
+            for (i, residue) in chain.iter_residues_mut().enumerate() {
+                if residue.position != ResiduePosition::None
+                    || residue.category != ResidueCategory::Standard
+                {
+                    continue;
+                }
+                let Some(standard) = residue.standard_name else {
+                    continue;
+                };
+
+                let linked_before = i > 0 && links[i - 1];
+                let linked_after = links.get(i).copied().unwrap_or(false);
+
+                residue.position = match (standard.is_protein(), standard.is_nucleic()) {
+                    (true, _) if !linked_before => ResiduePosition::NTerminal,
+                    (true, _) if !linked_after => ResiduePosition::CTerminal,
+                    (_, true) if !linked_before => ResiduePosition::FivePrime,
+                    (_, true) if !linked_after => ResiduePosition::ThreePrime,
+                    (true, _) | (_, true) => ResiduePosition::Internal,
+                    _ => continue,
+                };
+            }
+        }
+    }
+
+    /// Reports whether two consecutive residues are joined by a backbone bond.
+    fn is_backbone_linked(
+        curr: ConformerRef<'_>,
+        next: ConformerRef<'_>,
+        peptide_cutoff: f64,
+        nucleic_cutoff: f64,
+    ) -> bool {
+        if curr.residue.category != ResidueCategory::Standard
+            || next.residue.category != ResidueCategory::Standard
+        {
+            return false;
+        }
+        let (Some(std1), Some(std2)) = (curr.residue.standard_name, next.residue.standard_name)
+        else {
+            return false;
+        };
+
+        let (name1, name2, cutoff) = if std1.is_protein() && std2.is_protein() {
+            ("C", "N", peptide_cutoff)
+        } else if std1.is_nucleic() && std2.is_nucleic() {
+            ("O3'", "P", nucleic_cutoff)
+        } else {
+            return false;
+        };
+
+        match (curr.atom(name1), next.atom(name2)) {
+            (Some(a1), Some(a2)) => a1.distance_squared(a2) <= cutoff * cutoff,
+            _ => false,
+        }
+    }
+
+    /// Renames `CYS` and `CYM` residues that own an endpoint of a disulfide bond to `CYX`.
+    fn rename_bridged_cysteines(structure: &mut Structure, disulfide_bonds: &[Bond]) {
+        let bridged: HashSet<usize> = disulfide_bonds
+            .iter()
+            .flat_map(|b| [b.a1_idx, b.a2_idx])
+            .collect();
+
+        let mut offset = 0;
+        for chain in structure.iter_chains_mut() {
+            for residue in chain.iter_residues_mut() {
+                let atom_count = residue.atom_count();
+                if matches!(residue.name.as_str(), "CYS" | "CYM")
+                    && (offset..offset + atom_count).any(|i| bridged.contains(&i))
+                {
+                    residue.name = "CYX".into();
+                }
+                offset += atom_count;
+            }
+        }
     }
 
     /// Helper to generate intra-residue bonds for a single residue.
     fn build_intra_residue_for_residue(
-        residue: &crate::model::residue::Residue,
-        offset: usize,
+        conformer: ConformerRef<'_>,
         hetero_templates: &HashMap<String, Template>,
         bonds: &mut Vec<Bond>,
+        mut missing_atoms: Option<&mut Vec<String>>,
+        unknown_policy: UnknownPolicy,
     ) -> Result<(), Error> {
+        let residue = conformer.residue;
         if residue.category == ResidueCategory::Ion {
             return Ok(());
         }
 
         if residue.category == ResidueCategory::Standard {
             let tmpl_name = &residue.name;
-            let tmpl_view =
-                db::get_template(tmpl_name).ok_or_else(|| Error::MissingInternalTemplate {
-                    res_name: tmpl_name.to_string(),
-                })?;
+            let Some(tmpl_view) = db::get_template(tmpl_name) else {
+                return Self::handle_unknown_residue(
+                    conformer,
+                    unknown_policy,
+                    bonds,
+                    Error::MissingInternalTemplate {
+                        res_name: tmpl_name.to_string(),
+                    },
+                );
+            };
 
             for (a1_name, a2_name, order) in tmpl_view.bonds() {
-                Self::try_add_bond(residue, offset, a1_name, a2_name, order, bonds)?;
+                Self::try_add_bond(
+                    conformer,
+                    a1_name,
+                    a2_name,
+                    order,
+                    bonds,
+                    missing_atoms.as_deref_mut(),
+                )?;
             }
 
-            Self::handle_terminal_intra_bonds(residue, offset, bonds)?;
+            Self::handle_terminal_intra_bonds(conformer, bonds)?;
         } else if residue.category == ResidueCategory::Hetero {
-            let tmpl = hetero_templates.get(residue.name.as_str()).ok_or_else(|| {
-                Error::MissingHeteroTemplate {
-                    res_name: residue.name.to_string(),
-                }
-            })?;
+            let Some(tmpl) = hetero_templates.get(residue.name.as_str()) else {
+                return Self::handle_unknown_residue(
+                    conformer,
+                    unknown_policy,
+                    bonds,
+                    Error::MissingHeteroTemplate {
+                        res_name: residue.name.to_string(),
+                    },
+                );
+            };
 
             for (a1_name, a2_name, order) in tmpl.bonds() {
-                Self::try_add_bond(residue, offset, a1_name, a2_name, *order, bonds)?;
+                Self::try_add_bond(
+                    conformer,
+                    a1_name,
+                    a2_name,
+                    *order,
+                    bonds,
+                    missing_atoms.as_deref_mut(),
+                )?;
             }
         }
 
         Ok(())
     }
 
+    /// Drops the residue's bonds from `first_bond` onward that exceed `max_length`.
+    ///
+    /// Only bonds appended for `residue` (starting at `first_bond`) are inspected;
+    /// the removed bonds are returned with their measured lengths.
+    fn remove_stretched_bonds(
+        residue: &Residue,
+        offset: usize,
+        bonds: &mut Vec<Bond>,
+        first_bond: usize,
+        max_length: f64,
+    ) -> Vec<StretchedBond> {
+        let atoms = residue.atoms();
+        let mut stretched = Vec::new();
+        for bond in bonds.split_off(first_bond) {
+            let a1 = &atoms[bond.a1_idx - offset];
+            let a2 = &atoms[bond.a2_idx - offset];
+            let length = a1.distance(a2);
+            if length <= max_length {
+                bonds.push(bond);
+            } else {
+                stretched.push(StretchedBond {
+                    atom1: a1.name.to_string(),
+                    atom2: a2.name.to_string(),
+                    length,
+                });
+            }
+        }
+        stretched
+    }
+
+    /// Applies the [`UnknownPolicy`] to a residue whose template is missing.
+    ///
+    /// `missing` is returned unchanged under [`UnknownPolicy::Error`].
+    fn handle_unknown_residue(
+        conformer: ConformerRef<'_>,
+        policy: UnknownPolicy,
+        bonds: &mut Vec<Bond>,
+        missing: Error,
+    ) -> Result<(), Error> {
+        match policy {
+            UnknownPolicy::Error => Err(missing),
+            UnknownPolicy::Skip => Ok(()),
+            UnknownPolicy::GuessBonds => {
+                Self::guess_intra_residue_bonds(conformer, bonds);
+                Ok(())
+            }
+        }
+    }
+
+    /// Bonds every non-hydrogen-pair of the conformer's atoms lying within covalent bonding
+    /// distance.
+    fn guess_intra_residue_bonds(conformer: ConformerRef<'_>, bonds: &mut Vec<Bond>) {
+        let properties = ElementProperties::default();
+        let atoms = conformer.residue.atoms();
+        let offset = conformer.offset;
+        let in_use = |atom: &Atom| in_conformer(atom, conformer.alt_loc);
+
+        for (i, a1) in atoms.iter().enumerate().filter(|(_, a)| in_use(a)) {
+            for (j, a2) in atoms
+                .iter()
+                .enumerate()
+                .skip(i + 1)
+                .filter(|(_, a)| in_use(a))
+            {
+                if a1.element == Element::H && a2.element == Element::H {
+                    continue;
+                }
+                let max_dist = properties.covalent_radius(a1.element)
+                    + properties.covalent_radius(a2.element)
+                    + BOND_GUESS_TOLERANCE;
+                if a1.distance_squared(a2) <= max_dist * max_dist {
+                    bonds.push(Bond::new(offset + i, offset + j, BondOrder::Single));
+                }
+            }
+        }
+    }
+
     /// Helper to generate backbone bonds between two residues.
     fn build_backbone_bond(
-        curr: &crate::model::residue::Residue,
-        curr_offset: usize,
-        next: &crate::model::residue::Residue,
-        next_offset: usize,
+        curr: ConformerRef<'_>,
+        next: ConformerRef<'_>,
         peptide_cutoff: f64,
         nucleic_cutoff: f64,
         bonds: &mut Vec<Bond>,
     ) {
-        if curr.category != ResidueCategory::Standard || next.category != ResidueCategory::Standard
+        if curr.residue.category != ResidueCategory::Standard
+            || next.residue.category != ResidueCategory::Standard
         {
             return;
         }
 
-        if let (Some(std1), Some(std2)) = (curr.standard_name, next.standard_name) {
+        if let (Some(std1), Some(std2)) = (curr.residue.standard_name, next.residue.standard_name) {
             if std1.is_protein() && std2.is_protein() {
                 Self::connect_atoms_if_close(
-                    AtomLocator::new(curr, curr_offset, "C"),
-                    AtomLocator::new(next, next_offset, "N"),
+                    (curr, "C"),
+                    (next, "N"),
                     peptide_cutoff,
                     BondOrder::Single,
                     bonds,
                 );
             } else if std1.is_nucleic() && std2.is_nucleic() {
                 Self::connect_atoms_if_close(
-                    AtomLocator::new(curr, curr_offset, "O3'"),
-                    AtomLocator::new(next, next_offset, "P"),
+                    (curr, "O3'"),
+                    (next, "P"),
                     nucleic_cutoff,
                     BondOrder::Single,
                     bonds,
@@ -272,16 +889,20 @@ impl TopologyBuilder {
 
     /// Attempts to add a bond and reports informative errors when atoms are
     /// missing.
+    ///
+    /// When `missing_atoms` is provided, an absent atom is recorded there and
+    /// the bond is skipped instead of returning an error.
     fn try_add_bond(
-        residue: &crate::model::residue::Residue,
-        offset: usize,
+        conformer: ConformerRef<'_>,
         name1: &str,
         name2: &str,
         order: BondOrder,
         bonds: &mut Vec<Bond>,
+        missing_atoms: Option<&mut Vec<String>>,
     ) -> Result<(), Error> {
-        let idx1 = residue.iter_atoms().position(|a| a.name == name1);
-        let idx2 = residue.iter_atoms().position(|a| a.name == name2);
+        let (residue, offset) = (conformer.residue, conformer.offset);
+        let idx1 = conformer.index_of(name1);
+        let idx2 = conformer.index_of(name2);
 
         match (idx1, idx2) {
             (Some(i1), Some(i2)) => {
@@ -290,16 +911,28 @@ impl TopologyBuilder {
             }
             (None, _) if Self::is_optional_terminal_atom(residue, name1) => Ok(()),
             (_, None) if Self::is_optional_terminal_atom(residue, name2) => Ok(()),
-            (None, _) => Err(Error::topology_atom_missing(
-                &*residue.name,
-                residue.id,
-                name1,
-            )),
-            (_, None) => Err(Error::topology_atom_missing(
-                &*residue.name,
-                residue.id,
-                name2,
-            )),
+            (None, _) | (_, None) => {
+                let absent = [(idx1, name1), (idx2, name2)]
+                    .into_iter()
+                    .filter(|(idx, _)| idx.is_none())
+                    .map(|(_, name)| name);
+
+                match missing_atoms {
+                    Some(missing) => {
+                        for name in absent {
+                            if !missing.iter().any(|m| m == name) {
+                                missing.push(name.to_string());
+                            }
+                        }
+                        Ok(())
+                    }
+                    None => Err(Error::topology_atom_missing(
+                        &*residue.name,
+                        residue.id,
+                        if idx1.is_none() { name1 } else { name2 },
+                    )),
+                }
+            }
         }
     }
 
@@ -332,18 +965,17 @@ impl TopologyBuilder {
     /// Adds missing bonds for termini (protein and nucleic acid) that are not
     /// explicitly listed in templates.
     fn handle_terminal_intra_bonds(
-        residue: &crate::model::residue::Residue,
-        offset: usize,
+        conformer: ConformerRef<'_>,
         bonds: &mut Vec<Bond>,
     ) -> Result<(), Error> {
+        let (residue, offset) = (conformer.residue, conformer.offset);
         if residue.position == ResiduePosition::NTerminal
             && residue.standard_name.is_some_and(|s| s.is_protein())
         {
             for h_name in ["H1", "H2", "H3"] {
-                if let (Some(h_idx), Some(n_idx)) = (
-                    residue.iter_atoms().position(|a| a.name == h_name),
-                    residue.iter_atoms().position(|a| a.name == "N"),
-                ) {
+                if let (Some(h_idx), Some(n_idx)) =
+                    (conformer.index_of(h_name), conformer.index_of("N"))
+                {
                     bonds.push(Bond::new(offset + h_idx, offset + n_idx, BondOrder::Single));
                 }
             }
@@ -352,8 +984,8 @@ impl TopologyBuilder {
         if residue.position == ResiduePosition::CTerminal
             && residue.standard_name.is_some_and(|s| s.is_protein())
         {
-            let c_idx = residue.iter_atoms().position(|a| a.name == "C");
-            let oxt_idx = residue.iter_atoms().position(|a| a.name == "OXT");
+            let c_idx = conformer.index_of("C");
+            let oxt_idx = conformer.index_of("OXT");
 
             if let (Some(c_idx), Some(oxt_idx)) = (c_idx, oxt_idx) {
                 bonds.push(Bond::new(
@@ -363,7 +995,7 @@ impl TopologyBuilder {
                 ));
 
                 for h_name in ["HXT", "HOXT"] {
-                    if let Some(h_idx) = residue.iter_atoms().position(|a| a.name == h_name) {
+                    if let Some(h_idx) = conformer.index_of(h_name) {
                         bonds.push(Bond::new(
                             offset + oxt_idx,
                             offset + h_idx,
@@ -377,17 +1009,16 @@ impl TopologyBuilder {
         if residue.position == ResiduePosition::FivePrime
             && residue.standard_name.is_some_and(|s| s.is_nucleic())
         {
-            if let (Some(p_idx), Some(op3_idx)) = (
-                residue.iter_atoms().position(|a| a.name == "P"),
-                residue.iter_atoms().position(|a| a.name == "OP3"),
-            ) {
+            if let (Some(p_idx), Some(op3_idx)) =
+                (conformer.index_of("P"), conformer.index_of("OP3"))
+            {
                 bonds.push(Bond::new(
                     offset + p_idx,
                     offset + op3_idx,
                     BondOrder::Single,
                 ));
 
-                if let Some(hop3_idx) = residue.iter_atoms().position(|a| a.name == "HOP3") {
+                if let Some(hop3_idx) = conformer.index_of("HOP3") {
                     bonds.push(Bond::new(
                         offset + op3_idx,
                         offset + hop3_idx,
@@ -396,10 +1027,9 @@ impl TopologyBuilder {
                 }
             }
 
-            if let (Some(ho5_idx), Some(o5_idx)) = (
-                residue.iter_atoms().position(|a| a.name == "HO5'"),
-                residue.iter_atoms().position(|a| a.name == "O5'"),
-            ) {
+            if let (Some(ho5_idx), Some(o5_idx)) =
+                (conformer.index_of("HO5'"), conformer.index_of("O5'"))
+            {
                 bonds.push(Bond::new(
                     offset + ho5_idx,
                     offset + o5_idx,
@@ -411,8 +1041,8 @@ impl TopologyBuilder {
         if residue.position == ResiduePosition::ThreePrime
             && residue.standard_name.is_some_and(|s| s.is_nucleic())
         {
-            let ho3_idx = residue.iter_atoms().position(|a| a.name == "HO3'");
-            let o3_idx = residue.iter_atoms().position(|a| a.name == "O3'");
+            let ho3_idx = conformer.index_of("HO3'");
+            let o3_idx = conformer.index_of("O3'");
 
             if let (Some(h_idx), Some(o_idx)) = (ho3_idx, o3_idx) {
                 bonds.push(Bond::new(offset + h_idx, offset + o_idx, BondOrder::Single));
@@ -424,22 +1054,14 @@ impl TopologyBuilder {
 
     /// Adds a bond when the specified atoms are within the provided cutoff.
     fn connect_atoms_if_close(
-        first: AtomLocator<'_>,
-        second: AtomLocator<'_>,
+        (first, first_name): (ConformerRef<'_>, &str),
+        (second, second_name): (ConformerRef<'_>, &str),
         cutoff: f64,
         order: BondOrder,
         bonds: &mut Vec<Bond>,
     ) {
-        if let (Some(idx1), Some(idx2)) = (
-            first
-                .residue
-                .iter_atoms()
-                .position(|a| a.name == first.atom_name),
-            second
-                .residue
-                .iter_atoms()
-                .position(|a| a.name == second.atom_name),
-        ) {
+        if let (Some(idx1), Some(idx2)) = (first.index_of(first_name), second.index_of(second_name))
+        {
             let p1 = first.residue.atoms()[idx1].pos;
             let p2 = second.residue.atoms()[idx2].pos;
 
@@ -450,22 +1072,33 @@ impl TopologyBuilder {
     }
 }
 
-/// Utility that couples a residue reference with its global atom offset.
-struct AtomLocator<'a> {
-    residue: &'a crate::model::residue::Residue,
+/// Utility that couples a residue reference with its bonded conformer and global atom offset.
+#[derive(Clone, Copy)]
+struct ConformerRef<'a> {
+    residue: &'a Residue,
+    alt_loc: Option<char>,
     offset: usize,
-    atom_name: &'a str,
 }
 
-impl<'a> AtomLocator<'a> {
-    /// Creates a locator describing a specific atom inside a residue.
-    fn new(residue: &'a crate::model::residue::Residue, offset: usize, atom_name: &'a str) -> Self {
+impl<'a> ConformerRef<'a> {
+    /// Creates a reference to the conformer `alt_loc` of a residue.
+    fn new(residue: &'a Residue, alt_loc: Option<char>, offset: usize) -> Self {
         Self {
             residue,
+            alt_loc,
             offset,
-            atom_name,
         }
     }
+
+    /// Local index of the conformer's atom named `name`.
+    fn index_of(&self, name: &str) -> Option<usize> {
+        conformer_atom(self.residue, self.alt_loc, name)
+    }
+
+    /// The conformer's atom named `name`.
+    fn atom(&self, name: &str) -> Option<&'a Atom> {
+        self.index_of(name).map(|idx| &self.residue.atoms()[idx])
+    }
 }
 
 #[cfg(test)]
@@ -506,14 +1139,10 @@ mod tests {
         );
         residue.position = position;
 
-        for (atom_name, element, point) in template.heavy_atoms() {
+        for (atom_name, element, point) in template.all_atoms() {
             residue.add_atom(Atom::new(atom_name, element, point));
         }
 
-        for (atom_name, point, _) in template.hydrogens() {
-            residue.add_atom(Atom::new(atom_name, Element::H, point));
-        }
-
         residue
     }
 
@@ -623,6 +1252,173 @@ mod tests {
         assert!(has_bond(&topology, c_idx, n_idx, BondOrder::Single));
     }
 
+    #[test]
+    fn build_links_insertion_coded_residues_after_sorting() {
+        let residue1 = standard_residue("GLY", 10, ResiduePosition::NTerminal);
+        let mut residue2 = standard_residue("ALA", 10, ResiduePosition::Internal);
+        let mut residue3 = standard_residue("ALA", 10, ResiduePosition::Internal);
+        residue2.insertion_code = Some('A');
+        residue3.insertion_code = Some('B');
+
+        let link = |prev: &Residue, next: &mut Residue| {
+            let target = prev.atom("C").unwrap().pos + Vector3::new(1.33, 0.0, 0.0);
+            let offset = target - next.atom("N").unwrap().pos;
+            translate_residue(next, offset);
+        };
+        link(&residue1, &mut residue2);
+        link(&residue2, &mut residue3);
+
+        let mut structure = structure_from_residues(vec![residue3, residue1, residue2]);
+        assert_eq!(structure.residue_order_issues().len(), 1);
+        structure.sort_residues();
+
+        let topology = TopologyBuilder::new()
+            .build(structure)
+            .expect("build topology");
+        let index = |code: Option<char>, name: &str| {
+            topology
+                .structure()
+                .iter_atoms_with_context()
+                .position(|(_, r, a)| r.insertion_code == code && a.name == name)
+                .unwrap()
+        };
+
+        assert!(has_bond(
+            &topology,
+            index(None, "C"),
+            index(Some('A'), "N"),
+            BondOrder::Single
+        ));
+        assert!(has_bond(
+            &topology,
+            index(Some('A'), "C"),
+            index(Some('B'), "N"),
+            BondOrder::Single
+        ));
+    }
+
+    #[test]
+    fn build_infers_unset_positions_from_backbone_connectivity() {
+        let mut residue1 = standard_residue("GLY", 1, ResiduePosition::None);
+        let mut residue2 = standard_residue("ALA", 2, ResiduePosition::None);
+        let mut residue3 = standard_residue("ALA", 3, ResiduePosition::None);
+
+        let c_pos = residue1.atom("C").unwrap().pos;
+        let n_pos = residue2.atom("N").unwrap().pos;
+        translate_residue(&mut residue2, c_pos + Vector3::new(1.33, 0.0, 0.0) - n_pos);
+        translate_residue(&mut residue3, Vector3::new(30.0, 0.0, 0.0));
+        let h1_pos = residue1.atom("N").unwrap().pos + Vector3::new(0.0, 1.01, 0.0);
+        residue1.add_atom(Atom::new("H1", Element::H, h1_pos));
+
+        let structure = structure_from_residues(vec![residue1, residue2, residue3]);
+        let topology = TopologyBuilder::new()
+            .build(structure)
+            .expect("build topology");
+
+        let positions: Vec<_> = topology
+            .structure()
+            .iter_chains()
+            .flat_map(|c| c.iter_residues().map(|r| r.position))
+            .collect();
+        assert_eq!(
+            positions,
+            vec![
+                ResiduePosition::NTerminal,
+                ResiduePosition::CTerminal,
+                ResiduePosition::NTerminal,
+            ]
+        );
+
+        let n_idx = global_atom_index(&topology, "A", 1, "N");
+        let h1_idx = global_atom_index(&topology, "A", 1, "H1");
+        assert!(has_bond(&topology, n_idx, h1_idx, BondOrder::Single));
+    }
+
+    #[test]
+    fn build_accepts_unphosphorylated_five_prime_without_position() {
+        let mut residue = five_prime_residue_without_phosphate(1);
+        residue.position = ResiduePosition::None;
+        let structure = structure_from_residues(vec![residue]);
+
+        let topology = TopologyBuilder::new()
+            .build(structure)
+            .expect("build topology");
+
+        let residue = topology.structure().find_residue("A", 1, None).unwrap();
+        assert_eq!(residue.position, ResiduePosition::FivePrime);
+        let o5_idx = global_atom_index(&topology, "A", 1, "O5'");
+        let ho5_idx = global_atom_index(&topology, "A", 1, "HO5'");
+        assert!(has_bond(&topology, o5_idx, ho5_idx, BondOrder::Single));
+    }
+
+    #[test]
+    fn builder_exposes_configured_cutoffs() {
+        let builder = TopologyBuilder::new();
+        assert_eq!(builder.disulfide_bond_cutoff(), 2.2);
+        assert_eq!(builder.peptide_bond_cutoff(), 1.5);
+        assert_eq!(builder.nucleic_bond_cutoff(), 1.8);
+
+        let builder = builder
+            .disulfide_cutoff(2.5)
+            .peptide_cutoff(2.0)
+            .nucleic_cutoff(2.4);
+        assert_eq!(builder.disulfide_bond_cutoff(), 2.5);
+        assert_eq!(builder.peptide_bond_cutoff(), 2.0);
+        assert_eq!(builder.nucleic_bond_cutoff(), 2.4);
+    }
+
+    #[test]
+    fn build_respects_custom_peptide_cutoff() {
+        let residue1 = standard_residue("GLY", 1, ResiduePosition::NTerminal);
+        let mut residue2 = standard_residue("ALA", 2, ResiduePosition::Internal);
+
+        let c_pos = residue1.atom("C").unwrap().pos;
+        let n_pos = residue2.atom("N").unwrap().pos;
+        let target_n = c_pos + Vector3::new(1.8, 0.0, 0.0);
+        translate_residue(&mut residue2, target_n - n_pos);
+
+        let structure = structure_from_residues(vec![residue1, residue2]);
+
+        let strict = TopologyBuilder::new()
+            .build(structure.clone())
+            .expect("build topology");
+        let c_idx = global_atom_index(&strict, "A", 1, "C");
+        let n_idx = global_atom_index(&strict, "A", 2, "N");
+        assert!(!has_bond(&strict, c_idx, n_idx, BondOrder::Single));
+
+        let loose = TopologyBuilder::new()
+            .peptide_cutoff(2.0)
+            .build(structure)
+            .expect("build topology");
+        assert!(has_bond(&loose, c_idx, n_idx, BondOrder::Single));
+    }
+
+    #[test]
+    fn build_respects_custom_nucleic_cutoff() {
+        let residue1 = standard_residue("DA", 1, ResiduePosition::FivePrime);
+        let mut residue2 = standard_residue("DT", 2, ResiduePosition::ThreePrime);
+
+        let o3_pos = residue1.atom("O3'").unwrap().pos;
+        let p_pos = residue2.atom("P").unwrap().pos;
+        let target_p = o3_pos + Vector3::new(0.0, 0.0, 2.1);
+        translate_residue(&mut residue2, target_p - p_pos);
+
+        let structure = structure_from_residues(vec![residue1, residue2]);
+
+        let strict = TopologyBuilder::new()
+            .build(structure.clone())
+            .expect("build topology");
+        let o3_idx = global_atom_index(&strict, "A", 1, "O3'");
+        let p_idx = global_atom_index(&strict, "A", 2, "P");
+        assert!(!has_bond(&strict, o3_idx, p_idx, BondOrder::Single));
+
+        let loose = TopologyBuilder::new()
+            .nucleic_cutoff(2.5)
+            .build(structure)
+            .expect("build topology");
+        assert!(has_bond(&loose, o3_idx, p_idx, BondOrder::Single));
+    }
+
     #[test]
     fn build_creates_nucleic_backbone_bond() {
         let residue1 = standard_residue("DA", 1, ResiduePosition::FivePrime);
@@ -681,6 +1477,243 @@ mod tests {
         }
     }
 
+    #[test]
+    fn lenient_build_skips_bonds_to_missing_atoms_and_reports_them() {
+        let mut residue = standard_residue("GLY", 1, ResiduePosition::Internal);
+        assert!(residue.remove_atom("CA").is_some(), "expected CA atom");
+        let complete = standard_residue("GLY", 2, ResiduePosition::Internal);
+
+        let structure = structure_from_residues(vec![residue, complete]);
+        let (topology, warnings) = TopologyBuilder::new()
+            .lenient(true)
+            .build_reported(structure)
+            .unwrap();
+
+        assert_eq!(warnings.len(), 1);
+        assert_eq!(warnings[0].chain_id, "A");
+        assert_eq!(warnings[0].residue_id, 1);
+        assert_eq!(warnings[0].residue_name, "GLY");
+        assert_eq!(warnings[0].missing_atoms, vec!["CA".to_string()]);
+
+        let n_idx = global_atom_index(&topology, "A", 1, "N");
+        let h_idx = global_atom_index(&topology, "A", 1, "H");
+        assert!(has_bond(&topology, n_idx, h_idx, BondOrder::Single));
+        assert_eq!(topology.bonds_in_residue("A", 1, None).count(), 2);
+        assert_eq!(topology.bonds_in_residue("A", 2, None).count(), 6);
+    }
+
+    #[test]
+    fn max_intra_bond_length_skips_and_reports_stretched_template_bonds() {
+        let mut displaced = standard_residue("GLY", 1, ResiduePosition::Internal);
+        displaced
+            .iter_atoms_mut()
+            .find(|a| a.name == "HA2")
+            .expect("HA2 atom")
+            .translate_by(&Vector3::new(5.0, 0.0, 0.0));
+        let complete = standard_residue("GLY", 2, ResiduePosition::Internal);
+        let structure = structure_from_residues(vec![displaced, complete]);
+
+        let (topology, warnings) = TopologyBuilder::new()
+            .max_intra_bond_length(Some(2.0))
+            .build_reported(structure)
+            .unwrap();
+
+        assert_eq!(warnings.len(), 1);
+        assert_eq!(warnings[0].residue_id, 1);
+        assert!(warnings[0].missing_atoms.is_empty());
+        assert_eq!(warnings[0].stretched_bonds.len(), 1);
+        let stretched = &warnings[0].stretched_bonds[0];
+        assert_eq!(
+            (stretched.atom1.as_str(), stretched.atom2.as_str()),
+            ("CA", "HA2")
+        );
+        assert!(stretched.length > 4.0);
+
+        let ca_idx = global_atom_index(&topology, "A", 1, "CA");
+        let ha2_idx = global_atom_index(&topology, "A", 1, "HA2");
+        assert!(!has_bond(&topology, ca_idx, ha2_idx, BondOrder::Single));
+        assert_eq!(topology.bonds_in_residue("A", 1, None).count(), 5);
+        assert_eq!(topology.bonds_in_residue("A", 2, None).count(), 6);
+    }
+
+    #[test]
+    fn build_with_diagnostics_reports_skipped_atoms_and_bonds_as_warnings() {
+        let mut residue = standard_residue("GLY", 1, ResiduePosition::Internal);
+        assert!(residue.remove_atom("CA").is_some(), "expected CA atom");
+        let mut displaced = standard_residue("GLY", 2, ResiduePosition::Internal);
+        displaced
+            .iter_atoms_mut()
+            .find(|a| a.name == "HA2")
+            .expect("HA2 atom")
+            .translate_by(&Vector3::new(5.0, 0.0, 0.0));
+        let structure = structure_from_residues(vec![residue, displaced]);
+
+        let (topology, diagnostics) = TopologyBuilder::new()
+            .lenient(true)
+            .max_intra_bond_length(Some(2.0))
+            .build_with_diagnostics(structure)
+            .unwrap();
+
+        assert_eq!(topology.bonds_in_residue("A", 1, None).count(), 2);
+        assert_eq!(diagnostics.max_severity(), Some(Severity::Warning));
+        let messages: Vec<String> = diagnostics.iter().map(|d| d.to_string()).collect();
+        assert_eq!(messages.len(), 2);
+        assert_eq!(
+            messages[0],
+            "warning [topology] A:1: template atoms of GLY missing; their bonds were skipped: CA"
+        );
+        assert!(messages[1].starts_with("warning [topology] A:2: dropped template bond CA-HA2"));
+    }
+
+    #[test]
+    fn build_with_diagnostics_points_out_atoms_with_mismatched_case() {
+        let mut residue = standard_residue("GLY", 1, ResiduePosition::Internal);
+        residue.atom_mut("CA").expect("CA atom").name = "Ca".into();
+        let structure = structure_from_residues(vec![residue]);
+
+        let (_, diagnostics) = TopologyBuilder::new()
+            .lenient(true)
+            .build_with_diagnostics(structure)
+            .unwrap();
+
+        let messages: Vec<String> = diagnostics.iter().map(|d| d.to_string()).collect();
+        assert_eq!(messages.len(), 1);
+        assert!(
+            messages[0].ends_with(r#"their bonds were skipped: CA (found as "Ca")"#),
+            "{}",
+            messages[0]
+        );
+    }
+
+    #[test]
+    fn duplicate_atom_names_bind_only_the_first_conformer() {
+        let single =
+            structure_from_residues(vec![standard_residue("GLY", 1, ResiduePosition::Internal)]);
+        let expected = TopologyBuilder::new()
+            .build(single)
+            .unwrap()
+            .bonds_in_residue("A", 1, None)
+            .count();
+
+        let mut residue = standard_residue("GLY", 1, ResiduePosition::Internal);
+        let ca = residue.atom("CA").expect("CA atom").clone();
+        let mut alternate = Atom::new("CA_B", ca.element, ca.pos + Vector3::new(0.3, 0.0, 0.0));
+        alternate.occupancy = 0.4;
+        residue.add_atom(alternate);
+        residue.iter_atoms_mut().last().unwrap().name = "CA".into();
+        let structure = structure_from_residues(vec![residue]);
+
+        let topology = TopologyBuilder::new().build(structure).unwrap();
+
+        let alternate_idx = topology.atom_count() - 1;
+        assert_eq!(topology.bonds_in_residue("A", 1, None).count(), expected);
+        assert!(
+            topology
+                .bonds()
+                .iter()
+                .all(|b| b.a1_idx != alternate_idx && b.a2_idx != alternate_idx)
+        );
+    }
+
+    /// Glycine whose CA is split into conformer `A` (occupancy 0.4) and `B` (0.6).
+    fn glycine_with_split_alpha_carbon() -> Structure {
+        let mut residue = standard_residue("GLY", 1, ResiduePosition::Internal);
+        let mut ca_a = residue.remove_atom("CA").expect("CA atom");
+        let mut ca_b = ca_a.clone();
+        ca_a.alt_loc = Some('A');
+        ca_a.occupancy = 0.4;
+        ca_b.alt_loc = Some('B');
+        ca_b.occupancy = 0.6;
+        ca_b.pos += Vector3::new(0.3, 0.0, 0.0);
+        residue.add_atom(ca_a);
+        residue.add_atom(ca_b);
+        structure_from_residues(vec![residue])
+    }
+
+    fn bonded_alt_locs(topology: &Topology) -> HashSet<char> {
+        let atoms: Vec<_> = topology.structure().iter_atoms().collect();
+        topology
+            .bonds()
+            .iter()
+            .flat_map(|b| [b.a1_idx, b.a2_idx])
+            .filter_map(|idx| atoms[idx].alt_loc)
+            .collect()
+    }
+
+    #[test]
+    fn altloc_policy_selects_the_bonded_conformer() {
+        let structure = glycine_with_split_alpha_carbon();
+        assert!(structure.validate().is_empty());
+        let expected = TopologyBuilder::new()
+            .build(structure_from_residues(vec![standard_residue(
+                "GLY",
+                1,
+                ResiduePosition::Internal,
+            )]))
+            .unwrap()
+            .bond_count();
+
+        for (policy, label) in [
+            (AltLocPolicy::First, 'A'),
+            (AltLocPolicy::Highest, 'B'),
+            (AltLocPolicy::Specific('B'), 'B'),
+            (AltLocPolicy::Specific('C'), 'A'),
+        ] {
+            let topology = TopologyBuilder::new()
+                .altloc(policy)
+                .build(structure.clone())
+                .unwrap();
+
+            assert_eq!(topology.atom_count(), structure.atom_count());
+            assert_eq!(topology.bond_count(), expected, "{policy:?}");
+            assert_eq!(
+                bonded_alt_locs(&topology),
+                HashSet::from([label]),
+                "{policy:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn altloc_policy_defaults_to_the_first_conformer() {
+        let topology = TopologyBuilder::new()
+            .build(glycine_with_split_alpha_carbon())
+            .unwrap();
+
+        assert_eq!(bonded_alt_locs(&topology), HashSet::from(['A']));
+    }
+
+    #[test]
+    fn max_intra_bond_length_defaults_to_accepting_all_template_bonds() {
+        let mut displaced = standard_residue("GLY", 1, ResiduePosition::Internal);
+        displaced
+            .iter_atoms_mut()
+            .find(|a| a.name == "HA2")
+            .expect("HA2 atom")
+            .translate_by(&Vector3::new(5.0, 0.0, 0.0));
+        let structure = structure_from_residues(vec![displaced]);
+
+        let (topology, warnings) = TopologyBuilder::new().build_reported(structure).unwrap();
+
+        assert!(warnings.is_empty());
+        let ca_idx = global_atom_index(&topology, "A", 1, "CA");
+        let ha2_idx = global_atom_index(&topology, "A", 1, "HA2");
+        assert!(has_bond(&topology, ca_idx, ha2_idx, BondOrder::Single));
+    }
+
+    #[test]
+    fn build_reported_is_warning_free_for_complete_structures() {
+        let structure =
+            structure_from_residues(vec![standard_residue("ALA", 1, ResiduePosition::Internal)]);
+
+        let (_, warnings) = TopologyBuilder::new()
+            .lenient(true)
+            .build_reported(structure)
+            .unwrap();
+
+        assert!(warnings.is_empty());
+    }
+
     #[test]
     fn build_errors_when_hetero_template_missing() {
         let mut residue = Residue::new(1, None, "LIG", None, ResidueCategory::Hetero);
@@ -695,6 +1728,58 @@ mod tests {
         }
     }
 
+    fn unknown_ligand() -> Residue {
+        let mut residue = Residue::new(1, None, "LIG", None, ResidueCategory::Hetero);
+        residue.add_atom(Atom::new("C1", Element::C, Point::origin()));
+        residue.add_atom(Atom::new("O1", Element::O, Point::new(1.23, 0.0, 0.0)));
+        residue.add_atom(Atom::new("H1", Element::H, Point::new(-0.6, 0.9, 0.0)));
+        residue.add_atom(Atom::new("H2", Element::H, Point::new(-0.6, -0.9, 0.0)));
+        residue.add_atom(Atom::new("CL", Element::Cl, Point::new(6.0, 0.0, 0.0)));
+        residue
+    }
+
+    #[test]
+    fn unknown_policy_skip_leaves_residue_unbonded() {
+        let structure = structure_from_residues(vec![unknown_ligand()]);
+
+        let topology = TopologyBuilder::new()
+            .on_unknown(UnknownPolicy::Skip)
+            .build(structure)
+            .unwrap();
+
+        assert_eq!(topology.atom_count(), 5);
+        assert_eq!(topology.bond_count(), 0);
+    }
+
+    #[test]
+    fn unknown_policy_guess_bonds_uses_covalent_distances() {
+        let structure = structure_from_residues(vec![unknown_ligand()]);
+
+        let topology = TopologyBuilder::new()
+            .on_unknown(UnknownPolicy::GuessBonds)
+            .build(structure)
+            .unwrap();
+
+        assert_eq!(
+            topology.bonds(),
+            &[
+                Bond::new(0, 1, BondOrder::Single),
+                Bond::new(0, 2, BondOrder::Single),
+                Bond::new(0, 3, BondOrder::Single),
+            ]
+        );
+    }
+
+    #[test]
+    fn unknown_policy_defaults_to_error_for_unknown_standard_names() {
+        let residue = Residue::new(1, None, "XYZ", None, ResidueCategory::Standard);
+        let structure = structure_from_residues(vec![residue]);
+
+        let err = TopologyBuilder::new().build(structure).unwrap_err();
+
+        assert!(matches!(err, Error::MissingInternalTemplate { res_name } if res_name == "XYZ"));
+    }
+
     #[test]
     fn build_uses_hetero_template_for_hetero_residue() {
         let template = Template::new(
@@ -744,6 +1829,110 @@ mod tests {
         assert!(has_bond(&topology, sg1_idx, sg2_idx, BondOrder::Single));
     }
 
+    fn cys_pair_at_bonding_distance() -> Structure {
+        let residue1 = standard_residue("CYS", 1, ResiduePosition::Internal);
+        let mut residue2 = standard_residue("CYS", 2, ResiduePosition::Internal);
+
+        let sg1_pos = residue1.atom("SG").unwrap().pos;
+        let sg2_pos = residue2.atom("SG").unwrap().pos;
+        let target = sg1_pos + Vector3::new(2.05, 0.0, 0.0);
+        translate_residue(&mut residue2, target - sg2_pos);
+
+        structure_from_residues(vec![residue1, residue2])
+    }
+
+    #[test]
+    fn build_ignores_close_cys_sulfurs_by_default() {
+        let topology = TopologyBuilder::new()
+            .build(cys_pair_at_bonding_distance())
+            .expect("build topology");
+
+        let sg1_idx = global_atom_index(&topology, "A", 1, "SG");
+        let sg2_idx = global_atom_index(&topology, "A", 2, "SG");
+
+        assert!(!has_bond(&topology, sg1_idx, sg2_idx, BondOrder::Single));
+    }
+
+    #[test]
+    fn build_detects_cys_disulfide_by_distance_when_enabled() {
+        let topology = TopologyBuilder::new()
+            .detect_disulfides_by_distance(true)
+            .build(cys_pair_at_bonding_distance())
+            .expect("build topology");
+
+        let sg1_idx = global_atom_index(&topology, "A", 1, "SG");
+        let sg2_idx = global_atom_index(&topology, "A", 2, "SG");
+
+        assert!(has_bond(&topology, sg1_idx, sg2_idx, BondOrder::Single));
+        let names: Vec<_> = topology
+            .structure()
+            .iter_chains()
+            .flat_map(|c| c.iter_residues())
+            .map(|r| r.name.to_string())
+            .collect();
+        assert_eq!(names, vec!["CYS", "CYS"]);
+    }
+
+    #[test]
+    fn build_renames_distance_disulfides_to_cyx_when_requested() {
+        let mut structure = cys_pair_at_bonding_distance();
+        let mut lone = standard_residue("CYS", 3, ResiduePosition::Internal);
+        translate_residue(&mut lone, Vector3::new(50.0, 0.0, 0.0));
+        structure
+            .iter_chains_mut()
+            .next()
+            .unwrap()
+            .add_residue(lone);
+
+        let topology = TopologyBuilder::new()
+            .detect_disulfides_by_distance(true)
+            .rename_disulfide_cys(true)
+            .build(structure)
+            .expect("build topology");
+
+        let names: Vec<_> = topology
+            .structure()
+            .iter_chains()
+            .flat_map(|c| c.iter_residues())
+            .map(|r| r.name.to_string())
+            .collect();
+        assert_eq!(names, vec!["CYX", "CYX", "CYS"]);
+    }
+
+    #[test]
+    fn build_renames_name_detected_cym_bridge_partner_to_cyx() {
+        let residue1 = standard_residue("CYX", 1, ResiduePosition::Internal);
+        let mut residue2 = standard_residue("CYM", 2, ResiduePosition::Internal);
+        let sg1_pos = residue1.atom("SG").unwrap().pos;
+        let sg2_pos = residue2.atom("SG").unwrap().pos;
+        translate_residue(
+            &mut residue2,
+            sg1_pos + Vector3::new(2.05, 0.0, 0.0) - sg2_pos,
+        );
+        let structure = structure_from_residues(vec![residue1, residue2]);
+        let residue_names = |topology: &Topology| -> Vec<String> {
+            topology
+                .structure()
+                .iter_chains()
+                .flat_map(|c| c.iter_residues())
+                .map(|r| r.name.to_string())
+                .collect()
+        };
+
+        let kept = TopologyBuilder::new()
+            .build(structure.clone())
+            .expect("build topology");
+        let renamed = TopologyBuilder::new()
+            .rename_disulfide_cys(true)
+            .build(structure)
+            .expect("build topology");
+
+        assert_eq!(kept.disulfide_count(), 1);
+        assert_eq!(residue_names(&kept), vec!["CYX", "CYM"]);
+        assert_eq!(renamed.disulfide_count(), 1);
+        assert_eq!(residue_names(&renamed), vec!["CYX", "CYX"]);
+    }
+
     #[test]
     fn build_avoids_duplicate_bonds_for_standard_residue() {
         let residue = standard_residue("ALA", 1, ResiduePosition::Internal);
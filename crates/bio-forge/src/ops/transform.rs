@@ -2,10 +2,11 @@
 //!
 //! This module provides utilities for translating, centering, and rotating structures.
 
+use crate::model::properties::ElementProperties;
 use crate::model::structure::Structure;
 use crate::model::types::Point;
 use crate::utils::parallel::*;
-use nalgebra::{Rotation3, Vector3};
+use nalgebra::{Matrix3, Rotation3, Vector3};
 
 /// Collection of geometric transformation operations for structures.
 ///
@@ -34,13 +35,18 @@ impl Transform {
 
     /// Centers the structure's geometric centroid at the target point.
     ///
-    /// When `target` is `None`, the structure is centered at the origin.
+    /// When `target` is `None`, the structure is centered at the origin. Structures without
+    /// atoms have no centroid and are left untouched.
     ///
     /// # Arguments
     ///
     /// * `structure` - Mutable structure to be centered.
     /// * `target` - Optional target point; defaults to the origin.
     pub fn center_geometry(structure: &mut Structure, target: Option<Point>) {
+        if structure.atom_count() == 0 {
+            return;
+        }
+
         let current_center = structure.geometric_center();
         let target_point = target.unwrap_or(Point::origin());
         let translation = target_point - current_center;
@@ -55,14 +61,42 @@ impl Transform {
     /// Centers the structure's center of mass at the target point.
     ///
     /// Mass weighting uses atomic masses from element definitions. When `target` is
-    /// `None`, the structure is centered at the origin.
+    /// `None`, the structure is centered at the origin. Structures without atoms are left
+    /// untouched, and structures whose atoms carry no mass (unknown elements) fall back to
+    /// the geometric centroid.
     ///
     /// # Arguments
     ///
     /// * `structure` - Mutable structure to be centered.
     /// * `target` - Optional target point; defaults to the origin.
     pub fn center_mass(structure: &mut Structure, target: Option<Point>) {
-        let current_com = structure.center_of_mass();
+        Self::center_mass_with(structure, target, &ElementProperties::default());
+    }
+
+    /// Centers the structure's center of mass at the target point using custom masses.
+    ///
+    /// Behaves like [`Transform::center_mass`] but weights atoms with the masses from
+    /// `properties`, e.g. united-atom masses that fold hydrogens into heavy atoms.
+    ///
+    /// # Arguments
+    ///
+    /// * `structure` - Mutable structure to be centered.
+    /// * `target` - Optional target point; defaults to the origin.
+    /// * `properties` - Element parameters supplying the atomic masses.
+    pub fn center_mass_with(
+        structure: &mut Structure,
+        target: Option<Point>,
+        properties: &ElementProperties,
+    ) {
+        if structure.atom_count() == 0 {
+            return;
+        }
+
+        let current_com = if structure.total_mass_with(properties) > 1e-9 {
+            structure.center_of_mass_with(properties)
+        } else {
+            structure.geometric_center()
+        };
         let target_point = target.unwrap_or(Point::origin());
         let translation = target_point - current_com;
 
@@ -119,14 +153,56 @@ impl Transform {
         Self::apply_rotation(structure, rotation);
     }
 
-    /// Applies a rotation matrix to all atoms and box vectors.
+    /// Rotates the structure about an arbitrary pivot point.
+    ///
+    /// Each atom is moved to `pivot + rotation * (pos - pivot)`, so the pivot itself stays
+    /// fixed; passing the structure's own centroid rotates it in place without drifting.
+    /// Box vectors are lattice directions rather than positions and only receive the
+    /// rotation, as do anisotropic displacement tensors.
+    ///
+    /// # Arguments
+    ///
+    /// * `structure` - Mutable structure to be rotated.
+    /// * `pivot` - Point the rotation is performed about.
+    /// * `rotation` - Rotation to apply.
+    pub fn rotate_about(structure: &mut Structure, pivot: Point, rotation: Rotation3<f64>) {
+        structure.par_residues_mut().for_each(|residue| {
+            for atom in residue.iter_atoms_mut() {
+                atom.pos = pivot + rotation * (atom.pos - pivot);
+                atom.anisou = atom.anisou.map(|u| rotate_anisou(u, &rotation));
+            }
+        });
+
+        Self::rotate_box_vectors(structure, &rotation);
+    }
+
+    /// Prepares a rotation that can be applied to many structures without rebuilding it.
+    ///
+    /// # Arguments
+    ///
+    /// * `rotation` - Precomputed rotation about the origin.
+    ///
+    /// # Returns
+    ///
+    /// A [`PreparedRotation`] applying `rotation` to atoms and box vectors.
+    pub fn with_rotation(rotation: Rotation3<f64>) -> PreparedRotation {
+        PreparedRotation { rotation }
+    }
+
+    /// Applies a rotation matrix to all atoms, their anisotropic tensors, and box vectors.
     fn apply_rotation(structure: &mut Structure, rotation: Rotation3<f64>) {
         structure.par_residues_mut().for_each(|residue| {
             for atom in residue.iter_atoms_mut() {
                 atom.pos = rotation * atom.pos;
+                atom.anisou = atom.anisou.map(|u| rotate_anisou(u, &rotation));
             }
         });
 
+        Self::rotate_box_vectors(structure, &rotation);
+    }
+
+    /// Applies the linear part of a rotation to the box vectors, if present.
+    fn rotate_box_vectors(structure: &mut Structure, rotation: &Rotation3<f64>) {
         if let Some(box_vecs) = structure.box_vectors {
             let v1 = Vector3::from(box_vecs[0]);
             let v2 = Vector3::from(box_vecs[1]);
@@ -141,16 +217,83 @@ impl Transform {
     }
 }
 
+/// Rotates an anisotropic displacement tensor as `R·U·Rᵀ`.
+///
+/// # Arguments
+///
+/// * `u` - Components `[U11, U22, U33, U12, U13, U23]` in Å².
+/// * `rotation` - Rotation applied to the atom's coordinates.
+///
+/// # Returns
+///
+/// The rotated components in the same order.
+fn rotate_anisou(u: [f64; 6], rotation: &Rotation3<f64>) -> [f64; 6] {
+    let tensor = Matrix3::new(u[0], u[3], u[4], u[3], u[1], u[5], u[4], u[5], u[2]);
+    let r = rotation.matrix();
+    let rotated = r * tensor * r.transpose();
+    [
+        rotated[(0, 0)],
+        rotated[(1, 1)],
+        rotated[(2, 2)],
+        rotated[(0, 1)],
+        rotated[(0, 2)],
+        rotated[(1, 2)],
+    ]
+}
+
+/// Reusable rotation created by [`Transform::with_rotation`].
+///
+/// Ensemble and trajectory workflows apply the same rotation to every frame; holding the
+/// rotation here avoids rebuilding it per structure.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PreparedRotation {
+    rotation: Rotation3<f64>,
+}
+
+impl PreparedRotation {
+    /// Returns the wrapped rotation.
+    ///
+    /// # Returns
+    ///
+    /// The rotation applied by [`PreparedRotation::apply`].
+    pub fn rotation(&self) -> &Rotation3<f64> {
+        &self.rotation
+    }
+
+    /// Rotates a single structure about the origin, including its box vectors.
+    ///
+    /// # Arguments
+    ///
+    /// * `structure` - Mutable structure to be rotated.
+    pub fn apply(&self, structure: &mut Structure) {
+        Transform::apply_rotation(structure, self.rotation);
+    }
+
+    /// Rotates every structure in the batch, processing structures in parallel when the
+    /// `parallel` feature is enabled.
+    ///
+    /// # Arguments
+    ///
+    /// * `structures` - Structures to rotate, typically frames of an ensemble.
+    pub fn apply_to_all(&self, structures: &mut [&mut Structure]) {
+        structures
+            .par_iter_mut()
+            .for_each(|structure| self.apply(structure));
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::Transform;
     use crate::model::{
         atom::Atom,
         chain::Chain,
+        properties::ElementProperties,
         residue::Residue,
         structure::Structure,
         types::{Element, Point, ResidueCategory, StandardResidue},
     };
+    use nalgebra::{Rotation3, Vector3};
 
     fn structure_with_points(points: &[Point]) -> Structure {
         let mut chain = Chain::new("A");
@@ -224,6 +367,52 @@ mod tests {
         assert_point_close(&com, &Point::origin());
     }
 
+    #[test]
+    fn center_mass_with_applies_mass_overrides() {
+        let mut structure =
+            structure_with_points(&[Point::new(0.0, 0.0, 0.0), Point::new(4.0, 0.0, 0.0)]);
+        for (idx, atom) in structure.iter_atoms_mut().enumerate() {
+            atom.element = if idx == 0 { Element::C } else { Element::O };
+        }
+        let properties = ElementProperties::new()
+            .with_mass(Element::C, 3.0)
+            .with_mass(Element::O, 1.0);
+
+        Transform::center_mass_with(&mut structure, None, &properties);
+
+        let mut atoms = structure.iter_atoms();
+        assert_point_close(&atoms.next().unwrap().pos, &Point::new(-1.0, 0.0, 0.0));
+        assert_point_close(&atoms.next().unwrap().pos, &Point::new(3.0, 0.0, 0.0));
+    }
+
+    #[test]
+    fn centering_an_empty_structure_is_a_no_op() {
+        let mut structure = Structure::new();
+        structure.add_chain(Chain::new("A"));
+
+        Transform::center_geometry(&mut structure, Some(Point::new(1.0, 2.0, 3.0)));
+        Transform::center_mass(&mut structure, Some(Point::new(1.0, 2.0, 3.0)));
+
+        assert_eq!(structure.atom_count(), 0);
+        assert_eq!(structure.chain_count(), 1);
+        assert!(structure.geometric_center().iter().all(|c| c.is_finite()));
+    }
+
+    #[test]
+    fn center_mass_uses_geometric_center_for_massless_atoms() {
+        let mut structure =
+            structure_with_points(&[Point::new(2.0, 0.0, 0.0), Point::new(4.0, 0.0, 0.0)]);
+        for atom in structure.iter_atoms_mut() {
+            atom.element = Element::Unknown;
+        }
+
+        Transform::center_mass(&mut structure, None);
+
+        let mut atoms = structure.iter_atoms();
+        assert_point_close(&atoms.next().unwrap().pos, &Point::new(-1.0, 0.0, 0.0));
+        assert_point_close(&atoms.next().unwrap().pos, &Point::new(1.0, 0.0, 0.0));
+    }
+
     #[test]
     fn rotate_z_rotates_atoms_about_origin() {
         let mut structure =
@@ -236,6 +425,65 @@ mod tests {
         assert_point_close(&atoms.next().unwrap().pos, &Point::new(-2.0, 0.0, 0.0));
     }
 
+    #[test]
+    fn rotate_about_keeps_pivot_fixed_and_rotates_box_vectors() {
+        let mut structure =
+            structure_with_points(&[Point::new(2.0, 1.0, 0.0), Point::new(3.0, 1.0, 5.0)]);
+        structure.box_vectors = Some([[4.0, 0.0, 0.0], [0.0, 4.0, 0.0], [0.0, 0.0, 4.0]]);
+        let rotation = Rotation3::from_axis_angle(&Vector3::z_axis(), std::f64::consts::FRAC_PI_2);
+
+        Transform::rotate_about(&mut structure, Point::new(2.0, 1.0, 0.0), rotation);
+
+        let mut atoms = structure.iter_atoms();
+        assert_point_close(&atoms.next().unwrap().pos, &Point::new(2.0, 1.0, 0.0));
+        assert_point_close(&atoms.next().unwrap().pos, &Point::new(2.0, 2.0, 5.0));
+        let box_vectors = structure.box_vectors.unwrap();
+        assert_point_close(&Point::from(box_vectors[0]), &Point::new(0.0, 4.0, 0.0));
+        assert_point_close(&Point::from(box_vectors[1]), &Point::new(-4.0, 0.0, 0.0));
+    }
+
+    #[test]
+    fn rotations_transform_anisotropic_displacements() {
+        let mut structure =
+            structure_with_points(&[Point::new(1.0, 0.0, 0.0), Point::new(0.0, 0.0, 0.0)]);
+        for atom in structure.iter_atoms_mut() {
+            atom.anisou = Some([0.04, 0.01, 0.02, 0.003, 0.0, 0.0]);
+        }
+        let rotation = Rotation3::from_axis_angle(&Vector3::z_axis(), std::f64::consts::FRAC_PI_2);
+
+        let mut about_pivot = structure.clone();
+        Transform::rotate_about(&mut about_pivot, Point::new(5.0, 5.0, 5.0), rotation);
+        Transform::rotate_z(&mut structure, std::f64::consts::FRAC_PI_2);
+
+        for rotated in [&structure, &about_pivot] {
+            for atom in rotated.iter_atoms() {
+                let u = atom.anisou.expect("anisou kept");
+                let expected = [0.01, 0.04, 0.02, -0.003, 0.0, 0.0];
+                for (actual, expected) in u.iter().zip(expected) {
+                    assert!((actual - expected).abs() < 1e-12, "{u:?}");
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn rotate_about_centroid_preserves_geometric_center() {
+        let mut structure = structure_with_points(&[
+            Point::new(5.0, 5.0, 5.0),
+            Point::new(7.0, 5.0, 5.0),
+            Point::new(6.0, 8.0, 4.0),
+        ]);
+        let center = structure.geometric_center();
+
+        Transform::rotate_about(
+            &mut structure,
+            center,
+            Rotation3::from_euler_angles(0.3, -1.1, 2.0),
+        );
+
+        assert_point_close(&structure.geometric_center(), &center);
+    }
+
     #[test]
     fn rotate_euler_updates_box_vectors() {
         let mut structure = structure_with_points(&[Point::new(1.0, 0.0, 0.0)]);
@@ -248,4 +496,25 @@ mod tests {
         assert_point_close(&Point::from(box_vectors[1]), &Point::new(-2.0, 0.0, 0.0));
         assert_point_close(&Point::from(box_vectors[2]), &Point::new(0.0, 0.0, 3.0));
     }
+
+    #[test]
+    fn prepared_rotation_applies_to_every_structure() {
+        let mut first = structure_with_points(&[Point::new(1.0, 0.0, 0.0)]);
+        let mut second = structure_with_points(&[Point::new(0.0, 2.0, 0.0)]);
+        second.box_vectors = Some([[1.0, 0.0, 0.0], [0.0, 1.0, 0.0], [0.0, 0.0, 1.0]]);
+        let mut expected = second.clone();
+        Transform::rotate_z(&mut expected, std::f64::consts::FRAC_PI_2);
+
+        let rotation = Transform::with_rotation(nalgebra::Rotation3::from_axis_angle(
+            &nalgebra::Vector3::z_axis(),
+            std::f64::consts::FRAC_PI_2,
+        ));
+        rotation.apply_to_all(&mut [&mut first, &mut second]);
+
+        let first_atom = first.iter_atoms().next().unwrap();
+        assert_point_close(&first_atom.pos, &Point::new(0.0, 1.0, 0.0));
+        let second_atom = second.iter_atoms().next().unwrap();
+        assert_point_close(&second_atom.pos, &Point::new(-2.0, 0.0, 0.0));
+        assert_eq!(second.box_vectors, expected.box_vectors);
+    }
 }
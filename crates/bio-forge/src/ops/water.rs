@@ -0,0 +1,238 @@
+//! Renaming of water residues to a common naming convention.
+//!
+//! Structures assembled from different tools label water inconsistently (`HOH`, `WAT`,
+//! `TIP3`, `SOL`, with oxygen names such as `O`, `OW`, or `OH2`). This module detects water
+//! residues and rewrites their residue and atom names to a single convention so downstream
+//! writers and force-field tooling see uniform input.
+
+use crate::model::{
+    residue::Residue,
+    structure::Structure,
+    types::{Element, ResidueCategory, StandardResidue},
+};
+use crate::utils::parallel::*;
+
+/// Naming convention applied to water residues by [`standardize_water`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum WaterNaming {
+    /// PDB convention: residue `HOH` with atoms `O`, `H1`, `H2`.
+    #[default]
+    Pdb,
+    /// Amber convention: residue `WAT` with atoms `O`, `H1`, `H2`.
+    Amber,
+    /// CHARMM convention: residue `TIP3` with atoms `OH2`, `H1`, `H2`.
+    Charmm,
+    /// GROMACS convention: residue `SOL` with atoms `OW`, `HW1`, `HW2`.
+    Gromacs,
+}
+
+impl WaterNaming {
+    /// Returns the residue name used by this convention.
+    pub fn residue_name(self) -> &'static str {
+        match self {
+            WaterNaming::Pdb => "HOH",
+            WaterNaming::Amber => "WAT",
+            WaterNaming::Charmm => "TIP3",
+            WaterNaming::Gromacs => "SOL",
+        }
+    }
+
+    /// Returns the oxygen atom name used by this convention.
+    pub fn oxygen_name(self) -> &'static str {
+        match self {
+            WaterNaming::Pdb | WaterNaming::Amber => "O",
+            WaterNaming::Charmm => "OH2",
+            WaterNaming::Gromacs => "OW",
+        }
+    }
+
+    /// Returns the two hydrogen atom names used by this convention.
+    pub fn hydrogen_names(self) -> [&'static str; 2] {
+        match self {
+            WaterNaming::Pdb | WaterNaming::Amber | WaterNaming::Charmm => ["H1", "H2"],
+            WaterNaming::Gromacs => ["HW1", "HW2"],
+        }
+    }
+}
+
+/// Renames every water residue in-place to the chosen naming convention.
+///
+/// A residue counts as water when its standard name is `HOH` (which covers aliases such as
+/// `WAT`, `TIP3`, or `SOL` recognized at parse time), when its residue name is a known water
+/// alias, or when it consists of exactly one oxygen and two hydrogens. Matching residues are
+/// renamed, tagged as standard `HOH`, and have their oxygen and hydrogens renamed in file
+/// order. Names other than the PDB convention are not found in the template
+/// database, so convert back to [`WaterNaming::Pdb`] before running template-driven
+/// operations such as hydrogen addition or topology building.
+///
+/// # Arguments
+///
+/// * `structure` - Mutable structure whose water residues are renamed.
+/// * `target` - Naming convention to apply.
+///
+/// # Returns
+///
+/// The number of water residues whose residue or atom names changed.
+pub fn standardize_water(structure: &mut Structure, target: WaterNaming) -> usize {
+    structure
+        .par_residues_mut()
+        .filter(|residue| is_water(residue))
+        .map(|residue| usize::from(rename_water(residue, target)))
+        .sum()
+}
+
+/// Decides whether a residue is a water molecule.
+fn is_water(residue: &Residue) -> bool {
+    if residue.standard_name == Some(StandardResidue::HOH)
+        || StandardResidue::from_name(&residue.name) == Some(StandardResidue::HOH)
+    {
+        return true;
+    }
+
+    let mut oxygens = 0;
+    let mut hydrogens = 0;
+    for atom in residue.iter_atoms() {
+        match atom.element {
+            Element::O => oxygens += 1,
+            Element::H => hydrogens += 1,
+            _ => return false,
+        }
+    }
+    oxygens == 1 && hydrogens == 2
+}
+
+/// Applies the naming convention to a single water residue.
+///
+/// # Returns
+///
+/// `true` when any residue or atom name changed.
+fn rename_water(residue: &mut Residue, target: WaterNaming) -> bool {
+    let mut changed = residue.name != target.residue_name();
+    residue.name = target.residue_name().into();
+    residue.standard_name = Some(StandardResidue::HOH);
+    residue.category = ResidueCategory::Standard;
+
+    let mut hydrogen_names = target.hydrogen_names().into_iter();
+    for atom in residue.iter_atoms_mut() {
+        let name = match atom.element {
+            Element::O => target.oxygen_name(),
+            Element::H => match hydrogen_names.next() {
+                Some(name) => name,
+                None => continue,
+            },
+            _ => continue,
+        };
+        if atom.name != name {
+            atom.name = name.into();
+            changed = true;
+        }
+    }
+    changed
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::{
+        atom::Atom,
+        chain::Chain,
+        types::{Element, Point},
+    };
+
+    fn residue_with_atoms(
+        id: i32,
+        name: &str,
+        standard: Option<StandardResidue>,
+        category: ResidueCategory,
+        atoms: &[(&str, Element)],
+    ) -> Residue {
+        let mut residue = Residue::new(id, None, name, standard, category);
+        for (atom_name, element) in atoms {
+            residue.add_atom(Atom::new(atom_name, *element, Point::origin()));
+        }
+        residue
+    }
+
+    fn water_structure() -> Structure {
+        let mut chain = Chain::new("W");
+        chain.add_residue(residue_with_atoms(
+            1,
+            "HOH",
+            Some(StandardResidue::HOH),
+            ResidueCategory::Standard,
+            &[("O", Element::O), ("H1", Element::H), ("H2", Element::H)],
+        ));
+        chain.add_residue(residue_with_atoms(
+            2,
+            "TIP3",
+            Some(StandardResidue::HOH),
+            ResidueCategory::Standard,
+            &[("OH2", Element::O), ("H1", Element::H), ("H2", Element::H)],
+        ));
+        chain.add_residue(residue_with_atoms(
+            3,
+            "W",
+            None,
+            ResidueCategory::Hetero,
+            &[("OW", Element::O), ("HW1", Element::H), ("HW2", Element::H)],
+        ));
+        chain.add_residue(residue_with_atoms(
+            4,
+            "MOH",
+            None,
+            ResidueCategory::Hetero,
+            &[("C", Element::C), ("O", Element::O), ("HO", Element::H)],
+        ));
+        std::iter::once(chain).collect()
+    }
+
+    fn names(structure: &Structure, id: i32) -> (String, Vec<String>) {
+        let residue = structure.find_residue("W", id, None).unwrap();
+        (
+            residue.name.to_string(),
+            residue.iter_atoms().map(|a| a.name.to_string()).collect(),
+        )
+    }
+
+    #[test]
+    fn standardize_water_renames_to_gromacs_convention() {
+        let mut structure = water_structure();
+
+        let count = standardize_water(&mut structure, WaterNaming::Gromacs);
+
+        assert_eq!(count, 3);
+        for id in 1..=3 {
+            let (res_name, atoms) = names(&structure, id);
+            assert_eq!(res_name, "SOL");
+            assert_eq!(atoms, vec!["OW", "HW1", "HW2"]);
+        }
+        let heuristic = structure.find_residue("W", 3, None).unwrap();
+        assert_eq!(heuristic.standard_name, Some(StandardResidue::HOH));
+        assert_eq!(heuristic.category, ResidueCategory::Standard);
+    }
+
+    #[test]
+    fn standardize_water_leaves_non_water_residues_untouched() {
+        let mut structure = water_structure();
+
+        standardize_water(&mut structure, WaterNaming::Pdb);
+
+        let (res_name, atoms) = names(&structure, 4);
+        assert_eq!(res_name, "MOH");
+        assert_eq!(atoms, vec!["C", "O", "HO"]);
+    }
+
+    #[test]
+    fn standardize_water_counts_only_changed_residues() {
+        let mut structure = water_structure();
+
+        assert_eq!(standardize_water(&mut structure, WaterNaming::Pdb), 2);
+        assert_eq!(names(&structure, 2).1, vec!["O", "H1", "H2"]);
+        assert_eq!(standardize_water(&mut structure, WaterNaming::Pdb), 0);
+
+        assert_eq!(standardize_water(&mut structure, WaterNaming::Charmm), 3);
+        let (res_name, atoms) = names(&structure, 1);
+        assert_eq!(res_name, "TIP3");
+        assert_eq!(atoms, vec!["OH2", "H1", "H2"]);
+    }
+}